@@ -15,10 +15,28 @@ fn generate_shader_bindings() -> Result<(), Box<dyn std::error::Error>> {
     WgslBindgenOptionBuilder::default()
         .workspace_root("src/shaders")
         .add_entry_point("src/shaders/common.wgsl")
+        .add_entry_point("src/shaders/tool_state.wgsl")
         .add_entry_point("src/shaders/shape.wgsl")
         .add_entry_point("src/shaders/aabb_frame.wgsl")
         .add_entry_point("src/shaders/bvh.wgsl")
         .add_entry_point("src/shaders/integration.wgsl")
+        .add_entry_point("src/shaders/contacts.wgsl")
+        .add_entry_point("src/shaders/trail.wgsl")
+        .add_entry_point("src/shaders/heatmap_splat.wgsl")
+        .add_entry_point("src/shaders/heatmap.wgsl")
+        .add_entry_point("src/shaders/blackhole.wgsl")
+        .add_entry_point("src/shaders/pick.wgsl")
+        .add_entry_point("src/shaders/ui.wgsl")
+        .add_entry_point("src/shaders/diagnostics.wgsl")
+        .add_entry_point("src/shaders/constraints.wgsl")
+        .add_entry_point("src/shaders/static_collision.wgsl")
+        .add_entry_point("src/shaders/segment.wgsl")
+        .add_entry_point("src/shaders/sleep.wgsl")
+        .add_entry_point("src/shaders/accretion.wgsl")
+        .add_entry_point("src/shaders/materials.wgsl")
+        .add_entry_point("src/shaders/impacts.wgsl")
+        .add_entry_point("src/shaders/fracture.wgsl")
+        .add_entry_point("src/shaders/spawn.wgsl")
         .ir_capabilities(WgslShaderIrCapabilities::PUSH_CONSTANT)
         .serialization_strategy(WgslTypeSerializeStrategy::Bytemuck)
         .type_map(NalgebraWgslTypeMap)
@@ -14,4 +14,8 @@ impl AABB {
     pub fn size(&self) -> Vector2<f32> {
         self.max() - self.min()
     }
+
+    pub fn center(&self) -> Vector2<f32> {
+        (self.min() + self.max()) / 2.0
+    }
 }
@@ -1,11 +1,12 @@
 use std::ops::Range;
 
 use wgpu::{
-    BlendState, ColorTargetState, Device, MultisampleState, PipelineCache, PrimitiveState, RenderPass, RenderPipeline,
-    RenderPipelineDescriptor, TextureFormat,
+    BlendState, BufferUsages, ColorTargetState, Device, MultisampleState, PipelineCache, PrimitiveState, Queue,
+    RenderPass, RenderPipeline, RenderPipelineDescriptor, TextureFormat,
 };
 
 use crate::{
+    bvh_builder::calculate_passes,
     gpu_buffer::GpuBuffer,
     shaders::{
         aabb_frame,
@@ -13,16 +14,57 @@ use crate::{
     },
 };
 
+/// Which BVH levels are drawn, level 0 are the leaves and the last level is the root
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AabbLevels {
+    #[default]
+    All,
+    Single(usize),
+    /// The given level and all levels above it
+    From(usize),
+}
+
+impl AabbLevels {
+    /// Steps to the next single level, wrapping around to all levels after the root
+    pub fn next_single(self, level_count: usize) -> Self {
+        match self {
+            Self::Single(level) if level + 1 < level_count => Self::Single(level + 1),
+            Self::Single(_) => Self::All,
+            Self::All | Self::From(_) => Self::Single(0),
+        }
+    }
+
+    /// Steps the lowest drawn level up, wrapping around to all levels after the root
+    pub fn next_from(self, level_count: usize) -> Self {
+        match self {
+            Self::From(level) if level + 1 < level_count => Self::From(level + 1),
+            Self::From(_) => Self::All,
+            Self::All | Self::Single(_) => Self::From(1),
+        }
+    }
+
+    fn range(self, level_count: usize) -> Range<usize> {
+        match self {
+            Self::All => 0..level_count,
+            Self::Single(level) => level..level + 1,
+            Self::From(level) => level..level_count,
+        }
+    }
+}
+
 pub struct AabbRenderer {
     render_pipeline: RenderPipeline,
     bind_group: aabb_frame::WgpuBindGroup0,
+    /// First node of every level followed by the node count
+    level_bounds: Vec<usize>,
 }
 
 impl AabbRenderer {
     pub fn new(
         device: &Device,
-        swapchain_format: TextureFormat,
-        pipeline_cache: &PipelineCache,
+        target_format: TextureFormat,
+        pipeline_cache: Option<&PipelineCache>,
+        queue: &Queue,
         camera_buffer: GpuBuffer<Camera>,
         flags: GpuBuffer<Flags>,
         aabbs: GpuBuffer<AABB>,
@@ -35,7 +77,7 @@ impl AabbRenderer {
 
         let color_target_state = ColorTargetState {
             blend: Some(BlendState::ALPHA_BLENDING),
-            ..ColorTargetState::from(swapchain_format)
+            ..ColorTargetState::from(target_format)
         };
         let fragment_entry = aabb_frame::fs_main_entry([Some(color_target_state)]);
         let fragment_state = aabb_frame::fragment_state(&shader, &fragment_entry);
@@ -53,26 +95,50 @@ impl AabbRenderer {
             depth_stencil: None,
             multisample: MultisampleState::default(),
             multiview: None,
-            cache: Some(pipeline_cache),
+            cache: pipeline_cache,
         });
 
+        // The levels only depend on the object count, so they are the same for every BVH build
+        let mut passes = Vec::new();
+        calculate_passes(flags.len(), &mut passes);
+        let level_starts = [0].into_iter().chain(passes.iter().map(|pass| pass.dst_start)).collect::<Vec<_>>();
+        let level_starts_buffer = GpuBuffer::new(
+            level_starts.len(),
+            "aabb level starts buffer",
+            BufferUsages::STORAGE | BufferUsages::COPY_DST,
+            device,
+        );
+        level_starts_buffer.write(queue, &level_starts);
+        let node_count = passes.last().map_or(flags.len(), |pass| (pass.dst_start + pass.parent_count) as usize);
+        let level_bounds = level_starts.iter().map(|&start| start as usize).chain([node_count]).collect();
+
         let bind_group = aabb_frame::WgpuBindGroup0::from_bindings(
             device,
             aabb_frame::WgpuBindGroup0Entries::new(aabb_frame::WgpuBindGroup0EntriesParams {
                 camera: camera_buffer.buffer().as_entire_buffer_binding(),
                 flags: flags.buffer().as_entire_buffer_binding(),
                 aabbs: aabbs.buffer().as_entire_buffer_binding(),
+                level_starts: level_starts_buffer.buffer().as_entire_buffer_binding(),
             }),
         );
 
         Self {
             render_pipeline,
             bind_group,
+            level_bounds,
         }
     }
 
-    pub fn render(&self, render_pass: &mut RenderPass<'_>, instances: Range<usize>) {
-        println!("Rendering {} AABBs", instances.len());
+    pub fn level_count(&self) -> usize {
+        self.level_bounds.len() - 1
+    }
+
+    /// Draws the AABBs of the given levels, nodes at or above `node_count` are skipped
+    pub fn render(&self, render_pass: &mut RenderPass<'_>, node_count: usize, levels: AabbLevels) {
+        let level_range = levels.range(self.level_count());
+        let instances =
+            self.level_bounds[level_range.start].min(node_count)..self.level_bounds[level_range.end].min(node_count);
+        log::debug!("Rendering {} AABBs", instances.len());
         render_pass.set_pipeline(&self.render_pipeline);
         self.bind_group.set(render_pass);
         let start = u32::try_from(instances.start).unwrap();
@@ -0,0 +1,87 @@
+use wgpu::{
+    BlendState, BufferUsages, ColorTargetState, Device, MultisampleState, PipelineCache, PrimitiveState, Queue,
+    RenderPass, RenderPipeline, RenderPipelineDescriptor, TextureFormat,
+};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    shaders::{
+        blackhole::{self, BlackHoleParameters},
+        blackholes::{BLACKHOLE_COUNT, BlackHoleSet},
+        common::{AABB, Camera},
+    },
+};
+
+/// Draws the black holes that the integrator uses, so their positions, horizons and spins come from the same
+/// buffer
+pub struct BlackHoleRenderer {
+    horizon_pipeline: RenderPipeline,
+    potential_pipeline: RenderPipeline,
+    bind_group: blackhole::WgpuBindGroup0,
+}
+
+impl BlackHoleRenderer {
+    pub fn new(
+        device: &Device,
+        target_format: TextureFormat,
+        pipeline_cache: Option<&PipelineCache>,
+        queue: &Queue,
+        world_aabb: AABB,
+        camera: GpuBuffer<Camera>,
+        blackholes: GpuBuffer<BlackHoleSet>,
+    ) -> Self {
+        let parameters =
+            GpuBuffer::new(1, "black hole parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        parameters.write(queue, &[BlackHoleParameters::new(world_aabb.min, world_aabb.max)]);
+        let bind_group = blackhole::WgpuBindGroup0::from_bindings(
+            device,
+            blackhole::WgpuBindGroup0Entries::new(blackhole::WgpuBindGroup0EntriesParams {
+                camera: camera.buffer().as_entire_buffer_binding(),
+                parameters: parameters.buffer().as_entire_buffer_binding(),
+                blackhole_set: blackholes.buffer().as_entire_buffer_binding(),
+            }),
+        );
+
+        let pipeline_layout = blackhole::create_pipeline_layout(device);
+        let shader = blackhole::create_shader_module_embed_source(device);
+        let color_target_state = ColorTargetState {
+            blend: Some(BlendState::ALPHA_BLENDING),
+            ..ColorTargetState::from(target_format)
+        };
+        let horizon_vertex_entry = blackhole::vs_horizon_entry();
+        let horizon_fragment_entry = blackhole::fs_horizon_entry([Some(color_target_state.clone())]);
+        let potential_vertex_entry = blackhole::vs_potential_entry();
+        let potential_fragment_entry = blackhole::fs_potential_entry([Some(color_target_state)]);
+        let create_pipeline = |vertex_entry, fragment_entry| {
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                label: None,
+                layout: Some(&pipeline_layout),
+                vertex: blackhole::vertex_state(&shader, vertex_entry),
+                fragment: Some(blackhole::fragment_state(&shader, fragment_entry)),
+                primitive: PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: MultisampleState::default(),
+                multiview: None,
+                cache: pipeline_cache,
+            })
+        };
+
+        Self {
+            horizon_pipeline: create_pipeline(&horizon_vertex_entry, &horizon_fragment_entry),
+            potential_pipeline: create_pipeline(&potential_vertex_entry, &potential_fragment_entry),
+            bind_group,
+        }
+    }
+
+    /// Draws the event horizons and spin indicators, with the potential contours below them if `draw_potential`
+    pub fn render(&self, render_pass: &mut RenderPass<'_>, draw_potential: bool) {
+        if draw_potential {
+            render_pass.set_pipeline(&self.potential_pipeline);
+            self.bind_group.set(render_pass);
+            render_pass.draw(0..6, 0..1);
+        }
+        render_pass.set_pipeline(&self.horizon_pipeline);
+        self.bind_group.set(render_pass);
+        render_pass.draw(0..6, 0..BLACKHOLE_COUNT);
+    }
+}
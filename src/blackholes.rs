@@ -0,0 +1,93 @@
+use wgpu::{BufferUsages, CommandEncoder, ComputePassDescriptor, ComputePipeline, Device, Queue};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::{
+        accretion::{
+            Accretion, AccretionParameters, WORKGROUP_SIZE, WgpuBindGroup0, WgpuBindGroup0Entries,
+            WgpuBindGroup0EntriesParams,
+            compute::{create_accrete_pipeline_embed_source, create_consume_pipeline_embed_source},
+        },
+        blackholes::{BLACKHOLE_COUNT, BlackHole, BlackHoleSet},
+    },
+};
+
+/// The black holes at the start of every simulation, accretion moves them and makes them grow
+pub const BLACKHOLES: BlackHoleSet = BlackHoleSet::new([
+    BlackHole::new([-200.0, 500.0], [0.0, 0.0], 2.0, 10.0, 0.0),
+    BlackHole::new([500.0, 200.0], [0.0, 0.0], 1.0, 20.0, 0.0),
+    BlackHole::new([0.0, 0.0], [0.0, 0.0], 2.0, 10.0, 50.0),
+    BlackHole::new([-600.0, -300.0], [0.0, 0.0], 1.0, 20.0, 0.0),
+    BlackHole::new([600.0, -700.0], [0.0, 0.0], 1.0, 10.0, 0.0),
+]);
+
+/// Destroys the objects that cross an event horizon. With accretion enabled, their mass and momentum are added to the
+/// black hole, otherwise they vanish. Runs right after the integrator.
+pub struct Accretor {
+    consume_pipeline: ComputePipeline,
+    accrete_pipeline: ComputePipeline,
+    bind_group: WgpuBindGroup0,
+    parameters: GpuBuffer<AccretionParameters>,
+    accretion: GpuBuffer<Accretion>,
+    object_count: u32,
+}
+
+impl Accretor {
+    pub fn new(device: &Device, buffers: &ObjectBuffers) -> Self {
+        // Snapshots copy the consumed counts and the sums not yet added to the black holes out of and back into it
+        let usage = BufferUsages::STORAGE | BufferUsages::COPY_SRC | BufferUsages::COPY_DST;
+        let accretion = GpuBuffer::new(BLACKHOLE_COUNT as usize, "accretion buffer", usage, device);
+        let parameters =
+            GpuBuffer::new(1, "accretion parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        let bind_group = WgpuBindGroup0::from_bindings(
+            device,
+            WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
+                parameters: parameters.buffer().as_entire_buffer_binding(),
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                masses: buffers.masses.buffer().as_entire_buffer_binding(),
+                integrated_aabbs: buffers.integrated_aabbs.buffer().as_entire_buffer_binding(),
+                integrated_velocities: buffers.integrated_velocities.buffer().as_entire_buffer_binding(),
+                angular_velocities: buffers.angular_velocities.buffer().as_entire_buffer_binding(),
+                blackhole_set: buffers.blackholes.buffer().as_entire_buffer_binding(),
+                accretion: accretion.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        Self {
+            consume_pipeline: create_consume_pipeline_embed_source(device),
+            accrete_pipeline: create_accrete_pipeline_embed_source(device),
+            bind_group,
+            parameters,
+            accretion,
+            object_count: u32::try_from(buffers.flags.len()).unwrap(),
+        }
+    }
+
+    /// `dt` is the step size including the time scale, `accrete` keeps the consumed mass and momentum
+    pub fn write_parameters(&self, queue: &Queue, dt: f32, accrete: bool) {
+        self.parameters.write(queue, &[AccretionParameters::new(dt, u32::from(accrete))]);
+    }
+
+    pub fn compute(&self, encoder: &mut CommandEncoder) {
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("accretion pass"),
+            timestamp_writes: None,
+        });
+        let total_workgroups = self.object_count.div_ceil(WORKGROUP_SIZE);
+        for (pipeline, workgroups) in [
+            (&self.consume_pipeline, total_workgroups),
+            (&self.accrete_pipeline, BLACKHOLE_COUNT.div_ceil(WORKGROUP_SIZE)),
+        ] {
+            compute_pass.set_pipeline(pipeline);
+            self.bind_group.set(&mut compute_pass);
+            compute_pass.dispatch_workgroups(workgroups.min(65535), workgroups.div_ceil(65535), 1);
+        }
+    }
+
+    /// Holds the number of objects every black hole consumed, see [`Simulation::consumed_counts`]
+    ///
+    /// [`Simulation::consumed_counts`]: crate::simulation::Simulation::consumed_counts
+    pub fn accretion(&self) -> &GpuBuffer<Accretion> {
+        &self.accretion
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    DT,
+    blackholes::BLACKHOLES,
+    objects::{ObjectPrototype, Objects},
+    shaders::{
+        blackholes::BLACKHOLE_MASS_SCALE,
+        common::{FLAG_DESTROYED, FLAG_PHYSICAL},
+    },
+    simulation::Simulation,
+    test_util::{TestDevice, circle, test_device_or_skip},
+};
+
+#[test]
+fn accretion_conserves_mass_and_momentum() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // Two circles inside the horizon of the first black hole on either side of it, so its pull on them cancels out.
+    // The other black holes pull on them as well during the step, the tolerances cover that.
+    let [x, y] = BLACKHOLES.blackholes[0].position;
+    let moving = |position, velocity| ObjectPrototype {
+        velocity,
+        mass: 2.0,
+        ..circle(position, 2.0)
+    };
+    let objects = || {
+        let mut objects = Objects::default();
+        objects.push(moving([x - 15.0, y], [0.0, 5000.0]));
+        objects.push(moving([x + 15.0, y], [0.0, 5000.0]));
+        // Far from all black holes
+        objects.push(moving([-900.0, 900.0], [0.0, 0.0]));
+        objects
+    };
+    let total_mass = |simulation: &Simulation| {
+        let objects = simulation.read_objects();
+        let live = objects.iter().filter(|state| state.flags & FLAG_PHYSICAL != 0).count() as f32 * 2.0;
+        live + simulation.read_blackholes().iter().map(|blackhole| blackhole.mass * BLACKHOLE_MASS_SCALE).sum::<f32>()
+    };
+
+    let mut simulation = Simulation::new(objects(), device, queue);
+    simulation.set_accretion(true);
+    let initial_mass = total_mass(&simulation);
+    simulation.step(DT);
+
+    let flags = simulation.read_objects().iter().map(|state| state.flags & FLAG_DESTROYED != 0).collect::<Vec<_>>();
+    assert_eq!(flags, [true, true, false]);
+    assert_eq!(simulation.consumed_counts(), [2, 0, 0, 0, 0]);
+    assert!((total_mass(&simulation) - initial_mass).abs() < 1e-2);
+    let blackhole = simulation.read_blackholes()[0];
+    let momentum = blackhole.velocity.map(|v| v * blackhole.mass * BLACKHOLE_MASS_SCALE);
+    assert!(momentum[0].abs() < 200.0 && (momentum[1] / 20000.0 - 1.0).abs() < 0.01, "{momentum:?}");
+    assert!(blackhole.radius > BLACKHOLES.blackholes[0].radius);
+    simulation.step(DT);
+    assert!(simulation.read_blackholes()[0].position[1] > y, "the black hole doesn't drift");
+
+    // Rewinding restores the black holes along with the objects
+    assert!(simulation.rewind());
+    assert_eq!(simulation.read_blackholes(), BLACKHOLES.blackholes);
+    assert_eq!(simulation.consumed_counts(), [0; 5]);
+
+    // Without accretion the consumed matter vanishes
+    let mut simulation = Simulation::new(objects(), device, queue);
+    simulation.step(DT);
+    assert_eq!(simulation.consumed_counts(), [2, 0, 0, 0, 0]);
+    assert_eq!(simulation.read_blackholes(), BLACKHOLES.blackholes);
+}
+
+#[test]
+fn accretion_keeps_light_and_heavy_objects() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // Hundreds of light objects, each below the resolution of the black hole's mass, and two whose momentum alone
+    // would overflow a 32 bit sum
+    let [x, y] = BLACKHOLES.blackholes[0].position;
+    let mut objects = Objects::default();
+    // On two rings, closer to the center the pull would fling them out of the horizon within the step
+    for i in 0..400 {
+        let angle = i as f32 / 200.0 * std::f32::consts::TAU;
+        let distance = if i < 200 { 14.0 } else { 16.0 };
+        objects.push(ObjectPrototype {
+            mass: 1e-3,
+            ..circle([x + distance * angle.cos(), y + distance * angle.sin()], 0.1)
+        });
+    }
+    for side in [-1.0, 1.0] {
+        objects.push(ObjectPrototype {
+            velocity: [0.0, 5000.0],
+            mass: 1e5,
+            ..circle([x + 15.0 * side, y], 2.0)
+        });
+    }
+    let objects_mass = 400.0 * 1e-3 + 2.0 * 1e5;
+    let blackholes_mass = |simulation: &Simulation| {
+        simulation.read_blackholes().iter().map(|blackhole| blackhole.mass * BLACKHOLE_MASS_SCALE).sum::<f32>()
+    };
+
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.set_accretion(true);
+    let initial_mass = blackholes_mass(&simulation) + objects_mass;
+    simulation.step(DT);
+
+    assert_eq!(simulation.consumed_counts(), [402, 0, 0, 0, 0]);
+    let mass = blackholes_mass(&simulation);
+    assert_eq!(mass, initial_mass, "{mass} != {initial_mass}");
+    let blackhole = simulation.read_blackholes()[0];
+    let momentum = blackhole.velocity.map(|v| v * blackhole.mass * BLACKHOLE_MASS_SCALE);
+    assert!((momentum[1] / 1e9 - 1.0).abs() < 0.01, "{momentum:?}");
+}
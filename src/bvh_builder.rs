@@ -19,13 +19,21 @@ pub struct BvhBuilder {
 }
 
 impl BvhBuilder {
-    pub fn new(device: &Device, aabbs: GpuBuffer<AABB>, nodes: GpuBuffer<BvhNode>, object_count: usize) -> Self {
+    /// `sleeping_nodes` is as long as `nodes`, a zeroed one refits the whole tree every time
+    pub fn new(
+        device: &Device,
+        aabbs: GpuBuffer<AABB>,
+        nodes: GpuBuffer<BvhNode>,
+        sleeping_nodes: GpuBuffer<u32>,
+        object_count: usize,
+    ) -> Self {
         let pipeline = create_combine_nodes_pipeline_embed_source(device);
         let bind_group = WgpuBindGroup0::from_bindings(
             device,
             WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
                 aabbs: aabbs.buffer().as_entire_buffer_binding(),
                 nodes: nodes.buffer().as_entire_buffer_binding(),
+                sleeping_nodes: sleeping_nodes.buffer().as_entire_buffer_binding(),
             }),
         );
         Self {
@@ -49,8 +57,9 @@ impl BvhBuilder {
         }
     }
 
+    /// A single object is its own root and has no passes
     pub fn node_count(&self) -> u32 {
-        self.passes.last().unwrap().dst_start + 1
+        self.passes.last().map_or(u32::try_from(self.object_count).unwrap(), |pass| pass.dst_start + 1)
     }
 }
 
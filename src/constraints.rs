@@ -0,0 +1,129 @@
+use itertools::Itertools;
+use wgpu::{BufferUsages, CommandEncoder, ComputePass, ComputePassDescriptor, ComputePipeline, Device, Queue};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::constraints::{
+        Constraint, SolverPass, WORKGROUP_SIZE, WgpuBindGroup0, WgpuBindGroup0Entries, WgpuBindGroup0EntriesParams,
+        compute::{create_apply_corrections_pipeline_embed_source, create_solve_constraints_pipeline_embed_source},
+    },
+};
+
+/// Jacobi iterations per step, chains converge slower the longer they are
+pub const CONSTRAINT_ITERATIONS: usize = 8;
+
+/// How two objects are connected, see [`Objects::connect`](crate::objects::Objects::connect)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ConstraintKind {
+    /// Keeps the distance fixed
+    Distance,
+    /// Pulls towards the rest length with `stiffness` force per unit of stretch, `damping` is the force per unit of
+    /// stretching speed
+    Spring { stiffness: f32, damping: f32 },
+}
+
+impl ConstraintKind {
+    pub fn to_wgsl(self, a: usize, b: usize, rest_length: f32) -> Constraint {
+        let (a, b) = (u32::try_from(a).unwrap(), u32::try_from(b).unwrap());
+        match self {
+            Self::Distance => Constraint::new(a, b, rest_length, 0.0, 0.0),
+            Self::Spring { stiffness, damping } => Constraint::new(a, b, rest_length, 1.0 / stiffness, damping),
+        }
+    }
+}
+
+/// Solves distance constraints and springs with XPBD after the integrator has moved the objects. Springs are
+/// constraints with a compliance, so both kinds share one solver and stay stable at any stiffness.
+pub struct ConstraintSolver {
+    solve_pipeline: ComputePipeline,
+    apply_pipeline: ComputePipeline,
+    bind_group: WgpuBindGroup0,
+    lambdas: GpuBuffer<f32>,
+    constraint_count: u32,
+    constrained_object_count: u32,
+}
+
+impl ConstraintSolver {
+    /// Returns `None` if there are no constraints, empty buffers can't be bound
+    pub fn new(device: &Device, queue: &Queue, constraints: &[Constraint], buffers: &ObjectBuffers) -> Option<Self> {
+        if constraints.is_empty() {
+            return None;
+        }
+
+        // Every object lists the constraints it takes part in, so that it gathers its corrections without atomics
+        let by_object = constraints
+            .iter()
+            .enumerate()
+            .flat_map(|(index, constraint)| [(constraint.a, index), (constraint.b, index)])
+            .into_group_map();
+        let constrained_objects = by_object.keys().copied().sorted().collect_vec();
+        let mut constraint_offsets = vec![0];
+        let mut constraint_indices = Vec::with_capacity(constraints.len() * 2);
+        for object in &constrained_objects {
+            constraint_indices.extend(by_object[object].iter().map(|&index| u32::try_from(index).unwrap()));
+            constraint_offsets.push(u32::try_from(constraint_indices.len()).unwrap());
+        }
+
+        let storage = BufferUsages::STORAGE | BufferUsages::COPY_DST;
+        let constraint_buffer = GpuBuffer::new(constraints.len(), "constraint buffer", storage, device);
+        constraint_buffer.write(queue, constraints);
+        let lambdas = GpuBuffer::new(constraints.len(), "constraint lambda buffer", storage, device);
+        let corrections =
+            GpuBuffer::<[f32; 2]>::new(constraints.len(), "constraint correction buffer", storage, device);
+        let constrained_object_buffer =
+            GpuBuffer::new(constrained_objects.len(), "constrained object buffer", storage, device);
+        constrained_object_buffer.write(queue, &constrained_objects);
+        let offset_buffer = GpuBuffer::new(constraint_offsets.len(), "constraint offset buffer", storage, device);
+        offset_buffer.write(queue, &constraint_offsets);
+        let index_buffer = GpuBuffer::new(constraint_indices.len(), "constraint index buffer", storage, device);
+        index_buffer.write(queue, &constraint_indices);
+
+        let bind_group = WgpuBindGroup0::from_bindings(
+            device,
+            WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
+                constraints: constraint_buffer.buffer().as_entire_buffer_binding(),
+                lambdas: lambdas.buffer().as_entire_buffer_binding(),
+                corrections: corrections.buffer().as_entire_buffer_binding(),
+                constrained_objects: constrained_object_buffer.buffer().as_entire_buffer_binding(),
+                constraint_offsets: offset_buffer.buffer().as_entire_buffer_binding(),
+                constraint_indices: index_buffer.buffer().as_entire_buffer_binding(),
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                masses: buffers.masses.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                integrated_aabbs: buffers.integrated_aabbs.buffer().as_entire_buffer_binding(),
+                integrated_velocities: buffers.integrated_velocities.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        Some(Self {
+            solve_pipeline: create_solve_constraints_pipeline_embed_source(device),
+            apply_pipeline: create_apply_corrections_pipeline_embed_source(device),
+            bind_group,
+            lambdas,
+            constraint_count: u32::try_from(constraints.len()).unwrap(),
+            constrained_object_count: u32::try_from(constrained_objects.len()).unwrap(),
+        })
+    }
+
+    /// Moves the integrated positions onto the constraints and adjusts the integrated velocities to match, `dt` is
+    /// the step size including the time scale
+    pub fn compute(&self, encoder: &mut CommandEncoder, dt: f32) {
+        encoder.clear_buffer(self.lambdas.buffer(), 0, None);
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("constraint pass"),
+            timestamp_writes: None,
+        });
+        for _ in 0..CONSTRAINT_ITERATIONS {
+            self.dispatch(&mut compute_pass, &self.solve_pipeline, dt, self.constraint_count);
+            self.dispatch(&mut compute_pass, &self.apply_pipeline, dt, self.constrained_object_count);
+        }
+    }
+
+    fn dispatch(&self, compute_pass: &mut ComputePass, pipeline: &ComputePipeline, dt: f32, invocations: u32) {
+        compute_pass.set_pipeline(pipeline);
+        self.bind_group.set(compute_pass);
+        compute_pass.set_push_constants(0, bytemuck::cast_slice(&[SolverPass::new(dt)]));
+        let total_workgroups = invocations.div_ceil(WORKGROUP_SIZE);
+        compute_pass.dispatch_workgroups(total_workgroups.min(65535), total_workgroups.div_ceil(65535), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    DT,
+    constraints::ConstraintKind,
+    objects::{ObjectPrototype, Objects},
+    shaders::common::FLAG_DRAW_OBJECT,
+    simulation::Simulation,
+    test_util::{TestDevice, circle, test_device_or_skip},
+};
+
+#[test]
+fn distance_constraints_hold_a_chain() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // A chain hanging off a fixed anchor, pulled on by the black holes
+    let mut objects = Objects::default();
+    objects.push(ObjectPrototype {
+        flags: FLAG_DRAW_OBJECT,
+        ..circle([-900.0, 900.0], 1.0)
+    });
+    for i in 1..6 {
+        objects.push(circle([-900.0 + 5.0 * i as f32, 900.0], 1.0));
+        objects.connect(i - 1, i, ConstraintKind::Distance);
+    }
+    let mut simulation = Simulation::new(objects, device, queue);
+    for _ in 0..100 {
+        simulation.step(DT);
+    }
+
+    let states = simulation.read_objects();
+    assert_eq!(states[0].position, [-900.0, 900.0]);
+    // The chain swings, so the constraints are actually doing something
+    assert!(states[5].position[1] < 899.0, "{:?}", states[5].position);
+    for link in states.windows(2) {
+        let length = distance(link[0].position, link[1].position);
+        assert!((length - 5.0).abs() < 0.05, "link length {length}");
+    }
+}
+
+#[test]
+fn springs_pull_towards_their_rest_length() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let run = |damping: f32, steps: usize| {
+        let mut objects = Objects::default();
+        objects.push(circle([-900.0, 900.0], 1.0));
+        objects.push(circle([-880.0, 900.0], 1.0));
+        objects.connect_with_length(
+            0,
+            1,
+            10.0,
+            ConstraintKind::Spring {
+                stiffness: 1000.0,
+                damping,
+            },
+        );
+        let mut simulation = Simulation::new(objects, device, queue);
+        for _ in 0..steps {
+            simulation.step(DT);
+        }
+        simulation.read_objects()
+    };
+
+    // Stretched, so the objects start moving towards each other
+    let states = run(0.0, 1);
+    assert!(states[0].velocity[0] - states[1].velocity[0] > 0.0);
+    // A spring without damping keeps oscillating, a damped one settles near its rest length
+    let undamped = run(0.0, 300);
+    let damped = run(20.0, 300);
+    let undamped_error = (distance(undamped[0].position, undamped[1].position) - 10.0).abs();
+    let damped_error = (distance(damped[0].position, damped[1].position) - 10.0).abs();
+    assert!(damped_error < 1.0, "damped spring is {damped_error} off its rest length");
+    assert!(damped_error < undamped_error, "{damped_error} >= {undamped_error}");
+}
+
+fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    (a[0] - b[0]).hypot(a[1] - b[1])
+}
@@ -0,0 +1,87 @@
+use wgpu::{BufferUsages, CommandEncoder, ComputePass, ComputePassDescriptor, ComputePipeline, Device};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::{
+        contacts::{
+            ContactResponse, WORKGROUP_SIZE, WgpuBindGroup0, WgpuBindGroup0Entries, WgpuBindGroup0EntriesParams,
+            compute::{
+                create_apply_contacts_pipeline_embed_source, create_count_contacts_pipeline_embed_source,
+                create_solve_contacts_pipeline_embed_source,
+            },
+        },
+        tool_state::ToolState,
+    },
+};
+
+/// Jacobi iterations per step, piles pass impulses on by one object per iteration
+pub const CONTACT_ITERATIONS: usize = 3;
+
+/// Makes touching objects bounce off each other, with the torque of hits off their centers. Runs after the level
+/// collisions and changes the integrated velocities, angular velocities and positions. It also counts every awake
+/// object's contacts, the renderer colors by them.
+pub struct ContactSolver {
+    count_pipeline: ComputePipeline,
+    solve_pipeline: ComputePipeline,
+    apply_pipeline: ComputePipeline,
+    bind_group: WgpuBindGroup0,
+    object_count: u32,
+}
+
+impl ContactSolver {
+    pub fn new(device: &Device, buffers: &ObjectBuffers, tools: GpuBuffer<ToolState>) -> Self {
+        let object_count = buffers.flags.len();
+        let responses =
+            GpuBuffer::<ContactResponse>::new(object_count, "contact response buffer", BufferUsages::STORAGE, device);
+        let bind_group = WgpuBindGroup0::from_bindings(
+            device,
+            WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                masses: buffers.masses.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                nodes: buffers.bvh_nodes.buffer().as_entire_buffer_binding(),
+                angles: buffers.angles.buffer().as_entire_buffer_binding(),
+                integrated_aabbs: buffers.integrated_aabbs.buffer().as_entire_buffer_binding(),
+                integrated_velocities: buffers.integrated_velocities.buffer().as_entire_buffer_binding(),
+                angular_velocities: buffers.angular_velocities.buffer().as_entire_buffer_binding(),
+                contact_counts: buffers.contact_counts.buffer().as_entire_buffer_binding(),
+                responses: responses.buffer().as_entire_buffer_binding(),
+                tools: tools.buffer().as_entire_buffer_binding(),
+                wall_normals: buffers.wall_normals.buffer().as_entire_buffer_binding(),
+                shapes: buffers.shapes.buffer().as_entire_buffer_binding(),
+                sizes: buffers.sizes.buffer().as_entire_buffer_binding(),
+                polygons: buffers.polygons.buffer().as_entire_buffer_binding(),
+                polygon_vertices: buffers.polygon_vertices.buffer().as_entire_buffer_binding(),
+                moments: buffers.moments.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        Self {
+            count_pipeline: create_count_contacts_pipeline_embed_source(device),
+            solve_pipeline: create_solve_contacts_pipeline_embed_source(device),
+            apply_pipeline: create_apply_contacts_pipeline_embed_source(device),
+            bind_group,
+            object_count: u32::try_from(object_count).unwrap(),
+        }
+    }
+
+    pub fn compute(&self, encoder: &mut CommandEncoder) {
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("contact pass"),
+            timestamp_writes: None,
+        });
+        // Every dispatch needs the results of the previous one for all objects
+        self.dispatch(&mut compute_pass, &self.count_pipeline);
+        for _ in 0..CONTACT_ITERATIONS {
+            self.dispatch(&mut compute_pass, &self.solve_pipeline);
+            self.dispatch(&mut compute_pass, &self.apply_pipeline);
+        }
+    }
+
+    fn dispatch(&self, compute_pass: &mut ComputePass, pipeline: &ComputePipeline) {
+        compute_pass.set_pipeline(pipeline);
+        self.bind_group.set(compute_pass);
+        let total_workgroups = self.object_count.div_ceil(WORKGROUP_SIZE);
+        compute_pass.dispatch_workgroups(total_workgroups.min(65535), total_workgroups.div_ceil(65535), 1);
+    }
+}
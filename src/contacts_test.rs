@@ -0,0 +1,58 @@
+use crate::{
+    DT,
+    objects::{ObjectPrototype, Objects},
+    shaders::common::SHAPE_RECT,
+    simulation::Simulation,
+    test_util::{TestDevice, circle, object, test_device_or_skip},
+};
+
+#[test]
+fn off_center_hits_spin_objects_up() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // Circles falling onto bars, one onto the right end and one onto the middle
+    let mut objects = Objects::default();
+    for (bar_x, circle_x) in [(-900.0, -892.0), (-800.0, -800.0)] {
+        objects.push(object(SHAPE_RECT, [bar_x, 900.0], [20.0, 2.0]));
+        objects.push(ObjectPrototype {
+            velocity: [0.0, -400.0],
+            ..circle([circle_x, 903.0], 1.0)
+        });
+    }
+    let mut simulation = Simulation::new(objects, device, queue);
+    for _ in 0..20 {
+        simulation.step(DT);
+    }
+
+    let states = simulation.read_objects();
+    let [bar, circle, centered_bar, centered_circle] = &states[..] else {
+        unreachable!()
+    };
+    // Pushed down at its right end the bar turns clockwise, and turning it takes less of the circle's momentum
+    assert!(bar.angular_velocity < -10.0, "off-center hit: {}", bar.angular_velocity);
+    assert!(centered_bar.angular_velocity.abs() < 1.0, "centered hit: {}", centered_bar.angular_velocity);
+    assert!(circle.velocity[1] < centered_circle.velocity[1], "{:?} {:?}", circle.velocity, centered_circle.velocity);
+    for bar in [bar, centered_bar] {
+        assert!(bar.velocity[1] < -100.0, "bar wasn't pushed: {:?}", bar.velocity);
+    }
+    assert!(centered_circle.velocity[1] > centered_bar.velocity[1], "circle didn't bounce");
+}
+
+#[test]
+fn objects_pass_through_each_other_when_contacts_are_skipped() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // Two circles starting deep inside each other
+    let distance_after_steps = |skip_contacts| {
+        let mut objects = Objects::default();
+        objects.push(circle([-900.0, 900.0], 2.0));
+        objects.push(circle([-899.0, 900.0], 2.0));
+        objects.skip_contacts = skip_contacts;
+        let mut simulation = Simulation::new(objects, device, queue);
+        for _ in 0..10 {
+            simulation.step(DT);
+        }
+        let states = simulation.read_objects();
+        states[1].position[0] - states[0].position[0]
+    };
+    assert!(distance_after_steps(false) > 1.1, "contacts didn't push the circles apart");
+    assert!((distance_after_steps(true) - 1.0).abs() < 1e-3, "skipped contacts pushed the circles apart");
+}
@@ -0,0 +1,256 @@
+use std::{fmt::Debug, ops::RangeInclusive};
+
+use winit::dpi::PhysicalPosition;
+
+use crate::{
+    shaders::ui::{FONT_GLYPH_HEIGHT, UI_SOLID, UiQuad},
+    ui_renderer::{CHARACTER_ADVANCE, push_text},
+};
+
+/// Layout in font pixels, multiplied by [`DebugPanel::scale`]
+const LINE_HEIGHT: f32 = FONT_GLYPH_HEIGHT as f32 + 3.0;
+const PADDING: f32 = 4.0;
+const MARGIN: f32 = 4.0;
+
+const BACKGROUND_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 0.75];
+const SELECTION_COLOR: [f32; 4] = [0.1, 0.2, 0.6, 0.9];
+const HEADING_COLOR: [f32; 4] = [1.0, 0.8, 0.2, 1.0];
+const WIDGET_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const LABEL_COLOR: [f32; 4] = [0.6, 0.6, 0.6, 1.0];
+
+/// Keyboard and mouse input for the panel, applied to the selected widget in the next frame
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PanelInput {
+    /// Selects the widget above
+    Previous,
+    /// Selects the widget below
+    Next,
+    Decrease,
+    Increase,
+    Activate,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RowKind {
+    Heading,
+    Label,
+    Widget(usize),
+}
+
+struct Row {
+    text: String,
+    kind: RowKind,
+}
+
+/// Immediate-mode panel of text rows. The rows are rebuilt every frame with [`DebugPanel::begin`], widgets edit the
+/// values they are given directly and report whether they changed them.
+pub struct DebugPanel {
+    pub visible: bool,
+    /// Size of a font pixel in screen pixels
+    pub scale: f32,
+    selected: usize,
+    pending: Vec<PanelInput>,
+    rows: Vec<Row>,
+}
+
+impl Default for DebugPanel {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            scale: 2.0,
+            selected: 0,
+            pending: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+}
+
+impl DebugPanel {
+    pub fn input(&mut self, input: PanelInput) {
+        self.pending.push(input);
+    }
+
+    /// Selects the widget under `position` and adjusts it, `primary` increases and the other button decreases.
+    /// Returns whether the click was on the panel.
+    pub fn click(&mut self, position: PhysicalPosition<f32>, primary: bool) -> bool {
+        if !self.visible {
+            return false;
+        }
+        let [min, max] = self.bounds();
+        if position.x < min[0] || position.y < min[1] || position.x >= max[0] || position.y >= max[1] {
+            return false;
+        }
+        let row_index = ((position.y - min[1] - PADDING * self.scale) / (LINE_HEIGHT * self.scale)).floor();
+        if row_index >= 0.0 {
+            if let Some(RowKind::Widget(widget)) = self.rows.get(row_index as usize).map(|row| row.kind) {
+                self.selected = widget;
+                self.input(if primary {
+                    PanelInput::Increase
+                } else {
+                    PanelInput::Decrease
+                });
+            }
+        }
+        true
+    }
+
+    /// Starts building this frame's rows, the pending input is applied to the selected widget
+    pub fn begin(&mut self) -> PanelUi<'_> {
+        let widget_count = self.rows.iter().filter(|row| matches!(row.kind, RowKind::Widget(_))).count();
+        let mut input = None;
+        for pending in self.pending.drain(..) {
+            match pending {
+                PanelInput::Previous => self.selected = self.selected.saturating_sub(1),
+                PanelInput::Next => self.selected = (self.selected + 1).min(widget_count.saturating_sub(1)),
+                other => input = Some(other),
+            }
+        }
+        PanelUi {
+            panel: self,
+            rows: Vec::new(),
+            widget_count: 0,
+            input,
+        }
+    }
+
+    /// Quads for the [`UiRenderer`](crate::ui_renderer::UiRenderer), empty if the panel is hidden
+    pub fn quads(&self) -> Vec<UiQuad> {
+        if !self.visible || self.rows.is_empty() {
+            return Vec::new();
+        }
+        let [min, max] = self.bounds();
+        let mut quads = vec![UiQuad::new(
+            min,
+            [max[0] - min[0], max[1] - min[1]],
+            BACKGROUND_COLOR,
+            UI_SOLID,
+        )];
+        for (row_index, row) in self.rows.iter().enumerate() {
+            let y = min[1] + (PADDING + row_index as f32 * LINE_HEIGHT) * self.scale;
+            let color = match row.kind {
+                RowKind::Heading => HEADING_COLOR,
+                RowKind::Label => LABEL_COLOR,
+                RowKind::Widget(widget) => {
+                    if widget == self.selected {
+                        let size = [max[0] - min[0], LINE_HEIGHT * self.scale];
+                        quads.push(UiQuad::new([min[0], y], size, SELECTION_COLOR, UI_SOLID));
+                    }
+                    WIDGET_COLOR
+                }
+            };
+            // Glyphs are centered vertically in the line
+            let glyph_y = y + (LINE_HEIGHT - FONT_GLYPH_HEIGHT as f32) / 2.0 * self.scale;
+            push_text(&mut quads, &row.text, [min[0] + PADDING * self.scale, glyph_y], self.scale, color);
+        }
+        quads
+    }
+
+    /// Top left and bottom right corner in pixels
+    fn bounds(&self) -> [[f32; 2]; 2] {
+        let columns = self.rows.iter().map(|row| row.text.chars().count()).max().unwrap_or(0);
+        let min = [MARGIN * self.scale; 2];
+        let size = [
+            (columns as f32 * CHARACTER_ADVANCE + 2.0 * PADDING) * self.scale,
+            (self.rows.len() as f32 * LINE_HEIGHT + 2.0 * PADDING) * self.scale,
+        ];
+        [min, [min[0] + size[0], min[1] + size[1]]]
+    }
+}
+
+/// Rows of one frame, they replace the panel's rows when this is dropped
+pub struct PanelUi<'a> {
+    panel: &'a mut DebugPanel,
+    rows: Vec<Row>,
+    widget_count: usize,
+    input: Option<PanelInput>,
+}
+
+impl PanelUi<'_> {
+    pub fn heading(&mut self, text: &str) {
+        self.rows.push(Row {
+            text: text.to_uppercase(),
+            kind: RowKind::Heading,
+        });
+    }
+
+    pub fn label(&mut self, text: impl Into<String>) {
+        self.rows.push(Row {
+            text: text.into(),
+            kind: RowKind::Label,
+        });
+    }
+
+    /// Adds a row that can be selected, returns the input for it if it is selected
+    fn widget(&mut self, text: String) -> Option<PanelInput> {
+        let index = self.widget_count;
+        self.widget_count += 1;
+        self.rows.push(Row {
+            text,
+            kind: RowKind::Widget(index),
+        });
+        if index == self.panel.selected {
+            self.input.take()
+        } else {
+            None
+        }
+    }
+
+    /// Returns true when activated
+    pub fn button(&mut self, label: &str) -> bool {
+        self.widget(format!("[{label}]")).is_some()
+    }
+
+    pub fn checkbox(&mut self, label: &str, value: &mut bool) -> bool {
+        let changed = self.widget(format!("[{}] {label}", if *value { 'x' } else { ' ' })).is_some();
+        if changed {
+            *value = !*value;
+        }
+        changed
+    }
+
+    /// Steps through values with `next`, both directions go forward
+    pub fn cycle<T: Copy + Debug>(&mut self, label: &str, value: &mut T, next: impl Fn(T) -> T) -> bool {
+        let changed = self.widget(format!("{label}: <{value:?}>")).is_some();
+        if changed {
+            *value = next(*value);
+        }
+        changed
+    }
+
+    /// Multiplies or divides by `factor` within `range`
+    pub fn slider(&mut self, label: &str, value: &mut f32, range: RangeInclusive<f32>, factor: f32) -> bool {
+        let old = *value;
+        match self.widget(format!("{label}: <{}>", format_value(*value))) {
+            Some(PanelInput::Increase | PanelInput::Activate) => *value *= factor,
+            Some(PanelInput::Decrease) => *value /= factor,
+            _ => (),
+        }
+        *value = value.clamp(*range.start(), *range.end());
+        *value != old
+    }
+
+    /// A value that is owned elsewhere, returns 1 to increase it, -1 to decrease it and 0 otherwise
+    pub fn stepper(&mut self, text: impl Into<String>) -> i32 {
+        match self.widget(text.into()) {
+            Some(PanelInput::Increase | PanelInput::Activate) => 1,
+            Some(PanelInput::Decrease) => -1,
+            _ => 0,
+        }
+    }
+}
+
+impl Drop for PanelUi<'_> {
+    fn drop(&mut self) {
+        self.panel.rows = std::mem::take(&mut self.rows);
+        self.panel.selected = self.panel.selected.min(self.widget_count.saturating_sub(1));
+    }
+}
+
+/// Short text for a value, large values have no fraction and small ones get more digits
+pub fn format_value(value: f32) -> String {
+    match value.abs() {
+        100.0.. => format!("{value:.0}"),
+        1.0.. => format!("{value:.2}"),
+        _ => format!("{value:.4}"),
+    }
+}
@@ -0,0 +1,80 @@
+use winit::dpi::PhysicalPosition;
+
+use crate::debug_panel::{DebugPanel, PanelInput};
+
+struct Values {
+    flag: bool,
+    value: f32,
+    clicks: u32,
+}
+
+/// Builds a panel with a heading and three widgets, one per row after the heading
+fn build(panel: &mut DebugPanel, values: &mut Values) {
+    let mut ui = panel.begin();
+    ui.heading("values");
+    ui.checkbox("flag", &mut values.flag);
+    ui.slider("value", &mut values.value, 1.0..=8.0, 2.0);
+    if ui.button("click") {
+        values.clicks += 1;
+    }
+}
+
+#[test]
+fn keyboard_input_edits_selected_widget() {
+    let mut panel = DebugPanel::default();
+    panel.visible = true;
+    let mut values = Values {
+        flag: false,
+        value: 2.0,
+        clicks: 0,
+    };
+    build(&mut panel, &mut values);
+
+    panel.input(PanelInput::Activate);
+    build(&mut panel, &mut values);
+    assert!(values.flag);
+
+    panel.input(PanelInput::Next);
+    panel.input(PanelInput::Increase);
+    build(&mut panel, &mut values);
+    assert_eq!(values.value, 4.0);
+
+    // Clamped to the range
+    for _ in 0..3 {
+        panel.input(PanelInput::Increase);
+        build(&mut panel, &mut values);
+    }
+    assert_eq!(values.value, 8.0);
+
+    // Selection stops at the last widget
+    for _ in 0..5 {
+        panel.input(PanelInput::Next);
+    }
+    panel.input(PanelInput::Activate);
+    build(&mut panel, &mut values);
+    assert_eq!(values.clicks, 1);
+    assert!(values.flag);
+}
+
+#[test]
+fn click_selects_and_adjusts_row() {
+    let mut panel = DebugPanel::default();
+    panel.visible = true;
+    panel.scale = 1.0;
+    let mut values = Values {
+        flag: false,
+        value: 2.0,
+        clicks: 0,
+    };
+    build(&mut panel, &mut values);
+
+    // Rows are 10 pixels high and start after the 4 pixel margin and padding, the slider is the third row
+    assert!(panel.click(PhysicalPosition::new(20.0, 8.0 + 25.0), false));
+    build(&mut panel, &mut values);
+    assert_eq!(values.value, 1.0);
+    assert!(!values.flag);
+
+    assert!(!panel.click(PhysicalPosition::new(500.0, 500.0), true));
+    panel.visible = false;
+    assert!(!panel.click(PhysicalPosition::new(20.0, 8.0 + 25.0), true));
+}
@@ -0,0 +1,163 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use wgpu::{BufferUsages, CommandEncoder, ComputePass, ComputePipeline, Device, MapMode, Queue};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::diagnostics::{
+        self, Diagnostics, DiagnosticsParameters, WORKGROUP_SIZE,
+        compute::{create_cs_combine_pipeline_embed_source, create_cs_reduce_pipeline_embed_source},
+    },
+};
+
+impl Diagnostics {
+    pub fn total_energy(&self) -> f32 {
+        self.kinetic_energy + self.potential_energy
+    }
+}
+
+/// Diagnostics after a simulation step
+#[derive(Copy, Clone, Debug)]
+pub struct DiagnosticsSample {
+    /// Number of steps run before the sample was taken
+    pub step: u64,
+    pub diagnostics: Diagnostics,
+}
+
+/// Readback buffers that can be mapping at once, a step's totals are dropped if all of them are still busy
+const READBACK_COUNT: usize = 3;
+
+struct Readback {
+    buffer: GpuBuffer<Diagnostics>,
+    /// Step of the totals copied into the buffer, `None` while it is free
+    step: Option<u64>,
+    /// Set by the map callback once the totals can be read
+    mapped: Arc<AtomicBool>,
+}
+
+/// Sums energy, momentum and object counts over all objects with a parallel reduction on the GPU. The totals are
+/// read back through a few buffers that are mapped asynchronously, so no step waits for them.
+pub struct DiagnosticsReducer {
+    reduce_pipeline: ComputePipeline,
+    combine_pipeline: ComputePipeline,
+    bind_group: diagnostics::WgpuBindGroup0,
+    totals: GpuBuffer<Diagnostics>,
+    readbacks: Vec<Readback>,
+    /// Readback the last copy went to and that still has to be mapped
+    copied: Option<usize>,
+    workgroup_count: u32,
+}
+
+impl DiagnosticsReducer {
+    /// Angular momentum is taken about `origin`
+    pub fn new(device: &Device, queue: &Queue, buffers: &ObjectBuffers, origin: [f32; 2]) -> Self {
+        let workgroup_count = u32::try_from(buffers.flags.len()).unwrap().div_ceil(WORKGROUP_SIZE);
+        let parameters =
+            GpuBuffer::new(1, "diagnostics parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        parameters.write(queue, &[DiagnosticsParameters::new(origin)]);
+        let partials = GpuBuffer::<Diagnostics>::new(
+            usize::try_from(workgroup_count).unwrap(),
+            "diagnostics partials buffer",
+            BufferUsages::STORAGE,
+            device,
+        );
+        let totals =
+            GpuBuffer::new(1, "diagnostics totals buffer", BufferUsages::STORAGE | BufferUsages::COPY_SRC, device);
+        let readbacks = (0..READBACK_COUNT)
+            .map(|_| Readback {
+                buffer: GpuBuffer::new(
+                    1,
+                    "diagnostics readback buffer",
+                    BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+                    device,
+                ),
+                step: None,
+                mapped: Arc::new(AtomicBool::new(false)),
+            })
+            .collect();
+        let bind_group = diagnostics::WgpuBindGroup0::from_bindings(
+            device,
+            diagnostics::WgpuBindGroup0Entries::new(diagnostics::WgpuBindGroup0EntriesParams {
+                parameters: parameters.buffer().as_entire_buffer_binding(),
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                masses: buffers.masses.buffer().as_entire_buffer_binding(),
+                velocities: buffers.velocities.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                partials: partials.buffer().as_entire_buffer_binding(),
+                totals: totals.buffer().as_entire_buffer_binding(),
+                moments: buffers.moments.buffer().as_entire_buffer_binding(),
+                angular_velocities: buffers.angular_velocities.buffer().as_entire_buffer_binding(),
+                blackhole_set: buffers.blackholes.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        Self {
+            reduce_pipeline: create_cs_reduce_pipeline_embed_source(device),
+            combine_pipeline: create_cs_combine_pipeline_embed_source(device),
+            bind_group,
+            totals,
+            readbacks,
+            copied: None,
+            workgroup_count,
+        }
+    }
+
+    pub fn compute(&self, compute_pass: &mut ComputePass) {
+        compute_pass.set_pipeline(&self.reduce_pipeline);
+        self.bind_group.set(compute_pass);
+        let x = self.workgroup_count.min(65535);
+        let y = self.workgroup_count.div_ceil(65535);
+        compute_pass.dispatch_workgroups(x, y, 1);
+        compute_pass.set_pipeline(&self.combine_pipeline);
+        compute_pass.dispatch_workgroups(1, 1, 1);
+    }
+
+    /// Records a copy of the totals of `step` into a free readback buffer, must be submitted after [`Self::compute`]
+    /// and before [`Self::map_readback`]
+    pub fn copy_to_readback(&mut self, encoder: &mut CommandEncoder, step: u64) {
+        self.copied = self.readbacks.iter().position(|readback| readback.step.is_none());
+        let Some(index) = self.copied else {
+            log::warn!("Dropped the diagnostics of step {step}, all readback buffers are busy");
+            return;
+        };
+        let readback = &mut self.readbacks[index];
+        encoder.copy_buffer_to_buffer(self.totals.buffer(), 0, readback.buffer.buffer(), 0, None);
+        readback.step = Some(step);
+    }
+
+    /// Starts mapping the last copy, the totals can be taken once the device was polled after the submission
+    pub fn map_readback(&mut self) {
+        let Some(index) = self.copied.take() else {
+            return;
+        };
+        let mapped = self.readbacks[index].mapped.clone();
+        self.readbacks[index].buffer.buffer().map_async(MapMode::Read, .., move |result| {
+            // Fails if the reducer is dropped while mapping
+            mapped.store(result.is_ok(), Ordering::Release);
+        });
+    }
+
+    /// Returns the samples whose readback finished mapping in step order and frees their buffers
+    pub fn take_samples(&mut self) -> Vec<DiagnosticsSample> {
+        let mut samples = Vec::new();
+        for readback in &mut self.readbacks {
+            let Some(step) = readback.step else {
+                continue;
+            };
+            if !readback.mapped.swap(false, Ordering::Acquire) {
+                continue;
+            }
+            let view = readback.buffer.buffer().get_mapped_range(..);
+            let diagnostics = bytemuck::cast_slice::<_, Diagnostics>(&view)[0];
+            drop(view);
+            readback.buffer.buffer().unmap();
+            readback.step = None;
+            samples.push(DiagnosticsSample { step, diagnostics });
+        }
+        samples.sort_unstable_by_key(|sample| sample.step);
+        samples
+    }
+}
@@ -0,0 +1,108 @@
+use wgpu::{CommandEncoderDescriptor, ComputePassDescriptor};
+
+use crate::{
+    DT,
+    diagnostics::DiagnosticsReducer,
+    integration::Integrator,
+    objects::{ObjectPrototype, Objects},
+    shaders::common::{FLAG_DESTROYED, FLAG_DRAW_OBJECT, FLAG_PHYSICAL},
+    simulation::Simulation,
+    test_util::{TestDevice, circle, test_device_or_skip},
+    util::DeviceUtil,
+};
+
+#[test]
+fn reduces_known_objects() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // More objects than one workgroup reduces, every third one is not physical and every seventh one destroyed
+    let origin = [10.0, -20.0];
+    let mut objects = Objects::default();
+    let mut expected_kinetic_energy = 0.0;
+    let mut expected_momentum = [0.0, 0.0];
+    let mut expected_angular_momentum = 0.0;
+    let mut expected_mass = 0.0;
+    let (mut expected_live, mut expected_destroyed) = (0, 0);
+    for i in 0..600 {
+        let flags = match i {
+            _ if i % 7 == 0 => FLAG_DESTROYED,
+            _ if i % 3 == 0 => FLAG_DRAW_OBJECT,
+            _ => FLAG_DRAW_OBJECT | FLAG_PHYSICAL,
+        };
+        let position = [(i % 40) as f64 * 10.0 - 200.0, (i / 40) as f64 * 10.0 - 70.0];
+        let velocity = [(i % 11) as f64 - 5.0, (i % 13) as f64 - 6.0];
+        let mass = 1.0 + (i % 5) as f64;
+        objects.push(ObjectPrototype {
+            flags,
+            velocity: velocity.map(|x| x as f32),
+            mass: mass as f32,
+            ..circle(position.map(|x| x as f32), 1.0)
+        });
+        if flags & FLAG_DESTROYED != 0 {
+            expected_destroyed += 1;
+        }
+        if flags & FLAG_PHYSICAL != 0 {
+            let r = [position[0] - origin[0] as f64, position[1] - origin[1] as f64];
+            expected_kinetic_energy += mass * (velocity[0] * velocity[0] + velocity[1] * velocity[1]) / 2.0;
+            expected_momentum[0] += mass * velocity[0];
+            expected_momentum[1] += mass * velocity[1];
+            expected_angular_momentum += mass * (r[0] * velocity[1] - r[1] * velocity[0]);
+            expected_mass += mass;
+            expected_live += 1;
+        }
+    }
+    let buffers = objects.to_buffers(device, queue);
+
+    let mut reducer = DiagnosticsReducer::new(device, queue, &buffers, origin);
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+    let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor::default());
+    reducer.compute(&mut compute_pass);
+    drop(compute_pass);
+    reducer.copy_to_readback(&mut encoder, 1);
+    let submission_index = queue.submit([encoder.finish()]);
+    reducer.map_readback();
+    device.wait_for_submission(submission_index).unwrap();
+    let samples = reducer.take_samples();
+    assert_eq!(samples.len(), 1);
+    let diagnostics = samples[0].diagnostics;
+
+    let assert_close = |actual: f32, expected: f64| {
+        assert!((actual as f64 - expected).abs() <= expected.abs() * 1e-5 + 1e-3, "expected {expected}, got {actual}");
+    };
+    assert_close(diagnostics.kinetic_energy, expected_kinetic_energy);
+    assert_close(diagnostics.momentum[0], expected_momentum[0]);
+    assert_close(diagnostics.momentum[1], expected_momentum[1]);
+    assert_close(diagnostics.angular_momentum, expected_angular_momentum);
+    assert_close(diagnostics.mass, expected_mass);
+    assert_eq!((diagnostics.live, diagnostics.destroyed), (expected_live, expected_destroyed));
+    // Every live object is in the black holes' potential well
+    assert!(diagnostics.potential_energy < 0.0);
+}
+
+#[test]
+fn leapfrog_conserves_energy() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    for (position, velocity) in [([300.0, -200.0], [0.0, 100.0]), ([-400.0, 100.0], [-50.0, 0.0])] {
+        objects.push(ObjectPrototype {
+            velocity,
+            ..circle(position, 1.0)
+        });
+    }
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.set_integrator(Integrator::Leapfrog);
+    simulation.enable_diagnostics([0.0, 0.0]);
+    for _ in 0..200 {
+        simulation.step(DT);
+    }
+
+    let samples = simulation.diagnostics();
+    assert_eq!(samples.len(), 200);
+    assert_eq!((samples[0].step, samples[199].step), (1, 200));
+    let initial = samples[0].diagnostics;
+    let last = samples[199].diagnostics;
+    assert_eq!((last.live, last.destroyed), (2, 0));
+    // The objects do speed up, so the check isn't trivially satisfied
+    assert!(last.kinetic_energy > initial.kinetic_energy * 1.01);
+    let drift = (last.total_energy() - initial.total_energy()).abs() / initial.potential_energy.abs();
+    assert!(drift < 1e-3, "relative energy drift {drift}");
+}
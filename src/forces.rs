@@ -0,0 +1,290 @@
+use std::str::FromStr;
+
+use anyhow::{Context as _, anyhow, bail};
+use wgpu::{BufferUsages, Device, Queue};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    shaders::integration::{
+        self, FORCE_LINEAR_DRAG, FORCE_POINT_ATTRACTOR, FORCE_QUADRATIC_DRAG, FORCE_RADIAL_EXPLOSION,
+        FORCE_UNIFORM_GRAVITY, FORCE_VORTEX, FORCE_WIND, ForceSet, MAX_FORCES,
+    },
+};
+
+/// A force field acting on all physical objects in addition to the black holes and the cursor. Every force is an
+/// acceleration, so it acts the same on light and heavy objects.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Force {
+    UniformGravity {
+        acceleration: [f32; 2],
+    },
+    /// Inverse square attraction towards `position`, negative strengths repel
+    PointAttractor {
+        position: [f32; 2],
+        strength: f32,
+        /// Plummer softening radius, keeps the force finite at the center
+        softening: f32,
+    },
+    /// Proportional to the velocity, like a slow object in a viscous fluid
+    LinearDrag {
+        coefficient: f32,
+    },
+    /// Proportional to the squared speed, like a fast object in air
+    QuadraticDrag {
+        coefficient: f32,
+    },
+    /// Swirl around `center`, counterclockwise for positive strengths
+    Vortex {
+        center: [f32; 2],
+        strength: f32,
+        /// Radius of the core within which the swirl slows down instead of diverging
+        core_radius: f32,
+    },
+    /// Push away from `center` that falls off linearly to zero at `radius`, usually added for a few steps only
+    RadialExplosion {
+        center: [f32; 2],
+        strength: f32,
+        radius: f32,
+    },
+    /// Linear drag relative to air moving at `velocity`
+    Wind {
+        velocity: [f32; 2],
+        coefficient: f32,
+    },
+}
+
+impl Force {
+    pub fn to_wgsl(&self) -> integration::Force {
+        match *self {
+            Self::UniformGravity { acceleration } => {
+                integration::Force::new(FORCE_UNIFORM_GRAVITY, 0.0, 0.0, [0.0, 0.0], acceleration)
+            }
+            Self::PointAttractor {
+                position,
+                strength,
+                softening,
+            } => integration::Force::new(FORCE_POINT_ATTRACTOR, strength, softening, position, [0.0, 0.0]),
+            Self::LinearDrag { coefficient } => {
+                integration::Force::new(FORCE_LINEAR_DRAG, coefficient, 0.0, [0.0, 0.0], [0.0, 0.0])
+            }
+            Self::QuadraticDrag { coefficient } => {
+                integration::Force::new(FORCE_QUADRATIC_DRAG, coefficient, 0.0, [0.0, 0.0], [0.0, 0.0])
+            }
+            Self::Vortex {
+                center,
+                strength,
+                core_radius,
+            } => integration::Force::new(FORCE_VORTEX, strength, core_radius, center, [0.0, 0.0]),
+            Self::RadialExplosion {
+                center,
+                strength,
+                radius,
+            } => integration::Force::new(FORCE_RADIAL_EXPLOSION, strength, radius, center, [0.0, 0.0]),
+            Self::Wind { velocity, coefficient } => {
+                integration::Force::new(FORCE_WIND, coefficient, 0.0, [0.0, 0.0], velocity)
+            }
+        }
+    }
+}
+
+/// Parses `KIND:ARGS` as described for `--force` in [`USAGE`](crate::options::USAGE)
+impl FromStr for Force {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let (kind, arguments) = s.split_once(':').ok_or_else(|| anyhow!("Expected KIND:ARGS, got {s}"))?;
+        let arguments = arguments
+            .split(',')
+            .map(|argument| argument.trim().parse::<f32>().with_context(|| format!("Invalid number: {argument}")))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let expected = match kind {
+            "gravity" => 2,
+            "attractor" | "vortex" | "explosion" => 4,
+            "drag" | "quadratic-drag" => 1,
+            "wind" => 3,
+            _ => bail!("Unknown force: {kind}"),
+        };
+        if arguments.len() != expected {
+            bail!("{kind} takes {expected} arguments, got {}", arguments.len());
+        }
+        let a = &arguments;
+        Ok(match kind {
+            "gravity" => Self::UniformGravity {
+                acceleration: [a[0], a[1]],
+            },
+            "attractor" => Self::PointAttractor {
+                position: [a[0], a[1]],
+                strength: a[2],
+                softening: a[3],
+            },
+            "drag" => Self::LinearDrag { coefficient: a[0] },
+            "quadratic-drag" => Self::QuadraticDrag { coefficient: a[0] },
+            "vortex" => Self::Vortex {
+                center: [a[0], a[1]],
+                strength: a[2],
+                core_radius: a[3],
+            },
+            "explosion" => Self::RadialExplosion {
+                center: [a[0], a[1]],
+                strength: a[2],
+                radius: a[3],
+            },
+            _ => Self::Wind {
+                velocity: [a[0], a[1]],
+                coefficient: a[2],
+            },
+        })
+    }
+}
+
+/// A force together with how long it stays active, as given on the command line as `KIND:ARGS[@STEPS]`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TimedForce {
+    pub force: Force,
+    /// `None` keeps the force until it is removed
+    pub steps: Option<u64>,
+}
+
+impl FromStr for TimedForce {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let (force, steps) = match s.rsplit_once('@') {
+            Some((force, steps)) => {
+                let steps = steps.parse().with_context(|| format!("Invalid step count: {steps}"))?;
+                if steps == 0 {
+                    bail!("A force has to be active for at least one step");
+                }
+                (force, Some(steps))
+            }
+            None => (s, None),
+        };
+        Ok(Self {
+            force: force.parse()?,
+            steps,
+        })
+    }
+}
+
+/// Identifies a force in a [`ForceRegistry`], ids are never reused
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ForceId(u64);
+
+struct RegisteredForce {
+    id: ForceId,
+    force: Force,
+    remaining_steps: Option<u64>,
+}
+
+/// The forces the integrator applies besides the black holes and the cursor. Changes are uploaded before the next
+/// step, so the shader picks up any mix of forces without being recompiled.
+pub struct ForceRegistry {
+    forces: Vec<RegisteredForce>,
+    next_id: u64,
+    buffer: GpuBuffer<ForceSet>,
+    dirty: bool,
+}
+
+impl ForceRegistry {
+    pub fn new(device: &Device) -> Self {
+        Self {
+            forces: Vec::new(),
+            next_id: 0,
+            buffer: GpuBuffer::new(1, "force set buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device),
+            dirty: true,
+        }
+    }
+
+    /// Adds a force that stays until it is removed, fails if [`MAX_FORCES`] forces are registered already
+    pub fn add(&mut self, force: Force) -> anyhow::Result<ForceId> {
+        self.insert(force, None)
+    }
+
+    /// Adds a force that is removed after `steps` steps
+    pub fn add_for_steps(&mut self, force: Force, steps: u64) -> anyhow::Result<ForceId> {
+        if steps == 0 {
+            bail!("A force has to be active for at least one step");
+        }
+        self.insert(force, Some(steps))
+    }
+
+    pub fn add_timed(&mut self, timed: TimedForce) -> anyhow::Result<ForceId> {
+        match timed.steps {
+            Some(steps) => self.add_for_steps(timed.force, steps),
+            None => self.add(timed.force),
+        }
+    }
+
+    fn insert(&mut self, force: Force, remaining_steps: Option<u64>) -> anyhow::Result<ForceId> {
+        if self.forces.len() >= MAX_FORCES as usize {
+            bail!("At most {MAX_FORCES} forces can be registered");
+        }
+        let id = ForceId(self.next_id);
+        self.next_id += 1;
+        self.forces.push(RegisteredForce {
+            id,
+            force,
+            remaining_steps,
+        });
+        self.dirty = true;
+        Ok(id)
+    }
+
+    /// Returns false if the force was removed already or has expired
+    pub fn remove(&mut self, id: ForceId) -> bool {
+        let length = self.forces.len();
+        self.forces.retain(|registered| registered.id != id);
+        self.dirty |= self.forces.len() != length;
+        self.forces.len() != length
+    }
+
+    pub fn clear(&mut self) {
+        self.dirty |= !self.forces.is_empty();
+        self.forces.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ForceId, &Force)> {
+        self.forces.iter().map(|registered| (registered.id, &registered.force))
+    }
+
+    pub fn len(&self) -> usize {
+        self.forces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.forces.is_empty()
+    }
+
+    /// Uniform read by the integrator
+    pub(crate) fn buffer(&self) -> GpuBuffer<ForceSet> {
+        self.buffer.clone()
+    }
+
+    /// Writes the forces to the GPU if they changed since the last upload, returns whether they did
+    pub(crate) fn upload(&mut self, queue: &Queue) -> bool {
+        if !self.dirty {
+            return false;
+        }
+        let mut descriptors = [integration::Force::new(0, 0.0, 0.0, [0.0, 0.0], [0.0, 0.0]); MAX_FORCES as usize];
+        for (descriptor, registered) in descriptors.iter_mut().zip(&self.forces) {
+            *descriptor = registered.force.to_wgsl();
+        }
+        let count = u32::try_from(self.forces.len()).unwrap();
+        self.buffer.write(queue, &[ForceSet::new(descriptors, count)]);
+        self.dirty = false;
+        true
+    }
+
+    /// Counts down the forces added for a number of steps and drops the expired ones
+    pub(crate) fn finish_step(&mut self) {
+        let length = self.forces.len();
+        self.forces.retain_mut(|registered| match &mut registered.remaining_steps {
+            Some(steps) => {
+                *steps = steps.saturating_sub(1);
+                *steps > 0
+            }
+            None => true,
+        });
+        self.dirty |= self.forces.len() != length;
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    DT,
+    forces::{Force, TimedForce},
+    objects::{ObjectPrototype, Objects},
+    simulation::Simulation,
+    test_util::{TestDevice, circle, test_device_or_skip},
+};
+
+#[test]
+fn registered_forces_accelerate_objects() {
+    let test_device = &test_device_or_skip!();
+    let velocity = [10.0, 0.0];
+    let baseline = step_with_forces(test_device, velocity, &[]);
+    let acceleration = |forces: &[TimedForce]| {
+        let velocity = step_with_forces(test_device, velocity, forces);
+        [(velocity[0] - baseline[0]) / DT, (velocity[1] - baseline[1]) / DT]
+    };
+    let assert_close = |actual: [f32; 2], expected: [f32; 2]| {
+        let error = (actual[0] - expected[0]).abs().max((actual[1] - expected[1]).abs());
+        assert!(error < 0.05, "expected {expected:?}, got {actual:?}");
+    };
+    let permanent = |force| TimedForce { force, steps: None };
+
+    let gravity = permanent(Force::UniformGravity {
+        acceleration: [0.0, -50.0],
+    });
+    assert_close(acceleration(&[gravity]), [0.0, -50.0]);
+    let drag = permanent(Force::LinearDrag { coefficient: 2.0 });
+    assert_close(acceleration(&[drag]), [-20.0, 0.0]);
+    let quadratic_drag = permanent(Force::QuadraticDrag { coefficient: 0.5 });
+    assert_close(acceleration(&[quadratic_drag]), [-50.0, 0.0]);
+    let wind = permanent(Force::Wind {
+        velocity: [0.0, 20.0],
+        coefficient: 1.0,
+    });
+    assert_close(acceleration(&[wind]), [-10.0, 20.0]);
+    // Forces add up, in any mix
+    assert_close(acceleration(&[gravity, drag, wind]), [-30.0, -30.0]);
+
+    // The object starts at (300, 300)
+    let attractor = permanent(Force::PointAttractor {
+        position: [300.0, 0.0],
+        strength: 90000.0,
+        softening: 0.0,
+    });
+    assert_close(acceleration(&[attractor]), [0.0, -1.0]);
+    let vortex = permanent(Force::Vortex {
+        center: [300.0, 0.0],
+        strength: 300.0,
+        core_radius: 0.0,
+    });
+    assert_close(acceleration(&[vortex]), [-1.0, 0.0]);
+    let explosion = permanent(Force::RadialExplosion {
+        center: [300.0, 200.0],
+        strength: 40.0,
+        radius: 400.0,
+    });
+    assert_close(acceleration(&[explosion]), [0.0, 30.0]);
+    let out_of_reach = permanent(Force::RadialExplosion {
+        center: [300.0, 200.0],
+        strength: 40.0,
+        radius: 50.0,
+    });
+    assert_close(acceleration(&[out_of_reach]), [0.0, 0.0]);
+}
+
+#[test]
+fn timed_forces_expire() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut simulation = Simulation::new(single_object([0.0, 0.0]), device, queue);
+    let gravity = "gravity:0,-9.81".parse().unwrap();
+    let kept = simulation.forces_mut().add(gravity).unwrap();
+    let explosion: TimedForce = "explosion:300,200,40,400@2".parse().unwrap();
+    simulation.forces_mut().add_timed(explosion).unwrap();
+    assert_eq!(simulation.forces().len(), 2);
+    simulation.step(DT);
+    assert_eq!(simulation.forces().len(), 2);
+    simulation.step(DT);
+    assert_eq!(simulation.forces().iter().map(|(id, _)| id).collect::<Vec<_>>(), [kept]);
+    assert!(simulation.forces_mut().remove(kept));
+    assert!(!simulation.forces_mut().remove(kept));
+    assert!(simulation.forces().is_empty());
+}
+
+#[test]
+fn parses_force_specifications() {
+    assert_eq!(
+        "vortex:1,2,3,4@10".parse::<TimedForce>().unwrap(),
+        TimedForce {
+            force: Force::Vortex {
+                center: [1.0, 2.0],
+                strength: 3.0,
+                core_radius: 4.0,
+            },
+            steps: Some(10),
+        }
+    );
+    assert_eq!("drag:0.5".parse::<Force>().unwrap(), Force::LinearDrag { coefficient: 0.5 });
+    for invalid in ["drag", "drag:1,2", "magnetism:1", "gravity:x,1", "drag:1@0"] {
+        assert!(invalid.parse::<TimedForce>().is_err(), "{invalid} was accepted");
+    }
+}
+
+/// Runs one step with a single object at (300, 300) and returns its velocity afterwards
+fn step_with_forces(test_device: &TestDevice, velocity: [f32; 2], forces: &[TimedForce]) -> [f32; 2] {
+    let TestDevice { device, queue, .. } = test_device;
+    let mut simulation = Simulation::new(single_object(velocity), device, queue);
+    for &force in forces {
+        simulation.forces_mut().add_timed(force).unwrap();
+    }
+    simulation.step(DT);
+    simulation.read_objects()[0].velocity
+}
+
+fn single_object(velocity: [f32; 2]) -> Objects {
+    let mut objects = Objects::default();
+    objects.push(ObjectPrototype {
+        velocity,
+        ..circle([300.0, 300.0], 1.0)
+    });
+    objects
+}
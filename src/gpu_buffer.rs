@@ -6,7 +6,12 @@ use std::{
 
 use bytemuck::{NoUninit, Pod};
 use crossbeam::sync::WaitGroup;
-use wgpu::{Buffer, BufferSlice, COPY_BUFFER_ALIGNMENT, Device, MapMode, PollType, Queue};
+use wgpu::{
+    Buffer, BufferSlice, BufferUsages, COPY_BUFFER_ALIGNMENT, CommandEncoderDescriptor, Device, MapMode, PollType,
+    Queue,
+};
+
+use crate::util::DeviceUtil;
 
 #[derive(Clone)]
 pub struct GpuBuffer<T> {
@@ -68,9 +73,18 @@ impl<T> GpuBuffer<T> {
     where
         T: NoUninit,
     {
+        self.write_at(queue, 0, src);
+    }
+
+    /// Writes `src` starting at element `index`, the byte offset and size have to be multiples of 4
+    pub fn write_at(&self, queue: &Queue, index: usize, src: &[T])
+    where
+        T: NoUninit,
+    {
+        let offset = u64::try_from(index * size_of::<T>()).unwrap();
         let data_size = u64::try_from(size_of_val(src)).unwrap();
-        assert!(data_size <= self.buffer.size());
-        let mut view = queue.write_buffer_with(&self.buffer, 0, data_size.try_into().unwrap()).unwrap();
+        assert!(offset + data_size <= self.buffer.size());
+        let mut view = queue.write_buffer_with(&self.buffer, offset, data_size.try_into().unwrap()).unwrap();
         view.as_mut().copy_from_slice(bytemuck::cast_slice(src));
     }
 
@@ -99,4 +113,18 @@ impl<T> GpuBuffer<T> {
         drop(view);
         self.buffer.unmap();
     }
+
+    /// Copies the first `length` elements of a `COPY_SRC` buffer into a new staging buffer and reads them
+    pub fn read_back(&self, device: &Device, queue: &Queue, length: usize) -> Vec<T>
+    where
+        T: Pod,
+    {
+        let staging = Self::new(length, "readback buffer", BufferUsages::MAP_READ | BufferUsages::COPY_DST, device);
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(&self.buffer, 0, staging.buffer(), 0, staging.buffer.size());
+        device.wait_for_submission(queue.submit([encoder.finish()])).unwrap();
+        let mut data = vec![T::zeroed(); length];
+        staging.read(device, &mut data);
+        data
+    }
 }
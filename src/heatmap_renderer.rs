@@ -0,0 +1,222 @@
+use wgpu::{
+    BlendState, BufferUsages, ColorTargetState, CommandEncoder, ComputePass, ComputePipeline, Device, MultisampleState,
+    PipelineCache, PrimitiveState, Queue, RenderPass, RenderPipeline, RenderPipelineDescriptor, TextureFormat,
+};
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    debug_panel::format_value,
+    gpu_buffer::GpuBuffer,
+    shaders::{
+        common::{AABB, Camera, Flags, Velocity},
+        heatmap,
+        heatmap_grid::{
+            HEATMAP_CELL_STRIDE, HEATMAP_QUANTITY_DENSITY, HEATMAP_QUANTITY_DIVERGENCE, HEATMAP_QUANTITY_SPEED,
+            HEATMAP_QUANTITY_VORTICITY, HeatmapParameters,
+        },
+        heatmap_splat::{self, WORKGROUP_SIZE, compute::create_splat_pipeline_embed_source},
+        ui::{FONT_GLYPH_HEIGHT, UiQuad},
+    },
+    ui_renderer::{push_text, text_width},
+};
+
+const GRID_SIZE: [u32; 2] = [256, 256];
+const OPACITY: f32 = 0.8;
+/// Corners of the legend bar in clip space, the same as in `heatmap.wgsl`
+const LEGEND_MIN: [f32; 2] = [0.5, -0.95];
+const LEGEND_MAX: [f32; 2] = [0.95, -0.9];
+/// Size of a font pixel of the legend's labels in screen pixels
+const LABEL_SCALE: f32 = 2.0;
+const LABEL_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// Field shown by the heatmap
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapQuantity {
+    /// Objects per cell
+    #[default]
+    Density,
+    /// Mean speed of the objects in a cell
+    MeanSpeed,
+    /// Divergence of the mean velocity, positive where objects move apart
+    Divergence,
+    /// Curl of the mean velocity, positive for counterclockwise rotation
+    Vorticity,
+}
+
+impl HeatmapQuantity {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Density => Self::MeanSpeed,
+            Self::MeanSpeed => Self::Divergence,
+            Self::Divergence => Self::Vorticity,
+            Self::Vorticity => Self::Density,
+        }
+    }
+
+    /// Value that is mapped to the end of the colormap, signed quantities span `-range..range`
+    pub fn default_range(self) -> f32 {
+        match self {
+            Self::Density => 16.0,
+            Self::MeanSpeed => 80.0 * 80.0,
+            Self::Divergence | Self::Vorticity => 50.0,
+        }
+    }
+
+    /// Divergence and vorticity can be negative, their colormap is centered on zero
+    pub fn is_signed(self) -> bool {
+        matches!(self, Self::Divergence | Self::Vorticity)
+    }
+
+    fn to_wgsl(self) -> u32 {
+        match self {
+            Self::Density => HEATMAP_QUANTITY_DENSITY,
+            Self::MeanSpeed => HEATMAP_QUANTITY_SPEED,
+            Self::Divergence => HEATMAP_QUANTITY_DIVERGENCE,
+            Self::Vorticity => HEATMAP_QUANTITY_VORTICITY,
+        }
+    }
+}
+
+/// Splats the objects into a grid over the world and draws it as a colored overlay with a legend
+pub struct HeatmapRenderer {
+    splat_pipeline: ComputePipeline,
+    splat_bind_group: heatmap_splat::WgpuBindGroup0,
+    overlay_pipeline: RenderPipeline,
+    legend_pipeline: RenderPipeline,
+    render_bind_group: heatmap::WgpuBindGroup0,
+    parameters: GpuBuffer<HeatmapParameters>,
+    cells: GpuBuffer<i32>,
+    world_aabb: AABB,
+    object_count: usize,
+}
+
+impl HeatmapRenderer {
+    pub fn new(
+        device: &Device,
+        target_format: TextureFormat,
+        pipeline_cache: Option<&PipelineCache>,
+        world_aabb: AABB,
+        camera: GpuBuffer<Camera>,
+        flags: GpuBuffer<Flags>,
+        aabbs: GpuBuffer<AABB>,
+        velocities: GpuBuffer<Velocity>,
+    ) -> Self {
+        let parameters =
+            GpuBuffer::new(1, "heatmap parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        let cell_count = (GRID_SIZE[0] * GRID_SIZE[1] * HEATMAP_CELL_STRIDE) as usize;
+        let cells =
+            GpuBuffer::new(cell_count, "heatmap cells buffer", BufferUsages::STORAGE | BufferUsages::COPY_DST, device);
+
+        let splat_bind_group = heatmap_splat::WgpuBindGroup0::from_bindings(
+            device,
+            heatmap_splat::WgpuBindGroup0Entries::new(heatmap_splat::WgpuBindGroup0EntriesParams {
+                parameters: parameters.buffer().as_entire_buffer_binding(),
+                flags: flags.buffer().as_entire_buffer_binding(),
+                aabbs: aabbs.buffer().as_entire_buffer_binding(),
+                velocities: velocities.buffer().as_entire_buffer_binding(),
+                cells: cells.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        let render_bind_group = heatmap::WgpuBindGroup0::from_bindings(
+            device,
+            heatmap::WgpuBindGroup0Entries::new(heatmap::WgpuBindGroup0EntriesParams {
+                camera: camera.buffer().as_entire_buffer_binding(),
+                parameters: parameters.buffer().as_entire_buffer_binding(),
+                cells: cells.buffer().as_entire_buffer_binding(),
+            }),
+        );
+
+        let pipeline_layout = heatmap::create_pipeline_layout(device);
+        let shader = heatmap::create_shader_module_embed_source(device);
+        let color_target_state = ColorTargetState {
+            blend: Some(BlendState::ALPHA_BLENDING),
+            ..ColorTargetState::from(target_format)
+        };
+        let overlay_vertex_entry = heatmap::vs_overlay_entry();
+        let overlay_fragment_entry = heatmap::fs_overlay_entry([Some(color_target_state.clone())]);
+        let legend_vertex_entry = heatmap::vs_legend_entry();
+        let legend_fragment_entry = heatmap::fs_legend_entry([Some(color_target_state)]);
+        let create_pipeline = |vertex_entry, fragment_entry| {
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                label: None,
+                layout: Some(&pipeline_layout),
+                vertex: heatmap::vertex_state(&shader, vertex_entry),
+                fragment: Some(heatmap::fragment_state(&shader, fragment_entry)),
+                primitive: PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: MultisampleState::default(),
+                multiview: None,
+                cache: pipeline_cache,
+            })
+        };
+
+        Self {
+            splat_pipeline: create_splat_pipeline_embed_source(device),
+            splat_bind_group,
+            overlay_pipeline: create_pipeline(&overlay_vertex_entry, &overlay_fragment_entry),
+            legend_pipeline: create_pipeline(&legend_vertex_entry, &legend_fragment_entry),
+            render_bind_group,
+            parameters,
+            cells,
+            world_aabb,
+            object_count: flags.len(),
+        }
+    }
+
+    pub fn set_parameters(&self, queue: &Queue, quantity: HeatmapQuantity, range: f32) {
+        let parameters = HeatmapParameters::new(
+            self.world_aabb.min,
+            self.world_aabb.max,
+            GRID_SIZE,
+            quantity.to_wgsl(),
+            range,
+            OPACITY,
+        );
+        self.parameters.write(queue, &[parameters]);
+    }
+
+    /// Clears the grid, must be called before [`Self::compute`] in the same encoder
+    pub fn clear(&self, encoder: &mut CommandEncoder) {
+        encoder.clear_buffer(self.cells.buffer(), 0, None);
+    }
+
+    pub fn compute(&self, compute_pass: &mut ComputePass) {
+        compute_pass.set_pipeline(&self.splat_pipeline);
+        self.splat_bind_group.set(compute_pass);
+        let total_workgroups = u32::try_from(self.object_count).unwrap().div_ceil(WORKGROUP_SIZE);
+        let x = total_workgroups.min(65535);
+        let y = total_workgroups.div_ceil(65535);
+        compute_pass.dispatch_workgroups(x, y, 1);
+    }
+
+    /// Draws the grid over the world and the colormap legend in the corner
+    pub fn render(&self, render_pass: &mut RenderPass<'_>) {
+        render_pass.set_pipeline(&self.overlay_pipeline);
+        self.render_bind_group.set(render_pass);
+        render_pass.draw(0..6, 0..1);
+        render_pass.set_pipeline(&self.legend_pipeline);
+        render_pass.draw(0..6, 0..1);
+    }
+}
+
+/// Quads for the [`UiRenderer`](crate::ui_renderer::UiRenderer) that label the ends of the legend bar with the values
+/// they stand for, above the bar
+pub fn legend_labels(view_size: PhysicalSize<u32>, quantity: HeatmapQuantity, range: f32) -> Vec<UiQuad> {
+    let to_pixels = |[x, y]: [f32; 2]| {
+        [
+            (x + 1.0) / 2.0 * view_size.width as f32,
+            (1.0 - y) / 2.0 * view_size.height as f32,
+        ]
+    };
+    let [left, _] = to_pixels(LEGEND_MIN);
+    let [right, top] = to_pixels(LEGEND_MAX);
+    let y = top - (FONT_GLYPH_HEIGHT as f32 + 2.0) * LABEL_SCALE;
+    let min = if quantity.is_signed() { -range } else { 0.0 };
+    let min_text = if min == 0.0 { "0".to_owned() } else { format_value(min) };
+    let max_text = format_value(range);
+
+    let mut quads = Vec::new();
+    push_text(&mut quads, &min_text, [left, y], LABEL_SCALE, LABEL_COLOR);
+    push_text(&mut quads, &max_text, [right - text_width(&max_text, LABEL_SCALE), y], LABEL_SCALE, LABEL_COLOR);
+    quads
+}
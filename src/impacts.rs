@@ -0,0 +1,185 @@
+use wgpu::{BufferUsages, CommandEncoder, ComputePassDescriptor, ComputePipeline, Device, Queue};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::{
+        constraints::Constraint,
+        fracture::{
+            self,
+            compute::{create_list_free_slots_pipeline_embed_source, create_resolve_impacts_pipeline_embed_source},
+        },
+        impacts::{
+            WORKGROUP_SIZE, WgpuBindGroup0, WgpuBindGroup0Entries, WgpuBindGroup0EntriesParams,
+            compute::{
+                create_add_offsets_pipeline_embed_source, create_find_impacts_pipeline_embed_source,
+                create_scan_totals_pipeline_embed_source, create_scan_workgroups_pipeline_embed_source,
+            },
+        },
+        materials::Impact,
+        spawn::{
+            self,
+            compute::{create_refit_filled_slots_pipeline_embed_source, create_spawn_circle_pipeline_embed_source},
+        },
+        tool_state::SpawnRequest,
+    },
+};
+
+/// Merges circles that hit each other above their materials' merge speed and shatters circles above their shatter
+/// speed, with the fragments spawned into free slots. The paint tool's circles take the free slots left over. Runs
+/// after the accretion pass, on the integrated state, and grows the BVH around the filled slots.
+pub struct ImpactResolver {
+    find_pipeline: ComputePipeline,
+    scan_workgroups_pipeline: ComputePipeline,
+    scan_totals_pipeline: ComputePipeline,
+    add_offsets_pipeline: ComputePipeline,
+    list_free_slots_pipeline: ComputePipeline,
+    resolve_pipeline: ComputePipeline,
+    spawn_pipeline: ComputePipeline,
+    refit_pipeline: ComputePipeline,
+    bind_group: WgpuBindGroup0,
+    fracture_bind_group: fracture::WgpuBindGroup0,
+    spawn_bind_group: spawn::WgpuBindGroup0,
+    object_count: u32,
+}
+
+impl ImpactResolver {
+    /// Returns `None` if no object has a material and there are no free slots, nothing could merge, shatter or be
+    /// spawned then. Objects with constraints keep their slots, a fragment would inherit them.
+    pub fn new(
+        device: &Device,
+        queue: &Queue,
+        has_materials: bool,
+        has_free_slots: bool,
+        constraints: &[Constraint],
+        buffers: &ObjectBuffers,
+        spawn_request: &GpuBuffer<SpawnRequest>,
+    ) -> Option<Self> {
+        if !has_materials && !has_free_slots {
+            return None;
+        }
+
+        let object_count = buffers.flags.len();
+        let mut linked = vec![0u32; object_count];
+        for constraint in constraints {
+            linked[constraint.a as usize] = 1;
+            linked[constraint.b as usize] = 1;
+        }
+        let storage = BufferUsages::STORAGE | BufferUsages::COPY_DST;
+        let linked_buffer = GpuBuffer::new(object_count, "linked object buffer", storage, device);
+        linked_buffer.write(queue, &linked);
+        let impacts = GpuBuffer::<Impact>::new(object_count, "impact buffer", storage, device);
+        let prefixes = GpuBuffer::<[u32; 2]>::new(object_count + 1, "impact prefix buffer", storage, device);
+        let workgroup_count = object_count.div_ceil(WORKGROUP_SIZE as usize);
+        let workgroup_totals =
+            GpuBuffer::<[u32; 2]>::new(workgroup_count + 1, "impact workgroup total buffer", storage, device);
+        let free_slots = GpuBuffer::<u32>::new(object_count, "free slot buffer", storage, device);
+
+        let bind_group = WgpuBindGroup0::from_bindings(
+            device,
+            WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
+                material_table: buffers.material_table.buffer().as_entire_buffer_binding(),
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                shapes: buffers.shapes.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                nodes: buffers.bvh_nodes.buffer().as_entire_buffer_binding(),
+                velocities: buffers.velocities.buffer().as_entire_buffer_binding(),
+                materials: buffers.materials.buffer().as_entire_buffer_binding(),
+                linked: linked_buffer.buffer().as_entire_buffer_binding(),
+                impacts: impacts.buffer().as_entire_buffer_binding(),
+                prefixes: prefixes.buffer().as_entire_buffer_binding(),
+                workgroup_totals: workgroup_totals.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        let fracture_bind_group = fracture::WgpuBindGroup0::from_bindings(
+            device,
+            fracture::WgpuBindGroup0Entries::new(fracture::WgpuBindGroup0EntriesParams {
+                material_table: buffers.material_table.buffer().as_entire_buffer_binding(),
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                masses: buffers.masses.buffer().as_entire_buffer_binding(),
+                sizes: buffers.sizes.buffer().as_entire_buffer_binding(),
+                shapes: buffers.shapes.buffer().as_entire_buffer_binding(),
+                colors: buffers.colors.buffer().as_entire_buffer_binding(),
+                moments: buffers.moments.buffer().as_entire_buffer_binding(),
+                materials: buffers.materials.buffer().as_entire_buffer_binding(),
+                integrated_aabbs: buffers.integrated_aabbs.buffer().as_entire_buffer_binding(),
+                integrated_velocities: buffers.integrated_velocities.buffer().as_entire_buffer_binding(),
+                integrated_angles: buffers.integrated_angles.buffer().as_entire_buffer_binding(),
+                angular_velocities: buffers.angular_velocities.buffer().as_entire_buffer_binding(),
+                impacts: impacts.buffer().as_entire_buffer_binding(),
+                prefixes: prefixes.buffer().as_entire_buffer_binding(),
+                free_slots: free_slots.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        let spawn_bind_group = spawn::WgpuBindGroup0::from_bindings(
+            device,
+            spawn::WgpuBindGroup0Entries::new(spawn::WgpuBindGroup0EntriesParams {
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                masses: buffers.masses.buffer().as_entire_buffer_binding(),
+                sizes: buffers.sizes.buffer().as_entire_buffer_binding(),
+                shapes: buffers.shapes.buffer().as_entire_buffer_binding(),
+                colors: buffers.colors.buffer().as_entire_buffer_binding(),
+                moments: buffers.moments.buffer().as_entire_buffer_binding(),
+                materials: buffers.materials.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                integrated_aabbs: buffers.integrated_aabbs.buffer().as_entire_buffer_binding(),
+                integrated_velocities: buffers.integrated_velocities.buffer().as_entire_buffer_binding(),
+                integrated_angles: buffers.integrated_angles.buffer().as_entire_buffer_binding(),
+                angular_velocities: buffers.angular_velocities.buffer().as_entire_buffer_binding(),
+                sleeping_nodes: buffers.sleeping_nodes.buffer().as_entire_buffer_binding(),
+                prefixes: prefixes.buffer().as_entire_buffer_binding(),
+                free_slots: free_slots.buffer().as_entire_buffer_binding(),
+                spawn_request: spawn_request.buffer().as_entire_buffer_binding(),
+            }),
+        );
+
+        Some(Self {
+            find_pipeline: create_find_impacts_pipeline_embed_source(device),
+            scan_workgroups_pipeline: create_scan_workgroups_pipeline_embed_source(device),
+            scan_totals_pipeline: create_scan_totals_pipeline_embed_source(device),
+            add_offsets_pipeline: create_add_offsets_pipeline_embed_source(device),
+            list_free_slots_pipeline: create_list_free_slots_pipeline_embed_source(device),
+            resolve_pipeline: create_resolve_impacts_pipeline_embed_source(device),
+            spawn_pipeline: create_spawn_circle_pipeline_embed_source(device),
+            refit_pipeline: create_refit_filled_slots_pipeline_embed_source(device),
+            bind_group,
+            fracture_bind_group,
+            spawn_bind_group,
+            object_count: u32::try_from(object_count).unwrap(),
+        })
+    }
+
+    pub fn compute(&self, encoder: &mut CommandEncoder) {
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("impact pass"),
+            timestamp_writes: None,
+        });
+        let total_workgroups = self.object_count.div_ceil(WORKGROUP_SIZE);
+        let dispatch = |compute_pass: &mut wgpu::ComputePass, workgroups: u32| {
+            compute_pass.dispatch_workgroups(workgroups.min(65535), workgroups.div_ceil(65535), 1);
+        };
+
+        // Slots are handed out by an exclusive prefix sum, per workgroup and then over the workgroup totals
+        for (pipeline, workgroups) in [
+            (&self.find_pipeline, total_workgroups),
+            (&self.scan_workgroups_pipeline, total_workgroups),
+            (&self.scan_totals_pipeline, 1),
+            (&self.add_offsets_pipeline, total_workgroups),
+        ] {
+            compute_pass.set_pipeline(pipeline);
+            self.bind_group.set(&mut compute_pass);
+            dispatch(&mut compute_pass, workgroups);
+        }
+        for pipeline in [&self.list_free_slots_pipeline, &self.resolve_pipeline] {
+            compute_pass.set_pipeline(pipeline);
+            self.fracture_bind_group.set(&mut compute_pass);
+            dispatch(&mut compute_pass, total_workgroups);
+        }
+        for pipeline in [&self.spawn_pipeline, &self.refit_pipeline] {
+            compute_pass.set_pipeline(pipeline);
+            self.spawn_bind_group.set(&mut compute_pass);
+            compute_pass.dispatch_workgroups(1, 1, 1);
+        }
+    }
+}
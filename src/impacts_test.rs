@@ -0,0 +1,129 @@
+use crate::{
+    DT,
+    objects::{ObjectPrototype, Objects},
+    shaders::{
+        common::{FLAG_DESTROYED, FLAG_PHYSICAL},
+        materials::Material,
+    },
+    simulation::Simulation,
+    test_util::{TestDevice, circle, test_device_or_skip},
+};
+
+#[test]
+fn merging_conserves_mass_momentum_and_area() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // Touching and approaching at 200
+    let run = |material: Option<Material>| {
+        let mut objects = Objects::default();
+        objects.push(moving([-900.0, 900.0], [100.0, 0.0], 2.0, 1.0));
+        objects.push(moving([-896.1, 900.0], [-100.0, 20.0], 4.0, 3.0));
+        if let Some(material) = material {
+            let clay = objects.add_material(material);
+            objects.set_material(0, clay);
+            objects.set_material(1, clay);
+        }
+        let mut simulation = Simulation::new(objects, device, queue);
+        simulation.step(DT);
+        simulation
+    };
+
+    // The black holes pull on the circles during the step, so the momentum is compared with a plain bounce
+    let bounced = run(None).read_objects();
+    let momentum = [0, 1].map(|axis| bounced[0].velocity[axis] * 2.0 + bounced[1].velocity[axis] * 4.0);
+    assert!(bounced.iter().all(|state| state.flags & FLAG_DESTROYED == 0));
+    // Too slow to merge
+    let slow = run(Some(Material::new(500.0, f32::MAX, 1, 0.0))).read_objects();
+    assert!(slow.iter().all(|state| state.flags & FLAG_DESTROYED == 0));
+
+    let simulation = run(Some(Material::new(50.0, f32::MAX, 1, 0.0)));
+    let states = simulation.read_objects();
+    let destroyed = states.iter().map(|state| state.flags & FLAG_DESTROYED != 0).collect::<Vec<_>>();
+    assert_eq!(destroyed, [false, true]);
+    let buffers = simulation.buffers();
+    assert_eq!(buffers.masses.read_back(device, queue, 2)[0].inner, 6.0);
+    let radius = buffers.sizes.read_back(device, queue, 2)[0].inner[0] / 2.0;
+    assert!((radius * radius - (1.0 + 9.0)).abs() < 1e-4, "{radius}");
+    let merged = states[0].velocity.map(|v| v * 6.0);
+    assert!((0..2).all(|axis| (merged[axis] - momentum[axis]).abs() < 1e-2), "{merged:?} {momentum:?}");
+}
+
+#[test]
+fn shattering_fills_free_slots_and_rewinds() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let run = |material: Option<Material>| {
+        let mut objects = Objects::default();
+        objects.push(moving([-900.0, 900.0], [100.0, 0.0], 3.0, 2.0));
+        // Inert, so only the first circle shatters
+        objects.push(moving([-896.1, 900.0], [-100.0, 0.0], 3.0, 2.0));
+        if let Some(material) = material {
+            let glass = objects.add_material(material);
+            objects.set_material(0, glass);
+        }
+        // Only two free slots for the three extra fragments
+        objects.push_free_slots(2);
+        let mut simulation = Simulation::new(objects, device, queue);
+        simulation.step(DT);
+        simulation
+    };
+
+    let bounced = run(None).read_objects();
+    let momentum = [0, 1].map(|axis| (bounced[0].velocity[axis] + bounced[1].velocity[axis]) * 3.0);
+
+    let mut simulation = run(Some(Material::new(f32::MAX, 50.0, 4, 0.1)));
+    let states = simulation.read_objects();
+    let physical = states.iter().map(|state| state.flags & FLAG_PHYSICAL != 0).collect::<Vec<_>>();
+    assert_eq!(physical, [true, true, true, true]);
+    let buffers = simulation.buffers();
+    let masses = buffers.masses.read_back(device, queue, 4);
+    let sizes = buffers.sizes.read_back(device, queue, 4);
+    let materials = buffers.materials.read_back(device, queue, 4);
+    let fragments = [0, 2, 3];
+    for index in fragments {
+        assert_eq!(masses[index].inner, 1.0);
+        assert!((sizes[index].inner[0] - 2.0 * 2.0 / 3.0f32.sqrt()).abs() < 1e-4);
+        assert_eq!(materials[index].inner, 1);
+    }
+    let shattered = [0, 1].map(|axis| {
+        fragments.iter().map(|&index| states[index].velocity[axis]).sum::<f32>() + states[1].velocity[axis] * 3.0
+    });
+    assert!((0..2).all(|axis| (shattered[axis] - momentum[axis]).abs() < 1e-2), "{shattered:?} {momentum:?}");
+    // The fragments fly apart
+    assert!(states[2].velocity != states[0].velocity && states[3].velocity != states[0].velocity);
+
+    // Rewinding restores the shattered circle and empties the slots
+    assert!(simulation.rewind());
+    assert_eq!(simulation.buffers().masses.read_back(device, queue, 1)[0].inner, 3.0);
+    let states = simulation.read_objects();
+    assert!(states[2..].iter().all(|state| state.flags == 0));
+}
+
+/// Moving circle, the merged and shattered ones have other masses than the default
+fn moving(position: [f32; 2], velocity: [f32; 2], mass: f32, radius: f32) -> ObjectPrototype {
+    ObjectPrototype {
+        velocity,
+        mass,
+        ..circle(position, radius)
+    }
+}
+
+#[test]
+fn fragments_are_not_swept_from_their_slots() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    objects.push(moving([-900.0, 900.0], [100.0, 0.0], 3.0, 2.0));
+    objects.push(moving([-896.1, 900.0], [-100.0, 0.0], 3.0, 2.0));
+    let glass = objects.add_material(Material::new(f32::MAX, 50.0, 3, 0.1));
+    objects.set_material(0, glass);
+    // The free slots are at the origin, a wall lies between them and the circles
+    objects.push_free_slots(2);
+    objects.level.add_segment([-1000.0, 850.0], [-800.0, 850.0]);
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.step(DT);
+
+    let states = simulation.read_objects();
+    assert!(states.iter().all(|state| state.flags & FLAG_PHYSICAL != 0));
+    for state in &states[2..] {
+        let [x, y] = state.position;
+        assert!((-905.0..=-890.0).contains(&x) && (895.0..=905.0).contains(&y), "{:?}", state.position);
+    }
+}
@@ -3,14 +3,48 @@ use wgpu::{ComputePass, ComputePipeline, Device};
 use crate::{
     gpu_buffer::GpuBuffer,
     shaders::{
-        common::{AABB, BvhNode, Flags, Mass, Velocity},
+        blackholes::BlackHoleSet,
+        common::{
+            AABB, Angle, AngularVelocity, Flags, Mass, Polygon, PolygonVertex, SecondMoments, Shape, Size, Velocity,
+        },
         integration::{
+            ForceSet, INTEGRATOR_EULER_SYMPLECTIC, INTEGRATOR_LEAPFROG, INTEGRATOR_RK4, IntegrationParameters,
             WORKGROUP_SIZE, WgpuBindGroup0, WgpuBindGroup0Entries, WgpuBindGroup0EntriesParams,
             compute::create_cs_main_pipeline_embed_source,
         },
+        tool_state::ToolState,
     },
 };
 
+/// Scheme used to advance positions and velocities by one step
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Integrator {
+    #[default]
+    EulerSymplectic,
+    /// Kick-drift-kick, evaluates the forces twice per step
+    Leapfrog,
+    /// Fourth order Runge-Kutta, evaluates the forces four times per step
+    Rk4,
+}
+
+impl Integrator {
+    pub fn next(self) -> Self {
+        match self {
+            Self::EulerSymplectic => Self::Leapfrog,
+            Self::Leapfrog => Self::Rk4,
+            Self::Rk4 => Self::EulerSymplectic,
+        }
+    }
+
+    pub fn to_wgsl(self) -> u32 {
+        match self {
+            Self::EulerSymplectic => INTEGRATOR_EULER_SYMPLECTIC,
+            Self::Leapfrog => INTEGRATOR_LEAPFROG,
+            Self::Rk4 => INTEGRATOR_RK4,
+        }
+    }
+}
+
 pub struct GpuIntegrator {
     pipeline: ComputePipeline,
     bind_group: WgpuBindGroup0,
@@ -20,27 +54,47 @@ pub struct GpuIntegrator {
 impl GpuIntegrator {
     pub fn new(
         device: &Device,
-        dt: GpuBuffer<f32>,
+        parameters: GpuBuffer<IntegrationParameters>,
         flags: GpuBuffer<Flags>,
         masses: GpuBuffer<Mass>,
         velocities: GpuBuffer<Velocity>,
         aabbs: GpuBuffer<AABB>,
-        nodes: GpuBuffer<BvhNode>,
         integrated_velocities: GpuBuffer<Velocity>,
         integrated_aabbs: GpuBuffer<AABB>,
+        shapes: GpuBuffer<Shape>,
+        tools: GpuBuffer<ToolState>,
+        force_set: GpuBuffer<ForceSet>,
+        sizes: GpuBuffer<Size>,
+        angles: GpuBuffer<Angle>,
+        angular_velocities: GpuBuffer<AngularVelocity>,
+        integrated_angles: GpuBuffer<Angle>,
+        polygons: GpuBuffer<Polygon>,
+        polygon_vertices: GpuBuffer<PolygonVertex>,
+        moments: GpuBuffer<SecondMoments>,
+        blackholes: GpuBuffer<BlackHoleSet>,
     ) -> Self {
         let pipeline = create_cs_main_pipeline_embed_source(device);
         let bind_group = WgpuBindGroup0::from_bindings(
             device,
             WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
-                dt: dt.buffer().as_entire_buffer_binding(),
+                parameters: parameters.buffer().as_entire_buffer_binding(),
                 flags: flags.buffer().as_entire_buffer_binding(),
                 masses: masses.buffer().as_entire_buffer_binding(),
                 velocities: velocities.buffer().as_entire_buffer_binding(),
                 aabbs: aabbs.buffer().as_entire_buffer_binding(),
-                nodes: nodes.buffer().as_entire_buffer_binding(),
                 integrated_velocities: integrated_velocities.buffer().as_entire_buffer_binding(),
                 integrated_aabbs: integrated_aabbs.buffer().as_entire_buffer_binding(),
+                shapes: shapes.buffer().as_entire_buffer_binding(),
+                tools: tools.buffer().as_entire_buffer_binding(),
+                force_set: force_set.buffer().as_entire_buffer_binding(),
+                sizes: sizes.buffer().as_entire_buffer_binding(),
+                angles: angles.buffer().as_entire_buffer_binding(),
+                angular_velocities: angular_velocities.buffer().as_entire_buffer_binding(),
+                integrated_angles: integrated_angles.buffer().as_entire_buffer_binding(),
+                polygons: polygons.buffer().as_entire_buffer_binding(),
+                polygon_vertices: polygon_vertices.buffer().as_entire_buffer_binding(),
+                moments: moments.buffer().as_entire_buffer_binding(),
+                blackhole_set: blackholes.buffer().as_entire_buffer_binding(),
             }),
         );
         Self {
@@ -0,0 +1,188 @@
+use std::{fs, path::Path, str::FromStr};
+
+use anyhow::{Context as _, anyhow, bail};
+use itertools::Itertools as _;
+use wgpu::{
+    BufferUsages, CommandEncoder, CommandEncoderDescriptor, ComputePassDescriptor, ComputePipeline, Device, Queue,
+};
+
+use crate::{
+    bvh_builder::BvhBuilder,
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::{
+        common::{AABB, BvhNode, Segment},
+        static_collision::{
+            WORKGROUP_SIZE, WgpuBindGroup0, WgpuBindGroup0Entries, WgpuBindGroup0EntriesParams,
+            compute::create_cs_main_pipeline_embed_source,
+        },
+    },
+    util::DeviceUtil,
+};
+
+/// Static walls that objects collide with but never move. They are kept apart from the objects, so that long walls
+/// don't stretch the objects' BVH.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Level {
+    pub segments: Vec<Segment>,
+}
+
+impl Level {
+    /// A closed box along the edges of `aabb`
+    pub fn borders(aabb: AABB) -> Self {
+        let [left, bottom] = aabb.min;
+        let [right, top] = aabb.max;
+        let mut level = Self::default();
+        level.add_polyline(&[[left, bottom], [right, bottom], [right, top], [left, top]], true);
+        level
+    }
+
+    /// Parses a level file, see [`Self::from_str`] for the format
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        text.parse().with_context(|| format!("Invalid level file {}", path.display()))
+    }
+
+    pub fn add_segment(&mut self, start: [f32; 2], end: [f32; 2]) {
+        self.segments.push(Segment::new(start, end));
+    }
+
+    /// Connects consecutive points, `closed` also connects the last point to the first
+    pub fn add_polyline(&mut self, points: &[[f32; 2]], closed: bool) {
+        for (&start, &end) in points.iter().tuple_windows() {
+            self.add_segment(start, end);
+        }
+        if let (true, [first, .., last]) = (closed, points) {
+            self.add_segment(*last, *first);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+/// One shape per line, points are `X,Y` separated by whitespace and `#` starts a comment:
+///
+/// ```text
+/// segment X,Y X,Y     A single wall
+/// polyline X,Y ...    Walls between consecutive points
+/// loop X,Y ...        Like polyline, closed back to the first point
+/// ```
+impl FromStr for Level {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let mut level = Self::default();
+        for (number, line) in s.lines().enumerate() {
+            let line = line.split_once('#').map_or(line, |(line, _)| line);
+            let mut words = line.split_whitespace();
+            let Some(kind) = words.next() else {
+                continue;
+            };
+            let points = words
+                .map(parse_point)
+                .collect::<anyhow::Result<Vec<_>>>()
+                .with_context(|| format!("Line {}", number + 1))?;
+            match (kind, points.len()) {
+                ("segment", 2) => level.add_segment(points[0], points[1]),
+                ("polyline", 2..) => level.add_polyline(&points, false),
+                ("loop", 3..) => level.add_polyline(&points, true),
+                ("segment" | "polyline" | "loop", count) => {
+                    bail!("Line {}: {kind} can't have {count} points", number + 1)
+                }
+                _ => bail!("Line {}: unknown shape {kind}", number + 1),
+            }
+        }
+        Ok(level)
+    }
+}
+
+fn parse_point(s: &str) -> anyhow::Result<[f32; 2]> {
+    let (x, y) = s.split_once(',').ok_or_else(|| anyhow!("Expected X,Y, got {s}"))?;
+    let coordinate = |c: &str| c.trim().parse::<f32>().with_context(|| format!("Invalid number: {c}"));
+    Ok([coordinate(x)?, coordinate(y)?])
+}
+
+fn segment_aabb(segment: &Segment) -> AABB {
+    let [start, end] = [segment.start, segment.end];
+    AABB {
+        min: [start[0].min(end[0]), start[1].min(end[1])],
+        max: [start[0].max(end[0]), start[1].max(end[1])],
+    }
+}
+
+/// Pushes objects out of the level after the integrator has moved them and bounces them off the walls. Circles and
+/// objects flagged with `FLAG_BULLET` are swept from their position before the step, so they can't pass through walls.
+pub struct LevelCollider {
+    pipeline: ComputePipeline,
+    bind_group: WgpuBindGroup0,
+    object_count: usize,
+}
+
+impl LevelCollider {
+    /// Builds the level's BVH, returns `None` if there are no segments, empty buffers can't be bound
+    pub fn new(device: &Device, queue: &Queue, level: &Level, buffers: &ObjectBuffers) -> Option<Self> {
+        if level.is_empty() {
+            return None;
+        }
+        let segment_count = level.len();
+
+        let storage = BufferUsages::STORAGE | BufferUsages::COPY_DST;
+        let aabbs = GpuBuffer::new(segment_count * 2, "static aabb buffer", storage, device);
+        aabbs.write(queue, &level.segments.iter().map(segment_aabb).collect_vec());
+        let nodes = GpuBuffer::new(segment_count * 2, "static bvh node buffer", storage, device);
+        nodes.write(queue, &(0..u32::try_from(segment_count).unwrap()).map(BvhNode::new).collect_vec());
+
+        // Zeroed, the walls never sleep
+        let sleeping_nodes = GpuBuffer::new(segment_count * 2, "static sleeping node buffer", storage, device);
+        let mut bvh_builder = BvhBuilder::new(device, aabbs.clone(), nodes.clone(), sleeping_nodes, segment_count);
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("static bvh pass"),
+            timestamp_writes: None,
+        });
+        bvh_builder.compute(&mut compute_pass);
+        drop(compute_pass);
+        device.wait_for_submission(queue.submit([encoder.finish()])).unwrap();
+
+        let bind_group = WgpuBindGroup0::from_bindings(
+            device,
+            WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                integrated_aabbs: buffers.integrated_aabbs.buffer().as_entire_buffer_binding(),
+                integrated_velocities: buffers.integrated_velocities.buffer().as_entire_buffer_binding(),
+                integrated_angles: buffers.integrated_angles.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                segments: buffers.segments.buffer().as_entire_buffer_binding(),
+                static_aabbs: aabbs.buffer().as_entire_buffer_binding(),
+                static_nodes: nodes.buffer().as_entire_buffer_binding(),
+                wall_normals: buffers.wall_normals.buffer().as_entire_buffer_binding(),
+                shapes: buffers.shapes.buffer().as_entire_buffer_binding(),
+                sizes: buffers.sizes.buffer().as_entire_buffer_binding(),
+                polygons: buffers.polygons.buffer().as_entire_buffer_binding(),
+                polygon_vertices: buffers.polygon_vertices.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        Some(Self {
+            pipeline: create_cs_main_pipeline_embed_source(device),
+            bind_group,
+            object_count: buffers.flags.len(),
+        })
+    }
+
+    pub fn compute(&self, encoder: &mut CommandEncoder) {
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("static collision pass"),
+            timestamp_writes: None,
+        });
+        compute_pass.set_pipeline(&self.pipeline);
+        self.bind_group.set(&mut compute_pass);
+        let total_workgroups = u32::try_from(self.object_count).unwrap().div_ceil(WORKGROUP_SIZE);
+        compute_pass.dispatch_workgroups(total_workgroups.min(65535), total_workgroups.div_ceil(65535), 1);
+    }
+}
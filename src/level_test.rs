@@ -0,0 +1,96 @@
+use crate::{
+    DT,
+    level::Level,
+    objects::{ObjectPrototype, Objects},
+    shaders::common::{FLAG_BULLET, SHAPE_CIRCLE, SHAPE_RECT, Segment},
+    simulation::Simulation,
+    test_util::{TestDevice, object, test_device_or_skip},
+};
+
+#[test]
+fn level_files_parse_into_segments() {
+    let level: Level = "\
+        # A funnel
+        segment -10,0 10,0
+        polyline 0,0 1,1  2,0   # trailing comment
+
+        loop 0,0 1,0 0,1
+    "
+    .parse()
+    .unwrap();
+    let expected = [
+        ([-10.0, 0.0], [10.0, 0.0]),
+        ([0.0, 0.0], [1.0, 1.0]),
+        ([1.0, 1.0], [2.0, 0.0]),
+        ([0.0, 0.0], [1.0, 0.0]),
+        ([1.0, 0.0], [0.0, 1.0]),
+        ([0.0, 1.0], [0.0, 0.0]),
+    ];
+    assert_eq!(level.segments, expected.map(|(start, end)| Segment::new(start, end)).to_vec());
+
+    assert!("segment 0,0".parse::<Level>().is_err());
+    assert!("loop 0,0 1,1".parse::<Level>().is_err());
+    assert!("segment 0,0 1;1".parse::<Level>().is_err());
+    assert!("circle 0,0 1,1".parse::<Level>().is_err());
+}
+
+#[test]
+fn walls_keep_objects_inside() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // Fast enough to leave the box within a few dozen steps without the walls
+    let mut objects = Objects::default();
+    objects.push(ObjectPrototype {
+        velocity: [0.0, -400.0],
+        ..object(SHAPE_CIRCLE, [-900.0, 900.0], [4.0, 4.0])
+    });
+    objects.push(ObjectPrototype {
+        velocity: [300.0, 200.0],
+        ..object(SHAPE_RECT, [-905.0, 905.0], [6.0, 2.0])
+    });
+    objects.level = "loop -920,880 -880,880 -880,920 -920,920".parse().unwrap();
+    let mut simulation = Simulation::new(objects, device, queue);
+    let mut bounced = false;
+    for _ in 0..500 {
+        simulation.step(DT);
+        bounced |= simulation.read_objects()[0].velocity[1] > 0.0;
+    }
+
+    assert!(bounced);
+    for state in simulation.read_objects() {
+        let [x, y] = state.position;
+        assert!((-920.0..=-880.0).contains(&x) && (880.0..=920.0).contains(&y), "{:?}", state.position);
+    }
+}
+
+#[test]
+fn fast_circles_and_bullets_do_not_tunnel_through_walls() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // Each object moves 20 units per step, so it is fully past the wall after a single step
+    let velocity = [20.0 / DT, 0.0];
+    let run = |shape: u32, size: [f32; 2], bullet: bool| {
+        let mut objects = Objects::default();
+        let mut prototype = ObjectPrototype {
+            velocity,
+            ..object(shape, [-900.0, 900.0], size)
+        };
+        if bullet {
+            prototype.flags |= FLAG_BULLET;
+        }
+        objects.push(prototype);
+        objects.level.add_segment([-890.0, 880.0], [-890.0, 920.0]);
+        let mut simulation = Simulation::new(objects, device, queue);
+        for _ in 0..10 {
+            simulation.step(DT);
+        }
+        simulation.read_objects()[0]
+    };
+
+    for (shape, size, bullet) in [(SHAPE_CIRCLE, [4.0, 4.0], false), (SHAPE_RECT, [6.0, 2.0], true)] {
+        let state = run(shape, size, bullet);
+        assert!(state.position[0] < -890.0, "shape {shape} at {:?}", state.position);
+        assert!(state.velocity[0] < 0.0, "shape {shape} moving at {:?}", state.velocity);
+    }
+    // Without the sweep only the final position is tested
+    let state = run(SHAPE_RECT, [6.0, 2.0], false);
+    assert!(state.position[0] > -890.0, "{:?}", state.position);
+}
@@ -0,0 +1,127 @@
+//! GPU rigid body simulation with a BVH broad phase. [`Simulation`] runs the physics on object buffers, the renderers
+//! in [`render`] draw them.
+
+#![allow(clippy::too_many_arguments)]
+
+pub mod aabb;
+pub mod aabb_renderer;
+pub mod blackhole_renderer;
+pub mod blackholes;
+#[cfg(test)]
+mod blackholes_test;
+pub mod bvh_builder;
+pub mod constraints;
+#[cfg(test)]
+mod constraints_test;
+pub mod contacts;
+#[cfg(test)]
+mod contacts_test;
+pub mod debug_panel;
+#[cfg(test)]
+mod debug_panel_test;
+pub mod diagnostics;
+#[cfg(test)]
+mod diagnostics_test;
+pub mod forces;
+#[cfg(test)]
+mod forces_test;
+pub mod gpu_buffer;
+pub mod heatmap_renderer;
+pub mod impacts;
+#[cfg(test)]
+mod impacts_test;
+pub mod integration;
+pub mod level;
+#[cfg(test)]
+mod level_test;
+#[cfg(test)]
+mod mock_bvh_test;
+pub mod objects;
+pub mod offscreen;
+pub mod options;
+pub mod pass_duration;
+pub mod picker;
+#[cfg(test)]
+mod picker_test;
+pub mod render;
+#[cfg(test)]
+mod render_golden_test;
+pub mod replay;
+#[cfg(test)]
+mod rotation_test;
+pub mod scene;
+pub mod segment_renderer;
+pub mod shaders;
+pub mod shape_renderer;
+#[cfg(test)]
+mod shapes_test;
+pub mod simulation;
+#[cfg(test)]
+mod simulation_test;
+pub mod sleep;
+#[cfg(test)]
+mod sleep_test;
+pub mod stats;
+#[cfg(test)]
+mod stats_test;
+#[cfg(test)]
+mod test_util;
+pub mod tools;
+#[cfg(test)]
+mod tools_test;
+pub mod trail_renderer;
+#[cfg(test)]
+mod trail_renderer_test;
+pub mod trajectory;
+#[cfg(test)]
+mod trajectory_test;
+pub mod ui_renderer;
+#[allow(unused)]
+pub mod util;
+
+use std::mem::size_of;
+
+use pollster::block_on;
+use wgpu::RequestAdapterOptions;
+
+use crate::shaders::bvh::CombineNodePass;
+pub use crate::{
+    objects::{ObjectBuffers, ObjectPrototype, ObjectState, Objects},
+    simulation::Simulation,
+};
+
+/// Default step size
+pub const DT: f32 = 0.001;
+
+/// Requests an adapter, the power preference can be set with `WGPU_POWER_PREF`
+pub fn request_adapter(wgpu: &wgpu::Instance, compatible_surface: Option<&wgpu::Surface<'_>>) -> wgpu::Adapter {
+    block_on(wgpu.request_adapter(&RequestAdapterOptions {
+        power_preference: wgpu::PowerPreference::from_env().unwrap_or(wgpu::PowerPreference::None),
+        force_fallback_adapter: false,
+        compatible_surface,
+    }))
+    .expect("Failed to find an appropriate adapter")
+}
+
+/// Requests a device with the features and limits the simulation and the renderers need
+pub fn request_device(adapter: &wgpu::Adapter) -> (wgpu::Device, wgpu::Queue) {
+    let mut required_limits = wgpu::Limits::defaults().using_resolution(adapter.limits());
+    required_limits.max_push_constant_size = u32::try_from(size_of::<CombineNodePass>()).unwrap();
+    // The integrator binds more storage buffers than the default limit allows
+    required_limits.max_storage_buffers_per_shader_stage = adapter.limits().max_storage_buffers_per_shader_stage;
+    // Pipeline caching is only an optimization, so adapters without it are still usable
+    let optional_features = adapter.features() & wgpu::Features::PIPELINE_CACHE;
+    block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+        label: None,
+        required_features: wgpu::Features::TIMESTAMP_QUERY
+            | wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS
+            | wgpu::Features::PUSH_CONSTANTS
+            | wgpu::Features::POLYGON_MODE_LINE
+            | optional_features,
+        required_limits,
+        experimental_features: wgpu::ExperimentalFeatures::disabled(),
+        memory_hints: wgpu::MemoryHints::Performance,
+        trace: wgpu::Trace::Off,
+    }))
+    .expect("Failed to create device")
+}
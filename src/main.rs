@@ -1,79 +1,105 @@
 #![allow(clippy::too_many_arguments)]
 
-pub mod aabb;
-pub mod aabb_renderer;
-pub mod bvh_builder;
-pub mod gpu_buffer;
-pub mod integration;
-#[cfg(test)]
-mod mock_bvh_test;
-pub mod objects;
-pub mod pass_duration;
-pub mod scene;
-pub mod shaders;
-pub mod shape_renderer;
-#[allow(unused)]
-pub mod util;
-
-use crate::{
-    aabb_renderer::AabbRenderer,
-    bvh_builder::BvhBuilder,
+use collision2::{
+    DT,
+    debug_panel::{DebugPanel, PanelInput, PanelUi, format_value},
     gpu_buffer::GpuBuffer,
-    integration::GpuIntegrator,
+    heatmap_renderer::HeatmapQuantity,
+    integration::Integrator,
+    level::Level,
     objects::Objects,
-    pass_duration::PassDurationMeasurer,
-    scene::create_scene,
+    offscreen::OffscreenTarget,
+    options::{HeadlessOptions, Options, USAGE},
+    picker::{Picker, describe},
+    render::{
+        RenderParameters, Renderers, create_renderers, orthographic_camera, render_overlay, render_scene,
+        screen_to_world,
+    },
+    replay::{Replay, replay_objects},
+    request_adapter, request_device,
+    scene::{create_scene, world_aabb},
     shaders::{
-        bvh::CombineNodePass,
-        common::{AABB, BvhNode, Camera},
+        common::{AABB, Camera},
+        tool_state::ToolState,
     },
-    shape_renderer::ShapeRenderer,
-    util::DeviceUtil,
+    shape_renderer::ColorMode,
+    simulation::{Simulation, SimulationCommand, SimulationStatus},
+    stats::StatsWriter,
+    tools::{Tool, Tools},
+    trail_renderer::{TrailFade, TrailRenderer},
+    trajectory::{TrajectoryHeader, TrajectoryReader, TrajectoryRecorder},
+    util::spawn_device_poller,
 };
 use crossbeam::channel::{Receiver, Sender};
-use pollster::block_on;
-use shaders::common::{Flags, Mass, Velocity};
+use rand::{SeedableRng, rngs::StdRng};
 use std::{
-    mem::size_of,
-    ops::Range,
+    fs, mem,
+    path::Path,
+    process::exit,
     sync::{
-        Arc,
+        Arc, Mutex,
         atomic::{AtomicBool, AtomicU32, Ordering},
     },
-    thread::{self},
-    time::{Duration, Instant},
-};
-use wgpu::{
-    BufferUsages, CommandEncoderDescriptor, ComputePassDescriptor, PipelineCacheDescriptor, PollType, PresentMode,
-    RenderPassColorAttachment, RenderPassDescriptor, RequestAdapterOptions, SubmissionIndex, TextureFormat,
-    TextureView, TextureViewDescriptor,
+    thread::{self, JoinHandle},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
+use wgpu::{BufferUsages, PresentMode, SubmissionIndex, TextureFormat, TextureViewDescriptor};
 use winit::{
     application::ApplicationHandler,
-    dpi::PhysicalSize,
-    event::{ElementState, KeyEvent, MouseScrollDelta, WindowEvent},
+    dpi::{PhysicalPosition, PhysicalSize},
+    event::{ElementState, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::{ActiveEventLoop, EventLoop, EventLoopProxy},
     keyboard::{Key, KeyCode, NamedKey, PhysicalKey},
     window::{Fullscreen, Window, WindowAttributes, WindowId},
 };
 
+const HEADLESS_FORMAT: TextureFormat = TextureFormat::Rgba8UnormSrgb;
+/// Recorded frames shown per second during a replay at a time scale of 1
+const REPLAY_FRAME_RATE: f32 = 60.0;
+/// Hidden objects appended to the scene, tools spawn new objects and shattering circles their fragments into them
+const SPAWN_SLOT_COUNT: usize = 4096;
+
 fn main() {
+    // The status messages are logged at the info level, so they show up unless RUST_LOG says otherwise
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("error,collision2=info")).init();
+    let options = Options::from_args(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}\n\n{USAGE}");
+        exit(2);
+    });
+
+    if let Some(headless_options) = &options.headless {
+        if let Err(error) = run_headless(&options, headless_options) {
+            eprintln!("Headless run failed: {error:#}");
+            exit(1);
+        }
+        return;
+    }
+
     let event_loop = EventLoop::with_user_event().build().expect("Failed to create event loop");
     let event_loop_proxy = event_loop.create_proxy();
-    let mut app = App::new(event_loop_proxy);
+    let mut app = App::new(options, event_loop_proxy);
     event_loop.run_app(&mut app).expect("Failed to run app");
 }
 
 struct App<'a> {
+    options: Options,
     render_parameters: RenderParameters,
+    cursor_position: PhysicalPosition<f64>,
+    debug_panel: DebugPanel,
     gpu_state: Option<GpuState<'a>>,
     _event_loop_proxy: EventLoopProxy<AppEvent>,
 }
 
 impl App<'_> {
-    fn new(event_loop_proxy: EventLoopProxy<AppEvent>) -> Self {
+    fn new(options: Options, event_loop_proxy: EventLoopProxy<AppEvent>) -> Self {
         Self {
-            render_parameters: RenderParameters::default(),
+            render_parameters: RenderParameters {
+                trails: options.trails,
+                ..RenderParameters::default()
+            },
+            options,
+            cursor_position: PhysicalPosition::default(),
+            debug_panel: DebugPanel::default(),
             gpu_state: None,
             _event_loop_proxy: event_loop_proxy,
         }
@@ -83,31 +109,16 @@ impl App<'_> {
 #[derive(Copy, Clone, Debug)]
 enum AppEvent {}
 
-struct RenderParameters {
-    enabled: bool,
-    draw_aabbs: bool,
-    zoom: f32,
-}
-
-impl Default for RenderParameters {
-    fn default() -> Self {
-        Self {
-            enabled: true,
-            draw_aabbs: false,
-            zoom: 0.8,
-        }
-    }
-}
-
 struct GpuState<'a> {
-    shape_renderer: ShapeRenderer,
-    aabb_renderer: AabbRenderer,
+    scene: SceneState,
+    /// Screenshots draw their trails separately, starting from the ones on screen, so they don't disturb them
+    screenshot_trail_renderer: TrailRenderer,
+    /// Stops the device poller
     exit_requested: Arc<AtomicBool>,
     world_aabb: AABB,
-    object_count: usize,
-    camera: GpuBuffer<Camera>,
-    node_count_atomic: Arc<AtomicU32>,
-    render_start_sender: Sender<SubmissionIndex>,
+    title: String,
+    /// GPU time of the last frame's render passes
+    render_duration: Duration,
 
     surface_config: wgpu::SurfaceConfiguration,
     queue: wgpu::Queue,
@@ -116,6 +127,125 @@ struct GpuState<'a> {
     window: Arc<Window>,
 }
 
+/// The scene with the thread simulating or replaying it and everything bound to its buffers, replaced as a whole when
+/// the scene is reloaded
+struct SceneState {
+    renderers: Renderers,
+    picker: Picker,
+    tools: Tools,
+    object_count: usize,
+    node_count_atomic: Arc<AtomicU32>,
+    render_start_sender: Sender<SubmissionIndex>,
+    /// Stops the simulation thread, unlike `exit_requested` it leaves the device poller running
+    stop_requested: Arc<AtomicBool>,
+    /// Joined on exit and before reloading, so that the statistics file is complete
+    simulation_thread: Option<JoinHandle<()>>,
+    command_sender: Sender<SimulationCommand>,
+    status: Arc<Mutex<SimulationStatus>>,
+}
+
+impl SceneState {
+    /// Creates the scene from the options and starts simulating or replaying it. The seed gives the same scene every
+    /// time, reloading only picks up changes to the level and replay files. Statistics and trajectories are only
+    /// written with `record`, so that a reload doesn't overwrite the first scene's.
+    fn load(
+        options: &Options,
+        record: bool,
+        world_aabb: AABB,
+        format: TextureFormat,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> anyhow::Result<Self> {
+        log::info!("Seed: {}", options.seed);
+        let mut rng = StdRng::seed_from_u64(options.seed);
+        let (objects, replay_reader) = create_objects(options, world_aabb, &mut rng)?;
+        let object_count = objects.len();
+        let record_header = match (&options.record, record) {
+            (Some(_), true) => Some(TrajectoryHeader::from_objects(
+                &objects,
+                options.record_objects.as_deref(),
+                options.record_interval,
+            )?),
+            _ => None,
+        };
+        log::info!("Object count: {}", object_count);
+
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let node_count_atomic = Arc::new(AtomicU32::new(u32::try_from(object_count).unwrap()));
+        let (render_start_sender, render_start_receiver) = crossbeam::channel::bounded(1);
+        let (command_sender, command_receiver) = crossbeam::channel::unbounded();
+        let status = Arc::new(Mutex::new(SimulationStatus::default()));
+
+        let (buffers, tool_state, spawn_request, simulation_thread) = if let Some(reader) = replay_reader {
+            let buffers = objects.to_buffers(device, queue);
+            let replay = Replay::new(reader, &buffers, device, queue);
+            // The tools still work on the buffers, but nothing reads their uniform
+            let tool_state = GpuBuffer::<ToolState>::new(
+                1,
+                "tool state buffer",
+                BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+                device,
+            );
+            let replay_thread = spawn_replay_thread(
+                replay,
+                stop_requested.clone(),
+                node_count_atomic.clone(),
+                status.clone(),
+                render_start_receiver,
+                command_receiver,
+            );
+            // Nothing is simulated, so there is nothing to spawn into
+            (buffers, tool_state, None, replay_thread)
+        } else {
+            let simulation = create_simulation(options, objects, world_aabb, device, queue)?;
+            let buffers = simulation.buffers().clone();
+            let stats = match (&options.stats, record) {
+                (Some(path), true) => Some(StatsWriter::create(path, options.stats_interval, object_count)?),
+                _ => None,
+            };
+            let recorder = match (&options.record, &record_header) {
+                (Some(path), Some(header)) => Some(TrajectoryRecorder::new(path, header, &buffers, device, queue)?),
+                _ => None,
+            };
+            let tool_state = simulation.tool_state();
+            let spawn_request = simulation.spawn_request();
+            let simulation_thread = spawn_simulation_thread(
+                simulation,
+                stats,
+                recorder,
+                stop_requested.clone(),
+                node_count_atomic.clone(),
+                status.clone(),
+                render_start_receiver,
+                command_receiver,
+            );
+            (buffers, tool_state, spawn_request, simulation_thread)
+        };
+        let mut tools = Tools::new(tool_state, spawn_request, StdRng::from_rng(&mut rng));
+        tools.spawn = options.spawn;
+
+        Ok(Self {
+            renderers: create_renderers(device, queue, format, world_aabb, &buffers),
+            picker: Picker::new(device, queue, &buffers),
+            tools,
+            object_count,
+            node_count_atomic,
+            render_start_sender,
+            stop_requested,
+            simulation_thread: Some(simulation_thread),
+            command_sender,
+            status,
+        })
+    }
+
+    fn stop(&mut self) {
+        self.stop_requested.store(true, Ordering::SeqCst);
+        if let Some(simulation_thread) = self.simulation_thread.take() {
+            simulation_thread.join().unwrap();
+        }
+    }
+}
+
 impl ApplicationHandler<AppEvent> for App<'_> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         let mut window_attributes = WindowAttributes::default();
@@ -124,7 +254,9 @@ impl ApplicationHandler<AppEvent> for App<'_> {
         let window = Arc::new(event_loop.create_window(window_attributes).expect("Failed to create window"));
         let wgpu = wgpu::Instance::new(&wgpu::InstanceDescriptor::from_env_or_default());
         let surface = wgpu.create_surface(window.clone()).unwrap();
-        let (adapter, device, queue, swapchain_format) = init_wgpu(&wgpu, &surface);
+        let adapter = request_adapter(&wgpu, Some(&surface));
+        let (device, queue) = request_device(&adapter);
+        let swapchain_format = surface.get_capabilities(&adapter).formats[0];
         let window_size = window.inner_size();
         let surface_config = wgpu::SurfaceConfiguration {
             present_mode: PresentMode::AutoVsync,
@@ -132,93 +264,24 @@ impl ApplicationHandler<AppEvent> for App<'_> {
         };
         surface.configure(&device, &surface_config);
 
-        let world_aabb = AABB {
-            min: [-1000.0, -1000.0],
-            max: [1000.0, 1000.0],
-        };
-
-        let mut objects = Objects::default();
-        create_scene(&mut objects, world_aabb);
-        let object_count = objects.len();
-        let buffers = objects.to_buffers(&device, &queue);
-
-        println!("Window size: {}x{}", window_size.width, window_size.height);
-        println!("Object count: {}", object_count);
-
-        let pipeline_cache = unsafe {
-            device.create_pipeline_cache(&PipelineCacheDescriptor {
-                label: None,
-                data: None,
-                fallback: true,
-            })
-        };
-        let camera =
-            GpuBuffer::<Camera>::new(1, "camera buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, &device);
-        let size_factor =
-            GpuBuffer::new(1, "size factor buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, &device);
-        size_factor.write(&queue, &[1.0]);
-        let aabb_renderer = AabbRenderer::new(
-            &device,
-            swapchain_format,
-            &pipeline_cache,
-            camera.clone(),
-            buffers.flags.clone(),
-            buffers.aabbs.clone(),
-        );
-        let shape_renderer = ShapeRenderer::new(
-            &device,
-            swapchain_format,
-            &pipeline_cache,
-            camera.clone(),
-            size_factor.clone(),
-            buffers.flags.clone(),
-            buffers.aabbs.clone(),
-            buffers.colors,
-            buffers.shapes,
-            buffers.velocities.clone(),
-        );
+        let world_aabb = world_aabb();
+        log::info!("Window size: {}x{}", window_size.width, window_size.height);
+        let scene = SceneState::load(&self.options, true, world_aabb, swapchain_format, &device, &queue)
+            .unwrap_or_else(|error| {
+                eprintln!("{error:#}");
+                exit(1);
+            });
+        let screenshot_trail_renderer = TrailRenderer::new(&device, swapchain_format, None);
         let exit_requested = Arc::new(AtomicBool::new(false));
-        let node_count_atomic = Arc::new(AtomicU32::new(u32::try_from(object_count).unwrap()));
-        let (render_start_sender, render_start_receiver) = crossbeam::channel::bounded(1);
-
-        spawn_simulation_thread(
-            buffers.flags,
-            buffers.masses,
-            buffers.velocities,
-            buffers.aabbs,
-            buffers.bvh_nodes,
-            buffers.integrated_velocities,
-            buffers.integrated_aabbs,
-            device.clone(),
-            queue.clone(),
-            exit_requested.clone(),
-            node_count_atomic.clone(),
-            render_start_receiver,
-        );
-
-        thread::spawn({
-            let device = device.clone();
-            let exit_requested = exit_requested.clone();
-            move || {
-                loop {
-                    device.poll(PollType::Poll).unwrap();
-                    if exit_requested.load(Ordering::Relaxed) {
-                        break;
-                    }
-                    thread::sleep(Duration::from_millis(1));
-                }
-            }
-        });
+        spawn_device_poller(device.clone(), exit_requested.clone());
 
         self.gpu_state = Some(GpuState {
-            shape_renderer,
-            aabb_renderer,
+            scene,
+            screenshot_trail_renderer,
             exit_requested,
             world_aabb,
-            object_count,
-            camera,
-            node_count_atomic,
-            render_start_sender,
+            title: String::new(),
+            render_duration: Duration::ZERO,
 
             surface_config,
             queue,
@@ -240,34 +303,164 @@ impl ApplicationHandler<AppEvent> for App<'_> {
 
             WindowEvent::RedrawRequested => {
                 if let Some(state) = &mut self.gpu_state {
+                    let cursor = cursor_world_position(&self.render_parameters, state, self.cursor_position);
+                    state.scene.tools.update(&state.queue, cursor);
+                    let status = *state.scene.status.lock().unwrap();
+                    let mut title = format!(
+                        "{} | step {} | time x{}",
+                        if status.paused { "paused" } else { "running" },
+                        status.step,
+                        status.time_scale,
+                    );
+                    if status.snapshots > 0 {
+                        title += &format!(" | {} snapshots", status.snapshots);
+                    }
+                    if let Some(selected) = self.render_parameters.selected {
+                        if let Some(object) = state.scene.picker.follow(selected) {
+                            let center = (object.aabb.min() + object.aabb.max()) / 2.0;
+                            self.render_parameters.camera_center = center.into();
+                            title += &format!(" | {}", describe(&object));
+                        }
+                    }
+                    if title != state.title {
+                        state.window.set_title(&title);
+                        state.title = title;
+                    }
+                    if self.debug_panel.visible {
+                        let mut ui = self.debug_panel.begin();
+                        debug_panel_ui(&mut ui, &self.options, &mut self.render_parameters, state, &status);
+                    }
+
                     let view_size = state.window.inner_size();
                     let world_height = state.world_aabb.max().y - state.world_aabb.min().y;
-                    let camera = orthographic_camera(self.render_parameters.zoom, view_size.cast(), world_height);
-                    state.camera.write(&state.queue, &[Camera::new(camera)]);
+                    let camera = orthographic_camera(
+                        self.render_parameters.zoom,
+                        view_size.cast(),
+                        world_height,
+                        self.render_parameters.camera_center,
+                    );
 
                     let surface_texture =
                         state.surface.get_current_texture().expect("Failed to acquire next swap chain texture");
                     let surface_texture_view = surface_texture.texture.create_view(&TextureViewDescriptor::default());
-                    let node_count = usize::try_from(state.node_count_atomic.load(Ordering::Relaxed)).unwrap();
+                    let node_count = usize::try_from(state.scene.node_count_atomic.load(Ordering::Relaxed)).unwrap();
                     let start = Instant::now();
-                    let sumbission_index = render_scene(
-                        surface_texture_view,
+                    let (mut submission_index, render_duration) = render_scene(
+                        &surface_texture_view,
+                        view_size,
+                        None,
                         &self.render_parameters,
-                        &mut state.shape_renderer,
-                        &mut state.aabb_renderer,
-                        0..state.object_count,
+                        &mut state.scene.renderers,
+                        Camera::new(camera),
+                        0..state.scene.object_count,
                         node_count,
                         &state.device,
                         &state.queue,
                     );
-                    state.render_start_sender.send(sumbission_index).unwrap();
-                    println!("Render done in {:?}", start.elapsed());
+                    state.render_duration = render_duration;
+                    if self.debug_panel.visible {
+                        submission_index = render_overlay(
+                            &surface_texture_view,
+                            view_size,
+                            &self.debug_panel,
+                            &mut state.scene.renderers.ui_renderer,
+                            &state.device,
+                            &state.queue,
+                        );
+                    }
+                    state.scene.render_start_sender.send(submission_index).unwrap();
+                    log::trace!("Render done in {:?}", start.elapsed());
 
                     state.window.pre_present_notify();
                     surface_texture.present();
                 }
             }
 
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::F12),
+                        state: ElementState::Pressed,
+                        repeat: false,
+                        ..
+                    },
+                ..
+            } => {
+                if let Some(state) = &mut self.gpu_state {
+                    let size = self.options.screenshot_size.unwrap_or_else(|| state.window.inner_size());
+                    let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+                    let path = self.options.output_dir.join(format!("screenshot_{millis}.png"));
+                    match save_screenshot(state, &self.render_parameters, size, &path) {
+                        Ok(()) => log::info!("Saved screenshot to {}", path.display()),
+                        Err(error) => eprintln!("Failed to save screenshot: {error:#}"),
+                    }
+                }
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key:
+                            PhysicalKey::Code(
+                                code @ (KeyCode::Space
+                                | KeyCode::KeyN
+                                | KeyCode::Backspace
+                                | KeyCode::PageUp
+                                | KeyCode::PageDown),
+                            ),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                if let Some(state) = &self.gpu_state {
+                    let command = match code {
+                        KeyCode::Space => SimulationCommand::TogglePause,
+                        KeyCode::KeyN => SimulationCommand::Step,
+                        KeyCode::Backspace => SimulationCommand::Rewind,
+                        KeyCode::PageUp => SimulationCommand::ScaleTime(2.0),
+                        _ => SimulationCommand::ScaleTime(0.5),
+                    };
+                    state.scene.command_sender.send(command).unwrap();
+                }
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::F1),
+                        state: ElementState::Pressed,
+                        repeat: false,
+                        ..
+                    },
+                ..
+            } => self.debug_panel.visible = !self.debug_panel.visible,
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key:
+                            PhysicalKey::Code(
+                                code @ (KeyCode::ArrowUp
+                                | KeyCode::ArrowDown
+                                | KeyCode::ArrowLeft
+                                | KeyCode::ArrowRight
+                                | KeyCode::Enter),
+                            ),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } if self.debug_panel.visible => {
+                self.debug_panel.input(match code {
+                    KeyCode::ArrowUp => PanelInput::Previous,
+                    KeyCode::ArrowDown => PanelInput::Next,
+                    KeyCode::ArrowLeft => PanelInput::Decrease,
+                    KeyCode::ArrowRight => PanelInput::Increase,
+                    _ => PanelInput::Activate,
+                });
+            }
+
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
@@ -290,6 +483,146 @@ impl ApplicationHandler<AppEvent> for App<'_> {
                 self.render_parameters.draw_aabbs = !self.render_parameters.draw_aabbs;
             }
 
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(code @ (KeyCode::KeyL | KeyCode::KeyK)),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                if let Some(state) = &self.gpu_state {
+                    let level_count = state.scene.renderers.aabb_renderer.level_count();
+                    let levels = self.render_parameters.aabb_levels;
+                    self.render_parameters.aabb_levels = if code == KeyCode::KeyL {
+                        levels.next_single(level_count)
+                    } else {
+                        levels.next_from(level_count)
+                    };
+                    self.render_parameters.draw_aabbs = true;
+                    log::info!("AABB levels: {:?} of {}", self.render_parameters.aabb_levels, level_count);
+                }
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyH),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.render_parameters.draw_heatmap = !self.render_parameters.draw_heatmap;
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyJ),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                let quantity = self.render_parameters.heatmap_quantity.next();
+                self.render_parameters.heatmap_quantity = quantity;
+                log::info!("Heatmap: {:?}, full scale at {}", quantity, quantity.default_range());
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyB),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.render_parameters.draw_blackholes = !self.render_parameters.draw_blackholes;
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyG),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.render_parameters.draw_potential = !self.render_parameters.draw_potential;
+                self.render_parameters.draw_blackholes |= self.render_parameters.draw_potential;
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyC),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.render_parameters.color_mode = self.render_parameters.color_mode.next();
+                log::info!("Color mode: {:?}", self.render_parameters.color_mode);
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyV),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.render_parameters.scale_by_speed = !self.render_parameters.scale_by_speed;
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyT),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.render_parameters.trails = !self.render_parameters.trails;
+                if let Some(state) = &mut self.gpu_state {
+                    state.scene.renderers.trail_renderer.reset();
+                }
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::KeyF),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.render_parameters.trail_fade_mode = self.render_parameters.trail_fade_mode.next();
+                log::info!("Trail fade mode: {:?}", self.render_parameters.trail_fade_mode);
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(code @ (KeyCode::Minus | KeyCode::Equal)),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                let factor = if code == KeyCode::Equal { 1.25 } else { 0.8 };
+                self.render_parameters.trail_fade = (self.render_parameters.trail_fade * factor).clamp(0.001, 1.0);
+                log::info!("Trail fade: {}", self.render_parameters.trail_fade);
+            }
+
             WindowEvent::CloseRequested
             | WindowEvent::KeyboardInput {
                 event:
@@ -301,6 +634,79 @@ impl ApplicationHandler<AppEvent> for App<'_> {
                 ..
             } => event_loop.exit(),
 
+            WindowEvent::CursorMoved { position, .. } => self.cursor_position = position,
+
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button: button @ (MouseButton::Left | MouseButton::Right),
+                ..
+            } => {
+                if self.debug_panel.click(self.cursor_position.cast(), button == MouseButton::Left) {
+                    return;
+                }
+                if let Some(state) = &mut self.gpu_state {
+                    let point = cursor_world_position(&self.render_parameters, state, self.cursor_position);
+                    if state.scene.tools.tool != Tool::Inspect {
+                        state.scene.tools.press(&state.scene.picker, point, button == MouseButton::Left);
+                    } else if button == MouseButton::Left {
+                        let picked = state.scene.picker.pick(point);
+                        self.render_parameters.selected = picked.map(|object| object.index);
+                        match picked {
+                            Some(object) => log::info!("Selected {}", describe(&object)),
+                            None => log::info!("Nothing at ({:.2}, {:.2})", point[0], point[1]),
+                        }
+                    }
+                }
+            }
+
+            WindowEvent::MouseInput {
+                state: ElementState::Released,
+                ..
+            } => {
+                if let Some(state) = &mut self.gpu_state {
+                    state.scene.tools.release();
+                }
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key:
+                            PhysicalKey::Code(
+                                code @ (KeyCode::Digit1 | KeyCode::Digit2 | KeyCode::Digit3 | KeyCode::Digit4),
+                            ),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                if let Some(state) = &mut self.gpu_state {
+                    let index = [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4]
+                        .iter()
+                        .position(|&digit| digit == code)
+                        .unwrap();
+                    state.scene.tools.release();
+                    state.scene.tools.tool = Tool::ALL[index];
+                    log::info!("Tool: {:?}", state.scene.tools.tool);
+                }
+            }
+
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(code @ (KeyCode::BracketLeft | KeyCode::BracketRight)),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                if let Some(state) = &mut self.gpu_state {
+                    let factor = if code == KeyCode::BracketRight { 1.25 } else { 0.8 };
+                    state.scene.tools.spawn.radius *= factor;
+                    log::info!("Spawn radius: {}", state.scene.tools.spawn.radius);
+                }
+            }
+
             WindowEvent::MouseWheel {
                 delta: MouseScrollDelta::LineDelta(_, dy),
                 ..
@@ -313,7 +719,8 @@ impl ApplicationHandler<AppEvent> for App<'_> {
     }
 
     fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
-        if let Some(state) = &self.gpu_state {
+        if let Some(state) = &mut self.gpu_state {
+            state.scene.stop();
             state.exit_requested.store(true, Ordering::SeqCst);
         }
     }
@@ -325,191 +732,427 @@ impl ApplicationHandler<AppEvent> for App<'_> {
     }
 }
 
-fn init_wgpu(
-    wgpu: &wgpu::Instance,
-    surface: &wgpu::Surface<'_>,
-) -> (wgpu::Adapter, wgpu::Device, wgpu::Queue, TextureFormat) {
-    let adapter = block_on(wgpu.request_adapter(&RequestAdapterOptions {
-        power_preference: wgpu::PowerPreference::from_env().unwrap_or(wgpu::PowerPreference::None),
-        force_fallback_adapter: false,
-        compatible_surface: Some(surface),
-    }))
-    .expect("Failed to find an appropriate adapter");
-
-    let mut required_limits = wgpu::Limits::defaults().using_resolution(adapter.limits());
-    required_limits.max_push_constant_size = u32::try_from(size_of::<CombineNodePass>()).unwrap();
-    let (device, queue) = block_on(adapter.request_device(&wgpu::DeviceDescriptor {
-        label: None,
-        required_features: wgpu::Features::PIPELINE_CACHE
-            | wgpu::Features::TIMESTAMP_QUERY
-            | wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS
-            | wgpu::Features::PUSH_CONSTANTS
-            | wgpu::Features::POLYGON_MODE_LINE,
-        required_limits,
-        experimental_features: wgpu::ExperimentalFeatures::disabled(),
-        memory_hints: wgpu::MemoryHints::Performance,
-        trace: wgpu::Trace::Off,
-    }))
-    .expect("Failed to create device");
-
-    let swapchain_capabilities = surface.get_capabilities(&adapter);
-    let swapchain_format = swapchain_capabilities.formats[0];
-    (adapter, device, queue, swapchain_format)
+fn save_screenshot(
+    state: &mut GpuState<'_>,
+    render_parameters: &RenderParameters,
+    size: PhysicalSize<u32>,
+    path: &Path,
+) -> anyhow::Result<()> {
+    let target = OffscreenTarget::new(&state.device, size, state.surface_config.format)?;
+    let world_height = state.world_aabb.size().y;
+    let camera =
+        orthographic_camera(render_parameters.zoom, size.cast(), world_height, render_parameters.camera_center);
+    let node_count = usize::try_from(state.scene.node_count_atomic.load(Ordering::Relaxed)).unwrap();
+    let renderers = &mut state.scene.renderers;
+    state.screenshot_trail_renderer.continue_from(&renderers.trail_renderer, &state.queue);
+    mem::swap(&mut renderers.trail_renderer, &mut state.screenshot_trail_renderer);
+    render_scene(
+        target.view(),
+        size,
+        Some(&target),
+        render_parameters,
+        renderers,
+        Camera::new(camera),
+        0..state.scene.object_count,
+        node_count,
+        &state.device,
+        &state.queue,
+    );
+    mem::swap(&mut renderers.trail_renderer, &mut state.screenshot_trail_renderer);
+    target.save_png(&state.device, path)
 }
 
-fn render_scene(
-    surface_texture_view: TextureView,
-    render_parameters: &RenderParameters,
-    shape_renderer: &mut ShapeRenderer,
-    aabb_renderer: &mut AabbRenderer,
-    range: Range<usize>,
-    node_count: usize,
+fn run_headless(options: &Options, headless_options: &HeadlessOptions) -> anyhow::Result<()> {
+    let wgpu = wgpu::Instance::new(&wgpu::InstanceDescriptor::from_env_or_default());
+    let adapter = request_adapter(&wgpu, None);
+    let (device, queue) = request_device(&adapter);
+
+    let world_aabb = world_aabb();
+    log::info!("Seed: {}", options.seed);
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let (objects, replay_reader) = create_objects(options, world_aabb, &mut rng)?;
+    let object_count = objects.len();
+    let record_header = match &options.record {
+        Some(_) => {
+            Some(TrajectoryHeader::from_objects(&objects, options.record_objects.as_deref(), options.record_interval)?)
+        }
+        None => None,
+    };
+    let (mut simulation, buffers) = if replay_reader.is_some() {
+        (None, objects.to_buffers(&device, &queue))
+    } else {
+        let simulation = create_simulation(options, objects, world_aabb, &device, &queue)?;
+        let buffers = simulation.buffers().clone();
+        (Some(simulation), buffers)
+    };
+    log::info!("Object count: {}", object_count);
+
+    let exit_requested = Arc::new(AtomicBool::new(false));
+    spawn_device_poller(device.clone(), exit_requested.clone());
+
+    let render_parameters = RenderParameters {
+        trails: options.trails,
+        ..RenderParameters::default()
+    };
+    let mut renderers = create_renderers(&device, &queue, HEADLESS_FORMAT, world_aabb, &buffers);
+    let size = headless_options.size;
+    let camera =
+        orthographic_camera(render_parameters.zoom, size.cast(), world_aabb.size().y, render_parameters.camera_center);
+    let target = OffscreenTarget::new(&device, size, HEADLESS_FORMAT)?;
+    let mut replay = replay_reader.map(|reader| Replay::new(reader, &buffers, &device, &queue));
+
+    fs::create_dir_all(&options.output_dir)?;
+    let mut stats = match &options.stats {
+        Some(path) => Some(StatsWriter::create(path, options.stats_interval, object_count)?),
+        None => None,
+    };
+    let mut recorder = match (&options.record, &record_header) {
+        (Some(path), Some(header)) => Some(TrajectoryRecorder::new(path, header, &buffers, &device, &queue)?),
+        _ => None,
+    };
+    let mut node_count = object_count;
+    let mut run = || {
+        if let (Some(recorder), Some(simulation)) = (&mut recorder, &simulation) {
+            recorder.record(&simulation.status(false))?;
+        }
+        for frame in 0..headless_options.frames {
+            if let Some(replay) = &mut replay {
+                if !replay.advance()? {
+                    log::info!("End of replay");
+                    break;
+                }
+                node_count = usize::try_from(replay.node_count()).unwrap();
+            } else if let Some(simulation) = &mut simulation {
+                for _ in 0..headless_options.steps_per_frame {
+                    let step_node_count = simulation.step(DT);
+                    let status = simulation.status(false);
+                    if let Some(stats) = &mut stats {
+                        stats.record(&status, step_node_count)?;
+                    }
+                    if let Some(recorder) = &mut recorder {
+                        recorder.record(&status)?;
+                    }
+                    node_count = usize::try_from(step_node_count).unwrap();
+                }
+            }
+            render_scene(
+                target.view(),
+                size,
+                Some(&target),
+                &render_parameters,
+                &mut renderers,
+                Camera::new(camera),
+                0..object_count,
+                node_count,
+                &device,
+                &queue,
+            );
+            let path = options.output_dir.join(format!("frame_{frame:05}.png"));
+            target.save_png(&device, &path)?;
+            log::info!("Saved frame {}", path.display());
+        }
+        anyhow::Ok(())
+    };
+    let result = run();
+    exit_requested.store(true, Ordering::SeqCst);
+    // Finished even if rendering failed, so that the statistics and trajectories up to the failure are usable
+    let stats_result = stats.map_or(Ok(()), StatsWriter::finish);
+    let recorder_result = recorder.map_or(Ok(()), TrajectoryRecorder::finish);
+    result.and(stats_result).and(recorder_result)
+}
+
+/// Creates the scene, or the objects of the recording to replay, from the options
+fn create_objects(
+    options: &Options,
+    world_aabb: AABB,
+    rng: &mut StdRng,
+) -> anyhow::Result<(Objects, Option<TrajectoryReader>)> {
+    if let Some(path) = &options.replay {
+        let reader = TrajectoryReader::open(path)?;
+        return Ok((replay_objects(reader.header()), Some(reader)));
+    }
+    let mut objects = Objects::default();
+    create_scene(&mut objects, world_aabb, &options.scene, rng);
+    objects.push_free_slots(SPAWN_SLOT_COUNT);
+    if let Some(path) = &options.level {
+        objects.level = Level::load(path)?;
+    }
+    Ok((objects, None))
+}
+
+/// Creates the simulation with the options' forces, diagnostics and accretion
+fn create_simulation(
+    options: &Options,
+    objects: Objects,
+    world_aabb: AABB,
     device: &wgpu::Device,
     queue: &wgpu::Queue,
-) -> SubmissionIndex {
-    let pass_duration_measurer = PassDurationMeasurer::new(device);
-    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
-
-    let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
-        label: None,
-        color_attachments: &[Some(RenderPassColorAttachment {
-            view: &surface_texture_view,
-            depth_slice: None,
-            resolve_target: None,
-            ops: wgpu::Operations {
-                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                store: wgpu::StoreOp::Store,
-            },
-        })],
-        depth_stencil_attachment: None,
-        timestamp_writes: Some(pass_duration_measurer.render_pass_timestamp_writes()),
-        occlusion_query_set: None,
-    });
-    if render_parameters.enabled {
-        shape_renderer.render(&mut render_pass, range.clone());
+) -> anyhow::Result<Simulation> {
+    let mut simulation = Simulation::new(objects, device, queue);
+    for &force in &options.forces {
+        simulation.forces_mut().add_timed(force)?;
     }
-    if render_parameters.draw_aabbs {
-        aabb_renderer.render(&mut render_pass, 0..node_count);
+    if options.diagnostics {
+        simulation.enable_diagnostics(world_aabb.center().into());
     }
-    // TODO: EDF
-    drop(render_pass);
+    simulation.set_accretion(options.accretion);
+    Ok(simulation)
+}
 
-    pass_duration_measurer.update(&mut encoder);
-    let submission_index = queue.submit([encoder.finish()]);
-    let duration = pass_duration_measurer.duration();
-    println!("Rendered {} objects in {:?}", range.len(), duration);
+/// Builds the debug panel's rows, editing the parameters directly and sending commands for the simulation thread
+fn debug_panel_ui(
+    ui: &mut PanelUi<'_>,
+    options: &Options,
+    render_parameters: &mut RenderParameters,
+    state: &mut GpuState<'_>,
+    status: &SimulationStatus,
+) {
+    let send = |command| state.scene.command_sender.send(command).unwrap();
+    ui.heading("simulation");
+    ui.label(format!("step {} | {} objects", status.step, state.scene.object_count));
+    if ui.button(if status.paused { "resume" } else { "pause" }) {
+        send(SimulationCommand::TogglePause);
+    }
+    if ui.button("step") {
+        send(SimulationCommand::Step);
+    }
+    match ui.stepper(format!("dt: <{}> x{}", format_value(status.dt), status.time_scale)) {
+        1 => send(SimulationCommand::ScaleTime(2.0)),
+        -1 => send(SimulationCommand::ScaleTime(0.5)),
+        _ => (),
+    }
+    let mut integrator = status.integrator;
+    if ui.cycle("integrator", &mut integrator, Integrator::next) {
+        send(SimulationCommand::SetIntegrator(integrator));
+    }
+    let mut accretion = status.accretion;
+    if ui.checkbox("accretion", &mut accretion) {
+        send(SimulationCommand::SetAccretion(accretion));
+    }
+    if ui.button(&format!("rewind ({} snapshots)", status.snapshots)) {
+        send(SimulationCommand::Rewind);
+    }
+    if ui.button("reload scene") {
+        // Loaded before stopping the current scene, which keeps running if the level or replay can't be read
+        let format = state.surface_config.format;
+        match SceneState::load(options, false, state.world_aabb, format, &state.device, &state.queue) {
+            Ok(mut scene) => {
+                let tools = &state.scene.tools;
+                (scene.tools.tool, scene.tools.spawn, scene.tools.attractor) =
+                    (tools.tool, tools.spawn, tools.attractor);
+                state.scene.stop();
+                state.scene = scene;
+                render_parameters.selected = None;
+            }
+            Err(error) => eprintln!("Failed to reload the scene: {error:#}"),
+        }
+    }
+
+    ui.heading("timings");
+    ui.label(format!("bvh         {:?}", status.durations.bvh));
+    ui.label(format!("integration {:?}", status.durations.integration));
+    ui.label(format!("update      {:?}", status.durations.update));
+    ui.label(format!("render      {:?}", state.render_duration));
+    if let Some(diagnostics) = status.diagnostics {
+        ui.heading("diagnostics");
+        ui.label(format!("energy   {:.4e}", diagnostics.total_energy()));
+        ui.label(format!("kinetic  {:.4e}", diagnostics.kinetic_energy));
+        ui.label(format!("momentum ({:.3e}, {:.3e})", diagnostics.momentum[0], diagnostics.momentum[1]));
+        ui.label(format!("angular  {:.4e}", diagnostics.angular_momentum));
+        ui.label(format!("{} live, {} destroyed", diagnostics.live, diagnostics.destroyed));
+    }
+
+    ui.heading("render");
+    ui.checkbox("objects", &mut render_parameters.enabled);
+    ui.slider("zoom", &mut render_parameters.zoom, 0.05..=200.0, 1.25);
+    ui.cycle("color mode", &mut render_parameters.color_mode, ColorMode::next);
+    ui.checkbox("scale by speed", &mut render_parameters.scale_by_speed);
+    ui.checkbox("aabbs", &mut render_parameters.draw_aabbs);
+    if ui.checkbox("trails", &mut render_parameters.trails) {
+        state.scene.renderers.trail_renderer.reset();
+    }
+    ui.slider("trail fade", &mut render_parameters.trail_fade, 0.001..=1.0, 1.25);
+    ui.cycle("fade mode", &mut render_parameters.trail_fade_mode, TrailFade::next);
+    ui.checkbox("heatmap", &mut render_parameters.draw_heatmap);
+    ui.cycle("heatmap quantity", &mut render_parameters.heatmap_quantity, HeatmapQuantity::next);
+    ui.checkbox("black holes", &mut render_parameters.draw_blackholes);
+    ui.checkbox("potential", &mut render_parameters.draw_potential);
 
-    submission_index
+    ui.heading("tools");
+    let tools = &mut state.scene.tools;
+    let mut tool = tools.tool;
+    if ui.cycle("tool", &mut tool, Tool::next) {
+        tools.release();
+        tools.tool = tool;
+    }
+    ui.slider("attractor strength", &mut tools.attractor.strength, 10.0..=1e6, 1.5);
+    ui.slider("attractor softening", &mut tools.attractor.softening, 1.0..=500.0, 1.25);
+    ui.slider("spawn radius", &mut tools.spawn.radius, 0.1..=100.0, 1.25);
+    ui.slider("spawn mass", &mut tools.spawn.mass, 0.01..=1e4, 1.5);
 }
 
-fn orthographic_camera(zoom: f32, view_size: PhysicalSize<f32>, world_height: f32) -> [[f32; 4]; 4] {
-    let aspect = view_size.width / view_size.height;
-    let world_width = world_height * aspect;
-    let l = -world_width * 0.5;
-    let r = world_width * 0.5;
-    let b = -world_height * 0.5;
-    let t = world_height * 0.5;
-    // TODO: implement panning
-    let sx = zoom * 2.0 / (r - l);
-    let sy = zoom * 2.0 / (t - b);
-    [
-        [sx, 0.0, 0.0, 0.0],
-        [0.0, sy, 0.0, 0.0],
-        [0.0, 0.0, -1.0, 0.0],
-        [0.0, 0.0, 0.0, 1.0],
-    ]
+fn cursor_world_position(
+    render_parameters: &RenderParameters,
+    state: &GpuState<'_>,
+    cursor_position: PhysicalPosition<f64>,
+) -> [f32; 2] {
+    let view_size = state.window.inner_size();
+    let camera = orthographic_camera(
+        render_parameters.zoom,
+        view_size.cast(),
+        state.world_aabb.size().y,
+        render_parameters.camera_center,
+    );
+    screen_to_world(&camera, view_size.cast(), cursor_position.cast())
 }
 
 fn spawn_simulation_thread(
-    flags: GpuBuffer<Flags>,
-    masses: GpuBuffer<Mass>,
-    velocities: GpuBuffer<Velocity>,
-    aabbs: GpuBuffer<AABB>,
-    bvh_nodes: GpuBuffer<BvhNode>,
-    integrated_velocities: GpuBuffer<Velocity>,
-    integrated_aabbs: GpuBuffer<AABB>,
-    device: wgpu::Device,
-    queue: wgpu::Queue,
-    exit_requested: Arc<AtomicBool>,
+    mut simulation: Simulation,
+    mut stats: Option<StatsWriter>,
+    mut recorder: Option<TrajectoryRecorder>,
+    stop_requested: Arc<AtomicBool>,
     node_count_atomic: Arc<AtomicU32>,
+    status: Arc<Mutex<SimulationStatus>>,
     render_start_receiver: Receiver<SubmissionIndex>,
-) {
-    thread::spawn({
-        let dt = GpuBuffer::new(1, "dt buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, &device);
-        dt.write(&queue, &[0.001]);
-
-        let object_count = flags.len();
-        let mut bvh_builder = BvhBuilder::new(&device, aabbs.clone(), bvh_nodes.clone(), object_count);
-
-        let integrator = GpuIntegrator::new(
-            &device,
-            dt,
-            flags,
-            masses,
-            velocities.clone(),
-            aabbs.clone(),
-            bvh_nodes,
-            integrated_velocities.clone(),
-            integrated_aabbs.clone(),
-        );
-
-        let integration_duration_measurer = PassDurationMeasurer::new(&device);
-        let bvh_duration_measurer = PassDurationMeasurer::new(&device);
-        let update_duration_measurer = PassDurationMeasurer::new(&device);
-
-        move || loop {
-            if exit_requested.load(Ordering::Relaxed) {
+    command_receiver: Receiver<SimulationCommand>,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let mut paused = false;
+        if let Some(initial_recorder) = &mut recorder {
+            if let Err(error) = initial_recorder.record(&simulation.status(paused)) {
+                eprintln!("{error:#}, trajectories are not recorded");
+                recorder = None;
+            }
+        }
+        loop {
+            if stop_requested.load(Ordering::Relaxed) {
                 break;
             }
 
-            let compute_start = Instant::now();
-
-            let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
-
-            let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
-                label: Some("bvh pass"),
-                timestamp_writes: Some(bvh_duration_measurer.compute_pass_timestamp_writes()),
-            });
-            bvh_builder.compute(&mut compute_pass);
-            drop(compute_pass);
-
-            let node_count = bvh_builder.node_count();
-            node_count_atomic.store(node_count, Ordering::SeqCst);
-
-            let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
-                label: Some("integration pass"),
-                timestamp_writes: Some(integration_duration_measurer.compute_pass_timestamp_writes()),
-            });
-            integrator.compute(&mut compute_pass);
-            drop(compute_pass);
-
-            update_duration_measurer.measure(&mut encoder, |encoder| {
-                encoder.copy_buffer_to_buffer(integrated_velocities.buffer(), 0, velocities.buffer(), 0, None);
-                // Copying the entire buffer is okay because integrated_aabbs is of object_count length
-                encoder.copy_buffer_to_buffer(integrated_aabbs.buffer(), 0, aabbs.buffer(), 0, None);
-            });
-
-            bvh_duration_measurer.update(&mut encoder);
-            integration_duration_measurer.update(&mut encoder);
-            update_duration_measurer.update(&mut encoder);
+            let mut step_requested = false;
+            let commands = if paused {
+                // Nothing to do until a command arrives, but exit requests still have to be noticed
+                command_receiver.recv_timeout(Duration::from_millis(10)).into_iter().collect::<Vec<_>>()
+            } else {
+                command_receiver.try_iter().collect()
+            };
+            for command in commands {
+                match command {
+                    SimulationCommand::TogglePause => paused = !paused,
+                    SimulationCommand::Step => step_requested = true,
+                    SimulationCommand::ScaleTime(factor) => simulation.scale_time(factor),
+                    SimulationCommand::Rewind => {
+                        paused = true;
+                        if !simulation.rewind() {
+                            log::info!("Nothing to rewind");
+                        }
+                    }
+                    SimulationCommand::SetIntegrator(integrator) => simulation.set_integrator(integrator),
+                    SimulationCommand::SetAccretion(accretion) => simulation.set_accretion(accretion),
+                }
+            }
 
-            let command_buffer = encoder.finish();
-            if let Ok(render_submission_index) = render_start_receiver.try_recv() {
-                device.wait_for_submission(render_submission_index).unwrap();
+            let render_submission_index = render_start_receiver.try_recv().ok();
+            let stepped = !paused || step_requested;
+            if stepped {
+                let node_count = simulation.step_after(DT, render_submission_index);
+                node_count_atomic.store(node_count, Ordering::SeqCst);
             }
-            let submission_index = queue.submit([command_buffer]);
-            println!("Submitted command buffer at {:?}", compute_start.elapsed());
-            device.wait_for_submission(submission_index).unwrap();
-            println!("Compute done in {:?}", compute_start.elapsed());
+            let current_status = simulation.status(paused);
+            if let (true, Some(writer)) = (stepped, &mut stats) {
+                if let Err(error) = writer.record(&current_status, node_count_atomic.load(Ordering::SeqCst)) {
+                    eprintln!("{error:#}, statistics are no longer recorded");
+                    stats = None;
+                }
+            }
+            if let (true, Some(writer)) = (stepped, &mut recorder) {
+                if let Err(error) = writer.record(&current_status) {
+                    eprintln!("{error:#}, trajectories are no longer recorded");
+                    recorder = None;
+                }
+            }
+            *status.lock().unwrap() = current_status;
+        }
+        if let Some(stats) = stats {
+            if let Err(error) = stats.finish() {
+                eprintln!("{error:#}");
+            }
+        }
+        if let Some(recorder) = recorder {
+            if let Err(error) = recorder.finish() {
+                eprintln!("{error:#}");
+            }
+        }
+    })
+}
 
-            let bvh_duration = bvh_duration_measurer.duration();
-            println!("  Built BVH with {} nodes in {:?}", node_count, bvh_duration);
+/// Replaces the simulation thread during a replay, playing [`REPLAY_FRAME_RATE`] recorded frames per second times
+/// the time scale. The replay pauses on its last frame, rewinding starts it over.
+fn spawn_replay_thread(
+    mut replay: Replay,
+    stop_requested: Arc<AtomicBool>,
+    node_count_atomic: Arc<AtomicU32>,
+    status: Arc<Mutex<SimulationStatus>>,
+    render_start_receiver: Receiver<SubmissionIndex>,
+    command_receiver: Receiver<SimulationCommand>,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let mut paused = false;
+        let mut speed = 1.0f32;
+        let mut next_frame = Instant::now();
+        let show = |replay: &mut Replay, restart: bool| {
+            let result = if restart { replay.restart() } else { replay.advance() };
+            match result {
+                Ok(shown) => {
+                    node_count_atomic.store(replay.node_count(), Ordering::SeqCst);
+                    shown
+                }
+                Err(error) => {
+                    eprintln!("{error:#}");
+                    false
+                }
+            }
+        };
+        if !show(&mut replay, true) {
+            log::info!("The replay has no frames");
+            paused = true;
+        }
+        loop {
+            if stop_requested.load(Ordering::Relaxed) {
+                break;
+            }
+            // Frames are written with the queue, so they are ordered with the render submissions already
+            while render_start_receiver.try_recv().is_ok() {}
 
-            let integration_duration = integration_duration_measurer.duration();
-            println!("  Integrated {} objects in {:?}", object_count, integration_duration);
+            let mut step_requested = false;
+            let commands = if paused {
+                command_receiver.recv_timeout(Duration::from_millis(10)).into_iter().collect::<Vec<_>>()
+            } else {
+                command_receiver.try_iter().collect()
+            };
+            for command in commands {
+                match command {
+                    SimulationCommand::TogglePause => paused = !paused,
+                    SimulationCommand::Step => step_requested = true,
+                    SimulationCommand::ScaleTime(factor) => speed = (speed * factor).clamp(1.0 / 64.0, 64.0),
+                    SimulationCommand::Rewind => {
+                        show(&mut replay, true);
+                    }
+                    SimulationCommand::SetIntegrator(_) | SimulationCommand::SetAccretion(_) => (),
+                }
+            }
 
-            let update_duration = update_duration_measurer.duration();
-            println!("  Updated {} objects in {:?}", object_count, update_duration);
+            let now = Instant::now();
+            if step_requested || (!paused && now >= next_frame) {
+                next_frame = now + Duration::from_secs_f32(1.0 / (REPLAY_FRAME_RATE * speed));
+                if !show(&mut replay, false) {
+                    log::info!("End of replay");
+                    paused = true;
+                }
+            } else if !paused {
+                thread::sleep((next_frame - now).min(Duration::from_millis(10)));
+            }
+            *status.lock().unwrap() = replay.status(paused, speed);
         }
-    });
+    })
 }
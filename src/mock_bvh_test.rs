@@ -109,3 +109,13 @@ impl BvhNode {
         self.index & BVH_NODE_TREE_FLAG != 0
     }
 }
+
+#[test]
+fn bvh_depth_fits_the_traversal_stack() {
+    // The most leaves whose nodes all have indices below the tree flag. Their depth is the number of combine passes,
+    // which `BVH_MAX_DEPTH` in bvh_traversal.wgsl bounds.
+    let mut passes = Vec::new();
+    calculate_passes(1 << 30, &mut passes);
+    assert_eq!(passes.len(), 30);
+    assert!(passes.last().unwrap().dst_start < BVH_NODE_TREE_FLAG);
+}
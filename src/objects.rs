@@ -1,13 +1,32 @@
+use std::ops::Range;
+
 use color::{AlphaColor, Srgb};
 use itertools::Itertools;
 use nalgebra::Vector2;
 use wgpu::BufferUsages;
 
 use crate::{
+    blackholes::BLACKHOLES,
+    constraints::ConstraintKind,
     gpu_buffer::GpuBuffer,
-    shaders::common::{AABB, BvhNode, Color, Flags, Mass, Shape, Velocity},
+    level::Level,
+    shaders::{
+        blackholes::BlackHoleSet,
+        common::{
+            AABB, Angle, AngularVelocity, BvhNode, Color, Flags, Mass, Polygon, PolygonVertex, SHAPE_CAPSULE,
+            SHAPE_CIRCLE, SHAPE_POLYGON, SecondMoments, Segment, Shape, Size, Velocity,
+        },
+        constraints::Constraint,
+        materials::{MATERIAL_INERT, MAX_MATERIALS, Material, MaterialId, MaterialTable},
+    },
 };
 
+/// Polygons with more vertices are rejected, every vertex costs in the narrow phase and for every drawn pixel
+pub const MAX_POLYGON_VERTICES: usize = 16;
+
+/// Thresholds of [`MATERIAL_INERT`], impacts are never that fast
+const INERT: Material = Material::new(f32::MAX, f32::MAX, 1, 0.0);
+
 pub struct ObjectPrototype {
     pub flags: u32,
     pub position: [f32; 2],
@@ -16,6 +35,9 @@ pub struct ObjectPrototype {
     pub size: [f32; 2],
     pub color: AlphaColor<Srgb>,
     pub shape: u32,
+    /// Counterclockwise in radians
+    pub angle: f32,
+    pub angular_velocity: f32,
 }
 
 #[derive(Default)]
@@ -26,18 +48,128 @@ pub struct Objects {
     pub masses: Vec<Mass>,
     pub colors: Vec<Color>,
     pub shapes: Vec<Shape>,
+    pub sizes: Vec<Size>,
+    pub angles: Vec<Angle>,
+    pub angular_velocities: Vec<AngularVelocity>,
+    /// Vertex range of every object in [`Self::polygon_vertices`], empty unless it is a polygon
+    pub polygons: Vec<Polygon>,
+    /// Pool shared by all polygons
+    pub polygon_vertices: Vec<PolygonVertex>,
+    /// Per unit of mass, see [`second_moments`]
+    pub moments: Vec<SecondMoments>,
+    /// Index into [`Self::material_table`] plus one, [`MATERIAL_INERT`] unless set with [`Self::set_material`]
+    pub materials: Vec<MaterialId>,
+    /// Materials added with [`Self::add_material`]
+    pub material_table: Vec<Material>,
+    /// Springs and distance constraints between objects, they are uploaded by the simulation rather than
+    /// [`Self::to_buffers`]
+    pub constraints: Vec<Constraint>,
+    /// Static walls, uploaded for drawing, the simulation builds their BVH
+    pub level: Level,
+    /// The objects pass through each other instead of bouncing off, the simulation skips the contact pass. For dense
+    /// scenes whose objects start inside each other.
+    pub skip_contacts: bool,
 }
 
 impl Objects {
+    /// Pushes a rectangle, circle or capsule, polygons are pushed with [`Self::push_polygon`]
     pub fn push(&mut self, prototype: ObjectPrototype) {
+        assert_ne!(prototype.shape, SHAPE_POLYGON, "Polygons need their vertices");
+        let half_extents = rotated_half_extents(prototype.shape, prototype.size, prototype.angle);
+        self.polygons.push(Polygon::new(0, 0));
+        self.moments.push(second_moments(prototype.shape, prototype.size));
+        self.push_common(prototype, half_extents);
+    }
+
+    /// Pushes the convex hull of `points`, which are in the object's own frame. The hull is moved so that its
+    /// centroid is at the prototype's position, its size and shape are taken from the hull.
+    pub fn push_polygon(&mut self, prototype: ObjectPrototype, points: &[[f32; 2]]) {
+        let hull = convex_hull(points);
+        assert!(
+            (3..=MAX_POLYGON_VERTICES).contains(&hull.len()),
+            "Polygons need 3 to {MAX_POLYGON_VERTICES} vertices, the hull has {}",
+            hull.len()
+        );
+        let centroid = polygon_centroid(&hull);
+        let vertices = hull.iter().map(|&vertex| [vertex[0] - centroid[0], vertex[1] - centroid[1]]).collect_vec();
+        let extent = vertices.iter().fold([0.0f32; 2], |extent, vertex| {
+            [
+                extent[0].max(vertex[0].abs() * 2.0),
+                extent[1].max(vertex[1].abs() * 2.0),
+            ]
+        });
+        let offset = u32::try_from(self.polygon_vertices.len()).unwrap();
+        self.polygons.push(Polygon::new(offset, u32::try_from(vertices.len()).unwrap()));
+        self.polygon_vertices.extend(vertices.iter().map(|&vertex| PolygonVertex::new(vertex)));
+        self.moments.push(polygon_second_moments(&vertices));
+        let half_extents = polygon_half_extents(&vertices, prototype.angle);
+        self.push_common(
+            ObjectPrototype {
+                size: extent,
+                shape: SHAPE_POLYGON,
+                ..prototype
+            },
+            half_extents,
+        );
+    }
+
+    fn push_common(&mut self, prototype: ObjectPrototype, half_extents: [f32; 2]) {
         self.flags.push(Flags::new(prototype.flags));
         let position = Vector2::from(prototype.position);
-        let size = Vector2::from(prototype.size);
-        self.aabbs.push(AABB::new((position - size / 2.0).into(), (position + size / 2.0).into()));
+        let half_extents = Vector2::from(half_extents);
+        self.aabbs.push(AABB::new((position - half_extents).into(), (position + half_extents).into()));
         self.velocities.push(Velocity::new(prototype.velocity));
         self.masses.push(Mass::new(prototype.mass));
         self.colors.push(Color::new(prototype.color.components));
         self.shapes.push(Shape::new(prototype.shape));
+        self.sizes.push(Size::new(prototype.size));
+        self.angles.push(Angle::new(prototype.angle));
+        self.angular_velocities.push(AngularVelocity::new(prototype.angular_velocity));
+        self.materials.push(MaterialId::new(MATERIAL_INERT));
+    }
+
+    /// Pushes `count` hidden objects that tools spawn into and shattering circles fill with fragments, returns their
+    /// indices
+    pub fn push_free_slots(&mut self, count: usize) -> Range<usize> {
+        let start = self.len();
+        self.extend((0..count).map(|_| ObjectPrototype {
+            flags: 0,
+            position: [0.0, 0.0],
+            velocity: [0.0, 0.0],
+            mass: 1.0,
+            size: [0.0, 0.0],
+            color: AlphaColor::TRANSPARENT,
+            shape: SHAPE_CIRCLE,
+            angle: 0.0,
+            angular_velocity: 0.0,
+        }));
+        start..self.len()
+    }
+
+    /// Registers a material for [`Self::set_material`] and returns its id
+    pub fn add_material(&mut self, material: Material) -> u32 {
+        assert!(self.material_table.len() + 1 < MAX_MATERIALS as usize, "Too many materials");
+        self.material_table.push(material);
+        u32::try_from(self.material_table.len()).unwrap()
+    }
+
+    /// Only circles merge and shatter, other shapes ignore their material
+    pub fn set_material(&mut self, index: usize, material: u32) {
+        assert!(material as usize <= self.material_table.len(), "Unknown material {material}");
+        self.materials[index] = MaterialId::new(material);
+    }
+
+    /// Connects objects `a` and `b` at their current distance, both have to be pushed already. A non-physical
+    /// object is a fixed anchor for the other one.
+    pub fn connect(&mut self, a: usize, b: usize, kind: ConstraintKind) {
+        let center = |aabb: &AABB| (Vector2::from(aabb.min) + Vector2::from(aabb.max)) / 2.0;
+        let rest_length = (center(&self.aabbs[a]) - center(&self.aabbs[b])).norm();
+        self.connect_with_length(a, b, rest_length, kind);
+    }
+
+    pub fn connect_with_length(&mut self, a: usize, b: usize, rest_length: f32, kind: ConstraintKind) {
+        assert!(a < self.len() && b < self.len() && a != b, "Invalid constraint between {a} and {b}");
+        self.constraints.push(kind.to_wgsl(a, b, rest_length));
     }
 
     pub fn extend(&mut self, iter: impl IntoIterator<Item = ObjectPrototype>) {
@@ -55,6 +187,19 @@ impl Objects {
         self.masses.reserve(additional);
         self.colors.reserve(additional);
         self.shapes.reserve(additional);
+        self.sizes.reserve(additional);
+        self.angles.reserve(additional);
+        self.angular_velocities.reserve(additional);
+        self.polygons.reserve(additional);
+        self.moments.reserve(additional);
+        self.materials.reserve(additional);
+    }
+
+    /// Vertices of object `index` in its own frame, empty unless it is a polygon
+    pub fn polygon(&self, index: usize) -> Vec<[f32; 2]> {
+        let polygon = self.polygons[index];
+        let range = polygon.offset as usize..(polygon.offset + polygon.count) as usize;
+        self.polygon_vertices[range].iter().map(|vertex| vertex.inner).collect()
     }
 
     pub fn len(&self) -> usize {
@@ -68,17 +213,37 @@ impl Objects {
     pub fn to_buffers(self, device: &wgpu::Device, queue: &wgpu::Queue) -> ObjectBuffers {
         let storage_copy_dst: BufferUsages = BufferUsages::STORAGE | BufferUsages::COPY_DST;
         let storage_copy_src: BufferUsages = BufferUsages::STORAGE | BufferUsages::COPY_SRC;
+        // Snapshots for rewinding copy out of and back into these
+        let snapshotted: BufferUsages = storage_copy_dst | BufferUsages::COPY_SRC;
 
         // These are twice the size to hold the BVH AABBs and nodes
         // TODO: come up with a scheme that saves memory
-        let aabbs = GpuBuffer::new(self.len() * 2, "aabb buffer", storage_copy_dst, device);
+        let aabbs = GpuBuffer::new(self.len() * 2, "aabb buffer", snapshotted, device);
         let bvh_nodes = GpuBuffer::new(self.len() * 2, "bvh node buffer", storage_copy_dst, device);
+        let sleeping_nodes = GpuBuffer::new(self.len() * 2, "sleeping node buffer", storage_copy_dst, device);
 
-        let flags = GpuBuffer::new(self.len(), "flags buffer", storage_copy_dst, device);
-        let velocities = GpuBuffer::new(self.len(), "velocity buffer", storage_copy_dst, device);
-        let masses = GpuBuffer::new(self.len(), "mass buffer", storage_copy_dst, device);
-        let colors = GpuBuffer::new(self.len(), "color buffer", storage_copy_dst, device);
-        let shapes = GpuBuffer::new(self.len(), "shape buffer", storage_copy_dst, device);
+        let flags = GpuBuffer::new(self.len(), "flags buffer", snapshotted, device);
+        let velocities = GpuBuffer::new(self.len(), "velocity buffer", snapshotted, device);
+        // Merging and shattering change these, so they are snapshotted as well
+        let masses = GpuBuffer::new(self.len(), "mass buffer", snapshotted, device);
+        let colors = GpuBuffer::new(self.len(), "color buffer", snapshotted, device);
+        let shapes = GpuBuffer::new(self.len(), "shape buffer", snapshotted, device);
+        let sizes = GpuBuffer::new(self.len(), "size buffer", snapshotted, device);
+        let angles = GpuBuffer::new(self.len(), "angle buffer", snapshotted, device);
+        let angular_velocities = GpuBuffer::new(self.len(), "angular velocity buffer", snapshotted, device);
+        let contact_counts = GpuBuffer::new(self.len(), "contact count buffer", storage_copy_src, device);
+        let wall_normals = GpuBuffer::new(self.len(), "wall normal buffer", BufferUsages::STORAGE, device);
+        let polygons = GpuBuffer::new(self.len(), "polygon buffer", storage_copy_dst, device);
+        // Bindings can't be empty
+        let vertex_count = self.polygon_vertices.len().max(1);
+        let polygon_vertices = GpuBuffer::new(vertex_count, "polygon vertex buffer", storage_copy_dst, device);
+        let moments = GpuBuffer::new(self.len(), "second moment buffer", snapshotted, device);
+        let materials = GpuBuffer::new(self.len(), "material buffer", snapshotted, device);
+        let material_table =
+            GpuBuffer::new(1, "material table buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        let segments = GpuBuffer::new(self.level.len().max(1), "segment buffer", storage_copy_dst, device);
+        // A uniform for the integrator and the renderers, a storage buffer for the accretion pass
+        let blackholes = GpuBuffer::new(1, "black hole buffer", snapshotted | BufferUsages::UNIFORM, device);
 
         aabbs.write(queue, &self.aabbs);
 
@@ -87,35 +252,225 @@ impl Objects {
 
         let integrated_velocities = GpuBuffer::new(self.len(), "integrated velocity buffer", storage_copy_src, device);
         let integrated_aabbs = GpuBuffer::new(self.len(), "integrated aabb buffer", storage_copy_src, device);
+        let integrated_angles = GpuBuffer::new(self.len(), "integrated angle buffer", storage_copy_src, device);
 
         flags.write(queue, &self.flags);
         velocities.write(queue, &self.velocities);
         masses.write(queue, &self.masses);
         colors.write(queue, &self.colors);
         shapes.write(queue, &self.shapes);
+        sizes.write(queue, &self.sizes);
+        angles.write(queue, &self.angles);
+        angular_velocities.write(queue, &self.angular_velocities);
+        polygons.write(queue, &self.polygons);
+        if !self.polygon_vertices.is_empty() {
+            polygon_vertices.write(queue, &self.polygon_vertices);
+        }
+        moments.write(queue, &self.moments);
+        materials.write(queue, &self.materials);
+        let mut table = MaterialTable::new([INERT; MAX_MATERIALS as usize]);
+        table.materials[1..=self.material_table.len()].copy_from_slice(&self.material_table);
+        material_table.write(queue, &[table]);
+        if !self.level.is_empty() {
+            segments.write(queue, &self.level.segments);
+        }
+        blackholes.write(queue, &[BLACKHOLES]);
 
         ObjectBuffers {
             flags,
             aabbs,
             bvh_nodes,
+            sleeping_nodes,
             velocities,
             integrated_velocities,
             integrated_aabbs,
             masses,
             colors,
             shapes,
+            sizes,
+            angles,
+            angular_velocities,
+            integrated_angles,
+            polygons,
+            polygon_vertices,
+            moments,
+            materials,
+            material_table,
+            contact_counts,
+            wall_normals,
+            segments,
+            segment_count: self.level.len(),
+            blackholes,
         }
     }
 }
 
+#[derive(Clone)]
 pub struct ObjectBuffers {
     pub flags: GpuBuffer<Flags>,
     pub aabbs: GpuBuffer<AABB>,
     pub bvh_nodes: GpuBuffer<BvhNode>,
+    /// Non-zero for BVH nodes whose objects all sleep, see [`SleepManager`](crate::sleep::SleepManager)
+    pub sleeping_nodes: GpuBuffer<u32>,
     pub velocities: GpuBuffer<Velocity>,
     pub integrated_velocities: GpuBuffer<Velocity>,
     pub integrated_aabbs: GpuBuffer<AABB>,
     pub masses: GpuBuffer<Mass>,
     pub colors: GpuBuffer<Color>,
     pub shapes: GpuBuffer<Shape>,
+    /// Extent of the unrotated shapes, the AABBs bound the rotated ones
+    pub sizes: GpuBuffer<Size>,
+    pub angles: GpuBuffer<Angle>,
+    /// Updated in place by the integrator and the contact solver
+    pub angular_velocities: GpuBuffer<AngularVelocity>,
+    pub integrated_angles: GpuBuffer<Angle>,
+    pub polygons: GpuBuffer<Polygon>,
+    pub polygon_vertices: GpuBuffer<PolygonVertex>,
+    /// Second moments of area per unit of mass, they give the moment of inertia
+    pub moments: GpuBuffer<SecondMoments>,
+    /// What happens to circles on fast impacts, see [`ImpactResolver`](crate::impacts::ImpactResolver)
+    pub materials: GpuBuffer<MaterialId>,
+    pub material_table: GpuBuffer<MaterialTable>,
+    /// Number of touching objects, updated by the contact solver
+    pub contact_counts: GpuBuffer<u32>,
+    /// Normals of up to two walls every object was pushed out of in the last step, zero without a level
+    pub wall_normals: GpuBuffer<[f32; 4]>,
+    /// Walls of the level, holds one unused segment if there are none
+    pub segments: GpuBuffer<Segment>,
+    pub segment_count: usize,
+    /// Only changes with accretion, see [`Accretor`](crate::blackholes::Accretor)
+    pub blackholes: GpuBuffer<BlackHoleSet>,
+}
+
+/// State of an object that changes during a simulation, as read back from the GPU
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ObjectState {
+    pub flags: u32,
+    /// Center of the AABB
+    pub position: [f32; 2],
+    pub velocity: [f32; 2],
+    pub angle: f32,
+    pub angular_velocity: f32,
+}
+
+impl ObjectState {
+    pub fn new(flags: Flags, aabb: AABB, velocity: Velocity, angle: Angle, angular_velocity: AngularVelocity) -> Self {
+        Self {
+            flags: flags.inner,
+            position: [(aabb.min[0] + aabb.max[0]) / 2.0, (aabb.min[1] + aabb.max[1]) / 2.0],
+            velocity: velocity.inner,
+            angle: angle.inner,
+            angular_velocity: angular_velocity.inner,
+        }
+    }
+}
+
+/// Half the extent of the AABB of a shape of `size` rotated by `angle`, like `rotated_half_extents` in the shaders.
+/// Polygons are bounded exactly by [`polygon_half_extents`].
+pub fn rotated_half_extents(shape: u32, size: [f32; 2], angle: f32) -> [f32; 2] {
+    if shape == SHAPE_CIRCLE {
+        return [size[0] / 2.0, size[1] / 2.0];
+    }
+    let (sin, cos) = angle.sin_cos();
+    let (sin, cos) = (sin.abs(), cos.abs());
+    if shape == SHAPE_CAPSULE {
+        let radius = size[1] / 2.0;
+        let end = size[0] / 2.0 - radius;
+        return [cos * end + radius, sin * end + radius];
+    }
+    [
+        (cos * size[0] + sin * size[1]) / 2.0,
+        (sin * size[0] + cos * size[1]) / 2.0,
+    ]
+}
+
+/// Half the extent of the AABB of a polygon rotated by `angle`, centered on the polygon's origin
+pub fn polygon_half_extents(vertices: &[[f32; 2]], angle: f32) -> [f32; 2] {
+    let (sin, cos) = angle.sin_cos();
+    vertices.iter().fold([0.0, 0.0], |half_extents, &[x, y]| {
+        [
+            half_extents[0].max((cos * x - sin * y).abs()),
+            half_extents[1].max((sin * x + cos * y).abs()),
+        ]
+    })
+}
+
+/// Second moments of area of a uniform rectangle, disk or capsule about its center along its own axes, divided by
+/// the area so that multiplying with the mass gives the second moments of mass
+pub fn second_moments(shape: u32, size: [f32; 2]) -> SecondMoments {
+    let [width, height] = size;
+    match shape {
+        SHAPE_CIRCLE => {
+            let radius = width / 2.0;
+            SecondMoments::new(radius * radius / 4.0, radius * radius / 4.0, 0.0)
+        }
+        SHAPE_CAPSULE => {
+            // A rectangle between two half disks
+            let radius = height / 2.0;
+            let length = width - height;
+            let pi = std::f32::consts::PI;
+            let area = length * height + pi * radius * radius;
+            let caps_xx = pi * radius.powi(4) / 4.0
+                + 4.0 * length * radius.powi(3) / 3.0
+                + pi * length * length * radius * radius / 4.0;
+            let xx = (height * length.powi(3) / 12.0 + caps_xx) / area;
+            let yy = (length * height.powi(3) / 12.0 + pi * radius.powi(4) / 4.0) / area;
+            SecondMoments::new(xx, yy, 0.0)
+        }
+        _ => SecondMoments::new(width * width / 12.0, height * height / 12.0, 0.0),
+    }
+}
+
+/// Like [`second_moments`] for a counterclockwise polygon around its centroid
+pub fn polygon_second_moments(vertices: &[[f32; 2]]) -> SecondMoments {
+    let (mut area, mut xx, mut yy, mut xy) = (0.0, 0.0, 0.0, 0.0);
+    for (&[x0, y0], &[x1, y1]) in vertices.iter().circular_tuple_windows() {
+        let cross = x0 * y1 - x1 * y0;
+        area += cross / 2.0;
+        xx += cross * (x0 * x0 + x0 * x1 + x1 * x1) / 12.0;
+        yy += cross * (y0 * y0 + y0 * y1 + y1 * y1) / 12.0;
+        xy += cross * (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) / 24.0;
+    }
+    SecondMoments::new(xx / area, yy / area, xy / area)
+}
+
+fn polygon_centroid(vertices: &[[f32; 2]]) -> [f32; 2] {
+    let (mut area, mut x, mut y) = (0.0, 0.0, 0.0);
+    for (&[x0, y0], &[x1, y1]) in vertices.iter().circular_tuple_windows() {
+        let cross = x0 * y1 - x1 * y0;
+        area += cross / 2.0;
+        x += (x0 + x1) * cross / 6.0;
+        y += (y0 + y1) * cross / 6.0;
+    }
+    [x / area, y / area]
+}
+
+/// Counterclockwise convex hull without collinear points, by Andrew's monotone chain
+pub fn convex_hull(points: &[[f32; 2]]) -> Vec<[f32; 2]> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1])));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let turns_left =
+        |o: [f32; 2], a: [f32; 2], b: [f32; 2]| (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0]) > 0.0;
+    let mut hull: Vec<[f32; 2]> = Vec::with_capacity(sorted.len() + 1);
+    // The lower chain from left to right, then the upper chain back
+    for &point in &sorted {
+        while hull.len() >= 2 && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    let lower_length = hull.len() + 1;
+    for &point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_length && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    // The leftmost point closes the chain
+    hull.pop();
+    hull
 }
@@ -0,0 +1,133 @@
+use std::{fs::File, io::BufWriter, path::Path};
+
+use anyhow::{Context as _, bail, ensure};
+use wgpu::{
+    BufferUsages, COPY_BYTES_PER_ROW_ALIGNMENT, CommandEncoder, Device, Extent3d, TexelCopyBufferInfo,
+    TexelCopyBufferLayout, Texture, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages, TextureView,
+    TextureViewDescriptor,
+};
+use winit::dpi::PhysicalSize;
+
+use crate::gpu_buffer::GpuBuffer;
+
+/// A render target that is not presented to a window, but can be read back and encoded as PNG
+pub struct OffscreenTarget {
+    texture: Texture,
+    view: TextureView,
+    readback_buffer: GpuBuffer<u8>,
+    padded_bytes_per_row: u32,
+}
+
+impl OffscreenTarget {
+    /// Fails for formats that can't be saved as PNG and sizes beyond the device's texture limit
+    pub fn new(device: &Device, size: PhysicalSize<u32>, format: TextureFormat) -> anyhow::Result<Self> {
+        if !matches!(
+            format,
+            TextureFormat::Rgba8Unorm
+                | TextureFormat::Rgba8UnormSrgb
+                | TextureFormat::Bgra8Unorm
+                | TextureFormat::Bgra8UnormSrgb
+        ) {
+            bail!("Unsupported offscreen target format: {format:?}");
+        }
+        let max_size = device.limits().max_texture_dimension_2d;
+        ensure!(
+            size.width <= max_size && size.height <= max_size,
+            "{}x{} is larger than the device's maximum texture size of {max_size}x{max_size}",
+            size.width,
+            size.height
+        );
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("offscreen target"),
+            size: Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let padded_bytes_per_row = (size.width * 4).next_multiple_of(COPY_BYTES_PER_ROW_ALIGNMENT);
+        let readback_buffer = GpuBuffer::new(
+            usize::try_from(padded_bytes_per_row * size.height).unwrap(),
+            "offscreen readback buffer",
+            BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+            device,
+        );
+        Ok(Self {
+            texture,
+            view,
+            readback_buffer,
+            padded_bytes_per_row,
+        })
+    }
+
+    pub fn view(&self) -> &TextureView {
+        &self.view
+    }
+
+    pub fn size(&self) -> PhysicalSize<u32> {
+        PhysicalSize::new(self.texture.width(), self.texture.height())
+    }
+
+    pub fn format(&self) -> TextureFormat {
+        self.texture.format()
+    }
+
+    /// Records a copy of the target into the readback buffer, must be submitted before [`Self::read_rgba`]
+    pub fn copy_to_readback(&self, encoder: &mut CommandEncoder) {
+        encoder.copy_texture_to_buffer(
+            self.texture.as_image_copy(),
+            TexelCopyBufferInfo {
+                buffer: self.readback_buffer.buffer(),
+                layout: TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            self.texture.size(),
+        );
+    }
+
+    /// Returns tightly packed RGBA8 pixels, rows top to bottom
+    pub fn read_rgba(&self, device: &Device) -> Vec<u8> {
+        let mut padded = vec![0; self.readback_buffer.len()];
+        self.readback_buffer.read(device, &mut padded);
+
+        let size = self.size();
+        let row_size = usize::try_from(size.width * 4).unwrap();
+        let padded_row_size = usize::try_from(self.padded_bytes_per_row).unwrap();
+        let mut pixels = Vec::with_capacity(row_size * usize::try_from(size.height).unwrap());
+        for row in padded.chunks_exact(padded_row_size).take(size.height.try_into().unwrap()) {
+            pixels.extend_from_slice(&row[..row_size]);
+        }
+        if matches!(self.format(), TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb) {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+        pixels
+    }
+
+    pub fn save_png(&self, device: &Device, path: &Path) -> anyhow::Result<()> {
+        let pixels = self.read_rgba(device);
+        write_png(path, self.size(), &pixels)
+    }
+}
+
+pub fn write_png(path: &Path, size: PhysicalSize<u32>, rgba: &[u8]) -> anyhow::Result<()> {
+    let file = File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), size.width, size.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgba)?;
+    writer.finish()?;
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anyhow::{Context as _, anyhow, bail};
+use winit::dpi::PhysicalSize;
+
+use crate::{forces::TimedForce, scene::SceneOptions, shaders::integration::MAX_FORCES, tools::SpawnSettings};
+
+pub const USAGE: &str = "\
+Usage: collision2 [OPTIONS]
+
+Options:
+  --screenshot-size WxH    Resolution of screenshots taken with F12 (default: window size)
+  --output DIR             Directory for screenshots and frame sequences (default: .)
+  --trails                 Start with motion trails enabled
+  --seed N                 Seed for the scene and everything else that is random (default: random, logged at
+                           startup)
+  --diagnostics            Log energy, momentum and object counts after every simulation step
+  --stats FILE             Write per-step statistics to FILE, as JSON if it ends in .json and CSV otherwise,
+                           implies --diagnostics
+  --stats-interval N       Steps between two statistics samples (default: 1)
+  --record FILE            Record the objects' trajectories to FILE
+  --record-interval N      Steps between two recorded frames (default: 10)
+  --record-objects I,J,..  Indices of the recorded objects (default: all)
+  --replay FILE            Play back a recorded trajectory instead of simulating, headless mode renders one
+                           recorded frame per frame
+  --level FILE             Load the static walls from FILE instead of enclosing the world, one shape per line:
+                             segment X,Y X,Y            A single wall
+                             polyline X,Y X,Y ..        Walls between consecutive points
+                             loop X,Y X,Y X,Y ..        Like polyline, closed back to the first point
+  --accretion              Black holes keep the mass and momentum of the objects they consume, so they grow and drift
+  --collisions             The scene's circles bounce off each other, they are spaced further apart for it
+  --impacts                The scene's circles merge when they hit each other fast and shatter when they hit faster,
+                           implies --collisions
+  --bodies                 Add rods, rocks, paddles, ramps, a rope and a cloth to the scene, implies --collisions
+  --force KIND:ARGS[@N]    Add a force acting on all objects, only for N steps if given, can be repeated:
+                             gravity:GX,GY              Uniform acceleration
+                             attractor:X,Y,S,SOFTENING  Inverse square attraction, negative S repels
+                             drag:K                     Linear drag
+                             quadratic-drag:K           Quadratic drag
+                             vortex:X,Y,S,CORE          Swirl, counterclockwise for positive S
+                             explosion:X,Y,S,RADIUS     Push away falling off to zero at RADIUS
+                             wind:VX,VY,K               Linear drag relative to air moving at VX,VY
+  --spawn-radius R         Radius of circles spawned with the paint tool (default: 2)
+  --spawn-mass M           Mass of circles spawned with the paint tool (default: 2)
+  --spawn-velocity VX,VY   Velocity of circles spawned with the paint tool (default: 0,0)
+  --headless               Render a numbered PNG frame sequence without opening a window
+  --frames N               Number of frames to render in headless mode (default: 100)
+  --steps-per-frame N      Simulation steps between rendered frames in headless mode (default: 10)
+  --size WxH               Frame resolution in headless mode (default: 1920x1080)";
+
+pub struct Options {
+    pub headless: Option<HeadlessOptions>,
+    pub screenshot_size: Option<PhysicalSize<u32>>,
+    pub output_dir: PathBuf,
+    pub trails: bool,
+    /// Runs with the same seed, scene and dt give bit-identical results on the same adapter
+    pub seed: u64,
+    pub diagnostics: bool,
+    pub stats: Option<PathBuf>,
+    pub stats_interval: u64,
+    pub record: Option<PathBuf>,
+    pub record_interval: u32,
+    /// `None` records all objects
+    pub record_objects: Option<Vec<u32>>,
+    pub replay: Option<PathBuf>,
+    /// Replaces the default scene's borders
+    pub level: Option<PathBuf>,
+    pub accretion: bool,
+    pub scene: SceneOptions,
+    pub forces: Vec<TimedForce>,
+    pub spawn: SpawnSettings,
+}
+
+pub struct HeadlessOptions {
+    pub frames: usize,
+    pub steps_per_frame: usize,
+    pub size: PhysicalSize<u32>,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            frames: 100,
+            steps_per_frame: 10,
+            size: PhysicalSize::new(1920, 1080),
+        }
+    }
+}
+
+impl Options {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let mut headless = false;
+        let mut headless_options = HeadlessOptions::default();
+        let mut screenshot_size = None;
+        let mut output_dir = PathBuf::from(".");
+        let mut trails = false;
+        let mut seed = rand::random();
+        let mut diagnostics = false;
+        let mut stats = None;
+        let mut stats_interval = 1;
+        let mut record = None;
+        let mut record_interval = 10;
+        let mut record_objects = None;
+        let mut replay = None;
+        let mut level = None;
+        let mut accretion = false;
+        let mut collisions = false;
+        let mut impacts = false;
+        let mut bodies = false;
+        let mut forces = Vec::new();
+        let mut spawn = SpawnSettings::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| anyhow!("Missing value for {arg}"));
+            match arg.as_str() {
+                "--headless" => headless = true,
+                "--frames" => headless_options.frames = parse_value(&arg, value()?)?,
+                "--steps-per-frame" => headless_options.steps_per_frame = parse_value(&arg, value()?)?,
+                "--size" => headless_options.size = parse_size(&arg, value()?)?,
+                "--screenshot-size" => screenshot_size = Some(parse_size(&arg, value()?)?),
+                "--output" => output_dir = PathBuf::from(value()?),
+                "--trails" => trails = true,
+                "--seed" => seed = parse_value(&arg, value()?)?,
+                "--diagnostics" => diagnostics = true,
+                "--stats" => stats = Some(PathBuf::from(value()?)),
+                "--stats-interval" => stats_interval = parse_value(&arg, value()?)?,
+                "--record" => record = Some(PathBuf::from(value()?)),
+                "--record-interval" => record_interval = parse_value(&arg, value()?)?,
+                "--record-objects" => record_objects = Some(parse_list(&arg, value()?)?),
+                "--replay" => replay = Some(PathBuf::from(value()?)),
+                "--level" => level = Some(PathBuf::from(value()?)),
+                "--accretion" => accretion = true,
+                "--collisions" => collisions = true,
+                "--impacts" => impacts = true,
+                "--bodies" => bodies = true,
+                "--force" => forces.push(value()?.parse().with_context(|| format!("Invalid value for {arg}"))?),
+                "--spawn-radius" => spawn.radius = parse_value(&arg, value()?)?,
+                "--spawn-mass" => spawn.mass = parse_value(&arg, value()?)?,
+                "--spawn-velocity" => spawn.velocity = parse_vector(&arg, value()?)?,
+                _ => bail!("Unknown argument: {arg}"),
+            }
+        }
+
+        if stats_interval == 0 {
+            bail!("Invalid value for --stats-interval: 0, must be at least 1");
+        }
+        if record_interval == 0 {
+            bail!("Invalid value for --record-interval: 0, must be at least 1");
+        }
+        if forces.len() > MAX_FORCES as usize {
+            bail!("At most {MAX_FORCES} forces can be given with --force");
+        }
+        if replay.is_some()
+            && (record.is_some()
+                || stats.is_some()
+                || diagnostics
+                || !forces.is_empty()
+                || level.is_some()
+                || accretion
+                || collisions
+                || impacts
+                || bodies)
+        {
+            bail!(
+                "--replay can't be combined with --record, --stats, --diagnostics, --force, --level, --accretion, \
+                 --collisions, --impacts or --bodies"
+            );
+        }
+
+        Ok(Self {
+            headless: headless.then_some(headless_options),
+            screenshot_size,
+            output_dir,
+            trails,
+            seed,
+            diagnostics: diagnostics || stats.is_some(),
+            stats,
+            stats_interval,
+            record,
+            record_interval,
+            record_objects,
+            replay,
+            level,
+            accretion,
+            scene: SceneOptions {
+                collisions: collisions || impacts || bodies,
+                impacts,
+                bodies,
+            },
+            forces,
+            spawn,
+        })
+    }
+}
+
+fn parse_value<T: FromStr>(arg: &str, value: String) -> anyhow::Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value.parse().with_context(|| format!("Invalid value for {arg}: {value}"))
+}
+
+fn parse_size(arg: &str, value: String) -> anyhow::Result<PhysicalSize<u32>> {
+    let (width, height) =
+        value.split_once('x').ok_or_else(|| anyhow!("Invalid value for {arg}: {value}, expected WxH"))?;
+    let size = PhysicalSize::new(parse_value(arg, width.to_owned())?, parse_value(arg, height.to_owned())?);
+    if size.width == 0 || size.height == 0 {
+        bail!("Invalid value for {arg}: {value}, size must be non-zero");
+    }
+    Ok(size)
+}
+
+fn parse_list<T: FromStr>(arg: &str, value: String) -> anyhow::Result<Vec<T>>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value.split(',').map(|item| parse_value(arg, item.trim().to_owned())).collect()
+}
+
+fn parse_vector(arg: &str, value: String) -> anyhow::Result<[f32; 2]> {
+    let (x, y) = value.split_once(',').ok_or_else(|| anyhow!("Invalid value for {arg}: {value}, expected X,Y"))?;
+    Ok([parse_value(arg, x.to_owned())?, parse_value(arg, y.to_owned())?])
+}
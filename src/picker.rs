@@ -0,0 +1,189 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use bytemuck::Zeroable;
+use wgpu::{
+    BufferUsages, CommandEncoderDescriptor, ComputePassDescriptor, ComputePipeline, Device, MapMode, Queue,
+    SubmissionIndex,
+};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::{
+        common::{
+            FLAG_BULLET, FLAG_DESTROYED, FLAG_DRAW_AABB, FLAG_DRAW_OBJECT, FLAG_PHYSICAL, FLAG_SLEEPING, SHAPE_CAPSULE,
+            SHAPE_CIRCLE, SHAPE_POLYGON, SHAPE_RECT,
+        },
+        pick::{self, PICK_NONE, PickQuery, PickedObject, compute::create_pick_pipeline_embed_source},
+    },
+    util::DeviceUtil,
+};
+
+/// Readback of [`Picker::follow`], at most one query is in flight and the frames in between see the last result
+struct Following {
+    readback: GpuBuffer<PickedObject>,
+    /// Set by the map callback once the query in flight can be read
+    mapped: Arc<AtomicBool>,
+    in_flight: bool,
+    latest: Option<PickedObject>,
+}
+
+/// Finds objects at world positions with a BVH point query on the GPU and reads their state back
+pub struct Picker {
+    pipeline: ComputePipeline,
+    bind_group: pick::WgpuBindGroup0,
+    query: GpuBuffer<PickQuery>,
+    picked: GpuBuffer<PickedObject>,
+    readback: GpuBuffer<PickedObject>,
+    following: Following,
+    device: Device,
+    queue: Queue,
+}
+
+impl Picker {
+    pub fn new(device: &Device, queue: &Queue, buffers: &ObjectBuffers) -> Self {
+        let query = GpuBuffer::new(1, "pick query buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        let picked = GpuBuffer::new(1, "picked object buffer", BufferUsages::STORAGE | BufferUsages::COPY_SRC, device);
+        let readback_usage = BufferUsages::MAP_READ | BufferUsages::COPY_DST;
+        let readback = GpuBuffer::new(1, "picked object readback buffer", readback_usage, device);
+        let following = Following {
+            readback: GpuBuffer::new(1, "followed object readback buffer", readback_usage, device),
+            mapped: Arc::new(AtomicBool::new(false)),
+            in_flight: false,
+            latest: None,
+        };
+        let bind_group = pick::WgpuBindGroup0::from_bindings(
+            device,
+            pick::WgpuBindGroup0Entries::new(pick::WgpuBindGroup0EntriesParams {
+                query: query.buffer().as_entire_buffer_binding(),
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                nodes: buffers.bvh_nodes.buffer().as_entire_buffer_binding(),
+                velocities: buffers.velocities.buffer().as_entire_buffer_binding(),
+                masses: buffers.masses.buffer().as_entire_buffer_binding(),
+                shapes: buffers.shapes.buffer().as_entire_buffer_binding(),
+                colors: buffers.colors.buffer().as_entire_buffer_binding(),
+                angles: buffers.angles.buffer().as_entire_buffer_binding(),
+                angular_velocities: buffers.angular_velocities.buffer().as_entire_buffer_binding(),
+                picked: picked.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        Self {
+            pipeline: create_pick_pipeline_embed_source(device),
+            bind_group,
+            query,
+            picked,
+            readback,
+            following,
+            device: device.clone(),
+            queue: queue.clone(),
+        }
+    }
+
+    /// Returns the drawn object at `point` in world space, the one with the closest center if several overlap
+    pub fn pick(&self, point: [f32; 2]) -> Option<PickedObject> {
+        self.run(PickQuery::new(point, PICK_NONE))
+    }
+
+    /// Reads the current state of the object with the given index
+    pub fn inspect(&self, index: u32) -> Option<PickedObject> {
+        self.run(PickQuery::new([0.0, 0.0], index))
+    }
+
+    /// Like [`Self::inspect`] without waiting for the GPU, for reading an object every frame. Returns the result of
+    /// the last query that finished and starts the next one once it did, so the result lags a few frames behind and
+    /// is `None` until the first query for `index` came back. Needs a thread polling the device.
+    pub fn follow(&mut self, index: u32) -> Option<PickedObject> {
+        let following = &mut self.following;
+        if following.in_flight && following.mapped.swap(false, Ordering::Acquire) {
+            let view = following.readback.buffer().get_mapped_range(..);
+            let picked = bytemuck::cast_slice::<_, PickedObject>(&view)[0];
+            drop(view);
+            following.readback.buffer().unmap();
+            following.in_flight = false;
+            following.latest = Some(picked).filter(|picked| picked.index != PICK_NONE);
+        }
+        if !self.following.in_flight {
+            self.submit(PickQuery::new([0.0, 0.0], index), &self.following.readback);
+            let mapped = self.following.mapped.clone();
+            self.following.readback.buffer().map_async(MapMode::Read, .., move |result| {
+                // Fails if the picker is dropped while mapping
+                mapped.store(result.is_ok(), Ordering::Release);
+            });
+            self.following.in_flight = true;
+        }
+        self.following.latest.filter(|picked| picked.index == index)
+    }
+
+    fn run(&self, query: PickQuery) -> Option<PickedObject> {
+        let submission_index = self.submit(query, &self.readback);
+        self.device.wait_for_submission(submission_index).unwrap();
+
+        let mut picked = [PickedObject::zeroed()];
+        self.readback.read(&self.device, &mut picked);
+        Some(picked[0]).filter(|picked| picked.index != PICK_NONE)
+    }
+
+    /// Runs the query and copies the result to `readback`
+    fn submit(&self, query: PickQuery, readback: &GpuBuffer<PickedObject>) -> SubmissionIndex {
+        self.query.write(&self.queue, &[query]);
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor::default());
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("pick pass"),
+            timestamp_writes: None,
+        });
+        compute_pass.set_pipeline(&self.pipeline);
+        self.bind_group.set(&mut compute_pass);
+        compute_pass.dispatch_workgroups(1, 1, 1);
+        drop(compute_pass);
+        encoder.copy_buffer_to_buffer(self.picked.buffer(), 0, readback.buffer(), 0, None);
+        self.queue.submit([encoder.finish()])
+    }
+}
+
+/// One line summary of everything known about a picked object
+pub fn describe(object: &PickedObject) -> String {
+    let mut flags = [
+        (FLAG_DRAW_OBJECT, "DRAW_OBJECT"),
+        (FLAG_DRAW_AABB, "DRAW_AABB"),
+        (FLAG_PHYSICAL, "PHYSICAL"),
+        (FLAG_DESTROYED, "DESTROYED"),
+        (FLAG_BULLET, "BULLET"),
+        (FLAG_SLEEPING, "SLEEPING"),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| object.flags & flag != 0)
+    .map(|(_, name)| name)
+    .collect::<Vec<_>>()
+    .join("|");
+    if flags.is_empty() {
+        flags.push('-');
+    }
+    let shape = match object.shape {
+        SHAPE_RECT => "rect",
+        SHAPE_CIRCLE => "circle",
+        SHAPE_POLYGON => "polygon",
+        SHAPE_CAPSULE => "capsule",
+        _ => "unknown",
+    };
+    let [min, max] = [object.aabb.min, object.aabb.max];
+    let [vx, vy] = object.velocity;
+    let [r, g, b, a] = object.color;
+
+    format!(
+        "#{} {flags} {shape} aabb=({:.2}, {:.2})..({:.2}, {:.2}) v=({vx:.2}, {vy:.2}) |v|={:.2} angle={:.2} \
+         w={:.2} mass={} color=({r:.2}, {g:.2}, {b:.2}, {a:.2})",
+        object.index,
+        min[0],
+        min[1],
+        max[0],
+        max[1],
+        vx.hypot(vy),
+        object.angle,
+        object.angular_velocity,
+        object.mass,
+    )
+}
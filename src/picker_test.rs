@@ -0,0 +1,99 @@
+use bytemuck::Zeroable;
+use wgpu::{CommandEncoderDescriptor, ComputePassDescriptor, PollType};
+
+use crate::{
+    bvh_builder::BvhBuilder,
+    objects::{ObjectPrototype, Objects},
+    picker::{Picker, describe},
+    shaders::{
+        common::{SHAPE_CAPSULE, SHAPE_CIRCLE, SHAPE_POLYGON, SHAPE_RECT},
+        pick::PickedObject,
+    },
+    test_util::{TestDevice, object, test_device_or_skip},
+    util::DeviceUtil,
+};
+
+#[test]
+fn pick_and_inspect() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+
+    let mut objects = Objects::default();
+    for (i, (position, size)) in [
+        ([-10.0, 0.0], [4.0, 4.0]),
+        ([10.0, 0.0], [4.0, 4.0]),
+        // Overlaps the first object, but its center is further left
+        ([-12.0, 0.0], [6.0, 6.0]),
+        ([0.0, 20.0], [2.0, 2.0]),
+        ([0.0, -20.0], [8.0, 2.0]),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let shape = if i == 4 { SHAPE_RECT } else { SHAPE_CIRCLE };
+        objects.push(ObjectPrototype {
+            velocity: [i as f32, -(i as f32)],
+            mass: 1.0 + i as f32,
+            // Small enough to keep the rectangle around its picked point
+            angle: i as f32 / 10.0,
+            angular_velocity: i as f32 / 2.0,
+            ..object(shape, position, size)
+        });
+    }
+    let object_count = objects.len();
+    let buffers = objects.to_buffers(device, queue);
+
+    let mut bvh_builder = BvhBuilder::new(
+        device,
+        buffers.aabbs.clone(),
+        buffers.bvh_nodes.clone(),
+        buffers.sleeping_nodes.clone(),
+        object_count,
+    );
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+    let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor::default());
+    bvh_builder.compute(&mut compute_pass);
+    drop(compute_pass);
+    device.wait_for_submission(queue.submit([encoder.finish()])).unwrap();
+
+    let mut picker = Picker::new(device, queue, &buffers);
+    let picked_index = |point| picker.pick(point).map(|object| object.index);
+    assert_eq!(picked_index([-9.0, 1.0]), Some(0));
+    assert_eq!(picked_index([-13.0, 0.0]), Some(2));
+    assert_eq!(picked_index([11.0, -1.5]), Some(1));
+    assert_eq!(picked_index([3.5, -20.5]), Some(4));
+    assert_eq!(picked_index([0.0, 0.0]), None);
+
+    let object = picker.inspect(3).unwrap();
+    assert_eq!(object.index, 3);
+    assert_eq!(object.mass, 4.0);
+    assert_eq!(object.velocity, [3.0, -3.0]);
+    assert_eq!(object.shape, SHAPE_CIRCLE);
+    assert_eq!((object.angle, object.angular_velocity), (3.0 / 10.0, 1.5));
+    assert_eq!((object.aabb.min, object.aabb.max), ([-1.0, 19.0], [1.0, 21.0]));
+
+    // Following returns nothing until the first query came back
+    assert_eq!(picker.follow(3), None);
+    device.poll(PollType::wait_indefinitely()).unwrap();
+    assert_eq!(picker.follow(3), Some(object));
+    // The result for the previous object isn't returned after switching
+    device.poll(PollType::wait_indefinitely()).unwrap();
+    assert_eq!(picker.follow(1), None);
+    device.poll(PollType::wait_indefinitely()).unwrap();
+    assert_eq!(picker.follow(1).map(|object| object.index), Some(1));
+}
+
+#[test]
+fn describe_names_every_shape() {
+    for (shape, name) in [
+        (SHAPE_RECT, "rect"),
+        (SHAPE_CIRCLE, "circle"),
+        (SHAPE_POLYGON, "polygon"),
+        (SHAPE_CAPSULE, "capsule"),
+    ] {
+        let object = PickedObject {
+            shape,
+            ..PickedObject::zeroed()
+        };
+        assert!(describe(&object).starts_with(&format!("#0 - {name} ")), "{}", describe(&object));
+    }
+}
@@ -0,0 +1,345 @@
+use std::{ops::Range, time::Duration};
+
+use wgpu::{
+    BufferUsages, CommandEncoderDescriptor, ComputePassDescriptor, PipelineCache, PipelineCacheDescriptor,
+    RenderPassColorAttachment, RenderPassDescriptor, SubmissionIndex, TextureFormat, TextureView,
+};
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+
+use crate::{
+    aabb_renderer::{AabbLevels, AabbRenderer},
+    blackhole_renderer::BlackHoleRenderer,
+    debug_panel::DebugPanel,
+    gpu_buffer::GpuBuffer,
+    heatmap_renderer::{HeatmapQuantity, HeatmapRenderer, legend_labels},
+    objects::ObjectBuffers,
+    offscreen::OffscreenTarget,
+    pass_duration::PassDurationMeasurer,
+    segment_renderer::SegmentRenderer,
+    shaders::common::{AABB, Camera},
+    shape_renderer::{ColorMode, ShapeRenderer},
+    trail_renderer::{TrailFade, TrailRenderer},
+    ui_renderer::UiRenderer,
+};
+
+/// What [`render_scene`] draws and how
+pub struct RenderParameters {
+    pub enabled: bool,
+    pub draw_aabbs: bool,
+    pub aabb_levels: AabbLevels,
+    pub zoom: f32,
+    /// World position in the middle of the view
+    pub camera_center: [f32; 2],
+    /// Object selected by clicking, it is highlighted and followed by the camera
+    pub selected: Option<u32>,
+    pub color_mode: ColorMode,
+    pub scale_by_speed: bool,
+    pub trails: bool,
+    /// Fraction of the trail intensity removed per frame
+    pub trail_fade: f32,
+    pub trail_fade_mode: TrailFade,
+    pub draw_heatmap: bool,
+    pub heatmap_quantity: HeatmapQuantity,
+    pub draw_blackholes: bool,
+    /// Contours of the black holes' gravitational potential
+    pub draw_potential: bool,
+}
+
+impl Default for RenderParameters {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            draw_aabbs: false,
+            aabb_levels: AabbLevels::default(),
+            zoom: 0.8,
+            camera_center: [0.0, 0.0],
+            selected: None,
+            color_mode: ColorMode::default(),
+            scale_by_speed: true,
+            trails: false,
+            trail_fade: 0.05,
+            trail_fade_mode: TrailFade::default(),
+            draw_heatmap: false,
+            heatmap_quantity: HeatmapQuantity::default(),
+            draw_blackholes: false,
+            draw_potential: false,
+        }
+    }
+}
+
+/// Everything that draws the objects' buffers, created with [`create_renderers`]
+pub struct Renderers {
+    pub shape_renderer: ShapeRenderer,
+    pub aabb_renderer: AabbRenderer,
+    pub segment_renderer: SegmentRenderer,
+    pub trail_renderer: TrailRenderer,
+    pub heatmap_renderer: HeatmapRenderer,
+    pub blackhole_renderer: BlackHoleRenderer,
+    pub ui_renderer: UiRenderer,
+    pub camera: GpuBuffer<Camera>,
+}
+
+fn create_pipeline_cache(device: &wgpu::Device) -> Option<PipelineCache> {
+    device.features().contains(wgpu::Features::PIPELINE_CACHE).then(|| unsafe {
+        device.create_pipeline_cache(&PipelineCacheDescriptor {
+            label: None,
+            data: None,
+            fallback: true,
+        })
+    })
+}
+
+pub fn create_renderers(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    target_format: TextureFormat,
+    world_aabb: AABB,
+    buffers: &ObjectBuffers,
+) -> Renderers {
+    let pipeline_cache = create_pipeline_cache(device);
+    let camera = GpuBuffer::<Camera>::new(1, "camera buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+    let aabb_renderer = AabbRenderer::new(
+        device,
+        target_format,
+        pipeline_cache.as_ref(),
+        queue,
+        camera.clone(),
+        buffers.flags.clone(),
+        buffers.aabbs.clone(),
+    );
+    let shape_renderer = ShapeRenderer::new(
+        device,
+        target_format,
+        pipeline_cache.as_ref(),
+        camera.clone(),
+        buffers.flags.clone(),
+        buffers.aabbs.clone(),
+        buffers.colors.clone(),
+        buffers.shapes.clone(),
+        buffers.velocities.clone(),
+        buffers.masses.clone(),
+        buffers.contact_counts.clone(),
+        buffers.sizes.clone(),
+        buffers.angles.clone(),
+        buffers.polygons.clone(),
+        buffers.polygon_vertices.clone(),
+    );
+    let segment_renderer = SegmentRenderer::new(
+        device,
+        target_format,
+        pipeline_cache.as_ref(),
+        camera.clone(),
+        buffers.segments.clone(),
+        buffers.segment_count,
+    );
+    let trail_renderer = TrailRenderer::new(device, target_format, pipeline_cache.as_ref());
+    let heatmap_renderer = HeatmapRenderer::new(
+        device,
+        target_format,
+        pipeline_cache.as_ref(),
+        world_aabb,
+        camera.clone(),
+        buffers.flags.clone(),
+        buffers.aabbs.clone(),
+        buffers.velocities.clone(),
+    );
+    let blackhole_renderer = BlackHoleRenderer::new(
+        device,
+        target_format,
+        pipeline_cache.as_ref(),
+        queue,
+        world_aabb,
+        camera.clone(),
+        buffers.blackholes.clone(),
+    );
+    let ui_renderer = UiRenderer::new(device, target_format, pipeline_cache.as_ref(), queue);
+    Renderers {
+        shape_renderer,
+        aabb_renderer,
+        segment_renderer,
+        trail_renderer,
+        heatmap_renderer,
+        blackhole_renderer,
+        ui_renderer,
+        camera,
+    }
+}
+
+/// Draws the objects in `range` and the overlays enabled in `render_parameters`, returns the GPU time of the passes.
+/// If `readback_target` is given, the frame is copied to its readback buffer.
+pub fn render_scene(
+    target_view: &TextureView,
+    target_size: PhysicalSize<u32>,
+    readback_target: Option<&OffscreenTarget>,
+    render_parameters: &RenderParameters,
+    renderers: &mut Renderers,
+    camera: Camera,
+    range: Range<usize>,
+    node_count: usize,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+) -> (SubmissionIndex, Duration) {
+    renderers.camera.write(queue, &[camera]);
+    let shape_renderer = &renderers.shape_renderer;
+    shape_renderer.set_parameters(
+        queue,
+        render_parameters.color_mode,
+        render_parameters.scale_by_speed,
+        render_parameters.selected,
+    );
+
+    let pass_duration_measurer = PassDurationMeasurer::new(device);
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+    let draw_trails = render_parameters.enabled && render_parameters.trails;
+    let heatmap_renderer = &renderers.heatmap_renderer;
+    if render_parameters.draw_heatmap {
+        let quantity = render_parameters.heatmap_quantity;
+        let range = quantity.default_range();
+        heatmap_renderer.set_parameters(queue, quantity, range);
+        renderers.ui_renderer.prepare(queue, target_size, &legend_labels(target_size, quantity, range));
+    }
+
+    pass_duration_measurer.measure(&mut encoder, |encoder| {
+        if render_parameters.draw_heatmap {
+            heatmap_renderer.clear(encoder);
+            let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+                label: Some("heatmap pass"),
+                timestamp_writes: None,
+            });
+            heatmap_renderer.compute(&mut compute_pass);
+        }
+
+        if draw_trails {
+            let trail_renderer = &mut renderers.trail_renderer;
+            let accumulation_attachment =
+                trail_renderer.begin_frame(queue, target_size, camera, render_parameters.trail_fade);
+            let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("trail pass"),
+                color_attachments: &[Some(accumulation_attachment)],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            trail_renderer.fade(&mut render_pass, render_parameters.trail_fade_mode);
+            shape_renderer.render(&mut render_pass, range.clone());
+        }
+
+        let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: target_view,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        if draw_trails {
+            renderers.trail_renderer.blit(&mut render_pass);
+        } else if render_parameters.enabled {
+            shape_renderer.render(&mut render_pass, range.clone());
+        }
+        if render_parameters.enabled {
+            renderers.segment_renderer.render(&mut render_pass);
+        }
+        if let Some(selected) = render_parameters.selected {
+            // Drawn again on top of everything else
+            let selected = usize::try_from(selected).unwrap();
+            shape_renderer.render(&mut render_pass, selected..selected + 1);
+        }
+        if render_parameters.draw_heatmap {
+            heatmap_renderer.render(&mut render_pass);
+            renderers.ui_renderer.render(&mut render_pass);
+        }
+        if render_parameters.draw_blackholes {
+            renderers.blackhole_renderer.render(&mut render_pass, render_parameters.draw_potential);
+        }
+        if render_parameters.draw_aabbs {
+            renderers.aabb_renderer.render(&mut render_pass, node_count, render_parameters.aabb_levels);
+        }
+        // TODO: EDF
+    });
+
+    if let Some(readback_target) = readback_target {
+        readback_target.copy_to_readback(&mut encoder);
+    }
+    pass_duration_measurer.update(&mut encoder);
+    let submission_index = queue.submit([encoder.finish()]);
+    let duration = pass_duration_measurer.duration();
+    log::debug!("Rendered {} objects in {:?}", range.len(), duration);
+
+    (submission_index, duration)
+}
+
+/// Draws the debug panel over an already rendered frame
+pub fn render_overlay(
+    target_view: &TextureView,
+    target_size: PhysicalSize<u32>,
+    debug_panel: &DebugPanel,
+    ui_renderer: &mut UiRenderer,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+) -> SubmissionIndex {
+    ui_renderer.prepare(queue, target_size, &debug_panel.quads());
+    let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
+    let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+        label: Some("overlay pass"),
+        color_attachments: &[Some(RenderPassColorAttachment {
+            view: target_view,
+            depth_slice: None,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Load,
+                store: wgpu::StoreOp::Store,
+            },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    });
+    ui_renderer.render(&mut render_pass);
+    drop(render_pass);
+    queue.submit([encoder.finish()])
+}
+
+/// Camera matrix that fits `world_height` into the view at a zoom of 1
+pub fn orthographic_camera(
+    zoom: f32,
+    view_size: PhysicalSize<f32>,
+    world_height: f32,
+    center: [f32; 2],
+) -> [[f32; 4]; 4] {
+    let aspect = view_size.width / view_size.height;
+    let world_width = world_height * aspect;
+    let l = -world_width * 0.5;
+    let r = world_width * 0.5;
+    let b = -world_height * 0.5;
+    let t = world_height * 0.5;
+    let sx = zoom * 2.0 / (r - l);
+    let sy = zoom * 2.0 / (t - b);
+    [
+        [sx, 0.0, 0.0, 0.0],
+        [0.0, sy, 0.0, 0.0],
+        [0.0, 0.0, -1.0, 0.0],
+        [-center[0] * sx, -center[1] * sy, 0.0, 1.0],
+    ]
+}
+
+/// Inverts an [`orthographic_camera`] for a position in window pixels
+pub fn screen_to_world(
+    camera: &[[f32; 4]; 4],
+    view_size: PhysicalSize<f32>,
+    position: PhysicalPosition<f32>,
+) -> [f32; 2] {
+    let clip_x = position.x / view_size.width * 2.0 - 1.0;
+    let clip_y = 1.0 - position.y / view_size.height * 2.0;
+    [
+        (clip_x - camera[3][0]) / camera[0][0],
+        (clip_y - camera[3][1]) / camera[1][1],
+    ]
+}
@@ -0,0 +1,340 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+use color::{AlphaColor, palette::css};
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    aabb_renderer::AabbLevels,
+    debug_panel::{DebugPanel, PanelInput},
+    objects::{ObjectPrototype, Objects},
+    offscreen::{OffscreenTarget, write_png},
+    render::{RenderParameters, Renderers, create_renderers, orthographic_camera, render_overlay, render_scene},
+    scene::world_aabb,
+    shaders::common::{AABB, Camera, FLAG_DRAW_AABB, FLAG_DRAW_OBJECT, FLAG_PHYSICAL, SHAPE_CIRCLE, SHAPE_RECT},
+    shape_renderer::ColorMode,
+    test_util::{TestDevice, circle, object, test_device_or_skip},
+};
+
+const TARGET_SIZE: PhysicalSize<u32> = PhysicalSize::new(96, 64);
+const TARGET_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+const SPEED_LIMIT: f32 = 80.0 * 80.0;
+
+/// Set to regenerate the reference images instead of comparing against them
+const UPDATE_ENV: &str = "UPDATE_GOLDEN";
+
+struct Tolerance {
+    channel: u8,
+    mismatched_pixels: usize,
+}
+
+const DEFAULT_TOLERANCE: Tolerance = Tolerance {
+    channel: 8,
+    mismatched_pixels: 16,
+};
+
+#[test]
+fn golden_shapes_speed_spectrum() {
+    let mut objects = Objects::default();
+    for i in 0..6 {
+        let speed = SPEED_LIMIT * (i + 1) as f32 / 6.0;
+        objects.push(ObjectPrototype {
+            flags: FLAG_DRAW_OBJECT,
+            velocity: [speed, 0.0],
+            ..circle([-12.5 + i as f32 * 5.0, 4.0], 1.5)
+        });
+    }
+    objects.push(ObjectPrototype {
+        flags: FLAG_DRAW_OBJECT,
+        velocity: [0.0, SPEED_LIMIT / 2.0],
+        ..object(SHAPE_RECT, [0.0, -5.0], [12.0, 3.0])
+    });
+    let render_parameters = RenderParameters::default();
+    assert_golden("shapes_speed_spectrum", objects, &[], 20.0, &render_parameters, &DEFAULT_TOLERANCE);
+}
+
+#[test]
+fn golden_shapes_object_color() {
+    let mut objects = Objects::default();
+    let colors = [css::RED, css::LIME, css::BLUE, css::YELLOW];
+    for (i, color) in colors.into_iter().enumerate() {
+        let shape = if i % 2 == 0 { SHAPE_CIRCLE } else { SHAPE_RECT };
+        objects.push(ObjectPrototype {
+            flags: FLAG_DRAW_OBJECT,
+            velocity: [SPEED_LIMIT * i as f32, 0.0],
+            color,
+            ..object(shape, [-9.0 + i as f32 * 6.0, 0.0], [4.0, 4.0])
+        });
+    }
+    let render_parameters = RenderParameters {
+        color_mode: ColorMode::Object,
+        scale_by_speed: false,
+        ..RenderParameters::default()
+    };
+    assert_golden("shapes_object_color", objects, &[], 20.0, &render_parameters, &DEFAULT_TOLERANCE);
+}
+
+#[test]
+fn golden_aabb_frames() {
+    let render_parameters = RenderParameters {
+        enabled: false,
+        draw_aabbs: true,
+        ..RenderParameters::default()
+    };
+    assert_golden(
+        "aabb_frames",
+        aabb_frame_objects(),
+        &AABB_FRAME_TREE,
+        2000.0,
+        &render_parameters,
+        &DEFAULT_TOLERANCE,
+    );
+}
+
+#[test]
+fn golden_aabb_frames_single_level() {
+    let render_parameters = RenderParameters {
+        enabled: false,
+        draw_aabbs: true,
+        aabb_levels: AabbLevels::Single(1),
+        ..RenderParameters::default()
+    };
+    assert_golden(
+        "aabb_frames_single_level",
+        aabb_frame_objects(),
+        &AABB_FRAME_TREE,
+        2000.0,
+        &render_parameters,
+        &DEFAULT_TOLERANCE,
+    );
+}
+
+/// Tree nodes for the three objects of [`aabb_frame_objects`], one per BVH level
+const AABB_FRAME_TREE: [AABB; 2] = [
+    AABB::new([-900.0, -500.0], [50.0, 350.0]),
+    AABB::new([-950.0, -550.0], [950.0, 550.0]),
+];
+
+fn aabb_frame_objects() -> Objects {
+    let mut objects = Objects::default();
+    // The scene is in world units to show the frames at a realistic scale
+    for (position, size) in [
+        ([-800.0, -300.0], [200.0, 400.0]),
+        ([-200.0, 200.0], [500.0, 300.0]),
+        ([600.0, 0.0], [600.0, 600.0]),
+    ] {
+        objects.push(ObjectPrototype {
+            flags: FLAG_DRAW_AABB,
+            color: AlphaColor::TRANSPARENT,
+            ..object(SHAPE_RECT, position, size)
+        });
+    }
+    objects
+}
+
+#[test]
+fn golden_heatmap_density() {
+    let mut objects = Objects::default();
+    // A sparse and a dense block, the dense one saturates the colormap
+    for (center, spacing, count) in [([-400.0, 0.0], 4.0, 100), ([400.0, 0.0], 2.0, 100)] {
+        for x in 0..count {
+            for y in 0..count {
+                let offset = (count as f32 - 1.0) * spacing / 2.0;
+                let position = [
+                    center[0] + x as f32 * spacing - offset,
+                    center[1] + y as f32 * spacing - offset,
+                ];
+                objects.push(ObjectPrototype {
+                    flags: FLAG_PHYSICAL,
+                    ..circle(position, 0.5)
+                });
+            }
+        }
+    }
+    let render_parameters = RenderParameters {
+        enabled: false,
+        draw_heatmap: true,
+        ..RenderParameters::default()
+    };
+    assert_golden("heatmap_density", objects, &[], 2000.0, &render_parameters, &DEFAULT_TOLERANCE);
+}
+
+#[test]
+fn golden_blackholes() {
+    let render_parameters = RenderParameters {
+        enabled: false,
+        draw_blackholes: true,
+        draw_potential: true,
+        ..RenderParameters::default()
+    };
+    assert_golden("blackholes", invisible_object(), &[], 150.0, &render_parameters, &DEFAULT_TOLERANCE);
+}
+
+/// Buffers can't be empty, so scenes without objects have one that isn't drawn
+fn invisible_object() -> Objects {
+    let mut objects = Objects::default();
+    objects.push(ObjectPrototype {
+        flags: 0,
+        ..circle([0.0, 0.0], 0.5)
+    });
+    objects
+}
+
+#[test]
+fn golden_debug_panel() {
+    let mut debug_panel = DebugPanel::default();
+    debug_panel.visible = true;
+    debug_panel.scale = 1.0;
+    let (mut on, mut value) = (true, 0.5);
+    // Built twice so that the selection moves to the slider
+    for input in [None, Some(PanelInput::Next)] {
+        if let Some(input) = input {
+            debug_panel.input(input);
+        }
+        let mut ui = debug_panel.begin();
+        ui.heading("Panel");
+        ui.label("Label 123");
+        ui.checkbox("on", &mut on);
+        ui.slider("x", &mut value, 0.0..=1.0, 2.0);
+    }
+
+    let render_parameters = RenderParameters {
+        enabled: false,
+        ..RenderParameters::default()
+    };
+    let Some(actual) =
+        render_with(invisible_object(), &[], 20.0, &render_parameters, |target, device, queue, renderers| {
+            render_overlay(target.view(), TARGET_SIZE, &debug_panel, &mut renderers.ui_renderer, device, queue);
+        })
+    else {
+        eprintln!("Skipping golden test debug_panel: no software adapter available");
+        return;
+    };
+    compare_golden("debug_panel", actual, &DEFAULT_TOLERANCE);
+}
+
+/// Renders `objects` on a software adapter and compares the result with `src/golden/{name}.png`.
+/// `tree_aabbs` are placed after the objects' AABBs, like the BVH nodes are.
+#[test]
+fn offscreen_targets_fail_for_unsupported_formats_and_sizes() {
+    let TestDevice { device, .. } = &test_device_or_skip!();
+    assert!(OffscreenTarget::new(device, TARGET_SIZE, wgpu::TextureFormat::Rgba16Float).is_err());
+    let max_size = device.limits().max_texture_dimension_2d;
+    assert!(OffscreenTarget::new(device, PhysicalSize::new(max_size + 1, 1), TARGET_FORMAT).is_err());
+    assert!(OffscreenTarget::new(device, PhysicalSize::new(1, max_size + 1), TARGET_FORMAT).is_err());
+}
+
+fn assert_golden(
+    name: &str,
+    objects: Objects,
+    tree_aabbs: &[AABB],
+    world_height: f32,
+    render_parameters: &RenderParameters,
+    tolerance: &Tolerance,
+) {
+    let Some(actual) = render_with(objects, tree_aabbs, world_height, render_parameters, |_, _, _, _| ()) else {
+        eprintln!("Skipping golden test {name}: no software adapter available");
+        return;
+    };
+    compare_golden(name, actual, tolerance);
+}
+
+fn compare_golden(name: &str, actual: Vec<u8>, tolerance: &Tolerance) {
+    let reference_path = golden_dir().join(format!("{name}.png"));
+    if env::var_os(UPDATE_ENV).is_some() {
+        write_png(&reference_path, TARGET_SIZE, &actual).unwrap();
+        return;
+    }
+
+    let reference = read_png(&reference_path).unwrap_or_else(|error| {
+        panic!("Failed to read {}: {error}, run with {UPDATE_ENV}=1 to create it", reference_path.display())
+    });
+    assert_eq!(reference.len(), actual.len(), "Reference image {} has wrong size", reference_path.display());
+
+    let mut mismatched_pixels = 0;
+    let mut diff = Vec::with_capacity(actual.len());
+    for (expected, actual) in reference.chunks_exact(4).zip(actual.chunks_exact(4)) {
+        let max_difference = expected.iter().zip(actual).map(|(e, a)| e.abs_diff(*a)).max().unwrap();
+        if max_difference > tolerance.channel {
+            mismatched_pixels += 1;
+        }
+        let intensity = max_difference.saturating_mul(4);
+        diff.extend_from_slice(&[intensity, intensity, intensity, 255]);
+    }
+
+    if mismatched_pixels > tolerance.mismatched_pixels {
+        let output_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("target").join("golden");
+        fs::create_dir_all(&output_dir).unwrap();
+        let actual_path = output_dir.join(format!("{name}.actual.png"));
+        let diff_path = output_dir.join(format!("{name}.diff.png"));
+        write_png(&actual_path, TARGET_SIZE, &actual).unwrap();
+        write_png(&diff_path, TARGET_SIZE, &diff).unwrap();
+        panic!(
+            "{mismatched_pixels} pixels differ from {} by more than {}, see {} and {}",
+            reference_path.display(),
+            tolerance.channel,
+            actual_path.display(),
+            diff_path.display()
+        );
+    }
+}
+
+/// Renders the scene, then calls `overlay` to draw on top of it before reading the target back
+fn render_with(
+    objects: Objects,
+    tree_aabbs: &[AABB],
+    world_height: f32,
+    render_parameters: &RenderParameters,
+    overlay: impl FnOnce(&OffscreenTarget, &wgpu::Device, &wgpu::Queue, &mut Renderers),
+) -> Option<Vec<u8>> {
+    let TestDevice { device, queue, .. } = &TestDevice::new()?;
+
+    let object_count = objects.len();
+    let aabbs = objects.aabbs.iter().chain(tree_aabbs).copied().collect::<Vec<_>>();
+    let buffers = objects.to_buffers(device, queue);
+    buffers.aabbs.write(queue, &aabbs);
+
+    let mut renderers = create_renderers(device, queue, TARGET_FORMAT, world_aabb(), &buffers);
+    let camera =
+        orthographic_camera(render_parameters.zoom, TARGET_SIZE.cast(), world_height, render_parameters.camera_center);
+
+    let target = OffscreenTarget::new(device, TARGET_SIZE, TARGET_FORMAT).unwrap();
+    render_scene(
+        target.view(),
+        TARGET_SIZE,
+        None,
+        render_parameters,
+        &mut renderers,
+        Camera::new(camera),
+        0..object_count,
+        aabbs.len(),
+        device,
+        queue,
+    );
+    overlay(&target, device, queue, &mut renderers);
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    target.copy_to_readback(&mut encoder);
+    queue.submit([encoder.finish()]);
+    Some(target.read_rgba(device))
+}
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("golden")
+}
+
+fn read_png(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let decoder = png::Decoder::new(BufReader::new(File::open(path)?));
+    let mut reader = decoder.read_info()?;
+    let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+    let info = reader.next_frame(&mut pixels)?;
+    anyhow::ensure!(
+        info.color_type == png::ColorType::Rgba && info.bit_depth == png::BitDepth::Eight,
+        "Expected an 8-bit RGBA image"
+    );
+    pixels.truncate(info.buffer_size());
+    Ok(pixels)
+}
@@ -0,0 +1,148 @@
+use color::{AlphaColor, Srgb};
+use wgpu::{CommandEncoderDescriptor, ComputePassDescriptor, Device, Queue};
+
+use crate::{
+    bvh_builder::BvhBuilder,
+    gpu_buffer::GpuBuffer,
+    objects::{ObjectBuffers, ObjectPrototype, Objects, polygon_half_extents, rotated_half_extents},
+    shaders::common::{AABB, Angle, Flags, SHAPE_POLYGON, Velocity},
+    simulation::SimulationStatus,
+    trajectory::{TrajectoryFrame, TrajectoryHeader, TrajectoryReader},
+    util::DeviceUtil,
+};
+
+/// Objects for the recorded ones only, in the header's order. They are hidden until the first frame is shown.
+pub fn replay_objects(header: &TrajectoryHeader) -> Objects {
+    let mut objects = Objects::default();
+    objects.reserve(header.objects.len());
+    for object in &header.objects {
+        let prototype = ObjectPrototype {
+            flags: 0,
+            position: [0.0, 0.0],
+            velocity: [0.0, 0.0],
+            mass: object.mass,
+            size: object.size,
+            color: AlphaColor::<Srgb>::new(object.color),
+            shape: object.shape,
+            angle: 0.0,
+            angular_velocity: 0.0,
+        };
+        if object.shape == SHAPE_POLYGON {
+            objects.push_polygon(prototype, &object.vertices);
+        } else {
+            objects.push(prototype);
+        }
+    }
+    objects
+}
+
+/// Plays a recorded trajectory back into the buffers of [`replay_objects`] instead of simulating. The BVH is
+/// rebuilt for every frame, so that picking and the AABB renderer work as usual.
+pub struct Replay {
+    reader: TrajectoryReader,
+    bvh_builder: BvhBuilder,
+    flags: GpuBuffer<Flags>,
+    aabbs: GpuBuffer<AABB>,
+    velocities: GpuBuffer<Velocity>,
+    angles: GpuBuffer<Angle>,
+    /// Step and time of the frame that is shown
+    shown: Option<(u64, f64)>,
+    node_count: u32,
+    device: Device,
+    queue: Queue,
+}
+
+impl Replay {
+    pub fn new(reader: TrajectoryReader, buffers: &ObjectBuffers, device: &Device, queue: &Queue) -> Self {
+        let object_count = reader.header().objects.len();
+        let bvh_builder = BvhBuilder::new(
+            device,
+            buffers.aabbs.clone(),
+            buffers.bvh_nodes.clone(),
+            buffers.sleeping_nodes.clone(),
+            object_count,
+        );
+        Self {
+            reader,
+            bvh_builder,
+            flags: buffers.flags.clone(),
+            aabbs: buffers.aabbs.clone(),
+            velocities: buffers.velocities.clone(),
+            angles: buffers.angles.clone(),
+            node_count: u32::try_from(object_count).unwrap(),
+            shown: None,
+            device: device.clone(),
+            queue: queue.clone(),
+        }
+    }
+
+    pub fn header(&self) -> &TrajectoryHeader {
+        self.reader.header()
+    }
+
+    /// Shows the next frame and waits for its BVH, returns false after the last frame
+    pub fn advance(&mut self) -> anyhow::Result<bool> {
+        let Some(frame) = self.reader.next_frame()? else {
+            return Ok(false);
+        };
+        self.show(&frame);
+        Ok(true)
+    }
+
+    /// Shows the first frame again
+    pub fn restart(&mut self) -> anyhow::Result<bool> {
+        self.reader.restart()?;
+        self.advance()
+    }
+
+    fn show(&mut self, frame: &TrajectoryFrame) {
+        let flags = frame.objects.iter().map(|state| Flags::new(state.flags)).collect::<Vec<_>>();
+        let aabbs = frame
+            .objects
+            .iter()
+            .zip(&self.reader.header().objects)
+            .map(|(state, object)| {
+                let [x, y] = state.position;
+                let [half_width, half_height] = if object.shape == SHAPE_POLYGON {
+                    polygon_half_extents(&object.vertices, state.angle)
+                } else {
+                    rotated_half_extents(object.shape, object.size, state.angle)
+                };
+                AABB::new([x - half_width, y - half_height], [x + half_width, y + half_height])
+            })
+            .collect::<Vec<_>>();
+        let velocities = frame.objects.iter().map(|state| Velocity::new(state.velocity)).collect::<Vec<_>>();
+        let angles = frame.objects.iter().map(|state| Angle::new(state.angle)).collect::<Vec<_>>();
+        self.flags.write(&self.queue, &flags);
+        self.aabbs.write(&self.queue, &aabbs);
+        self.velocities.write(&self.queue, &velocities);
+        self.angles.write(&self.queue, &angles);
+
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor::default());
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("replay bvh pass"),
+            timestamp_writes: None,
+        });
+        self.bvh_builder.compute(&mut compute_pass);
+        drop(compute_pass);
+        self.device.wait_for_submission(self.queue.submit([encoder.finish()])).unwrap();
+        self.node_count = self.bvh_builder.node_count();
+        self.shown = Some((frame.step, frame.time));
+    }
+
+    pub fn node_count(&self) -> u32 {
+        self.node_count
+    }
+
+    /// Status of the shown frame, the time scale is the playback speed
+    pub fn status(&self, paused: bool, speed: f32) -> SimulationStatus {
+        let (step, time) = self.shown.unwrap_or_default();
+        SimulationStatus {
+            paused,
+            step,
+            time,
+            time_scale: speed,
+            ..SimulationStatus::default()
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::f32::consts::FRAC_PI_2;
+
+use crate::{
+    DT,
+    forces::Force,
+    objects::{ObjectPrototype, ObjectState, Objects},
+    shaders::common::SHAPE_RECT,
+    simulation::Simulation,
+    test_util::{TestDevice, circle, object, test_device_or_skip},
+};
+
+#[test]
+fn aabbs_bound_rotated_rectangles() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    objects.push(rod([-900.0, 900.0], FRAC_PI_2, 0.0));
+    objects.push(rod([-900.0, 850.0], 0.0, 100.0));
+    let initial = objects.aabbs[0];
+    assert!((initial.max[0] - initial.min[0] - 2.0).abs() < 1e-5);
+    assert!((initial.max[1] - initial.min[1] - 10.0).abs() < 1e-5);
+
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.step(DT);
+    let states = simulation.read_objects();
+    let aabbs = simulation.buffers().aabbs.read_back(device, queue, 2);
+    // Spun by 0.1 radians in one step
+    assert!((states[1].angle - 0.1).abs() < 2e-3, "angle {}", states[1].angle);
+    let (sin, cos) = states[1].angle.sin_cos();
+    let expected = [10.0 * cos + 2.0 * sin, 10.0 * sin + 2.0 * cos];
+    let extent = [aabbs[1].max[0] - aabbs[1].min[0], aabbs[1].max[1] - aabbs[1].min[1]];
+    assert!((extent[0] - expected[0]).abs() < 1e-3 && (extent[1] - expected[1]).abs() < 1e-3, "{extent:?}");
+}
+
+#[test]
+fn tidal_gravity_turns_rods_towards_black_holes() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // 60 below the black hole at (-200, 500), which dominates the tidal field there
+    let step_rod = |angle: f32| step(device, queue, rod([-200.0, 440.0], angle, 0.0), None);
+    let turned_left = step_rod(FRAC_PI_2 - 0.3);
+    let turned_right = step_rod(FRAC_PI_2 + 0.3);
+    let aligned = step_rod(FRAC_PI_2);
+
+    // 3 G M / r^3 (I_yy - I_xx) sin(0.3) cos(0.3) / I
+    let expected = 3.0 * 1e9 / 60f32.powi(3) * (100.0 - 4.0) / 12.0 * (0.6f32).sin() / 2.0 / (104.0 / 12.0) * DT;
+    let error = (turned_left.angular_velocity - expected).abs() / expected;
+    assert!(error < 0.05, "angular velocity {}, expected {expected}", turned_left.angular_velocity);
+    assert!(turned_right.angular_velocity < 0.0);
+    assert!(aligned.angular_velocity.abs() < expected * 0.05, "{}", aligned.angular_velocity);
+}
+
+#[test]
+fn force_field_curl_spins_bodies_but_not_circles() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let square = || object(SHAPE_RECT, [300.0, 300.0], [2.0, 2.0]);
+    let baseline = step(device, queue, square(), None);
+    // The vortex core turns at half its curl, 2 * strength / core_radius^2
+    let vortex = Force::Vortex {
+        center: [300.0, 300.0],
+        strength: 10000.0,
+        core_radius: 10.0,
+    };
+    let spun = step(device, queue, square(), Some(vortex));
+    let angular_velocity = spun.angular_velocity - baseline.angular_velocity;
+    assert!((angular_velocity - 100.0 * DT).abs() < 5.0 * DT, "angular velocity {angular_velocity}");
+
+    // Their spin wouldn't change anything, so it isn't computed
+    let circle = step(device, queue, circle([300.0, 300.0], 1.0), Some(vortex));
+    assert_eq!(circle.angular_velocity, 0.0);
+}
+
+/// Runs one step with a single object and returns its state afterwards
+fn step(device: &wgpu::Device, queue: &wgpu::Queue, object: ObjectPrototype, force: Option<Force>) -> ObjectState {
+    let mut objects = Objects::default();
+    objects.push(object);
+    let mut simulation = Simulation::new(objects, device, queue);
+    if let Some(force) = force {
+        simulation.forces_mut().add(force).unwrap();
+    }
+    simulation.step(DT);
+    simulation.read_objects()[0]
+}
+
+fn rod(position: [f32; 2], angle: f32, angular_velocity: f32) -> ObjectPrototype {
+    ObjectPrototype {
+        angle,
+        angular_velocity,
+        ..object(SHAPE_RECT, position, [10.0, 2.0])
+    }
+}
@@ -3,42 +3,76 @@ use std::ops::{Range, RangeInclusive};
 use color::{AlphaColor, palette::css};
 use itertools::Itertools as _;
 use nalgebra::Vector2;
-use rand::random_range;
+use rand::Rng;
 
 use crate::{
+    constraints::ConstraintKind,
+    level::Level,
     objects::{ObjectPrototype, Objects},
     shaders::{
-        common::{AABB, FLAG_DRAW_AABB, FLAG_DRAW_OBJECT, FLAG_PHYSICAL},
-        shape::{SHAPE_CIRCLE, SHAPE_RECT},
+        common::{
+            AABB, FLAG_BULLET, FLAG_DRAW_AABB, FLAG_DRAW_OBJECT, FLAG_PHYSICAL, SHAPE_CAPSULE, SHAPE_CIRCLE,
+            SHAPE_POLYGON, SHAPE_RECT,
+        },
+        materials::Material,
     },
 };
 
-pub fn create_scene(objects: &mut Objects, world_aabb: AABB) {
-    let world_size = world_aabb.size();
+/// Area the default scene fills
+pub fn world_aabb() -> AABB {
+    AABB {
+        min: [-1000.0, -1000.0],
+        max: [1000.0, 1000.0],
+    }
+}
 
-    println!("World size: {}x{}", world_size.x, world_size.y);
+/// What [`create_scene`] puts into the world besides the circles
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SceneOptions {
+    /// The circles bounce off each other. Otherwise they are packed densely and pass through each other.
+    pub collisions: bool,
+    /// The circles merge when they hit each other fast and shatter when they hit faster, needs `collisions`
+    pub impacts: bool,
+    /// Adds rigid bodies of all shapes, ramps, a rope and a cloth among the circles, needs `collisions`
+    pub bodies: bool,
+}
+
+/// Fills `objects` with the scene. All randomness comes from `rng`, so
+/// the same seed gives the same scene.
+pub fn create_scene(objects: &mut Objects, world_aabb: AABB, options: &SceneOptions, rng: &mut impl Rng) {
+    let world_size = world_aabb.size();
 
     let circles = {
         const RADIUS: f32 = 2.0;
-        const MARGIN: f32 = -1.3;
         const POSITION_RAND_FACTOR: f32 = 0.5;
         const VELOCITY_RAND_MAX: f32 = 10.0;
         const VELOCITY_RAND_RANGE_X: RangeInclusive<f32> = -VELOCITY_RAND_MAX..=VELOCITY_RAND_MAX;
         const VELOCITY_RAND_RANGE_Y: RangeInclusive<f32> = -VELOCITY_RAND_MAX..=VELOCITY_RAND_MAX;
         const _COLOR_RAND_RANGE: Range<f32> = 0.8..1.0;
-        const EFFECTIVE_RADIUS: f32 = RADIUS + MARGIN;
-        let shape_count_f32 = world_size / (EFFECTIVE_RADIUS * 2.0);
+        // Colliding circles are spaced so that the jitter doesn't make neighbors overlap, they would be pushed apart
+        // violently in the first steps
+        let margin = if options.collisions {
+            RADIUS * POSITION_RAND_FACTOR
+        } else {
+            -1.3
+        };
+        let effective_radius = RADIUS + margin;
+        let shape_count_f32 = world_size / (effective_radius * 2.0);
         let shape_count: Vector2<usize> = shape_count_f32.try_cast().unwrap();
+        let rng = &mut *rng;
         (0..shape_count.x).cartesian_product(0..shape_count.y).map(move |(i, j)| {
             let (i, j) = (i as f32, j as f32);
             let range = -RADIUS * POSITION_RAND_FACTOR..=RADIUS * POSITION_RAND_FACTOR;
             let position = world_aabb.min()
-                + Vector2::new(EFFECTIVE_RADIUS * (i * 2.0 + 1.0), EFFECTIVE_RADIUS * (j * 2.0 + 1.0))
-                + Vector2::new(random_range(range.clone()), random_range(range));
+                + Vector2::new(effective_radius * (i * 2.0 + 1.0), effective_radius * (j * 2.0 + 1.0))
+                + Vector2::new(rng.random_range(range.clone()), rng.random_range(range));
             ObjectPrototype {
                 flags: FLAG_DRAW_OBJECT | FLAG_DRAW_AABB | FLAG_PHYSICAL,
                 position: position.into(),
-                velocity: [random_range(VELOCITY_RAND_RANGE_X), random_range(VELOCITY_RAND_RANGE_Y)],
+                velocity: [
+                    rng.random_range(VELOCITY_RAND_RANGE_X),
+                    rng.random_range(VELOCITY_RAND_RANGE_Y),
+                ],
                 mass: 2.0,
                 size: [RADIUS * 2.0, RADIUS * 2.0],
                 color: AlphaColor::new([
@@ -48,55 +82,184 @@ pub fn create_scene(objects: &mut Objects, world_aabb: AABB) {
                     1.0,
                 ]),
                 shape: SHAPE_CIRCLE,
+                angle: 0.0,
+                angular_velocity: 0.0,
             }
         })
     };
+    let circles_start = objects.len();
     objects.extend(circles);
+    objects.skip_contacts = !options.collisions;
+    if options.impacts {
+        // Circles flung together by the black holes merge, and shatter when they hit even faster
+        let clay = objects.add_material(Material::new(150.0, 400.0, 4, 0.25));
+        for index in circles_start..objects.len() {
+            objects.set_material(index, clay);
+        }
+    }
 
-    let _borders = world_borders(world_aabb);
-    // for border in _borders {
-    //     objects.push(border);
-    // }
+    if options.bodies {
+        add_bodies(objects, world_aabb, rng);
+    }
+
+    objects.level = Level::borders(world_aabb);
 }
 
-fn world_borders(world_aabb: AABB) -> Vec<ObjectPrototype> {
-    let world_size = world_aabb.size();
-    let border_thickness = world_size.y / 400.0;
-    let top = ObjectPrototype {
-        flags: FLAG_DRAW_OBJECT,
-        position: [0.0, world_aabb.max().y - border_thickness / 2.0],
+/// Rods, rocks and paddles scattered over the world, two ramps at the bottom, and a rope and a cloth hanging at the
+/// top. Everything is placed relative to `world_aabb`, which has to be large enough for the rope and the cloth.
+fn add_bodies(objects: &mut Objects, world_aabb: AABB, rng: &mut impl Rng) {
+    // Rods make rotation visible, the black holes' tidal pull turns them towards themselves. They are thin enough to
+    // pass through walls when they get fast, so they are swept as bullets.
+    let rods = (0..256).map(|_| ObjectPrototype {
+        flags: FLAG_DRAW_OBJECT | FLAG_DRAW_AABB | FLAG_PHYSICAL | FLAG_BULLET,
+        position: random_position(world_aabb, rng),
         velocity: [0.0, 0.0],
-        mass: f32::INFINITY,
-        size: [world_size.x, border_thickness],
-        color: css::RED,
+        mass: 4.0,
+        size: [12.0, 2.0],
+        color: css::WHITE,
         shape: SHAPE_RECT,
-    };
-    let bottom = ObjectPrototype {
-        flags: FLAG_DRAW_OBJECT,
-        position: [0.0, world_aabb.min().y + border_thickness / 2.0],
+        angle: rng.random_range(0.0..std::f32::consts::TAU),
+        angular_velocity: 0.0,
+    });
+    objects.extend(rods.collect_vec());
+
+    for _ in 0..64 {
+        let rock = ObjectPrototype {
+            flags: FLAG_DRAW_OBJECT | FLAG_DRAW_AABB | FLAG_PHYSICAL,
+            position: random_position(world_aabb, rng),
+            velocity: [0.0, 0.0],
+            mass: 20.0,
+            size: [0.0, 0.0],
+            color: css::SLATE_GRAY,
+            shape: SHAPE_POLYGON,
+            angle: 0.0,
+            angular_velocity: rng.random_range(-1.0..1.0),
+        };
+        let radius = rng.random_range(4.0..10.0);
+        objects.push_polygon(rock, &rock_outline(radius, rng));
+    }
+    let paddles = (0..32).map(|_| ObjectPrototype {
+        flags: FLAG_DRAW_OBJECT | FLAG_DRAW_AABB | FLAG_PHYSICAL,
+        position: random_position(world_aabb, rng),
         velocity: [0.0, 0.0],
-        mass: f32::INFINITY,
-        size: [world_size.x, border_thickness],
-        color: css::RED,
-        shape: SHAPE_RECT,
-    };
-    let left = ObjectPrototype {
+        mass: 8.0,
+        size: [20.0, 4.0],
+        color: css::ORANGE,
+        shape: SHAPE_CAPSULE,
+        angle: rng.random_range(0.0..std::f32::consts::TAU),
+        angular_velocity: rng.random_range(-4.0..4.0),
+    });
+    objects.extend(paddles.collect_vec());
+    // Fixed, like the rope and cloth anchors
+    for (corner, slope) in [([0.25, 0.075], 1.0), ([0.75, 0.075], -1.0)] {
+        add_ramp(objects, world_point(world_aabb, corner), [160.0 * slope, 60.0]);
+    }
+
+    add_rope(objects, world_point(world_aabb, [0.15, 0.9]), 60, 4.0, ConstraintKind::Distance);
+    add_cloth(
+        objects,
+        world_point(world_aabb, [0.65, 0.9]),
+        [24, 16],
+        6.0,
+        ConstraintKind::Spring {
+            stiffness: 4000.0,
+            damping: 20.0,
+        },
+    );
+}
+
+/// Hangs a rope of `segment_count` circles to the right of a fixed anchor at `anchor`
+pub fn add_rope(objects: &mut Objects, anchor: [f32; 2], segment_count: usize, spacing: f32, kind: ConstraintKind) {
+    let start = objects.len();
+    objects.push(linked_circle(anchor, spacing, FLAG_DRAW_OBJECT));
+    for i in 1..=segment_count {
+        let position = [anchor[0] + spacing * i as f32, anchor[1]];
+        objects.push(linked_circle(position, spacing, FLAG_DRAW_OBJECT | FLAG_PHYSICAL));
+        objects.connect(start + i - 1, start + i, kind);
+    }
+}
+
+/// A grid of `size` circles connected to their horizontal and vertical neighbors, hanging from its fixed top row
+/// whose left end is at `top_left`
+pub fn add_cloth(objects: &mut Objects, top_left: [f32; 2], size: [usize; 2], spacing: f32, kind: ConstraintKind) {
+    let start = objects.len();
+    let [columns, rows] = size;
+    for (row, column) in (0..rows).cartesian_product(0..columns) {
+        let position = [
+            top_left[0] + spacing * column as f32,
+            top_left[1] - spacing * row as f32,
+        ];
+        let flags = if row == 0 {
+            FLAG_DRAW_OBJECT
+        } else {
+            FLAG_DRAW_OBJECT | FLAG_PHYSICAL
+        };
+        objects.push(linked_circle(position, spacing, flags));
+        let index = start + row * columns + column;
+        if column > 0 {
+            objects.connect(index - 1, index, kind);
+        }
+        if row > 0 {
+            objects.connect(index - columns, index, kind);
+        }
+    }
+}
+
+/// A fixed right triangle with its right angle at `corner`, rising over `extent` which may be negative to mirror it
+pub fn add_ramp(objects: &mut Objects, corner: [f32; 2], extent: [f32; 2]) {
+    // Polygons are centered on their centroid, which is a third of the way along both legs
+    let [x, y] = [-extent[0] / 3.0, -extent[1] / 3.0];
+    let ramp = ObjectPrototype {
         flags: FLAG_DRAW_OBJECT,
+        position: [corner[0] - x, corner[1] - y],
         velocity: [0.0, 0.0],
-        position: [world_aabb.min().x + border_thickness / 2.0, 0.0],
         mass: f32::INFINITY,
-        size: [border_thickness, world_size.y],
-        color: css::RED,
-        shape: SHAPE_RECT,
+        size: [0.0, 0.0],
+        color: css::DARK_KHAKI,
+        shape: SHAPE_POLYGON,
+        angle: 0.0,
+        angular_velocity: 0.0,
     };
-    let right = ObjectPrototype {
-        flags: FLAG_DRAW_OBJECT,
-        position: [world_aabb.max().x - border_thickness / 2.0, 0.0],
+    objects.push_polygon(ramp, &[[x, y], [x + extent[0], y], [x, y + extent[1]]]);
+}
+
+/// Points around a circle of `radius` with jittered angles and distances, their hull is a lumpy convex rock
+fn rock_outline(radius: f32, rng: &mut impl Rng) -> Vec<[f32; 2]> {
+    const POINTS: usize = 9;
+    (0..POINTS)
+        .map(|i| {
+            let angle = (i as f32 + rng.random_range(-0.3..0.3)) / POINTS as f32 * std::f32::consts::TAU;
+            let distance = radius * rng.random_range(0.7..1.0);
+            [distance * angle.cos(), distance * angle.sin()]
+        })
+        .collect()
+}
+
+/// Point at the fractions `[x, y]` of the way from the minimum to the maximum corner of `world_aabb`
+fn world_point(world_aabb: AABB, [x, y]: [f32; 2]) -> [f32; 2] {
+    [
+        world_aabb.min[0] + x * (world_aabb.max[0] - world_aabb.min[0]),
+        world_aabb.min[1] + y * (world_aabb.max[1] - world_aabb.min[1]),
+    ]
+}
+
+fn random_position(world_aabb: AABB, rng: &mut impl Rng) -> [f32; 2] {
+    [
+        rng.random_range(world_aabb.min[0]..world_aabb.max[0]),
+        rng.random_range(world_aabb.min[1]..world_aabb.max[1]),
+    ]
+}
+
+fn linked_circle(position: [f32; 2], spacing: f32, flags: u32) -> ObjectPrototype {
+    ObjectPrototype {
+        flags,
+        position,
         velocity: [0.0, 0.0],
-        mass: f32::INFINITY,
-        size: [border_thickness, world_size.y],
-        color: css::RED,
-        shape: SHAPE_RECT,
-    };
-    vec![top, bottom, left, right]
+        mass: 1.0,
+        size: [spacing * 0.75, spacing * 0.75],
+        color: css::WHITE,
+        shape: SHAPE_CIRCLE,
+        angle: 0.0,
+        angular_velocity: 0.0,
+    }
 }
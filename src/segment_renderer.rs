@@ -0,0 +1,82 @@
+use wgpu::{
+    BlendState, ColorTargetState, Device, MultisampleState, PipelineCache, PrimitiveState, RenderPass, RenderPipeline,
+    RenderPipelineDescriptor, TextureFormat,
+};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    shaders::{
+        common::{Camera, Segment},
+        segment,
+    },
+};
+
+/// Draws the level's walls as lines, like [`AabbRenderer`](crate::aabb_renderer::AabbRenderer) draws the AABB frames
+pub struct SegmentRenderer {
+    render_pipeline: RenderPipeline,
+    bind_group: segment::WgpuBindGroup0,
+    segment_count: usize,
+}
+
+impl SegmentRenderer {
+    pub fn new(
+        device: &Device,
+        target_format: TextureFormat,
+        pipeline_cache: Option<&PipelineCache>,
+        camera_buffer: GpuBuffer<Camera>,
+        segments: GpuBuffer<Segment>,
+        segment_count: usize,
+    ) -> Self {
+        let pipeline_layout = segment::create_pipeline_layout(device);
+        let shader = segment::create_shader_module_embed_source(device);
+
+        let vertex_entry = segment::vs_main_entry();
+        let vertex_state = segment::vertex_state(&shader, &vertex_entry);
+
+        let color_target_state = ColorTargetState {
+            blend: Some(BlendState::ALPHA_BLENDING),
+            ..ColorTargetState::from(target_format)
+        };
+        let fragment_entry = segment::fs_main_entry([Some(color_target_state)]);
+        let fragment_state = segment::fragment_state(&shader, &fragment_entry);
+
+        let render_pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: None,
+            layout: Some(&pipeline_layout),
+            vertex: vertex_state,
+            fragment: Some(fragment_state),
+            primitive: PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList,
+                polygon_mode: wgpu::PolygonMode::Line,
+                ..PrimitiveState::default()
+            },
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            multiview: None,
+            cache: pipeline_cache,
+        });
+
+        let bind_group = segment::WgpuBindGroup0::from_bindings(
+            device,
+            segment::WgpuBindGroup0Entries::new(segment::WgpuBindGroup0EntriesParams {
+                camera: camera_buffer.buffer().as_entire_buffer_binding(),
+                segments: segments.buffer().as_entire_buffer_binding(),
+            }),
+        );
+
+        Self {
+            render_pipeline,
+            bind_group,
+            segment_count,
+        }
+    }
+
+    pub fn render(&self, render_pass: &mut RenderPass<'_>) {
+        if self.segment_count == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&self.render_pipeline);
+        self.bind_group.set(render_pass);
+        render_pass.draw(0..2, 0..u32::try_from(self.segment_count).unwrap());
+    }
+}
@@ -2,34 +2,88 @@
 //
 // ^ wgsl_bindgen version 0.21.3
 // Changes made to this file will not be saved.
-// SourceHash: 001f6b7bd17c925371743fede88d8d3c0a4eb095a56dfeb3faba5e34a7e5c96c
+// SourceHash: 3f9e4eddb9c0f384d86722f0eb9c4fc2ac62a5c0f1d998e1d033e9311b124d18
 
 #![allow(unused, non_snake_case, non_camel_case_types, non_upper_case_globals)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ShaderEntry {
     Common,
+    ToolState,
     Shape,
     AabbFrame,
     Bvh,
     Integration,
+    Contacts,
+    Trail,
+    HeatmapSplat,
+    Heatmap,
+    Blackhole,
+    Pick,
+    Ui,
+    Diagnostics,
+    Constraints,
+    StaticCollision,
+    Segment,
+    Sleep,
+    Accretion,
+    Materials,
+    Impacts,
+    Fracture,
+    Spawn,
 }
 impl ShaderEntry {
     pub fn create_pipeline_layout(&self, device: &wgpu::Device) -> wgpu::PipelineLayout {
         match self {
             Self::Common => common::create_pipeline_layout(device),
+            Self::ToolState => tool_state::create_pipeline_layout(device),
             Self::Shape => shape::create_pipeline_layout(device),
             Self::AabbFrame => aabb_frame::create_pipeline_layout(device),
             Self::Bvh => bvh::create_pipeline_layout(device),
             Self::Integration => integration::create_pipeline_layout(device),
+            Self::Contacts => contacts::create_pipeline_layout(device),
+            Self::Trail => trail::create_pipeline_layout(device),
+            Self::HeatmapSplat => heatmap_splat::create_pipeline_layout(device),
+            Self::Heatmap => heatmap::create_pipeline_layout(device),
+            Self::Blackhole => blackhole::create_pipeline_layout(device),
+            Self::Pick => pick::create_pipeline_layout(device),
+            Self::Ui => ui::create_pipeline_layout(device),
+            Self::Diagnostics => diagnostics::create_pipeline_layout(device),
+            Self::Constraints => constraints::create_pipeline_layout(device),
+            Self::StaticCollision => static_collision::create_pipeline_layout(device),
+            Self::Segment => segment::create_pipeline_layout(device),
+            Self::Sleep => sleep::create_pipeline_layout(device),
+            Self::Accretion => accretion::create_pipeline_layout(device),
+            Self::Materials => materials::create_pipeline_layout(device),
+            Self::Impacts => impacts::create_pipeline_layout(device),
+            Self::Fracture => fracture::create_pipeline_layout(device),
+            Self::Spawn => spawn::create_pipeline_layout(device),
         }
     }
     pub fn create_shader_module_embed_source(&self, device: &wgpu::Device) -> wgpu::ShaderModule {
         match self {
             Self::Common => common::create_shader_module_embed_source(device),
+            Self::ToolState => tool_state::create_shader_module_embed_source(device),
             Self::Shape => shape::create_shader_module_embed_source(device),
             Self::AabbFrame => aabb_frame::create_shader_module_embed_source(device),
             Self::Bvh => bvh::create_shader_module_embed_source(device),
             Self::Integration => integration::create_shader_module_embed_source(device),
+            Self::Contacts => contacts::create_shader_module_embed_source(device),
+            Self::Trail => trail::create_shader_module_embed_source(device),
+            Self::HeatmapSplat => heatmap_splat::create_shader_module_embed_source(device),
+            Self::Heatmap => heatmap::create_shader_module_embed_source(device),
+            Self::Blackhole => blackhole::create_shader_module_embed_source(device),
+            Self::Pick => pick::create_shader_module_embed_source(device),
+            Self::Ui => ui::create_shader_module_embed_source(device),
+            Self::Diagnostics => diagnostics::create_shader_module_embed_source(device),
+            Self::Constraints => constraints::create_shader_module_embed_source(device),
+            Self::StaticCollision => static_collision::create_shader_module_embed_source(device),
+            Self::Segment => segment::create_shader_module_embed_source(device),
+            Self::Sleep => sleep::create_shader_module_embed_source(device),
+            Self::Accretion => accretion::create_shader_module_embed_source(device),
+            Self::Materials => materials::create_shader_module_embed_source(device),
+            Self::Impacts => impacts::create_shader_module_embed_source(device),
+            Self::Fracture => fracture::create_shader_module_embed_source(device),
+            Self::Spawn => spawn::create_shader_module_embed_source(device),
         }
     }
 }
@@ -65,6 +119,10 @@ pub mod layout_asserts {
         assert!(std::mem::offset_of!(common::Velocity, inner) == 0);
         assert!(std::mem::size_of::<common::Velocity>() == 8);
     };
+    const COMMON_MASS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::Mass, inner) == 0);
+        assert!(std::mem::size_of::<common::Mass>() == 4);
+    };
     const COMMON_FLAGS_ASSERTS: () = {
         assert!(std::mem::offset_of!(common::Flags, inner) == 0);
         assert!(std::mem::size_of::<common::Flags>() == 4);
@@ -77,11 +135,34 @@ pub mod layout_asserts {
         assert!(std::mem::offset_of!(common::Shape, inner) == 0);
         assert!(std::mem::size_of::<common::Shape>() == 4);
     };
+    const COMMON_ANGLE_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::Angle, inner) == 0);
+        assert!(std::mem::size_of::<common::Angle>() == 4);
+    };
+    const COMMON_SIZE_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::Size, inner) == 0);
+        assert!(std::mem::size_of::<common::Size>() == 8);
+    };
+    const COMMON_POLYGON_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::Polygon, offset) == 0);
+        assert!(std::mem::offset_of!(common::Polygon, count) == 4);
+        assert!(std::mem::size_of::<common::Polygon>() == 8);
+    };
+    const COMMON_POLYGON_VERTEX_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::PolygonVertex, inner) == 0);
+        assert!(std::mem::size_of::<common::PolygonVertex>() == 8);
+    };
     const COMMON_A_A_B_B_ASSERTS: () = {
         assert!(std::mem::offset_of!(common::AABB, min) == 0);
         assert!(std::mem::offset_of!(common::AABB, max) == 8);
         assert!(std::mem::size_of::<common::AABB>() == 16);
     };
+    const SHAPE_SHAPE_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(shape::ShapeParameters, color_mode) == 0);
+        assert!(std::mem::offset_of!(shape::ShapeParameters, scale_by_speed) == 4);
+        assert!(std::mem::offset_of!(shape::ShapeParameters, highlighted) == 8);
+        assert!(std::mem::size_of::<shape::ShapeParameters>() == 12);
+    };
     const COMMON_BVH_NODE_ASSERTS: () = {
         assert!(std::mem::offset_of!(common::BvhNode, index) == 0);
         assert!(std::mem::size_of::<common::BvhNode>() == 4);
@@ -92,9 +173,191 @@ pub mod layout_asserts {
         assert!(std::mem::offset_of!(bvh::CombineNodePass, parent_count) == 8);
         assert!(std::mem::size_of::<bvh::CombineNodePass>() == 12);
     };
-    const COMMON_MASS_ASSERTS: () = {
-        assert!(std::mem::offset_of!(common::Mass, inner) == 0);
-        assert!(std::mem::size_of::<common::Mass>() == 4);
+    const COMMON_ANGULAR_VELOCITY_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::AngularVelocity, inner) == 0);
+        assert!(std::mem::size_of::<common::AngularVelocity>() == 4);
+    };
+    const COMMON_SECOND_MOMENTS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::SecondMoments, xx) == 0);
+        assert!(std::mem::offset_of!(common::SecondMoments, yy) == 4);
+        assert!(std::mem::offset_of!(common::SecondMoments, xy) == 8);
+        assert!(std::mem::size_of::<common::SecondMoments>() == 12);
+    };
+    const TOOL_STATE_TOOL_STATE_ASSERTS: () = {
+        assert!(std::mem::offset_of!(tool_state::ToolState, cursor) == 0);
+        assert!(std::mem::offset_of!(tool_state::ToolState, cursor_strength) == 8);
+        assert!(std::mem::offset_of!(tool_state::ToolState, cursor_softening) == 12);
+        assert!(std::mem::offset_of!(tool_state::ToolState, grabbed) == 16);
+        assert!(std::mem::offset_of!(tool_state::ToolState, grab_velocity) == 24);
+        assert!(std::mem::size_of::<tool_state::ToolState>() == 32);
+    };
+    const BLACKHOLES_BLACK_HOLE_ASSERTS: () = {
+        assert!(std::mem::offset_of!(blackholes::BlackHole, position) == 0);
+        assert!(std::mem::offset_of!(blackholes::BlackHole, velocity) == 8);
+        assert!(std::mem::offset_of!(blackholes::BlackHole, radius) == 16);
+        assert!(std::mem::offset_of!(blackholes::BlackHole, mass) == 20);
+        assert!(std::mem::offset_of!(blackholes::BlackHole, spin) == 24);
+        assert!(std::mem::size_of::<blackholes::BlackHole>() == 32);
+    };
+    const BLACKHOLES_BLACK_HOLE_SET_ASSERTS: () = {
+        assert!(std::mem::offset_of!(blackholes::BlackHoleSet, blackholes) == 0);
+        assert!(std::mem::size_of::<blackholes::BlackHoleSet>() == 160);
+    };
+    const INTEGRATION_INTEGRATION_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(integration::IntegrationParameters, dt) == 0);
+        assert!(std::mem::offset_of!(integration::IntegrationParameters, integrator) == 4);
+        assert!(std::mem::size_of::<integration::IntegrationParameters>() == 8);
+    };
+    const INTEGRATION_FORCE_ASSERTS: () = {
+        assert!(std::mem::offset_of!(integration::Force, kind) == 0);
+        assert!(std::mem::offset_of!(integration::Force, strength) == 4);
+        assert!(std::mem::offset_of!(integration::Force, radius) == 8);
+        assert!(std::mem::offset_of!(integration::Force, position) == 16);
+        assert!(std::mem::offset_of!(integration::Force, vector) == 24);
+        assert!(std::mem::size_of::<integration::Force>() == 32);
+    };
+    const INTEGRATION_FORCE_SET_ASSERTS: () = {
+        assert!(std::mem::offset_of!(integration::ForceSet, forces) == 0);
+        assert!(std::mem::offset_of!(integration::ForceSet, count) == 1024);
+        assert!(std::mem::size_of::<integration::ForceSet>() == 1032);
+    };
+    const CONTACTS_CONTACT_RESPONSE_ASSERTS: () = {
+        assert!(std::mem::offset_of!(contacts::ContactResponse, velocity) == 0);
+        assert!(std::mem::offset_of!(contacts::ContactResponse, push) == 8);
+        assert!(std::mem::offset_of!(contacts::ContactResponse, angular_velocity) == 16);
+        assert!(std::mem::size_of::<contacts::ContactResponse>() == 24);
+    };
+    const TRAIL_TRAIL_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(trail::TrailParameters, fade) == 0);
+        assert!(std::mem::size_of::<trail::TrailParameters>() == 4);
+    };
+    const HEATMAP_GRID_HEATMAP_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(heatmap_grid::HeatmapParameters, world_min) == 0);
+        assert!(std::mem::offset_of!(heatmap_grid::HeatmapParameters, world_max) == 8);
+        assert!(std::mem::offset_of!(heatmap_grid::HeatmapParameters, grid_size) == 16);
+        assert!(std::mem::offset_of!(heatmap_grid::HeatmapParameters, quantity) == 24);
+        assert!(std::mem::offset_of!(heatmap_grid::HeatmapParameters, value_range) == 28);
+        assert!(std::mem::offset_of!(heatmap_grid::HeatmapParameters, opacity) == 32);
+        assert!(std::mem::size_of::<heatmap_grid::HeatmapParameters>() == 40);
+    };
+    const BLACKHOLE_BLACK_HOLE_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(blackhole::BlackHoleParameters, world_min) == 0);
+        assert!(std::mem::offset_of!(blackhole::BlackHoleParameters, world_max) == 8);
+        assert!(std::mem::size_of::<blackhole::BlackHoleParameters>() == 16);
+    };
+    const PICK_PICK_QUERY_ASSERTS: () = {
+        assert!(std::mem::offset_of!(pick::PickQuery, point) == 0);
+        assert!(std::mem::offset_of!(pick::PickQuery, index) == 8);
+        assert!(std::mem::size_of::<pick::PickQuery>() == 16);
+    };
+    const PICK_PICKED_OBJECT_ASSERTS: () = {
+        assert!(std::mem::offset_of!(pick::PickedObject, color) == 0);
+        assert!(std::mem::offset_of!(pick::PickedObject, aabb) == 16);
+        assert!(std::mem::offset_of!(pick::PickedObject, velocity) == 32);
+        assert!(std::mem::offset_of!(pick::PickedObject, index) == 40);
+        assert!(std::mem::offset_of!(pick::PickedObject, flags) == 44);
+        assert!(std::mem::offset_of!(pick::PickedObject, mass) == 48);
+        assert!(std::mem::offset_of!(pick::PickedObject, shape) == 52);
+        assert!(std::mem::offset_of!(pick::PickedObject, angle) == 56);
+        assert!(std::mem::offset_of!(pick::PickedObject, angular_velocity) == 60);
+        assert!(std::mem::size_of::<pick::PickedObject>() == 64);
+    };
+    const UI_UI_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(ui::UiParameters, view_size) == 0);
+        assert!(std::mem::size_of::<ui::UiParameters>() == 8);
+    };
+    const UI_UI_QUAD_ASSERTS: () = {
+        assert!(std::mem::offset_of!(ui::UiQuad, min) == 0);
+        assert!(std::mem::offset_of!(ui::UiQuad, size) == 8);
+        assert!(std::mem::offset_of!(ui::UiQuad, color) == 16);
+        assert!(std::mem::offset_of!(ui::UiQuad, character) == 32);
+        assert!(std::mem::size_of::<ui::UiQuad>() == 48);
+    };
+    const DIAGNOSTICS_DIAGNOSTICS_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(diagnostics::DiagnosticsParameters, origin) == 0);
+        assert!(std::mem::size_of::<diagnostics::DiagnosticsParameters>() == 8);
+    };
+    const DIAGNOSTICS_DIAGNOSTICS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(diagnostics::Diagnostics, kinetic_energy) == 0);
+        assert!(std::mem::offset_of!(diagnostics::Diagnostics, potential_energy) == 4);
+        assert!(std::mem::offset_of!(diagnostics::Diagnostics, momentum) == 8);
+        assert!(std::mem::offset_of!(diagnostics::Diagnostics, angular_momentum) == 16);
+        assert!(std::mem::offset_of!(diagnostics::Diagnostics, mass) == 20);
+        assert!(std::mem::offset_of!(diagnostics::Diagnostics, live) == 24);
+        assert!(std::mem::offset_of!(diagnostics::Diagnostics, destroyed) == 28);
+        assert!(std::mem::size_of::<diagnostics::Diagnostics>() == 32);
+    };
+    const CONSTRAINTS_CONSTRAINT_ASSERTS: () = {
+        assert!(std::mem::offset_of!(constraints::Constraint, a) == 0);
+        assert!(std::mem::offset_of!(constraints::Constraint, b) == 4);
+        assert!(std::mem::offset_of!(constraints::Constraint, rest_length) == 8);
+        assert!(std::mem::offset_of!(constraints::Constraint, compliance) == 12);
+        assert!(std::mem::offset_of!(constraints::Constraint, damping) == 16);
+        assert!(std::mem::size_of::<constraints::Constraint>() == 20);
+    };
+    const CONSTRAINTS_SOLVER_PASS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(constraints::SolverPass, dt) == 0);
+        assert!(std::mem::size_of::<constraints::SolverPass>() == 4);
+    };
+    const COMMON_SEGMENT_ASSERTS: () = {
+        assert!(std::mem::offset_of!(common::Segment, start) == 0);
+        assert!(std::mem::offset_of!(common::Segment, end) == 8);
+        assert!(std::mem::size_of::<common::Segment>() == 16);
+    };
+    const SLEEP_SLEEP_SETTINGS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(sleep::SleepSettings, speed) == 0);
+        assert!(std::mem::offset_of!(sleep::SleepSettings, angular_speed) == 4);
+        assert!(std::mem::offset_of!(sleep::SleepSettings, acceleration) == 8);
+        assert!(std::mem::offset_of!(sleep::SleepSettings, delay) == 12);
+        assert!(std::mem::size_of::<sleep::SleepSettings>() == 16);
+    };
+    const SLEEP_SLEEP_PASS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(sleep::SleepPass, dt) == 0);
+        assert!(std::mem::offset_of!(sleep::SleepPass, wake_all) == 4);
+        assert!(std::mem::size_of::<sleep::SleepPass>() == 8);
+    };
+    const ACCRETION_ACCRETION_PARAMETERS_ASSERTS: () = {
+        assert!(std::mem::offset_of!(accretion::AccretionParameters, dt) == 0);
+        assert!(std::mem::offset_of!(accretion::AccretionParameters, accrete) == 4);
+        assert!(std::mem::size_of::<accretion::AccretionParameters>() == 8);
+    };
+    const ACCRETION_FIXED_SUM_ASSERTS: () = {
+        assert!(std::mem::offset_of!(accretion::FixedSum, low) == 0);
+        assert!(std::mem::offset_of!(accretion::FixedSum, high) == 4);
+        assert!(std::mem::size_of::<accretion::FixedSum>() == 8);
+    };
+    const ACCRETION_ACCRETION_ASSERTS: () = {
+        assert!(std::mem::offset_of!(accretion::Accretion, sums) == 0);
+        assert!(std::mem::offset_of!(accretion::Accretion, consumed) == 24);
+        assert!(std::mem::size_of::<accretion::Accretion>() == 28);
+    };
+    const MATERIALS_MATERIAL_ASSERTS: () = {
+        assert!(std::mem::offset_of!(materials::Material, merge_speed) == 0);
+        assert!(std::mem::offset_of!(materials::Material, shatter_speed) == 4);
+        assert!(std::mem::offset_of!(materials::Material, fragment_count) == 8);
+        assert!(std::mem::offset_of!(materials::Material, spread) == 12);
+        assert!(std::mem::size_of::<materials::Material>() == 16);
+    };
+    const MATERIALS_MATERIAL_TABLE_ASSERTS: () = {
+        assert!(std::mem::offset_of!(materials::MaterialTable, materials) == 0);
+        assert!(std::mem::size_of::<materials::MaterialTable>() == 256);
+    };
+    const MATERIALS_MATERIAL_ID_ASSERTS: () = {
+        assert!(std::mem::offset_of!(materials::MaterialId, inner) == 0);
+        assert!(std::mem::size_of::<materials::MaterialId>() == 4);
+    };
+    const MATERIALS_IMPACT_ASSERTS: () = {
+        assert!(std::mem::offset_of!(materials::Impact, partner) == 0);
+        assert!(std::mem::offset_of!(materials::Impact, speed) == 4);
+        assert!(std::mem::size_of::<materials::Impact>() == 8);
+    };
+    const TOOL_STATE_SPAWN_REQUEST_ASSERTS: () = {
+        assert!(std::mem::offset_of!(tool_state::SpawnRequest, position) == 0);
+        assert!(std::mem::offset_of!(tool_state::SpawnRequest, velocity) == 8);
+        assert!(std::mem::offset_of!(tool_state::SpawnRequest, radius) == 16);
+        assert!(std::mem::offset_of!(tool_state::SpawnRequest, mass) == 20);
+        assert!(std::mem::offset_of!(tool_state::SpawnRequest, pending) == 24);
+        assert!(std::mem::size_of::<tool_state::SpawnRequest>() == 32);
     };
 }
 pub mod common {
@@ -102,6 +365,13 @@ pub mod common {
     pub const FLAG_DRAW_OBJECT: u32 = 1u32;
     pub const FLAG_DRAW_AABB: u32 = 2u32;
     pub const FLAG_PHYSICAL: u32 = 4u32;
+    pub const FLAG_DESTROYED: u32 = 8u32;
+    pub const FLAG_BULLET: u32 = 16u32;
+    pub const FLAG_SLEEPING: u32 = 32u32;
+    pub const SHAPE_RECT: u32 = 0u32;
+    pub const SHAPE_CIRCLE: u32 = 1u32;
+    pub const SHAPE_POLYGON: u32 = 2u32;
+    pub const SHAPE_CAPSULE: u32 = 3u32;
     pub const BVH_NODE_TREE_FLAG: u32 = 2147483648u32;
     #[repr(C, align(16))]
     #[derive(Debug, PartialEq, Clone, Copy)]
@@ -127,6 +397,17 @@ pub mod common {
     }
     #[repr(C, align(4))]
     #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Mass {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub inner: f32,
+    }
+    impl Mass {
+        pub const fn new(inner: f32) -> Self {
+            Self { inner }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
     pub struct Flags {
         #[doc = "offset: 0, size: 4, type: `u32`"]
         pub inner: u32,
@@ -158,6 +439,52 @@ pub mod common {
             Self { inner }
         }
     }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Angle {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub inner: f32,
+    }
+    impl Angle {
+        pub const fn new(inner: f32) -> Self {
+            Self { inner }
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Size {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub inner: [f32; 2],
+    }
+    impl Size {
+        pub const fn new(inner: [f32; 2]) -> Self {
+            Self { inner }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Polygon {
+        #[doc = "offset: 0, size: 4, type: `u32`"]
+        pub offset: u32,
+        #[doc = "offset: 4, size: 4, type: `u32`"]
+        pub count: u32,
+    }
+    impl Polygon {
+        pub const fn new(offset: u32, count: u32) -> Self {
+            Self { offset, count }
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct PolygonVertex {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub inner: [f32; 2],
+    }
+    impl PolygonVertex {
+        pub const fn new(inner: [f32; 2]) -> Self {
+            Self { inner }
+        }
+    }
     #[repr(C, align(8))]
     #[derive(Debug, PartialEq, Clone, Copy)]
     pub struct AABB {
@@ -184,15 +511,43 @@ pub mod common {
     }
     #[repr(C, align(4))]
     #[derive(Debug, PartialEq, Clone, Copy)]
-    pub struct Mass {
+    pub struct AngularVelocity {
         #[doc = "offset: 0, size: 4, type: `f32`"]
         pub inner: f32,
     }
-    impl Mass {
+    impl AngularVelocity {
         pub const fn new(inner: f32) -> Self {
             Self { inner }
         }
     }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct SecondMoments {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub xx: f32,
+        #[doc = "offset: 4, size: 4, type: `f32`"]
+        pub yy: f32,
+        #[doc = "offset: 8, size: 4, type: `f32`"]
+        pub xy: f32,
+    }
+    impl SecondMoments {
+        pub const fn new(xx: f32, yy: f32, xy: f32) -> Self {
+            Self { xx, yy, xy }
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Segment {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub start: [f32; 2],
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub end: [f32; 2],
+    }
+    impl Segment {
+        pub const fn new(start: [f32; 2], end: [f32; 2]) -> Self {
+            Self { start, end }
+        }
+    }
     #[derive(Debug)]
     pub struct WgpuPipelineLayout;
     impl WgpuPipelineLayout {
@@ -239,11 +594,43 @@ struct Shape {
     inner: u32,
 }
 
+struct Angle {
+    inner: f32,
+}
+
+struct AngularVelocity {
+    inner: f32,
+}
+
+struct Size {
+    inner: vec2<f32>,
+}
+
+struct Polygon {
+    offset: u32,
+    count: u32,
+}
+
+struct PolygonVertex {
+    inner: vec2<f32>,
+}
+
+struct SecondMoments {
+    xx: f32,
+    yy: f32,
+    xy: f32,
+}
+
 struct AABB {
     min: vec2<f32>,
     max: vec2<f32>,
 }
 
+struct Segment {
+    start: vec2<f32>,
+    end: vec2<f32>,
+}
+
 struct BvhNode {
     index: u32,
 }
@@ -252,12 +639,209 @@ const UNIT_QUAD_VERTICES: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(0.
 const FLAG_DRAW_OBJECT: u32 = 1u;
 const FLAG_DRAW_AABB: u32 = 2u;
 const FLAG_PHYSICAL: u32 = 4u;
+const FLAG_DESTROYED: u32 = 8u;
+const FLAG_BULLET: u32 = 16u;
+const FLAG_SLEEPING: u32 = 32u;
+const SHAPE_RECT: u32 = 0u;
+const SHAPE_CIRCLE: u32 = 1u;
+const SHAPE_POLYGON: u32 = 2u;
+const SHAPE_CAPSULE: u32 = 3u;
 const BVH_NODE_TREE_FLAG: u32 = 2147483648u;
 
+fn rotation(angle: f32) -> mat2x2<f32> {
+    let c = cos(angle);
+    let s = sin(angle);
+    return mat2x2<f32>(vec2<f32>(c, s), vec2<f32>(-(s), c));
+}
+
+fn second_moment_tensor(moments: SecondMoments, mass: f32, angle_1: f32) -> mat2x2<f32> {
+    let _e1 = rotation(angle_1);
+    return (((_e1 * mat2x2<f32>(vec2<f32>(moments.xx, moments.xy), vec2<f32>(moments.xy, moments.yy))) * transpose(_e1)) * mass);
+}
+
+fn moment_of_inertia(moments_1: SecondMoments, mass_1: f32) -> f32 {
+    return (mass_1 * (moments_1.xx + moments_1.yy));
+}
+
+fn rotated_half_extents(shape: u32, size: vec2<f32>, angle_2: f32) -> vec2<f32> {
+    if (shape == SHAPE_CIRCLE) {
+        return (size / vec2(2f));
+    }
+    if (shape == SHAPE_CAPSULE) {
+        let radius = (size.y / 2f);
+        return ((abs(vec2<f32>(cos(angle_2), sin(angle_2))) * ((size.x / 2f) - radius)) + vec2(radius));
+    }
+    let c_1 = abs(cos(angle_2));
+    let s_1 = abs(sin(angle_2));
+    return (vec2<f32>(((c_1 * size.x) + (s_1 * size.y)), ((s_1 * size.x) + (c_1 * size.y))) / vec2(2f));
+}
+
 fn invocation_index(gid: vec3<u32>, workgroup_size: u32) -> u32 {
     return (gid.x + ((gid.y * 65535u) * workgroup_size));
 }
 
+fn hsv_to_rgb(hsv: vec3<f32>) -> vec3<f32> {
+    let k = ((fract((vec3(hsv.x) + vec3<f32>(1f, 0.6666667f, 0.33333334f))) * 6f) - vec3(3f));
+    return (hsv.z * mix(vec3(1f), clamp((abs(k) - vec3(1f)), vec3(0f), vec3(1f)), hsv.y));
+}
+
+"#;
+}
+pub mod tool_state {
+    use super::{_root, _root::*};
+    pub const TOOL_NONE: u32 = 4294967295u32;
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ToolState {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub cursor: [f32; 2],
+        #[doc = "offset: 8, size: 4, type: `f32`"]
+        pub cursor_strength: f32,
+        #[doc = "offset: 12, size: 4, type: `f32`"]
+        pub cursor_softening: f32,
+        #[doc = "offset: 16, size: 4, type: `u32`"]
+        pub grabbed: u32,
+        pub _pad_grabbed: [u8; 0x4],
+        #[doc = "offset: 24, size: 8, type: `vec2<f32>`"]
+        pub grab_velocity: [f32; 2],
+    }
+    impl ToolState {
+        pub const fn new(
+            cursor: [f32; 2],
+            cursor_strength: f32,
+            cursor_softening: f32,
+            grabbed: u32,
+            grab_velocity: [f32; 2],
+        ) -> Self {
+            Self {
+                cursor,
+                cursor_strength,
+                cursor_softening,
+                grabbed,
+                _pad_grabbed: [0; 0x4],
+                grab_velocity,
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ToolStateInit {
+        pub cursor: [f32; 2],
+        pub cursor_strength: f32,
+        pub cursor_softening: f32,
+        pub grabbed: u32,
+        pub grab_velocity: [f32; 2],
+    }
+    impl ToolStateInit {
+        pub fn build(&self) -> ToolState {
+            ToolState {
+                cursor: self.cursor,
+                cursor_strength: self.cursor_strength,
+                cursor_softening: self.cursor_softening,
+                grabbed: self.grabbed,
+                _pad_grabbed: [0; 0x4],
+                grab_velocity: self.grab_velocity,
+            }
+        }
+    }
+    impl From<ToolStateInit> for ToolState {
+        fn from(data: ToolStateInit) -> Self {
+            data.build()
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct SpawnRequest {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub position: [f32; 2],
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub velocity: [f32; 2],
+        #[doc = "offset: 16, size: 4, type: `f32`"]
+        pub radius: f32,
+        #[doc = "offset: 20, size: 4, type: `f32`"]
+        pub mass: f32,
+        #[doc = "offset: 24, size: 4, type: `u32`"]
+        pub pending: u32,
+        pub _pad_pending: [u8; 0x4],
+    }
+    impl SpawnRequest {
+        pub const fn new(position: [f32; 2], velocity: [f32; 2], radius: f32, mass: f32, pending: u32) -> Self {
+            Self {
+                position,
+                velocity,
+                radius,
+                mass,
+                pending,
+                _pad_pending: [0; 0x4],
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct SpawnRequestInit {
+        pub position: [f32; 2],
+        pub velocity: [f32; 2],
+        pub radius: f32,
+        pub mass: f32,
+        pub pending: u32,
+    }
+    impl SpawnRequestInit {
+        pub fn build(&self) -> SpawnRequest {
+            SpawnRequest {
+                position: self.position,
+                velocity: self.velocity,
+                radius: self.radius,
+                mass: self.mass,
+                pending: self.pending,
+                _pad_pending: [0; 0x4],
+            }
+        }
+    }
+    impl From<SpawnRequestInit> for SpawnRequest {
+        fn from(data: SpawnRequestInit) -> Self {
+            data.build()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 0]) -> [wgpu::BindGroupLayout; 0] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("ToolState::PipelineLayout"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("tool_state.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct ToolState {
+    cursor: vec2<f32>,
+    cursor_strength: f32,
+    cursor_softening: f32,
+    grabbed: u32,
+    grab_velocity: vec2<f32>,
+}
+
+struct SpawnRequest {
+    position: vec2<f32>,
+    velocity: vec2<f32>,
+    radius: f32,
+    mass: f32,
+    pending: u32,
+}
+
+const TOOL_NONE: u32 = 4294967295u;
+
 "#;
 }
 pub mod bytemuck_impls {
@@ -266,26 +850,127 @@ pub mod bytemuck_impls {
     unsafe impl bytemuck::Pod for common::Camera {}
     unsafe impl bytemuck::Zeroable for common::Velocity {}
     unsafe impl bytemuck::Pod for common::Velocity {}
+    unsafe impl bytemuck::Zeroable for common::Mass {}
+    unsafe impl bytemuck::Pod for common::Mass {}
     unsafe impl bytemuck::Zeroable for common::Flags {}
     unsafe impl bytemuck::Pod for common::Flags {}
     unsafe impl bytemuck::Zeroable for common::Color {}
     unsafe impl bytemuck::Pod for common::Color {}
     unsafe impl bytemuck::Zeroable for common::Shape {}
     unsafe impl bytemuck::Pod for common::Shape {}
+    unsafe impl bytemuck::Zeroable for common::Angle {}
+    unsafe impl bytemuck::Pod for common::Angle {}
+    unsafe impl bytemuck::Zeroable for common::Size {}
+    unsafe impl bytemuck::Pod for common::Size {}
+    unsafe impl bytemuck::Zeroable for common::Polygon {}
+    unsafe impl bytemuck::Pod for common::Polygon {}
+    unsafe impl bytemuck::Zeroable for common::PolygonVertex {}
+    unsafe impl bytemuck::Pod for common::PolygonVertex {}
     unsafe impl bytemuck::Zeroable for common::AABB {}
     unsafe impl bytemuck::Pod for common::AABB {}
+    unsafe impl bytemuck::Zeroable for shape::ShapeParameters {}
+    unsafe impl bytemuck::Pod for shape::ShapeParameters {}
     unsafe impl bytemuck::Zeroable for common::BvhNode {}
     unsafe impl bytemuck::Pod for common::BvhNode {}
     unsafe impl bytemuck::Zeroable for bvh::CombineNodePass {}
     unsafe impl bytemuck::Pod for bvh::CombineNodePass {}
-    unsafe impl bytemuck::Zeroable for common::Mass {}
-    unsafe impl bytemuck::Pod for common::Mass {}
+    unsafe impl bytemuck::Zeroable for common::AngularVelocity {}
+    unsafe impl bytemuck::Pod for common::AngularVelocity {}
+    unsafe impl bytemuck::Zeroable for common::SecondMoments {}
+    unsafe impl bytemuck::Pod for common::SecondMoments {}
+    unsafe impl bytemuck::Zeroable for tool_state::ToolState {}
+    unsafe impl bytemuck::Pod for tool_state::ToolState {}
+    unsafe impl bytemuck::Zeroable for blackholes::BlackHole {}
+    unsafe impl bytemuck::Pod for blackholes::BlackHole {}
+    unsafe impl bytemuck::Zeroable for blackholes::BlackHoleSet {}
+    unsafe impl bytemuck::Pod for blackholes::BlackHoleSet {}
+    unsafe impl bytemuck::Zeroable for integration::IntegrationParameters {}
+    unsafe impl bytemuck::Pod for integration::IntegrationParameters {}
+    unsafe impl bytemuck::Zeroable for integration::Force {}
+    unsafe impl bytemuck::Pod for integration::Force {}
+    unsafe impl bytemuck::Zeroable for integration::ForceSet {}
+    unsafe impl bytemuck::Pod for integration::ForceSet {}
+    unsafe impl bytemuck::Zeroable for contacts::ContactResponse {}
+    unsafe impl bytemuck::Pod for contacts::ContactResponse {}
+    unsafe impl bytemuck::Zeroable for trail::TrailParameters {}
+    unsafe impl bytemuck::Pod for trail::TrailParameters {}
+    unsafe impl bytemuck::Zeroable for heatmap_grid::HeatmapParameters {}
+    unsafe impl bytemuck::Pod for heatmap_grid::HeatmapParameters {}
+    unsafe impl bytemuck::Zeroable for blackhole::BlackHoleParameters {}
+    unsafe impl bytemuck::Pod for blackhole::BlackHoleParameters {}
+    unsafe impl bytemuck::Zeroable for pick::PickQuery {}
+    unsafe impl bytemuck::Pod for pick::PickQuery {}
+    unsafe impl bytemuck::Zeroable for pick::PickedObject {}
+    unsafe impl bytemuck::Pod for pick::PickedObject {}
+    unsafe impl bytemuck::Zeroable for ui::UiParameters {}
+    unsafe impl bytemuck::Pod for ui::UiParameters {}
+    unsafe impl bytemuck::Zeroable for ui::UiQuad {}
+    unsafe impl bytemuck::Pod for ui::UiQuad {}
+    unsafe impl bytemuck::Zeroable for diagnostics::DiagnosticsParameters {}
+    unsafe impl bytemuck::Pod for diagnostics::DiagnosticsParameters {}
+    unsafe impl bytemuck::Zeroable for diagnostics::Diagnostics {}
+    unsafe impl bytemuck::Pod for diagnostics::Diagnostics {}
+    unsafe impl bytemuck::Zeroable for constraints::Constraint {}
+    unsafe impl bytemuck::Pod for constraints::Constraint {}
+    unsafe impl bytemuck::Zeroable for constraints::SolverPass {}
+    unsafe impl bytemuck::Pod for constraints::SolverPass {}
+    unsafe impl bytemuck::Zeroable for common::Segment {}
+    unsafe impl bytemuck::Pod for common::Segment {}
+    unsafe impl bytemuck::Zeroable for sleep::SleepSettings {}
+    unsafe impl bytemuck::Pod for sleep::SleepSettings {}
+    unsafe impl bytemuck::Zeroable for sleep::SleepPass {}
+    unsafe impl bytemuck::Pod for sleep::SleepPass {}
+    unsafe impl bytemuck::Zeroable for accretion::AccretionParameters {}
+    unsafe impl bytemuck::Pod for accretion::AccretionParameters {}
+    unsafe impl bytemuck::Zeroable for accretion::FixedSum {}
+    unsafe impl bytemuck::Pod for accretion::FixedSum {}
+    unsafe impl bytemuck::Zeroable for accretion::Accretion {}
+    unsafe impl bytemuck::Pod for accretion::Accretion {}
+    unsafe impl bytemuck::Zeroable for materials::Material {}
+    unsafe impl bytemuck::Pod for materials::Material {}
+    unsafe impl bytemuck::Zeroable for materials::MaterialTable {}
+    unsafe impl bytemuck::Pod for materials::MaterialTable {}
+    unsafe impl bytemuck::Zeroable for materials::MaterialId {}
+    unsafe impl bytemuck::Pod for materials::MaterialId {}
+    unsafe impl bytemuck::Zeroable for materials::Impact {}
+    unsafe impl bytemuck::Pod for materials::Impact {}
+    unsafe impl bytemuck::Zeroable for tool_state::SpawnRequest {}
+    unsafe impl bytemuck::Pod for tool_state::SpawnRequest {}
 }
 pub mod shape {
     use super::{_root, _root::*};
-    pub const SHAPE_RECT: u32 = 0u32;
-    pub const SHAPE_CIRCLE: u32 = 1u32;
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ShapeParameters {
+        #[doc = "offset: 0, size: 4, type: `u32`"]
+        pub color_mode: u32,
+        #[doc = "offset: 4, size: 4, type: `u32`"]
+        pub scale_by_speed: u32,
+        #[doc = "offset: 8, size: 4, type: `u32`"]
+        pub highlighted: u32,
+    }
+    impl ShapeParameters {
+        pub const fn new(color_mode: u32, scale_by_speed: u32, highlighted: u32) -> Self {
+            Self {
+                color_mode,
+                scale_by_speed,
+                highlighted,
+            }
+        }
+    }
+    pub const COLOR_MODE_OBJECT: u32 = 0u32;
+    pub const COLOR_MODE_SPEED: u32 = 1u32;
+    pub const COLOR_MODE_DIRECTION: u32 = 2u32;
+    pub const COLOR_MODE_MASS: u32 = 3u32;
+    pub const COLOR_MODE_KINETIC_ENERGY: u32 = 4u32;
+    pub const COLOR_MODE_CONTACTS: u32 = 5u32;
+    pub const COLOR_MODE_INDEX: u32 = 6u32;
+    pub const COLOR_MODE_SLEEP: u32 = 7u32;
     pub const COLORING_SPEED_LIMIT: f32 = 6400f32;
+    pub const COLORING_MASS_LOG2_LIMIT: f32 = 16f32;
+    pub const COLORING_KINETIC_ENERGY_LOG10_LIMIT: f32 = 10f32;
+    pub const COLORING_CONTACT_LIMIT: f32 = 8f32;
+    pub const FIELD_ALPHA: f32 = 0.1f32;
     pub const ENTRY_VS_MAIN: &str = "vs_main";
     pub const ENTRY_FS_MAIN: &str = "fs_main";
     #[derive(Debug)]
@@ -345,22 +1030,34 @@ pub mod shape {
     #[derive(Debug)]
     pub struct WgpuBindGroup0EntriesParams<'a> {
         pub camera: wgpu::BufferBinding<'a>,
-        pub size_factor: wgpu::BufferBinding<'a>,
+        pub parameters: wgpu::BufferBinding<'a>,
         pub flags: wgpu::BufferBinding<'a>,
         pub aabbs: wgpu::BufferBinding<'a>,
         pub colors: wgpu::BufferBinding<'a>,
         pub shapes: wgpu::BufferBinding<'a>,
         pub velocities: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub contact_counts: wgpu::BufferBinding<'a>,
+        pub sizes: wgpu::BufferBinding<'a>,
+        pub angles: wgpu::BufferBinding<'a>,
+        pub polygons: wgpu::BufferBinding<'a>,
+        pub polygon_vertices: wgpu::BufferBinding<'a>,
     }
     #[derive(Clone, Debug)]
     pub struct WgpuBindGroup0Entries<'a> {
         pub camera: wgpu::BindGroupEntry<'a>,
-        pub size_factor: wgpu::BindGroupEntry<'a>,
+        pub parameters: wgpu::BindGroupEntry<'a>,
         pub flags: wgpu::BindGroupEntry<'a>,
         pub aabbs: wgpu::BindGroupEntry<'a>,
         pub colors: wgpu::BindGroupEntry<'a>,
         pub shapes: wgpu::BindGroupEntry<'a>,
         pub velocities: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub contact_counts: wgpu::BindGroupEntry<'a>,
+        pub sizes: wgpu::BindGroupEntry<'a>,
+        pub angles: wgpu::BindGroupEntry<'a>,
+        pub polygons: wgpu::BindGroupEntry<'a>,
+        pub polygon_vertices: wgpu::BindGroupEntry<'a>,
     }
     impl<'a> WgpuBindGroup0Entries<'a> {
         pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
@@ -369,9 +1066,9 @@ pub mod shape {
                     binding: 0,
                     resource: wgpu::BindingResource::Buffer(params.camera),
                 },
-                size_factor: wgpu::BindGroupEntry {
+                parameters: wgpu::BindGroupEntry {
                     binding: 1,
-                    resource: wgpu::BindingResource::Buffer(params.size_factor),
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
                 },
                 flags: wgpu::BindGroupEntry {
                     binding: 2,
@@ -393,17 +1090,47 @@ pub mod shape {
                     binding: 6,
                     resource: wgpu::BindingResource::Buffer(params.velocities),
                 },
+                masses: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                contact_counts: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.contact_counts),
+                },
+                sizes: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.sizes),
+                },
+                angles: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.angles),
+                },
+                polygons: wgpu::BindGroupEntry {
+                    binding: 11,
+                    resource: wgpu::BindingResource::Buffer(params.polygons),
+                },
+                polygon_vertices: wgpu::BindGroupEntry {
+                    binding: 12,
+                    resource: wgpu::BindingResource::Buffer(params.polygon_vertices),
+                },
             }
         }
-        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 7] {
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 13] {
             [
                 self.camera,
-                self.size_factor,
+                self.parameters,
                 self.flags,
                 self.aabbs,
                 self.colors,
                 self.shapes,
                 self.velocities,
+                self.masses,
+                self.contact_counts,
+                self.sizes,
+                self.angles,
+                self.polygons,
+                self.polygon_vertices,
             ]
         }
         pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
@@ -427,14 +1154,16 @@ pub mod shape {
                     },
                     count: None,
                 },
-                #[doc = " @binding(1): \"size_factor\""]
+                #[doc = " @binding(1): \"parameters\""]
                 wgpu::BindGroupLayoutEntry {
                     binding: 1,
                     visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Uniform,
                         has_dynamic_offset: false,
-                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<f32>() as _),
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::shape::ShapeParameters>() as _,
+                        ),
                     },
                     count: None,
                 },
@@ -493,35 +1222,101 @@ pub mod shape {
                     },
                     count: None,
                 },
-            ],
-        };
-        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
-            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
-        }
-        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
-            let bind_group_layout = Self::get_bind_group_layout(device);
-            let entries = bindings.into_array();
-            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("Shape::BindGroup0"),
-                layout: &bind_group_layout,
-                entries: &entries,
-            });
-            Self(bind_group)
-        }
-        pub fn set(&self, pass: &mut impl SetBindGroup) {
-            pass.set_bind_group(0, &self.0, &[]);
-        }
-    }
-    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
-    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
-    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
-    #[doc = "   - Bind group 1: More frequent updates"]
-    #[doc = "   - Bind group 2: More frequent updates"]
-    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
-    #[derive(Debug, Copy, Clone)]
-    pub struct WgpuBindGroups<'a> {
-        pub bind_group0: &'a WgpuBindGroup0,
-    }
+                #[doc = " @binding(7): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"contact_counts\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"sizes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"angles\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(11): \"polygons\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 11,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(12): \"polygon_vertices\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 12,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Shape::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
     impl<'a> WgpuBindGroups<'a> {
         pub fn set(&self, pass: &mut impl SetBindGroup) {
             self.bind_group0.set(pass);
@@ -557,6 +1352,10 @@ struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
     inner: vec2<f32>,
 }
 
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
 struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
     inner: u32,
 }
@@ -569,6 +1368,23 @@ struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
     inner: u32,
 }
 
+struct AngleX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct SizeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct PolygonX_naga_oil_mod_XMNXW23LPNYX {
+    offset: u32,
+    count: u32,
+}
+
+struct PolygonVertexX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
 struct AABBX_naga_oil_mod_XMNXW23LPNYX {
     min: vec2<f32>,
     max: vec2<f32>,
@@ -576,9 +1392,17 @@ struct AABBX_naga_oil_mod_XMNXW23LPNYX {
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
-    @location(0) quad_position: vec2<f32>,
+    @location(0) local_position: vec2<f32>,
     @location(1) color: vec4<f32>,
     @location(2) @interpolate(flat) shape: u32,
+    @location(3) size: vec2<f32>,
+    @location(4) @interpolate(flat) index: u32,
+}
+
+struct ShapeParameters {
+    color_mode: u32,
+    scale_by_speed: u32,
+    highlighted: u32,
 }
 
 struct FragmentOutput {
@@ -587,14 +1411,29 @@ struct FragmentOutput {
 
 const UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(0.5f, 0.5f), vec2<f32>(-0.5f, 0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(0.5f, -0.5f), vec2<f32>(0.5f, 0.5f));
 const FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
-const SHAPE_RECT: u32 = 0u;
-const SHAPE_CIRCLE: u32 = 1u;
+const FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX: u32 = 32u;
+const SHAPE_RECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 0u;
+const SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const SHAPE_POLYGONX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
+const SHAPE_CAPSULEX_naga_oil_mod_XMNXW23LPNYX: u32 = 3u;
+const COLOR_MODE_OBJECT: u32 = 0u;
+const COLOR_MODE_SPEED: u32 = 1u;
+const COLOR_MODE_DIRECTION: u32 = 2u;
+const COLOR_MODE_MASS: u32 = 3u;
+const COLOR_MODE_KINETIC_ENERGY: u32 = 4u;
+const COLOR_MODE_CONTACTS: u32 = 5u;
+const COLOR_MODE_INDEX: u32 = 6u;
+const COLOR_MODE_SLEEP: u32 = 7u;
 const COLORING_SPEED_LIMIT: f32 = 6400f;
+const COLORING_MASS_LOG2_LIMIT: f32 = 16f;
+const COLORING_KINETIC_ENERGY_LOG10_LIMIT: f32 = 10f;
+const COLORING_CONTACT_LIMIT: f32 = 8f;
+const FIELD_ALPHA: f32 = 0.1f;
 
 @group(0) @binding(0) 
 var<uniform> camera: CameraX_naga_oil_mod_XMNXW23LPNYX;
 @group(0) @binding(1) 
-var<uniform> size_factor: f32;
+var<uniform> parameters: ShapeParameters;
 @group(0) @binding(2) 
 var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(3) 
@@ -605,6 +1444,29 @@ var<storage> colors: array<ColorX_naga_oil_mod_XMNXW23LPNYX>;
 var<storage> shapes: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(6) 
 var<storage> velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(7) 
+var<storage> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(8) 
+var<storage> contact_counts: array<u32>;
+@group(0) @binding(9) 
+var<storage> sizes: array<SizeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(10) 
+var<storage> angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(11) 
+var<storage> polygons: array<PolygonX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(12) 
+var<storage> polygon_vertices: array<PolygonVertexX_naga_oil_mod_XMNXW23LPNYX>;
+
+fn rotationX_naga_oil_mod_XMNXW23LPNYX(angle: f32) -> mat2x2<f32> {
+    let c = cos(angle);
+    let s = sin(angle);
+    return mat2x2<f32>(vec2<f32>(c, s), vec2<f32>(-(s), c));
+}
+
+fn hsv_to_rgbX_naga_oil_mod_XMNXW23LPNYX(hsv: vec3<f32>) -> vec3<f32> {
+    let k = ((fract((vec3(hsv.x) + vec3<f32>(1f, 0.6666667f, 0.33333334f))) * 6f) - vec3(3f));
+    return (hsv.z * mix(vec3(1f), clamp((abs(k) - vec3(1f)), vec3(0f), vec3(1f)), hsv.y));
+}
 
 fn wavelength_to_rgb(lambda: f32) -> vec3<f32> {
     var r: f32 = 0f;
@@ -655,16 +1517,121 @@ fn spectral_intensity(lambda_1: f32) -> f32 {
     return 1f;
 }
 
-fn velocity_to_color(velocity: vec2<f32>, relative_speed: f32) -> vec4<f32> {
-    let t = clamp(relative_speed, 0f, 1f);
-    let lambda_2 = mix(700f, 380f, t);
+fn spectrum_color(t: f32) -> vec3<f32> {
+    let lambda_2 = mix(700f, 380f, clamp(t, 0f, 1f));
     let _e7 = wavelength_to_rgb(lambda_2);
     let _e8 = spectral_intensity(lambda_2);
-    return vec4<f32>((_e7 * _e8), 0.1f);
+    return (_e7 * _e8);
+}
+
+fn speed_to_color(relative_speed: f32) -> vec4<f32> {
+    let _e1 = spectrum_color(relative_speed);
+    return vec4<f32>(_e1, FIELD_ALPHA);
+}
+
+fn object_color(i_1: u32, velocity: vec2<f32>, relative_speed_1: f32) -> vec4<f32> {
+    let _e2 = parameters.color_mode;
+    switch _e2 {
+        case 0u: {
+            let _e7 = colors[i_1].inner;
+            return _e7;
+        }
+        case 2u: {
+            let hue = ((atan2(velocity.y, velocity.x) / 6.2831855f) + 0.5f);
+            let value = clamp((sqrt(relative_speed_1) * 4f), 0.2f, 1f);
+            let _e25 = hsv_to_rgbX_naga_oil_mod_XMNXW23LPNYX(vec3<f32>(hue, 1f, value));
+            return vec4<f32>(_e25, FIELD_ALPHA);
+        }
+        case 3u: {
+            let _e31 = masses[i_1].inner;
+            let t_1 = (log2((1f + _e31)) / COLORING_MASS_LOG2_LIMIT);
+            let _e37 = spectrum_color(t_1);
+            return vec4<f32>(_e37, FIELD_ALPHA);
+        }
+        case 4u: {
+            let _e43 = masses[i_1].inner;
+            let kinetic_energy = ((0.5f * _e43) * dot(velocity, velocity));
+            let t_2 = ((log((1f + kinetic_energy)) / 2.3025851f) / COLORING_KINETIC_ENERGY_LOG10_LIMIT);
+            let _e55 = spectrum_color(t_2);
+            return vec4<f32>(_e55, FIELD_ALPHA);
+        }
+        case 5u: {
+            let _e60 = contact_counts[i_1];
+            let t_3 = (f32(_e60) / COLORING_CONTACT_LIMIT);
+            let _e64 = spectrum_color(t_3);
+            return vec4<f32>(_e64, FIELD_ALPHA);
+        }
+        case 7u: {
+            let _e70 = flags[i_1].inner;
+            let sleeping = ((_e70 & FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX) != 0u);
+            return select(vec4<f32>(1f, 0.6f, 0.2f, 0.1f), vec4<f32>(0.2f, 0.4f, 1f, 0.1f), sleeping);
+        }
+        case 6u: {
+            let hue_1 = fract((f32(i_1) * 0.618034f));
+            let _e93 = hsv_to_rgbX_naga_oil_mod_XMNXW23LPNYX(vec3<f32>(hue_1, 0.8f, 1f));
+            return vec4<f32>(_e93, FIELD_ALPHA);
+        }
+        default: {
+            let _e97 = speed_to_color(sqrt(relative_speed_1));
+            return _e97;
+        }
+    }
+}
+
+fn sdf_polygon(p: vec2<f32>, polygon: PolygonX_naga_oil_mod_XMNXW23LPNYX) -> f32 {
+    var inside: f32 = -1000000000000000000000000000000f;
+    var outside: f32 = 1000000000000000000000000000000f;
+    var v_1: u32 = 0u;
+
+    loop {
+        let _e5 = v_1;
+        if (_e5 < polygon.count) {
+        } else {
+            break;
+        }
+        {
+            let _e10 = v_1;
+            let a = polygon_vertices[(polygon.offset + _e10)].inner;
+            let _e16 = v_1;
+            let b_1 = polygon_vertices[(polygon.offset + ((_e16 + 1u) % polygon.count))].inner;
+            let edge = (b_1 - a);
+            let t_4 = clamp((dot((p - a), edge) / dot(edge, edge)), 0f, 1f);
+            let _e36 = outside;
+            outside = min(_e36, length(((p - a) - (edge * t_4))));
+            let _e43 = inside;
+            inside = max(_e43, dot((p - a), normalize(vec2<f32>(edge.y, -(edge.x)))));
+        }
+        continuing {
+            let _e53 = v_1;
+            v_1 = (_e53 + 1u);
+        }
+    }
+    let _e55 = outside;
+    let _e56 = inside;
+    let _e57 = inside;
+    return select(_e55, _e56, (_e57 <= 0f));
 }
 
-fn sdf_cirle(p: vec2<f32>) -> f32 {
-    return (length(p) - 0.5f);
+fn sdf_shape(shape: u32, p_1: vec2<f32>, size: vec2<f32>, i_2: u32) -> f32 {
+    switch shape {
+        case 1u: {
+            return (length(p_1) - (size.x / 2f));
+        }
+        case 3u: {
+            let radius = (size.y / 2f);
+            let end = ((size.x / 2f) - radius);
+            return (length(vec2<f32>((p_1.x - clamp(p_1.x, -(end), end)), p_1.y)) - radius);
+        }
+        case 2u: {
+            let _e27 = polygons[i_2];
+            let _e28 = sdf_polygon(p_1, _e27);
+            return _e28;
+        }
+        default: {
+            let q = (abs(p_1) - (size / vec2(2f)));
+            return (length(max(q, vec2<f32>())) + min(max(q.x, q.y), 0f));
+        }
+    }
 }
 
 @vertex 
@@ -679,29 +1646,42 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) i:
         return _e11;
     }
     let aabb = aabbs[i];
-    let _e19 = size_factor;
-    scale = ((aabb.max - aabb.min) * _e19);
-    let _e25 = velocities[i].inner;
-    v = _e25;
-    let _e27 = v;
-    let relative_speed_1 = (length(_e27) / COLORING_SPEED_LIMIT);
-    let _e32 = v;
-    let _e34 = velocity_to_color(_e32, sqrt(relative_speed_1));
-    out.color = _e34;
-    let _e39 = scale;
-    scale = (_e39 * (sqrt(sqrt(relative_speed_1)) * 1.5f));
+    let size_1 = sizes[i].inner;
+    scale = size_1;
+    let _e23 = velocities[i].inner;
+    v = _e23;
+    let _e25 = v;
+    let relative_speed_2 = (length(_e25) / COLORING_SPEED_LIMIT);
+    let _e30 = v;
+    let _e31 = object_color(i, _e30, relative_speed_2);
+    out.color = _e31;
+    let _e34 = parameters.scale_by_speed;
+    if (_e34 != 0u) {
+        let _e41 = scale;
+        scale = (_e41 * (sqrt(sqrt(relative_speed_2)) * 1.5f));
+    }
+    let _e45 = parameters.highlighted;
+    if (i == _e45) {
+        out.color = vec4(1f);
+        let _e50 = scale;
+        scale = max(_e50, size_1);
+    }
     let center = ((aabb.min + aabb.max) / vec2(2f));
-    let _e48 = scale.x;
-    let _e50 = scale.y;
-    let model = mat4x4<f32>(vec4<f32>(_e48, 0f, 0f, 0f), vec4<f32>(0f, _e50, 0f, 0f), vec4<f32>(0f, 0f, 1f, 0f), vec4<f32>(center.x, center.y, 0f, 1f));
+    let _e61 = angles[i].inner;
+    let _e62 = rotationX_naga_oil_mod_XMNXW23LPNYX(_e61);
+    let _e65 = scale.x;
+    let _e72 = scale.y;
+    let model = mat4x4<f32>(vec4<f32>((_e62[0] * _e65), 0f, 0f), vec4<f32>((_e62[1] * _e72), 0f, 0f), vec4<f32>(0f, 0f, 1f, 0f), vec4<f32>(center, 0f, 1f));
     let vertex = UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX[vertex_index];
-    let _e76 = camera.inner;
-    out.clip_position = ((_e76 * model) * vec4<f32>(vertex, 0f, 1f));
-    out.quad_position = vertex;
-    let _e87 = shapes[i].inner;
-    out.shape = _e87;
-    let _e88 = out;
-    return _e88;
+    let _e92 = camera.inner;
+    out.clip_position = ((_e92 * model) * vec4<f32>(vertex, 0f, 1f));
+    out.local_position = (vertex * size_1);
+    let _e104 = shapes[i].inner;
+    out.shape = _e104;
+    out.size = size_1;
+    out.index = i;
+    let _e107 = out;
+    return _e107;
 }
 
 @fragment 
@@ -709,15 +1689,15 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
     var color: vec4<f32>;
 
     color = in.color;
-    let _e4 = sdf_cirle(in.quad_position);
-    let _e5 = fwidth(_e4);
-    let w = (_e5 / 2f);
-    if (in.shape == SHAPE_CIRCLE) {
-        let _e14 = color.w;
-        color.w = (_e14 * smoothstep(w, -(w), _e4));
+    let _e7 = sdf_shape(in.shape, in.local_position, in.size, in.index);
+    let _e8 = fwidth(_e7);
+    let w = (_e8 / 2f);
+    if (in.shape != SHAPE_RECTX_naga_oil_mod_XMNXW23LPNYX) {
+        let _e17 = color.w;
+        color.w = (_e17 * smoothstep(w, -(w), _e7));
     }
-    let _e16 = color;
-    return FragmentOutput(_e16);
+    let _e19 = color;
+    return FragmentOutput(_e19);
 }
 "#;
 }
@@ -784,12 +1764,14 @@ pub mod aabb_frame {
         pub camera: wgpu::BufferBinding<'a>,
         pub flags: wgpu::BufferBinding<'a>,
         pub aabbs: wgpu::BufferBinding<'a>,
+        pub level_starts: wgpu::BufferBinding<'a>,
     }
     #[derive(Clone, Debug)]
     pub struct WgpuBindGroup0Entries<'a> {
         pub camera: wgpu::BindGroupEntry<'a>,
         pub flags: wgpu::BindGroupEntry<'a>,
         pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub level_starts: wgpu::BindGroupEntry<'a>,
     }
     impl<'a> WgpuBindGroup0Entries<'a> {
         pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
@@ -806,10 +1788,14 @@ pub mod aabb_frame {
                     binding: 2,
                     resource: wgpu::BindingResource::Buffer(params.aabbs),
                 },
+                level_starts: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.level_starts),
+                },
             }
         }
-        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 3] {
-            [self.camera, self.flags, self.aabbs]
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 4] {
+            [self.camera, self.flags, self.aabbs, self.level_starts]
         }
         pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
             self.into_array().into_iter().collect()
@@ -854,6 +1840,17 @@ pub mod aabb_frame {
                     },
                     count: None,
                 },
+                #[doc = " @binding(3): \"level_starts\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         };
         pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
@@ -926,7 +1923,7 @@ struct AABBX_naga_oil_mod_XMNXW23LPNYX {
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) @interpolate(flat) flags: u32,
-    @location(1) scale: f32,
+    @location(1) @interpolate(flat) level: u32,
     @location(2) quad_position: vec2<f32>,
 }
 
@@ -935,6 +1932,7 @@ struct FragmentOutput {
 }
 
 const FLAG_DRAW_AABBX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
+const LEAF_COLOR: vec4<f32> = vec4<f32>(0.6f, 0.6f, 0.6f, 0.5f);
 const UNIT_QUAD_VERTICES: array<vec2<f32>, 5> = array<vec2<f32>, 5>(vec2<f32>(0.5f, 0.5f), vec2<f32>(-0.5f, 0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(0.5f, -0.5f), vec2<f32>(0.5f, 0.5f));
 
 @group(0) @binding(0) 
@@ -943,6 +1941,41 @@ var<uniform> camera: CameraX_naga_oil_mod_XMNXW23LPNYX;
 var<storage> flags_1: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(2) 
 var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> level_starts: array<u32>;
+
+fn hsv_to_rgbX_naga_oil_mod_XMNXW23LPNYX(hsv: vec3<f32>) -> vec3<f32> {
+    let k = ((fract((vec3(hsv.x) + vec3<f32>(1f, 0.6666667f, 0.33333334f))) * 6f) - vec3(3f));
+    return (hsv.z * mix(vec3(1f), clamp((abs(k) - vec3(1f)), vec3(0f), vec3(1f)), hsv.y));
+}
+
+fn node_level(i_1: u32) -> u32 {
+    var level: u32 = 0u;
+    var l: u32 = 1u;
+
+    loop {
+        let _e3 = l;
+        if (_e3 < arrayLength((&level_starts))) {
+        } else {
+            break;
+        }
+        {
+            let _e9 = l;
+            let _e11 = level_starts[_e9];
+            if (_e11 > i_1) {
+                break;
+            }
+            let _e13 = l;
+            level = _e13;
+        }
+        continuing {
+            let _e16 = l;
+            l = (_e16 + 1u);
+        }
+    }
+    let _e18 = level;
+    return _e18;
+}
 
 @vertex 
 fn vs_main(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) i: u32) -> VertexOutput {
@@ -967,10 +2000,11 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) i:
     out.clip_position = ((_e57 * model) * vec4<f32>(vertex, 0f, 1f));
     let _e64 = flags;
     out.flags = _e64;
-    out.scale = max(scale.x, scale.y);
+    let _e66 = node_level(i);
+    out.level = _e66;
     out.quad_position = vertex;
-    let _e70 = out;
-    return _e70;
+    let _e68 = out;
+    return _e68;
 }
 
 @fragment 
@@ -978,8 +2012,13 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
     if ((in.flags & FLAG_DRAW_AABBX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
         discard;
     }
-    let intensity = min(1f, (length(in.scale) / 2000f));
-    return FragmentOutput(vec4<f32>(intensity, intensity, intensity, intensity));
+    if (in.level == 0u) {
+        return FragmentOutput(vec4<f32>(0.6f, 0.6f, 0.6f, 0.5f));
+    }
+    let top_level = max((arrayLength((&level_starts)) - 1u), 2u);
+    let hue = ((f32((in.level - 1u)) / f32((top_level - 1u))) * 0.8f);
+    let _e34 = hsv_to_rgbX_naga_oil_mod_XMNXW23LPNYX(vec3<f32>(hue, 0.9f, 1f));
+    return FragmentOutput(vec4<f32>(_e34, 1f));
 }
 "#;
 }
@@ -1026,11 +2065,13 @@ pub mod bvh {
     pub struct WgpuBindGroup0EntriesParams<'a> {
         pub aabbs: wgpu::BufferBinding<'a>,
         pub nodes: wgpu::BufferBinding<'a>,
+        pub sleeping_nodes: wgpu::BufferBinding<'a>,
     }
     #[derive(Clone, Debug)]
     pub struct WgpuBindGroup0Entries<'a> {
         pub aabbs: wgpu::BindGroupEntry<'a>,
         pub nodes: wgpu::BindGroupEntry<'a>,
+        pub sleeping_nodes: wgpu::BindGroupEntry<'a>,
     }
     impl<'a> WgpuBindGroup0Entries<'a> {
         pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
@@ -1043,10 +2084,14 @@ pub mod bvh {
                     binding: 1,
                     resource: wgpu::BindingResource::Buffer(params.nodes),
                 },
+                sleeping_nodes: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.sleeping_nodes),
+                },
             }
         }
-        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 2] {
-            [self.aabbs, self.nodes]
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 3] {
+            [self.aabbs, self.nodes, self.sleeping_nodes]
         }
         pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
             self.into_array().into_iter().collect()
@@ -1080,6 +2125,17 @@ pub mod bvh {
                     },
                     count: None,
                 },
+                #[doc = " @binding(2): \"sleeping_nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         };
         pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
@@ -1162,6 +2218,8 @@ var<push_constant> params: CombineNodePass;
 var<storage, read_write> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(1) 
 var<storage, read_write> nodes: array<BvhNodeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage, read_write> sleeping_nodes: array<u32>;
 
 fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1: vec3<u32>, workgroup_size: u32) -> u32 {
     return (gid_1.x + ((gid_1.y * 65535u) * workgroup_size));
@@ -1179,6 +2237,13 @@ fn combine_nodes(@builtin(global_invocation_id) gid: vec3<u32>) {
     let _e15 = params.dst_start;
     let dst = (_e15 + _e2);
     nodes[dst] = BvhNodeX_naga_oil_mod_XMNXW23LPNYX((src | BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX));
+    let _e24 = sleeping_nodes[src];
+    let _e31 = sleeping_nodes[(src + 1u)];
+    let sleeping = ((_e24 != 0u) && (_e31 != 0u));
+    sleeping_nodes[dst] = u32(sleeping);
+    if sleeping {
+        return;
+    }
     let left_aabb = aabbs[src];
     let right_aabb = aabbs[(src + 1u)];
     let aabb_min = min(left_aabb.min, right_aabb.min);
@@ -1188,14 +2253,196 @@ fn combine_nodes(@builtin(global_invocation_id) gid: vec3<u32>) {
 }
 "#;
 }
-pub mod integration {
+pub mod blackholes {
     use super::{_root, _root::*};
-    pub const WORKGROUP_SIZE: u32 = 64u32;
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct BlackHole {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub position: [f32; 2],
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub velocity: [f32; 2],
+        #[doc = "offset: 16, size: 4, type: `f32`"]
+        pub radius: f32,
+        #[doc = "offset: 20, size: 4, type: `f32`"]
+        pub mass: f32,
+        #[doc = "offset: 24, size: 4, type: `f32`"]
+        pub spin: f32,
+        pub _pad_spin: [u8; 0x4],
+    }
+    impl BlackHole {
+        pub const fn new(position: [f32; 2], velocity: [f32; 2], radius: f32, mass: f32, spin: f32) -> Self {
+            Self {
+                position,
+                velocity,
+                radius,
+                mass,
+                spin,
+                _pad_spin: [0; 0x4],
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct BlackHoleInit {
+        pub position: [f32; 2],
+        pub velocity: [f32; 2],
+        pub radius: f32,
+        pub mass: f32,
+        pub spin: f32,
+    }
+    impl BlackHoleInit {
+        pub fn build(&self) -> BlackHole {
+            BlackHole {
+                position: self.position,
+                velocity: self.velocity,
+                radius: self.radius,
+                mass: self.mass,
+                spin: self.spin,
+                _pad_spin: [0; 0x4],
+            }
+        }
+    }
+    impl From<BlackHoleInit> for BlackHole {
+        fn from(data: BlackHoleInit) -> Self {
+            data.build()
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct BlackHoleSet {
+        #[doc = "offset: 0, size: 160, type: `array<blackholes::BlackHole, 5>`"]
+        pub blackholes: [_root::blackholes::BlackHole; 5],
+    }
+    impl BlackHoleSet {
+        pub const fn new(blackholes: [_root::blackholes::BlackHole; 5]) -> Self {
+            Self { blackholes }
+        }
+    }
     pub const BLACKHOLE_COUNT: u32 = 5u32;
+    pub const GRAVITATIONAL_CONSTANT: f32 = 100000f32;
     pub const BLACKHOLE_MASS_SCALE: f32 = 1000f32;
     pub const BLACKHOLE_SIZE_SCALE: f32 = 10f32;
     pub const BLACKHOLE_DESTROY_MATTER: bool = true;
-    pub const GRAVITATIONAL_CONSTANT: f32 = 100000f32;
+}
+pub mod integration {
+    use super::{_root, _root::*};
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct IntegrationParameters {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub dt: f32,
+        #[doc = "offset: 4, size: 4, type: `u32`"]
+        pub integrator: u32,
+    }
+    impl IntegrationParameters {
+        pub const fn new(dt: f32, integrator: u32) -> Self {
+            Self { dt, integrator }
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Force {
+        #[doc = "offset: 0, size: 4, type: `u32`"]
+        pub kind: u32,
+        #[doc = "offset: 4, size: 4, type: `f32`"]
+        pub strength: f32,
+        #[doc = "offset: 8, size: 4, type: `f32`"]
+        pub radius: f32,
+        pub _pad_radius: [u8; 0x4],
+        #[doc = "offset: 16, size: 8, type: `vec2<f32>`"]
+        pub position: [f32; 2],
+        #[doc = "offset: 24, size: 8, type: `vec2<f32>`"]
+        pub vector: [f32; 2],
+    }
+    impl Force {
+        pub const fn new(kind: u32, strength: f32, radius: f32, position: [f32; 2], vector: [f32; 2]) -> Self {
+            Self {
+                kind,
+                strength,
+                radius,
+                _pad_radius: [0; 0x4],
+                position,
+                vector,
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ForceInit {
+        pub kind: u32,
+        pub strength: f32,
+        pub radius: f32,
+        pub position: [f32; 2],
+        pub vector: [f32; 2],
+    }
+    impl ForceInit {
+        pub fn build(&self) -> Force {
+            Force {
+                kind: self.kind,
+                strength: self.strength,
+                radius: self.radius,
+                _pad_radius: [0; 0x4],
+                position: self.position,
+                vector: self.vector,
+            }
+        }
+    }
+    impl From<ForceInit> for Force {
+        fn from(data: ForceInit) -> Self {
+            data.build()
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ForceSet {
+        #[doc = "offset: 0, size: 1024, type: `array<Force, 32>`"]
+        pub forces: [Force; 32],
+        #[doc = "offset: 1024, size: 4, type: `u32`"]
+        pub count: u32,
+        pub _pad_count: [u8; 0x4],
+    }
+    impl ForceSet {
+        pub const fn new(forces: [Force; 32], count: u32) -> Self {
+            Self {
+                forces,
+                count,
+                _pad_count: [0; 0x4],
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ForceSetInit {
+        pub forces: [Force; 32],
+        pub count: u32,
+    }
+    impl ForceSetInit {
+        pub fn build(&self) -> ForceSet {
+            ForceSet {
+                forces: self.forces,
+                count: self.count,
+                _pad_count: [0; 0x4],
+            }
+        }
+    }
+    impl From<ForceSetInit> for ForceSet {
+        fn from(data: ForceSetInit) -> Self {
+            data.build()
+        }
+    }
+    pub const MAX_FORCES: u32 = 32u32;
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub const INTEGRATOR_EULER_SYMPLECTIC: u32 = 0u32;
+    pub const INTEGRATOR_LEAPFROG: u32 = 1u32;
+    pub const INTEGRATOR_RK4: u32 = 2u32;
+    pub const FORCE_UNIFORM_GRAVITY: u32 = 0u32;
+    pub const FORCE_POINT_ATTRACTOR: u32 = 1u32;
+    pub const FORCE_LINEAR_DRAG: u32 = 2u32;
+    pub const FORCE_QUADRATIC_DRAG: u32 = 3u32;
+    pub const FORCE_VORTEX: u32 = 4u32;
+    pub const FORCE_RADIAL_EXPLOSION: u32 = 5u32;
+    pub const FORCE_WIND: u32 = 6u32;
     pub mod compute {
         use super::{_root, _root::*};
         pub const CS_MAIN_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
@@ -1215,32 +2462,68 @@ pub mod integration {
     pub const ENTRY_CS_MAIN: &str = "cs_main";
     #[derive(Debug)]
     pub struct WgpuBindGroup0EntriesParams<'a> {
-        pub dt: wgpu::BufferBinding<'a>,
+        pub shapes: wgpu::BufferBinding<'a>,
+        pub sizes: wgpu::BufferBinding<'a>,
+        pub polygons: wgpu::BufferBinding<'a>,
+        pub polygon_vertices: wgpu::BufferBinding<'a>,
+        pub parameters: wgpu::BufferBinding<'a>,
         pub flags: wgpu::BufferBinding<'a>,
         pub masses: wgpu::BufferBinding<'a>,
         pub velocities: wgpu::BufferBinding<'a>,
         pub aabbs: wgpu::BufferBinding<'a>,
-        pub nodes: wgpu::BufferBinding<'a>,
         pub integrated_velocities: wgpu::BufferBinding<'a>,
         pub integrated_aabbs: wgpu::BufferBinding<'a>,
+        pub tools: wgpu::BufferBinding<'a>,
+        pub force_set: wgpu::BufferBinding<'a>,
+        pub angles: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub integrated_angles: wgpu::BufferBinding<'a>,
+        pub moments: wgpu::BufferBinding<'a>,
+        pub blackhole_set: wgpu::BufferBinding<'a>,
     }
     #[derive(Clone, Debug)]
     pub struct WgpuBindGroup0Entries<'a> {
-        pub dt: wgpu::BindGroupEntry<'a>,
+        pub shapes: wgpu::BindGroupEntry<'a>,
+        pub sizes: wgpu::BindGroupEntry<'a>,
+        pub polygons: wgpu::BindGroupEntry<'a>,
+        pub polygon_vertices: wgpu::BindGroupEntry<'a>,
+        pub parameters: wgpu::BindGroupEntry<'a>,
         pub flags: wgpu::BindGroupEntry<'a>,
         pub masses: wgpu::BindGroupEntry<'a>,
         pub velocities: wgpu::BindGroupEntry<'a>,
         pub aabbs: wgpu::BindGroupEntry<'a>,
-        pub nodes: wgpu::BindGroupEntry<'a>,
         pub integrated_velocities: wgpu::BindGroupEntry<'a>,
         pub integrated_aabbs: wgpu::BindGroupEntry<'a>,
+        pub tools: wgpu::BindGroupEntry<'a>,
+        pub force_set: wgpu::BindGroupEntry<'a>,
+        pub angles: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub integrated_angles: wgpu::BindGroupEntry<'a>,
+        pub moments: wgpu::BindGroupEntry<'a>,
+        pub blackhole_set: wgpu::BindGroupEntry<'a>,
     }
     impl<'a> WgpuBindGroup0Entries<'a> {
         pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
             Self {
-                dt: wgpu::BindGroupEntry {
+                shapes: wgpu::BindGroupEntry {
+                    binding: 20,
+                    resource: wgpu::BindingResource::Buffer(params.shapes),
+                },
+                sizes: wgpu::BindGroupEntry {
+                    binding: 21,
+                    resource: wgpu::BindingResource::Buffer(params.sizes),
+                },
+                polygons: wgpu::BindGroupEntry {
+                    binding: 22,
+                    resource: wgpu::BindingResource::Buffer(params.polygons),
+                },
+                polygon_vertices: wgpu::BindGroupEntry {
+                    binding: 23,
+                    resource: wgpu::BindingResource::Buffer(params.polygon_vertices),
+                },
+                parameters: wgpu::BindGroupEntry {
                     binding: 0,
-                    resource: wgpu::BindingResource::Buffer(params.dt),
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
                 },
                 flags: wgpu::BindGroupEntry {
                     binding: 1,
@@ -1258,10 +2541,6 @@ pub mod integration {
                     binding: 4,
                     resource: wgpu::BindingResource::Buffer(params.aabbs),
                 },
-                nodes: wgpu::BindGroupEntry {
-                    binding: 5,
-                    resource: wgpu::BindingResource::Buffer(params.nodes),
-                },
                 integrated_velocities: wgpu::BindGroupEntry {
                     binding: 6,
                     resource: wgpu::BindingResource::Buffer(params.integrated_velocities),
@@ -1270,18 +2549,56 @@ pub mod integration {
                     binding: 7,
                     resource: wgpu::BindingResource::Buffer(params.integrated_aabbs),
                 },
+                tools: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.tools),
+                },
+                force_set: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.force_set),
+                },
+                angles: wgpu::BindGroupEntry {
+                    binding: 11,
+                    resource: wgpu::BindingResource::Buffer(params.angles),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 12,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                integrated_angles: wgpu::BindGroupEntry {
+                    binding: 13,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_angles),
+                },
+                moments: wgpu::BindGroupEntry {
+                    binding: 14,
+                    resource: wgpu::BindingResource::Buffer(params.moments),
+                },
+                blackhole_set: wgpu::BindGroupEntry {
+                    binding: 15,
+                    resource: wgpu::BindingResource::Buffer(params.blackhole_set),
+                },
             }
         }
-        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 8] {
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 18] {
             [
-                self.dt,
+                self.shapes,
+                self.sizes,
+                self.polygons,
+                self.polygon_vertices,
+                self.parameters,
                 self.flags,
                 self.masses,
                 self.velocities,
                 self.aabbs,
-                self.nodes,
                 self.integrated_velocities,
                 self.integrated_aabbs,
+                self.tools,
+                self.force_set,
+                self.angles,
+                self.angular_velocities,
+                self.integrated_angles,
+                self.moments,
+                self.blackhole_set,
             ]
         }
         pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
@@ -1294,14 +2611,60 @@ pub mod integration {
         pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
             label: Some("Integration::BindGroup0::LayoutDescriptor"),
             entries: &[
-                #[doc = " @binding(0): \"dt\""]
+                #[doc = " @binding(20): \"_root::geometry::shapes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 20,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(21): \"_root::geometry::sizes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 21,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(22): \"_root::geometry::polygons\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 22,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(23): \"_root::geometry::polygon_vertices\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 23,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(0): \"parameters\""]
                 wgpu::BindGroupLayoutEntry {
                     binding: 0,
                     visibility: wgpu::ShaderStages::COMPUTE,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Uniform,
                         has_dynamic_offset: false,
-                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<f32>() as _),
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<
+                            _root::integration::IntegrationParameters,
+                        >() as _),
                     },
                     count: None,
                 },
@@ -1310,7 +2673,7 @@ pub mod integration {
                     binding: 1,
                     visibility: wgpu::ShaderStages::COMPUTE,
                     ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
                         has_dynamic_offset: false,
                         min_binding_size: None,
                     },
@@ -1349,9 +2712,57 @@ pub mod integration {
                     },
                     count: None,
                 },
-                #[doc = " @binding(5): \"nodes\""]
+                #[doc = " @binding(6): \"integrated_velocities\""]
                 wgpu::BindGroupLayoutEntry {
-                    binding: 5,
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"integrated_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"tools\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::tool_state::ToolState>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"force_set\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::integration::ForceSet>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(11): \"angles\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 11,
                     visibility: wgpu::ShaderStages::COMPUTE,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Storage { read_only: true },
@@ -1360,9 +2771,9 @@ pub mod integration {
                     },
                     count: None,
                 },
-                #[doc = " @binding(6): \"integrated_velocities\""]
+                #[doc = " @binding(12): \"angular_velocities\""]
                 wgpu::BindGroupLayoutEntry {
-                    binding: 6,
+                    binding: 12,
                     visibility: wgpu::ShaderStages::COMPUTE,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Storage { read_only: false },
@@ -1371,9 +2782,9 @@ pub mod integration {
                     },
                     count: None,
                 },
-                #[doc = " @binding(7): \"integrated_aabbs\""]
+                #[doc = " @binding(13): \"integrated_angles\""]
                 wgpu::BindGroupLayoutEntry {
-                    binding: 7,
+                    binding: 13,
                     visibility: wgpu::ShaderStages::COMPUTE,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Storage { read_only: false },
@@ -1382,6 +2793,30 @@ pub mod integration {
                     },
                     count: None,
                 },
+                #[doc = " @binding(14): \"moments\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 14,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(15): \"blackhole_set\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 15,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::blackholes::BlackHoleSet>() as _,
+                        ),
+                    },
+                    count: None,
+                },
             ],
         };
         pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
@@ -1450,181 +2885,9702 @@ struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
     inner: u32,
 }
 
+struct AngleX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct SecondMomentsX_naga_oil_mod_XMNXW23LPNYX {
+    xx: f32,
+    yy: f32,
+    xy: f32,
+}
+
 struct AABBX_naga_oil_mod_XMNXW23LPNYX {
     min: vec2<f32>,
     max: vec2<f32>,
 }
 
-struct BvhNodeX_naga_oil_mod_XMNXW23LPNYX {
-    index: u32,
+struct ToolStateX_naga_oil_mod_XORXW63C7ON2GC5DFX {
+    cursor: vec2<f32>,
+    cursor_strength: f32,
+    cursor_softening: f32,
+    grabbed: u32,
+    grab_velocity: vec2<f32>,
 }
 
-struct BlackHole {
+struct BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
     position: vec2<f32>,
+    velocity: vec2<f32>,
     radius: f32,
     mass: f32,
     spin: f32,
 }
 
+struct BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    blackholes: array<BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, 5>,
+}
+
+struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct SizeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct PolygonX_naga_oil_mod_XMNXW23LPNYX {
+    offset: u32,
+    count: u32,
+}
+
+struct PolygonVertexX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct IntegrationParameters {
+    dt: f32,
+    integrator: u32,
+}
+
+struct Force {
+    kind: u32,
+    strength: f32,
+    radius: f32,
+    position: vec2<f32>,
+    vector: vec2<f32>,
+}
+
+struct ForceSet {
+    forces: array<Force, 32>,
+    count: u32,
+}
+
 struct State {
     position: vec2<f32>,
     velocity: vec2<f32>,
 }
 
-const FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
-const FLAG_DRAW_AABBX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
 const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX: u32 = 32u;
+const SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: u32 = 5u;
+const GRAVITATIONAL_CONSTANTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 100000f;
+const BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 1000f;
+const SHAPE_POLYGONX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
+const SHAPE_CAPSULEX_naga_oil_mod_XMNXW23LPNYX: u32 = 3u;
+const MAX_FORCES: u32 = 32u;
 const WORKGROUP_SIZE: u32 = 64u;
-const BLACKHOLE_COUNT: u32 = 5u;
-const BLACKHOLES: array<BlackHole, 5> = array<BlackHole, 5>(BlackHole(vec2<f32>(-200f, 500f), 2f, 10f, 0f), BlackHole(vec2<f32>(500f, 200f), 1f, 20f, 0f), BlackHole(vec2<f32>(), 2f, 10f, 50f), BlackHole(vec2<f32>(-600f, -300f), 1f, 20f, 0f), BlackHole(vec2<f32>(600f, -700f), 1f, 10f, 0f));
-const BLACKHOLE_MASS_SCALE: f32 = 1000f;
-const BLACKHOLE_SIZE_SCALE: f32 = 10f;
-const BLACKHOLE_DESTROY_MATTER: bool = true;
-const GRAVITATIONAL_CONSTANT: f32 = 100000f;
-const GLOBAL_FORCE: vec2<f32> = vec2<f32>();
+const INTEGRATOR_EULER_SYMPLECTIC: u32 = 0u;
+const INTEGRATOR_LEAPFROG: u32 = 1u;
+const INTEGRATOR_RK4_: u32 = 2u;
+const FORCE_UNIFORM_GRAVITY: u32 = 0u;
+const FORCE_POINT_ATTRACTOR: u32 = 1u;
+const FORCE_LINEAR_DRAG: u32 = 2u;
+const FORCE_QUADRATIC_DRAG: u32 = 3u;
+const FORCE_VORTEX: u32 = 4u;
+const FORCE_RADIAL_EXPLOSION: u32 = 5u;
+const FORCE_WIND: u32 = 6u;
 
+@group(0) @binding(20) 
+var<storage> shapesX_naga_oil_mod_XM5SW63LFORZHSX: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(21) 
+var<storage> sizesX_naga_oil_mod_XM5SW63LFORZHSX: array<SizeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(22) 
+var<storage> polygonsX_naga_oil_mod_XM5SW63LFORZHSX: array<PolygonX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(23) 
+var<storage> polygon_verticesX_naga_oil_mod_XM5SW63LFORZHSX: array<PolygonVertexX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(0) 
-var<uniform> dt: f32;
+var<uniform> parameters: IntegrationParameters;
 @group(0) @binding(1) 
-var<storage, read_write> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(2) 
 var<storage> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(3) 
 var<storage, read_write> velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(4) 
 var<storage, read_write> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
-@group(0) @binding(5) 
-var<storage> nodes: array<BvhNodeX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(6) 
 var<storage, read_write> integrated_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
 @group(0) @binding(7) 
 var<storage, read_write> integrated_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(9) 
+var<uniform> tools: ToolStateX_naga_oil_mod_XORXW63C7ON2GC5DFX;
+@group(0) @binding(10) 
+var<uniform> force_set: ForceSet;
+@group(0) @binding(11) 
+var<storage> angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(12) 
+var<storage, read_write> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(13) 
+var<storage, read_write> integrated_angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(14) 
+var<storage> moments_3: array<SecondMomentsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(15) 
+var<uniform> blackhole_set: BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX;
+
+fn rotationX_naga_oil_mod_XMNXW23LPNYX(angle_1: f32) -> mat2x2<f32> {
+    let c = cos(angle_1);
+    let s = sin(angle_1);
+    return mat2x2<f32>(vec2<f32>(c, s), vec2<f32>(-(s), c));
+}
+
+fn second_moment_tensorX_naga_oil_mod_XMNXW23LPNYX(moments: SecondMomentsX_naga_oil_mod_XMNXW23LPNYX, mass: f32, angle_2: f32) -> mat2x2<f32> {
+    let _e1 = rotationX_naga_oil_mod_XMNXW23LPNYX(angle_2);
+    return (((_e1 * mat2x2<f32>(vec2<f32>(moments.xx, moments.xy), vec2<f32>(moments.xy, moments.yy))) * transpose(_e1)) * mass);
+}
+
+fn moment_of_inertiaX_naga_oil_mod_XMNXW23LPNYX(moments_1: SecondMomentsX_naga_oil_mod_XMNXW23LPNYX, mass_1: f32) -> f32 {
+    return (mass_1 * (moments_1.xx + moments_1.yy));
+}
 
 fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1: vec3<u32>, workgroup_size: u32) -> u32 {
     return (gid_1.x + ((gid_1.y * 65535u) * workgroup_size));
 }
 
-fn blackhole_gravity(blackhole: BlackHole, position: vec2<f32>) -> vec2<f32> {
+fn blackhole_gravityX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole: BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, position: vec2<f32>) -> vec2<f32> {
     let to_blackhole = (blackhole.position - position);
     let direction = normalize(to_blackhole);
     let distance = length(to_blackhole);
-    let bh_gravity = ((((direction * GRAVITATIONAL_CONSTANT) * blackhole.mass) * BLACKHOLE_MASS_SCALE) / vec2((distance * distance)));
+    let bh_gravity = ((((direction * GRAVITATIONAL_CONSTANTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) * blackhole.mass) * BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) / vec2((distance * distance)));
     return bh_gravity;
 }
 
-fn frame_dragging(blackhole_1: BlackHole, state_1: State) -> vec2<f32> {
-    let r_vec = (blackhole_1.position - state_1.position);
+fn frame_draggingX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole_1: BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, position_1: vec2<f32>, velocity: vec2<f32>) -> vec2<f32> {
+    let r_vec = (blackhole_1.position - position_1);
     let r = length(r_vec);
     let J = blackhole_1.spin;
-    let v_perp = vec2<f32>(-(state_1.velocity.y), state_1.velocity.x);
+    let v_perp = vec2<f32>(-(velocity.y), velocity.x);
     let a = (((200000f * J) / pow(r, 3f)) * v_perp);
     return a;
 }
 
+fn rotated_half_extentsX_naga_oil_mod_XMNXW23LPNYX(shape: u32, size: vec2<f32>, angle_3: f32) -> vec2<f32> {
+    if (shape == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX) {
+        return (size / vec2(2f));
+    }
+    if (shape == SHAPE_CAPSULEX_naga_oil_mod_XMNXW23LPNYX) {
+        let radius = (size.y / 2f);
+        return ((abs(vec2<f32>(cos(angle_3), sin(angle_3))) * ((size.x / 2f) - radius)) + vec2(radius));
+    }
+    let c_1 = abs(cos(angle_3));
+    let s_1 = abs(sin(angle_3));
+    return (vec2<f32>(((c_1 * size.x) + (s_1 * size.y)), ((s_1 * size.x) + (c_1 * size.y))) / vec2(2f));
+}
+
+fn object_half_extentsX_naga_oil_mod_XM5SW63LFORZHSX(i: u32, angle_4: f32) -> vec2<f32> {
+    var half_extents: vec2<f32> = vec2<f32>();
+    var v: u32;
+
+    let shape_1 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[i].inner;
+    if (shape_1 != SHAPE_POLYGONX_naga_oil_mod_XMNXW23LPNYX) {
+        let _e11 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[i].inner;
+        let _e13 = rotated_half_extentsX_naga_oil_mod_XMNXW23LPNYX(shape_1, _e11, angle_4);
+        return _e13;
+    }
+    let _e14 = rotationX_naga_oil_mod_XMNXW23LPNYX(angle_4);
+    let polygon = polygonsX_naga_oil_mod_XM5SW63LFORZHSX[i];
+    v = polygon.offset;
+    loop {
+        let _e20 = v;
+        if (_e20 < (polygon.offset + polygon.count)) {
+        } else {
+            break;
+        }
+        {
+            let _e26 = half_extents;
+            let _e28 = v;
+            let _e31 = polygon_verticesX_naga_oil_mod_XM5SW63LFORZHSX[_e28].inner;
+            half_extents = max(_e26, abs((_e14 * _e31)));
+        }
+        continuing {
+            let _e36 = v;
+            v = (_e36 + 1u);
+        }
+    }
+    let _e38 = half_extents;
+    return _e38;
+}
+
+fn registered_force(force: Force, state_1: State) -> vec2<f32> {
+    switch force.kind {
+        case 0u: {
+            return force.vector;
+        }
+        case 1u: {
+            let to_center = (force.position - state_1.position);
+            let distance_squared = (dot(to_center, to_center) + (force.radius * force.radius));
+            return ((to_center * force.strength) / vec2(pow(distance_squared, 1.5f)));
+        }
+        case 2u: {
+            return (-(force.strength) * state_1.velocity);
+        }
+        case 3u: {
+            return ((-(force.strength) * length(state_1.velocity)) * state_1.velocity);
+        }
+        case 4u: {
+            let from_center = (state_1.position - force.position);
+            let tangent = vec2<f32>(-(from_center.y), from_center.x);
+            return ((tangent * force.strength) / vec2((dot(from_center, from_center) + (force.radius * force.radius))));
+        }
+        case 5u: {
+            let from_center_1 = (state_1.position - force.position);
+            let distance_1 = length(from_center_1);
+            if ((distance_1 >= force.radius) || (distance_1 == 0f)) {
+                return vec2<f32>();
+            }
+            return (((from_center_1 / vec2(distance_1)) * force.strength) * (1f - (distance_1 / force.radius)));
+        }
+        case 6u: {
+            return (force.strength * (force.vector - state_1.velocity));
+        }
+        default: {
+            return vec2<f32>();
+        }
+    }
+}
+
+fn cursor_force(position_2: vec2<f32>) -> vec2<f32> {
+    let _e2 = tools.cursor_strength;
+    if (_e2 == 0f) {
+        return vec2<f32>();
+    }
+    let _e9 = tools.cursor;
+    let to_cursor = (_e9 - position_2);
+    let _e14 = tools.cursor_softening;
+    let _e17 = tools.cursor_softening;
+    let distance_squared_1 = (dot(to_cursor, to_cursor) + (_e14 * _e17));
+    let _e24 = tools.cursor_strength;
+    return (((to_cursor * GRAVITATIONAL_CONSTANTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) * _e24) / vec2(pow(distance_squared_1, 1.5f)));
+}
+
 fn forces(state_2: State) -> vec2<f32> {
-    var acc: vec2<f32> = GLOBAL_FORCE;
-    var bh_index_1: u32 = 0u;
-    var blackhole_2: BlackHole;
+    var acc: vec2<f32> = vec2<f32>();
+    var force_index: u32 = 0u;
+    var bh_index: u32 = 0u;
 
     loop {
-        let _e3 = bh_index_1;
-        if (_e3 < BLACKHOLE_COUNT) {
+        let _e3 = force_index;
+        let _e6 = force_set.count;
+        if (_e3 < min(_e6, MAX_FORCES)) {
         } else {
             break;
         }
         {
-            let _e7 = bh_index_1;
-            blackhole_2 = BLACKHOLES[_e7];
-            let _e11 = blackhole_2;
-            let _e13 = blackhole_gravity(_e11, state_2.position);
-            let _e15 = acc;
-            acc = (_e15 + _e13);
-            let _e17 = blackhole_2;
-            let _e18 = frame_dragging(_e17, state_2);
-            let _e19 = acc;
-            acc = (_e19 + _e18);
+            let _e12 = force_index;
+            let _e14 = force_set.forces[_e12];
+            let _e16 = registered_force(_e14, state_2);
+            let _e18 = acc;
+            acc = (_e18 + _e16);
         }
         continuing {
-            let _e22 = bh_index_1;
-            bh_index_1 = (_e22 + 1u);
+            let _e21 = force_index;
+            force_index = (_e21 + 1u);
         }
     }
-    let _e24 = acc;
-    return _e24;
+    loop {
+        let _e24 = bh_index;
+        if (_e24 < BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) {
+        } else {
+            break;
+        }
+        {
+            let _e29 = bh_index;
+            let blackhole_2 = blackhole_set.blackholes[_e29];
+            let _e33 = blackhole_gravityX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole_2, state_2.position);
+            let _e34 = acc;
+            acc = (_e34 + _e33);
+            let _e38 = frame_draggingX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole_2, state_2.position, state_2.velocity);
+            let _e39 = acc;
+            acc = (_e39 + _e38);
+        }
+        continuing {
+            let _e42 = bh_index;
+            bh_index = (_e42 + 1u);
+        }
+    }
+    let _e45 = cursor_force(state_2.position);
+    let _e46 = acc;
+    acc = (_e46 + _e45);
+    let _e48 = acc;
+    return _e48;
+}
+
+fn torque(state_3: State, angle_5: f32, angular_velocity_1: f32, moments_2: SecondMomentsX_naga_oil_mod_XMNXW23LPNYX, size_1: vec2<f32>, mass_2: f32) -> f32 {
+    var field: mat2x2<f32>;
+
+    let _e3 = second_moment_tensorX_naga_oil_mod_XMNXW23LPNYX(moments_2, mass_2, angle_5);
+    let h_1 = max((max(size_1.x, size_1.y) / 2f), 0.001f);
+    let dx = vec2<f32>(h_1, 0f);
+    let dy = vec2<f32>(0f, h_1);
+    let _e21 = forces(State((state_3.position + dx), state_3.velocity));
+    let _e26 = forces(State((state_3.position - dx), state_3.velocity));
+    let _e32 = forces(State((state_3.position + dy), state_3.velocity));
+    let _e37 = forces(State((state_3.position - dy), state_3.velocity));
+    field = (mat2x2<f32>((_e21 - _e26), (_e32 - _e37)) * (1f / (2f * h_1)));
+    if (angular_velocity_1 != 0f) {
+        let hv = (max(length(state_3.velocity), 10f) * 0.1f);
+        let dvx = vec2<f32>(hv, 0f);
+        let dvy = vec2<f32>(0f, hv);
+        let _e63 = forces(State(state_3.position, (state_3.velocity + dvx)));
+        let _e68 = forces(State(state_3.position, (state_3.velocity - dvx)));
+        let _e74 = forces(State(state_3.position, (state_3.velocity + dvy)));
+        let _e79 = forces(State(state_3.position, (state_3.velocity - dvy)));
+        let velocity_jacobian = (mat2x2<f32>((_e63 - _e68), (_e74 - _e79)) * (1f / (2f * hv)));
+        let _e96 = field;
+        field = (_e96 + ((velocity_jacobian * mat2x2<f32>(vec2<f32>(0f, 1f), vec2<f32>(-1f, 0f))) * angular_velocity_1));
+    }
+    let _e98 = field;
+    let moment = (_e98 * _e3);
+    return (moment[0].y - moment[1].x);
 }
 
-fn integrate_euler_symplectic(state_3: State) -> State {
+fn integrate_leapfrog(state_4: State) -> State {
     var new_state: State;
 
-    let _e1 = forces(state_3);
-    new_state = state_3;
-    let _e5 = dt;
-    let _e7 = new_state.velocity;
-    new_state.velocity = (_e7 + (_e1 * _e5));
-    let _e11 = new_state.velocity;
-    let _e13 = dt;
-    let _e15 = new_state.position;
-    new_state.position = (_e15 + (_e11 * _e13));
-    let _e17 = new_state;
-    return _e17;
+    let dt = parameters.dt;
+    new_state = state_4;
+    let _e6 = new_state;
+    let _e7 = forces(_e6);
+    let _e12 = new_state.velocity;
+    new_state.velocity = (_e12 + ((_e7 * dt) / vec2(2f)));
+    let _e16 = new_state.velocity;
+    let _e18 = new_state.position;
+    new_state.position = (_e18 + (_e16 * dt));
+    let _e21 = new_state;
+    let _e22 = forces(_e21);
+    let _e27 = new_state.velocity;
+    new_state.velocity = (_e27 + ((_e22 * dt) / vec2(2f)));
+    let _e29 = new_state;
+    return _e29;
+}
+
+fn derivative(state_5: State, slope: State, h: f32) -> State {
+    let advanced = State((state_5.position + (slope.position * h)), (state_5.velocity + (slope.velocity * h)));
+    let _e13 = forces(advanced);
+    return State(advanced.velocity, _e13);
+}
+
+fn integrate_rk4_(state_6: State) -> State {
+    let dt_1 = parameters.dt;
+    let _e5 = forces(state_6);
+    let k1_ = State(state_6.velocity, _e5);
+    let _e9 = derivative(state_6, k1_, (dt_1 / 2f));
+    let _e12 = derivative(state_6, _e9, (dt_1 / 2f));
+    let _e13 = derivative(state_6, _e12, dt_1);
+    return State((state_6.position + (((((k1_.position + (2f * _e9.position)) + (2f * _e12.position)) + _e13.position) * dt_1) / vec2(6f))), (state_6.velocity + (((((k1_.velocity + (2f * _e9.velocity)) + (2f * _e12.velocity)) + _e13.velocity) * dt_1) / vec2(6f))));
+}
+
+fn integrate_euler_symplectic(state_7: State) -> State {
+    var new_state_1: State;
+
+    let dt_2 = parameters.dt;
+    let _e4 = forces(state_7);
+    new_state_1 = state_7;
+    let _e8 = new_state_1.velocity;
+    new_state_1.velocity = (_e8 + (_e4 * dt_2));
+    let _e12 = new_state_1.velocity;
+    let _e14 = new_state_1.position;
+    new_state_1.position = (_e14 + (_e12 * dt_2));
+    let _e16 = new_state_1;
+    return _e16;
 }
 
 @compute @workgroup_size(64, 1, 1) 
 fn cs_main(@builtin(global_invocation_id) gid: vec3<u32>) {
-    var f: u32;
     var state: State;
-    var bh_index: u32 = 0u;
+    var angle: f32;
+    var angular_velocity: f32;
 
-    let _e3 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
-    if (_e3 >= arrayLength((&masses))) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (_e2 >= arrayLength((&masses))) {
         return;
     }
-    let _e10 = flags[_e3].inner;
-    f = _e10;
-    let _e12 = f;
-    if ((_e12 & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+    let f = flags[_e2].inner;
+    if (((f & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) == 0u) || ((f & FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX) != 0u)) {
+        let _e23 = velocities[_e2];
+        integrated_velocities[_e2] = _e23;
+        let _e28 = aabbs[_e2];
+        integrated_aabbs[_e2] = _e28;
+        let _e33 = angles[_e2];
+        integrated_angles[_e2] = _e33;
         return;
     }
-    let aabb = aabbs[_e3];
+    let aabb = aabbs[_e2];
     let start_position = ((aabb.min + aabb.max) / vec2(2f));
-    let _e29 = velocities[_e3].inner;
-    state = State(start_position, _e29);
-    let _e32 = state;
-    let _e33 = integrate_euler_symplectic(_e32);
-    state = _e33;
-    let size = (aabb.max - aabb.min);
-    if BLACKHOLE_DESTROY_MATTER {
-        loop {
-            let _e39 = bh_index;
-            let _e42 = f;
-            if ((_e39 < BLACKHOLE_COUNT) && ((_e42 & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) != 0u)) {
-            } else {
-                break;
-            }
-            {
-                let _e49 = bh_index;
-                let blackhole_3 = BLACKHOLES[_e49];
-                let _e53 = state.position;
-                let distance_1 = (length((blackhole_3.position - _e53)) - (max(size.x, size.y) / 2f));
-                if (distance_1 < (blackhole_3.radius * BLACKHOLE_SIZE_SCALE)) {
-                    let _e67 = f;
-                    f = (_e67 & 4294967288u);
-                    state.velocity = vec2<f32>();
-                }
+    let _e46 = velocities[_e2].inner;
+    state = State(start_position, _e46);
+    let size_2 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[_e2].inner;
+    let mass_3 = masses[_e2].inner;
+    let _e60 = angles[_e2].inner;
+    angle = _e60;
+    let _e65 = angular_velocities[_e2].inner;
+    angular_velocity = _e65;
+    let _e69 = moments_3[_e2];
+    let _e70 = moment_of_inertiaX_naga_oil_mod_XMNXW23LPNYX(_e69, mass_3);
+    let _e76 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[_e2].inner;
+    if ((_e70 > 0f) && (_e76 != SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX)) {
+        let _e80 = state;
+        let _e81 = angle;
+        let _e82 = angular_velocity;
+        let _e85 = moments_3[_e2];
+        let _e86 = torque(_e80, _e81, _e82, _e85, size_2, mass_3);
+        let _e90 = parameters.dt;
+        let _e92 = angular_velocity;
+        angular_velocity = (_e92 + ((_e86 / _e70) * _e90));
+    }
+    let _e94 = angular_velocity;
+    let _e97 = parameters.dt;
+    let _e99 = angle;
+    angle = (_e99 + (_e94 * _e97));
+    let _e103 = parameters.integrator;
+    switch _e103 {
+        case 1u: {
+            let _e104 = state;
+            let _e105 = integrate_leapfrog(_e104);
+            state = _e105;
+        }
+        case 2u: {
+            let _e106 = state;
+            let _e107 = integrate_rk4_(_e106);
+            state = _e107;
+        }
+        default: {
+            let _e108 = state;
+            let _e109 = integrate_euler_symplectic(_e108);
+            state = _e109;
+        }
+    }
+    let _e112 = tools.grabbed;
+    if (_e2 == _e112) {
+        let _e116 = tools.cursor;
+        let _e119 = tools.grab_velocity;
+        state = State(_e116, _e119);
+    }
+    let _e121 = angle;
+    let _e122 = object_half_extentsX_naga_oil_mod_XM5SW63LFORZHSX(_e2, _e121);
+    let _e127 = state.velocity;
+    integrated_velocities[_e2].inner = _e127;
+    let _e131 = state.position;
+    let _e134 = state.position;
+    integrated_aabbs[_e2] = AABBX_naga_oil_mod_XMNXW23LPNYX((_e131 - _e122), (_e134 + _e122));
+    let _e140 = angle;
+    integrated_angles[_e2].inner = _e140;
+    let _e144 = angular_velocity;
+    angular_velocities[_e2].inner = _e144;
+    return;
+}
+"#;
+}
+pub mod contacts {
+    use super::{_root, _root::*};
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ContactResponse {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub velocity: [f32; 2],
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub push: [f32; 2],
+        #[doc = "offset: 16, size: 4, type: `f32`"]
+        pub angular_velocity: f32,
+        pub _pad_angular_velocity: [u8; 0x4],
+    }
+    impl ContactResponse {
+        pub const fn new(velocity: [f32; 2], push: [f32; 2], angular_velocity: f32) -> Self {
+            Self {
+                velocity,
+                push,
+                angular_velocity,
+                _pad_angular_velocity: [0; 0x4],
             }
-            continuing {
-                let _e72 = bh_index;
-                bh_index = (_e72 + 1u);
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct ContactResponseInit {
+        pub velocity: [f32; 2],
+        pub push: [f32; 2],
+        pub angular_velocity: f32,
+    }
+    impl ContactResponseInit {
+        pub fn build(&self) -> ContactResponse {
+            ContactResponse {
+                velocity: self.velocity,
+                push: self.push,
+                angular_velocity: self.angular_velocity,
+                _pad_angular_velocity: [0; 0x4],
             }
         }
     }
-    let _e77 = f;
-    flags[_e3].inner = _e77;
-    let _e82 = state.velocity;
-    integrated_velocities[_e3].inner = _e82;
-    let _e84 = state.position;
-    let offset = (_e84 - start_position);
-    integrated_aabbs[_e3] = AABBX_naga_oil_mod_XMNXW23LPNYX((aabb.min + offset), (aabb.max + offset));
+    impl From<ContactResponseInit> for ContactResponse {
+        fn from(data: ContactResponseInit) -> Self {
+            data.build()
+        }
+    }
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub const CONTACT_RESTITUTION: f32 = 0.5f32;
+    pub const RESTITUTION_SPEED_THRESHOLD: f32 = 5f32;
+    pub const CONTACT_SLOP: f32 = 0.01f32;
+    pub const OVERLAP_CORRECTION: f32 = 0.2f32;
+    pub const GJK_MAX_ITERATIONS: u32 = 32u32;
+    pub const GJK_TOLERANCE: f32 = 0.0001f32;
+    pub const EPA_MAX_VERTICES: u32 = 16u32;
+    pub const EPA_TOLERANCE: f32 = 0.001f32;
+    pub const FEATURE_ANGLE: f32 = 0.01f32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const COUNT_CONTACTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_count_contacts_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline count_contacts"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("count_contacts"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const SOLVE_CONTACTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_solve_contacts_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline solve_contacts"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("solve_contacts"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const APPLY_CONTACTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_apply_contacts_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline apply_contacts"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("apply_contacts"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_COUNT_CONTACTS: &str = "count_contacts";
+    pub const ENTRY_SOLVE_CONTACTS: &str = "solve_contacts";
+    pub const ENTRY_APPLY_CONTACTS: &str = "apply_contacts";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub shapes: wgpu::BufferBinding<'a>,
+        pub sizes: wgpu::BufferBinding<'a>,
+        pub polygons: wgpu::BufferBinding<'a>,
+        pub polygon_vertices: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub moments: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub nodes: wgpu::BufferBinding<'a>,
+        pub angles: wgpu::BufferBinding<'a>,
+        pub integrated_aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_velocities: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub contact_counts: wgpu::BufferBinding<'a>,
+        pub responses: wgpu::BufferBinding<'a>,
+        pub tools: wgpu::BufferBinding<'a>,
+        pub wall_normals: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub shapes: wgpu::BindGroupEntry<'a>,
+        pub sizes: wgpu::BindGroupEntry<'a>,
+        pub polygons: wgpu::BindGroupEntry<'a>,
+        pub polygon_vertices: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub moments: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub nodes: wgpu::BindGroupEntry<'a>,
+        pub angles: wgpu::BindGroupEntry<'a>,
+        pub integrated_aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_velocities: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub contact_counts: wgpu::BindGroupEntry<'a>,
+        pub responses: wgpu::BindGroupEntry<'a>,
+        pub tools: wgpu::BindGroupEntry<'a>,
+        pub wall_normals: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                shapes: wgpu::BindGroupEntry {
+                    binding: 20,
+                    resource: wgpu::BindingResource::Buffer(params.shapes),
+                },
+                sizes: wgpu::BindGroupEntry {
+                    binding: 21,
+                    resource: wgpu::BindingResource::Buffer(params.sizes),
+                },
+                polygons: wgpu::BindGroupEntry {
+                    binding: 22,
+                    resource: wgpu::BindingResource::Buffer(params.polygons),
+                },
+                polygon_vertices: wgpu::BindGroupEntry {
+                    binding: 23,
+                    resource: wgpu::BindingResource::Buffer(params.polygon_vertices),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                masses: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                moments: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.moments),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                nodes: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.nodes),
+                },
+                angles: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.angles),
+                },
+                integrated_aabbs: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_aabbs),
+                },
+                integrated_velocities: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_velocities),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                contact_counts: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.contact_counts),
+                },
+                responses: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.responses),
+                },
+                tools: wgpu::BindGroupEntry {
+                    binding: 11,
+                    resource: wgpu::BindingResource::Buffer(params.tools),
+                },
+                wall_normals: wgpu::BindGroupEntry {
+                    binding: 12,
+                    resource: wgpu::BindingResource::Buffer(params.wall_normals),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 17] {
+            [
+                self.shapes,
+                self.sizes,
+                self.polygons,
+                self.polygon_vertices,
+                self.flags,
+                self.masses,
+                self.moments,
+                self.aabbs,
+                self.nodes,
+                self.angles,
+                self.integrated_aabbs,
+                self.integrated_velocities,
+                self.angular_velocities,
+                self.contact_counts,
+                self.responses,
+                self.tools,
+                self.wall_normals,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Contacts::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(20): \"_root::geometry::shapes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 20,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(21): \"_root::geometry::sizes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 21,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(22): \"_root::geometry::polygons\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 22,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(23): \"_root::geometry::polygon_vertices\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 23,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(0): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"moments\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"angles\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"integrated_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"integrated_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"angular_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"contact_counts\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"responses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(11): \"tools\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 11,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::tool_state::ToolState>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(12): \"wall_normals\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 12,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Contacts::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Contacts::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("contacts.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngleX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct SecondMomentsX_naga_oil_mod_XMNXW23LPNYX {
+    xx: f32,
+    yy: f32,
+    xy: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct BvhNodeX_naga_oil_mod_XMNXW23LPNYX {
+    index: u32,
+}
+
+struct ToolStateX_naga_oil_mod_XORXW63C7ON2GC5DFX {
+    cursor: vec2<f32>,
+    cursor_strength: f32,
+    cursor_softening: f32,
+    grabbed: u32,
+    grab_velocity: vec2<f32>,
+}
+
+struct BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    stack: array<u32, 31>,
+    size: u32,
+}
+
+struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct SizeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct PolygonX_naga_oil_mod_XMNXW23LPNYX {
+    offset: u32,
+    count: u32,
+}
+
+struct PolygonVertexX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct ContactResponse {
+    velocity: vec2<f32>,
+    push: vec2<f32>,
+    angular_velocity: f32,
+}
+
+struct Contact {
+    normal: vec2<f32>,
+    depth: f32,
+    point: vec2<f32>,
+}
+
+struct Visit {
+    count: u32,
+    response: ContactResponse,
+}
+
+struct Separation {
+    closest: vec2<f32>,
+    simplex: array<vec2<f32>, 3>,
+    count: u32,
+}
+
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX: u32 = 32u;
+const SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const SHAPE_CAPSULEX_naga_oil_mod_XMNXW23LPNYX: u32 = 3u;
+const BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX: u32 = 2147483648u;
+const SHAPE_POLYGONX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
+const WORKGROUP_SIZE: u32 = 64u;
+const CONTACT_RESTITUTION: f32 = 0.5f;
+const RESTITUTION_SPEED_THRESHOLD: f32 = 5f;
+const CONTACT_SLOP: f32 = 0.01f;
+const OVERLAP_CORRECTION: f32 = 0.2f;
+const GJK_MAX_ITERATIONS: u32 = 32u;
+const GJK_TOLERANCE: f32 = 0.0001f;
+const EPA_MAX_VERTICES: u32 = 16u;
+const EPA_TOLERANCE: f32 = 0.001f;
+const FEATURE_ANGLE: f32 = 0.01f;
+
+@group(0) @binding(20) 
+var<storage> shapesX_naga_oil_mod_XM5SW63LFORZHSX: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(21) 
+var<storage> sizesX_naga_oil_mod_XM5SW63LFORZHSX: array<SizeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(22) 
+var<storage> polygonsX_naga_oil_mod_XM5SW63LFORZHSX: array<PolygonX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(23) 
+var<storage> polygon_verticesX_naga_oil_mod_XM5SW63LFORZHSX: array<PolygonVertexX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(0) 
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(1) 
+var<storage> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage> moments_1: array<SecondMomentsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage> nodes: array<BvhNodeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage> angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(6) 
+var<storage, read_write> integrated_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(7) 
+var<storage, read_write> integrated_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(8) 
+var<storage, read_write> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(9) 
+var<storage, read_write> contact_counts: array<u32>;
+@group(0) @binding(10) 
+var<storage, read_write> responses: array<ContactResponse>;
+@group(0) @binding(11) 
+var<uniform> tools: ToolStateX_naga_oil_mod_XORXW63C7ON2GC5DFX;
+@group(0) @binding(12) 
+var<storage> wall_normals: array<vec4<f32>>;
+
+fn rotationX_naga_oil_mod_XMNXW23LPNYX(angle: f32) -> mat2x2<f32> {
+    let c = cos(angle);
+    let s = sin(angle);
+    return mat2x2<f32>(vec2<f32>(c, s), vec2<f32>(-(s), c));
+}
+
+fn moment_of_inertiaX_naga_oil_mod_XMNXW23LPNYX(moments: SecondMomentsX_naga_oil_mod_XMNXW23LPNYX, mass: f32) -> f32 {
+    return (mass * (moments.xx + moments.yy));
+}
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_3: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_3.x + ((gid_3.y * 65535u) * workgroup_size));
+}
+
+fn bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(leaf_count: u32) -> BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    var traversal: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    traversal.stack[0] = ((2u * leaf_count) - 2u);
+    traversal.size = 1u;
+    let _e10 = traversal;
+    return _e10;
+}
+
+fn bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_1: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>) -> u32 {
+    let _e3 = (*traversal_1).size;
+    (*traversal_1).size = (_e3 - 1u);
+    let _e7 = (*traversal_1).size;
+    let _e9 = (*traversal_1).stack[_e7];
+    return _e9;
+}
+
+fn bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_2: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>, node: u32) -> bool {
+    if ((node & BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+        return false;
+    }
+    let left = (node & 2147483647u);
+    let size_1 = (*traversal_2).size;
+    (*traversal_2).stack[size_1] = left;
+    (*traversal_2).stack[(size_1 + 1u)] = (left + 1u);
+    (*traversal_2).size = (size_1 + 2u);
+    return true;
+}
+
+fn aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(a: AABBX_naga_oil_mod_XMNXW23LPNYX, b: AABBX_naga_oil_mod_XMNXW23LPNYX) -> bool {
+    return (all((a.min <= b.max)) && all((b.min <= a.max)));
+}
+
+fn rotated_half_extentsX_naga_oil_mod_XMNXW23LPNYX(shape: u32, size: vec2<f32>, angle_1: f32) -> vec2<f32> {
+    if (shape == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX) {
+        return (size / vec2(2f));
+    }
+    if (shape == SHAPE_CAPSULEX_naga_oil_mod_XMNXW23LPNYX) {
+        let radius = (size.y / 2f);
+        return ((abs(vec2<f32>(cos(angle_1), sin(angle_1))) * ((size.x / 2f) - radius)) + vec2(radius));
+    }
+    let c_1 = abs(cos(angle_1));
+    let s_1 = abs(sin(angle_1));
+    return (vec2<f32>(((c_1 * size.x) + (s_1 * size.y)), ((s_1 * size.x) + (c_1 * size.y))) / vec2(2f));
+}
+
+fn supportX_naga_oil_mod_XM5SW63LFORZHSX(i: u32, angle_2: f32, direction: vec2<f32>) -> vec2<f32> {
+    var local: vec2<f32> = vec2<f32>();
+    var best: f32 = -1000000000000000000000000000000f;
+    var v: u32;
+
+    let size_2 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[i].inner;
+    let _e8 = rotationX_naga_oil_mod_XMNXW23LPNYX(angle_2);
+    let local_direction = (transpose(_e8) * direction);
+    let unit = (local_direction / vec2(max(length(local_direction), 0.00000000000000000001f)));
+    let _e20 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[i].inner;
+    switch _e20 {
+        case 1u: {
+            local = ((unit * size_2.x) / vec2(2f));
+        }
+        case 3u: {
+            let radius_1 = (size_2.y / 2f);
+            let end_1 = ((size_2.x / 2f) - radius_1);
+            local = (vec2<f32>(select(-(end_1), end_1, (local_direction.x >= 0f)), 0f) + (unit * radius_1));
+        }
+        case 2u: {
+            let polygon = polygonsX_naga_oil_mod_XM5SW63LFORZHSX[i];
+            v = polygon.offset;
+            loop {
+                let _e48 = v;
+                if (_e48 < (polygon.offset + polygon.count)) {
+                } else {
+                    break;
+                }
+                {
+                    let _e54 = v;
+                    let vertex = polygon_verticesX_naga_oil_mod_XM5SW63LFORZHSX[_e54].inner;
+                    let distance = dot(vertex, local_direction);
+                    let _e60 = best;
+                    if (distance > _e60) {
+                        best = distance;
+                        local = vertex;
+                    }
+                }
+                continuing {
+                    let _e63 = v;
+                    v = (_e63 + 1u);
+                }
+            }
+        }
+        default: {
+            local = select((-(size_2) / vec2(2f)), (size_2 / vec2(2f)), (local_direction >= vec2<f32>()));
+        }
+    }
+    let _e75 = local;
+    return (_e8 * _e75);
+}
+
+fn takes_part(i_1: u32) -> bool {
+    let f = flags[i_1].inner;
+    return (((f & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) != 0u) && ((f & FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX) == 0u));
+}
+
+fn object_center(i_2: u32) -> vec2<f32> {
+    let _e4 = aabbs[i_2].min;
+    let _e8 = aabbs[i_2].max;
+    return ((_e4 + _e8) / vec2(2f));
+}
+
+fn circle_contact(a_1: u32, b_1: u32) -> Contact {
+    let _e5 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[a_1].inner.x;
+    let radius_a = (_e5 / 2f);
+    let _e13 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[b_1].inner.x;
+    let radius_b = (_e13 / 2f);
+    let _e16 = object_center(b_1);
+    let _e17 = object_center(a_1);
+    let offset_1 = (_e16 - _e17);
+    let distance_1 = length(offset_1);
+    let normal_3 = select(vec2<f32>(1f, 0f), (offset_1 / vec2(distance_1)), (distance_1 > 0f));
+    let depth_1 = ((radius_a + radius_b) - distance_1);
+    let _e30 = object_center(a_1);
+    return Contact(normal_3, depth_1, (_e30 + (normal_3 * (radius_a - (depth_1 / 2f)))));
+}
+
+fn core_support(i_3: u32, direction_1: vec2<f32>) -> vec2<f32> {
+    let angle_3 = angles[i_3].inner;
+    let size_3 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[i_3].inner;
+    let _e12 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[i_3].inner;
+    switch _e12 {
+        case 1u: {
+            let _e13 = object_center(i_3);
+            return _e13;
+        }
+        case 3u: {
+            let _e14 = rotationX_naga_oil_mod_XMNXW23LPNYX(angle_3);
+            let axis = (_e14 * vec2<f32>(((size_3.x / 2f) - (size_3.y / 2f)), 0f));
+            let _e25 = object_center(i_3);
+            return (_e25 + select(-(axis), axis, (dot(axis, direction_1) >= 0f)));
+        }
+        default: {
+            let _e33 = object_center(i_3);
+            let _e34 = supportX_naga_oil_mod_XM5SW63LFORZHSX(i_3, angle_3, direction_1);
+            return (_e33 + _e34);
+        }
+    }
+}
+
+fn core_difference_support(a_2: u32, b_2: u32, direction_2: vec2<f32>) -> vec2<f32> {
+    let _e2 = core_support(a_2, direction_2);
+    let _e5 = core_support(b_2, -(direction_2));
+    return (_e2 - _e5);
+}
+
+fn closest_on_segment(start: vec2<f32>, end: vec2<f32>) -> vec2<f32> {
+    let edge = (end - start);
+    let t = clamp((-(dot(start, edge)) / max(dot(edge, edge), 0.00000000000000000001f)), 0f, 1f);
+    return (start + (edge * t));
+}
+
+fn cross(a_3: vec2<f32>, b_3: vec2<f32>) -> f32 {
+    return ((a_3.x * b_3.y) - (a_3.y * b_3.x));
+}
+
+fn separation(a_4: u32, b_4: u32) -> Separation {
+    var separation_1: Separation;
+    var iteration: u32 = 0u;
+
+    let _e2 = object_center(b_4);
+    let _e4 = object_center(a_4);
+    let _e6 = core_difference_support(a_4, b_4, (_e2 - _e4));
+    separation_1 = Separation(_e6, array<vec2<f32>, 3>(_e6, vec2<f32>(), vec2<f32>()), 1u);
+    loop {
+        let _e14 = iteration;
+        if (_e14 < GJK_MAX_ITERATIONS) {
+        } else {
+            break;
+        }
+        {
+            let closest_1 = separation_1.closest;
+            let distance_squared = dot(closest_1, closest_1);
+            if (distance_squared < 0.000000000001f) {
+                separation_1.closest = vec2<f32>();
+                let _e24 = separation_1;
+                return _e24;
+            }
+            let _e26 = core_difference_support(a_4, b_4, -(closest_1));
+            if ((distance_squared - dot(closest_1, _e26)) <= (GJK_TOLERANCE * distance_squared)) {
+                let _e32 = separation_1;
+                return _e32;
+            }
+            let _e35 = separation_1.count;
+            separation_1.simplex[_e35] = _e26;
+            let _e39 = separation_1.count;
+            separation_1.count = (_e39 + 1u);
+            let _e42 = separation_1.count;
+            if (_e42 == 2u) {
+                let _e48 = separation_1.simplex[0];
+                let _e49 = closest_on_segment(_e48, _e26);
+                separation_1.closest = _e49;
+                continue;
+            }
+            let simplex = separation_1.simplex;
+            let _e57 = cross((simplex[1] - simplex[0]), -(simplex[0]));
+            let _e63 = cross((simplex[2] - simplex[1]), -(simplex[1]));
+            let _e69 = cross((simplex[0] - simplex[2]), -(simplex[2]));
+            let sides = vec3<f32>(_e57, _e63, _e69);
+            if (all((sides >= vec3<f32>())) || all((sides <= vec3<f32>()))) {
+                separation_1.closest = vec2<f32>();
+                let _e80 = separation_1;
+                return _e80;
+            }
+            let _e83 = closest_on_segment(simplex[0], simplex[2]);
+            let _e86 = closest_on_segment(simplex[1], simplex[2]);
+            separation_1.count = 2u;
+            separation_1.simplex[1] = simplex[2];
+            separation_1.closest = _e83;
+            if (dot(_e86, _e86) < dot(_e83, _e83)) {
+                separation_1.simplex[0] = simplex[1];
+                separation_1.closest = _e86;
+            }
+        }
+        continuing {
+            let _e101 = iteration;
+            iteration = (_e101 + 1u);
+        }
+    }
+    let _e103 = separation_1;
+    return _e103;
+}
+
+fn rounding(i_4: u32) -> f32 {
+    let _e4 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[i_4].inner;
+    switch _e4 {
+        case 1u: {
+            let _e9 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[i_4].inner.x;
+            return (_e9 / 2f);
+        }
+        case 3u: {
+            let _e16 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[i_4].inner.y;
+            return (_e16 / 2f);
+        }
+        default: {
+            return 0f;
+        }
+    }
+}
+
+fn objects_touch(a_5: u32, b_5: u32) -> bool {
+    let _e4 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[a_5].inner;
+    let _e11 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[b_5].inner;
+    if ((_e4 == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX) && (_e11 == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX)) {
+        let _e15 = circle_contact(a_5, b_5);
+        return (_e15.depth >= 0f);
+    }
+    let _e19 = separation(a_5, b_5);
+    let _e22 = rounding(a_5);
+    let _e23 = rounding(b_5);
+    return (length(_e19.closest) <= (_e22 + _e23));
+}
+
+fn epa(a_6: u32, b_6: u32, triangle: array<vec2<f32>, 3>) -> vec3<f32> {
+    var polytope: array<vec2<f32>, 16>;
+    var count: u32 = 3u;
+    var closest: vec3<f32> = vec3<f32>();
+    var iteration_1: u32 = 0u;
+    var closest_edge: u32;
+    var e: u32;
+    var v_1: u32;
+
+    polytope[0] = triangle[0];
+    let _e13 = cross((triangle[1] - triangle[0]), (triangle[2] - triangle[0]));
+    let clockwise = (_e13 < 0f);
+    polytope[1] = select(triangle[1], triangle[2], clockwise);
+    polytope[2] = select(triangle[2], triangle[1], clockwise);
+    loop {
+        let _e25 = iteration_1;
+        if (_e25 < EPA_MAX_VERTICES) {
+        } else {
+            break;
+        }
+        {
+            closest_edge = 0u;
+            closest = vec3<f32>(0f, 0f, 1000000000000000000000000000000f);
+            e = 0u;
+            loop {
+                let _e38 = e;
+                let _e39 = count;
+                if (_e38 < _e39) {
+                } else {
+                    break;
+                }
+                {
+                    let _e41 = e;
+                    let _e44 = count;
+                    let _e47 = polytope[((_e41 + 1u) % _e44)];
+                    let _e48 = e;
+                    let _e50 = polytope[_e48];
+                    let edge_1 = (_e47 - _e50);
+                    let edge_length = length(edge_1);
+                    if (edge_length < 0.000001f) {
+                        continue;
+                    }
+                    let normal_4 = (vec2<f32>(edge_1.y, -(edge_1.x)) / vec2(edge_length));
+                    let _e61 = e;
+                    let _e63 = polytope[_e61];
+                    let distance_2 = dot(normal_4, _e63);
+                    let _e66 = closest.z;
+                    if (distance_2 < _e66) {
+                        closest = vec3<f32>(normal_4, distance_2);
+                        let _e69 = e;
+                        closest_edge = _e69;
+                    }
+                }
+                continuing {
+                    let _e71 = e;
+                    e = (_e71 + 1u);
+                }
+            }
+            let _e73 = closest;
+            let _e77 = core_difference_support(a_6, b_6, _e73.xy);
+            let _e78 = closest;
+            let _e82 = closest.z;
+            let _e86 = count;
+            if (((dot(_e77, _e78.xy) - _e82) < EPA_TOLERANCE) || (_e86 == EPA_MAX_VERTICES)) {
+                break;
+            }
+            let _e90 = count;
+            v_1 = _e90;
+            loop {
+                let _e92 = v_1;
+                let _e93 = closest_edge;
+                if (_e92 > (_e93 + 1u)) {
+                } else {
+                    break;
+                }
+                {
+                    let _e97 = v_1;
+                    let _e99 = v_1;
+                    let _e103 = polytope[(_e99 - 1u)];
+                    polytope[_e97] = _e103;
+                }
+                continuing {
+                    let _e105 = v_1;
+                    v_1 = (_e105 - 1u);
+                }
+            }
+            let _e107 = closest_edge;
+            polytope[(_e107 + 1u)] = _e77;
+            let _e112 = count;
+            count = (_e112 + 1u);
+        }
+        continuing {
+            let _e115 = iteration_1;
+            iteration_1 = (_e115 + 1u);
+        }
+    }
+    let _e117 = closest;
+    return _e117;
+}
+
+fn feature(i_5: u32, direction_3: vec2<f32>) -> array<vec2<f32>, 2> {
+    let angle_4 = angles[i_5].inner;
+    let _e5 = object_center(i_5);
+    let _e7 = rotationX_naga_oil_mod_XMNXW23LPNYX(FEATURE_ANGLE);
+    let _e10 = supportX_naga_oil_mod_XM5SW63LFORZHSX(i_5, angle_4, (_e7 * direction_3));
+    let _e13 = rotationX_naga_oil_mod_XMNXW23LPNYX(-0.01f);
+    let _e15 = supportX_naga_oil_mod_XM5SW63LFORZHSX(i_5, angle_4, (_e13 * direction_3));
+    return array<vec2<f32>, 2>((_e5 + _e10), (_e5 + _e15));
+}
+
+fn contact_point(a_7: u32, b_7: u32, normal: vec2<f32>) -> vec2<f32> {
+    let _e2 = feature(a_7, normal);
+    let _e5 = feature(b_7, -(normal));
+    let tangent = vec2<f32>(-(normal.y), normal.x);
+    let along_a = vec2<f32>(dot(_e2[0], tangent), dot(_e2[1], tangent));
+    let along_b = vec2<f32>(dot(_e5[0], tangent), dot(_e5[1], tangent));
+    let low = max(min(along_a.x, along_a.y), min(along_b.x, along_b.y));
+    let high = min(max(along_a.x, along_a.y), max(along_b.x, along_b.y));
+    let across = (dot((((_e2[0] + _e2[1]) + _e5[0]) + _e5[1]), normal) / 4f);
+    return (((tangent * (low + high)) / vec2(2f)) + (normal * across));
+}
+
+fn find_contact(a_8: u32, b_8: u32) -> Contact {
+    var normal_1: vec2<f32>;
+    var depth: f32;
+
+    let _e4 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[a_8].inner;
+    let _e11 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[b_8].inner;
+    if ((_e4 == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX) && (_e11 == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX)) {
+        let _e15 = circle_contact(a_8, b_8);
+        return _e15;
+    }
+    let _e16 = separation(a_8, b_8);
+    let _e17 = rounding(a_8);
+    let _e18 = rounding(b_8);
+    let roundings = (_e17 + _e18);
+    let distance_3 = length(_e16.closest);
+    if (distance_3 > 0f) {
+        normal_1 = (-(_e16.closest) / vec2(distance_3));
+        depth = (roundings - distance_3);
+    } else {
+        if (_e16.count == 3u) {
+            let _e35 = epa(a_8, b_8, _e16.simplex);
+            normal_1 = _e35.xy;
+            depth = (_e35.z + roundings);
+        } else {
+            let _e39 = object_center(b_8);
+            let _e40 = object_center(a_8);
+            let offset_2 = (_e39 - _e40);
+            normal_1 = select(vec2<f32>(1f, 0f), normalize(offset_2), any((offset_2 != vec2<f32>())));
+            depth = roundings;
+        }
+    }
+    let _e50 = depth;
+    if (_e50 < 0f) {
+        let _e53 = depth;
+        return Contact(vec2<f32>(), _e53, vec2<f32>());
+    }
+    let _e57 = normal_1;
+    let _e58 = depth;
+    let _e59 = normal_1;
+    let _e60 = contact_point(a_8, b_8, _e59);
+    return Contact(_e57, _e58, _e60);
+}
+
+fn inverse_mass(i_6: u32) -> f32 {
+    let _e1 = takes_part(i_6);
+    let _e5 = tools.grabbed;
+    if (!(_e1) || (i_6 == _e5)) {
+        return 0f;
+    }
+    let _e12 = masses[i_6].inner;
+    return (1f / _e12);
+}
+
+fn contact_count(i_7: u32) -> u32 {
+    let _e3 = contact_counts[i_7];
+    let _e4 = takes_part(i_7);
+    return select(1u, _e3, _e4);
+}
+
+fn inverse_inertia(i_8: u32) -> f32 {
+    let _e3 = moments_1[i_8];
+    let _e7 = masses[i_8].inner;
+    let _e8 = moment_of_inertiaX_naga_oil_mod_XMNXW23LPNYX(_e3, _e7);
+    let _e9 = inverse_mass(i_8);
+    if ((_e9 == 0f) || (_e8 <= 0f)) {
+        return 0f;
+    }
+    return (1f / _e8);
+}
+
+fn point_velocity(i_9: u32, offset: vec2<f32>) -> vec2<f32> {
+    let angular_velocity = angular_velocities[i_9].inner;
+    let _e9 = integrated_velocities[i_9].inner;
+    return (_e9 + (vec2<f32>(-(offset.y), offset.x) * angular_velocity));
+}
+
+fn respond(i_10: u32, j: u32, contact: Contact) -> ContactResponse {
+    var response: ContactResponse = ContactResponse(vec2<f32>(), vec2<f32>(), 0f);
+
+    let _e4 = inverse_mass(i_10);
+    let _e6 = inverse_mass(j);
+    if (_e4 == 0f) {
+        return ContactResponse(vec2<f32>(), vec2<f32>(), 0f);
+    }
+    let _e13 = contact_count(i_10);
+    let _e14 = contact_count(j);
+    let share = f32(max(max(_e13, _e14), 1u));
+    let normal_5 = contact.normal;
+    let _e22 = object_center(i_10);
+    let r_i = (contact.point - _e22);
+    let _e25 = object_center(j);
+    let r_j = (contact.point - _e25);
+    let _e27 = inverse_inertia(i_10);
+    let _e28 = inverse_inertia(j);
+    let _e29 = point_velocity(j, r_j);
+    let _e30 = point_velocity(i_10, r_i);
+    let relative_velocity = (_e29 - _e30);
+    let normal_speed = dot(relative_velocity, normal_5);
+    let _e33 = cross(r_i, normal_5);
+    let _e34 = cross(r_j, normal_5);
+    let k = (((_e4 + _e6) + ((_e33 * _e33) * _e27)) + ((_e34 * _e34) * _e28));
+    if ((normal_speed < 0f) && (k > 0f)) {
+        let restitution = select(0f, CONTACT_RESTITUTION, (-(normal_speed) > RESTITUTION_SPEED_THRESHOLD));
+        let impulse = ((((normal_5 * -((1f + restitution))) * normal_speed) / vec2(k)) / vec2(share));
+        response.velocity = (-(impulse) * _e4);
+        let _e68 = cross(r_i, -(impulse));
+        response.angular_velocity = (_e68 * _e27);
+    }
+    let correction = ((max((contact.depth - CONTACT_SLOP), 0f) * OVERLAP_CORRECTION) / share);
+    response.push = (((-(normal_5) * correction) * _e4) / vec2((_e4 + _e6)));
+    let _e85 = response;
+    return _e85;
+}
+
+fn visit_contacts(i_11: u32, solve: bool) -> Visit {
+    var visit: Visit = Visit(0u, ContactResponse(vec2<f32>(), vec2<f32>(), 0f));
+    var traversal_3: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    let object_count = arrayLength((&flags));
+    let aabb = aabbs[i_11];
+    let _e11 = bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(object_count);
+    traversal_3 = _e11;
+    loop {
+        let _e14 = traversal_3.size;
+        if (_e14 > 0u) {
+        } else {
+            break;
+        }
+        {
+            let _e17 = bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3));
+            let _e20 = aabbs[_e17];
+            let _e21 = aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(aabb, _e20);
+            if !(_e21) {
+                continue;
+            }
+            let node_1 = nodes[_e17].index;
+            let _e27 = bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3), node_1);
+            if _e27 {
+                continue;
+            }
+            let _e32 = flags[node_1].inner;
+            if ((node_1 == i_11) || ((_e32 & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) == 0u)) {
+                continue;
+            }
+            if !(solve) {
+                let _e42 = objects_touch(i_11, node_1);
+                let _e44 = visit.count;
+                visit.count = (_e44 + u32(_e42));
+                continue;
+            }
+            let _e46 = find_contact(i_11, node_1);
+            if (_e46.depth > 0f) {
+                let _e50 = respond(i_11, node_1, _e46);
+                let _e54 = visit.response.velocity;
+                visit.response.velocity = (_e54 + _e50.velocity);
+                let _e59 = visit.response.push;
+                visit.response.push = (_e59 + _e50.push);
+                let _e64 = visit.response.angular_velocity;
+                visit.response.angular_velocity = (_e64 + _e50.angular_velocity);
+            }
+        }
+    }
+    let _e66 = visit;
+    return _e66;
+}
+
+fn off_wall(motion: vec2<f32>, normal_2: vec2<f32>) -> vec2<f32> {
+    return (motion - (normal_2 * min(dot(motion, normal_2), 0f)));
+}
+
+fn off_walls(motion_1: vec2<f32>, walls: vec4<f32>) -> vec2<f32> {
+    let _e3 = off_wall(motion_1, walls.xy);
+    let _e5 = off_wall(_e3, walls.zw);
+    return select(_e5, vec2<f32>(), (dot(_e5, walls.xy) < -0.000001f));
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn count_contacts(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    let _e6 = takes_part(_e2);
+    if ((_e2 >= arrayLength((&flags))) || !(_e6)) {
+        return;
+    }
+    let _e12 = visit_contacts(_e2, false);
+    contact_counts[_e2] = _e12.count;
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn solve_contacts(@builtin(global_invocation_id) gid_1: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1, WORKGROUP_SIZE);
+    let _e6 = takes_part(_e2);
+    if ((_e2 >= arrayLength((&flags))) || !(_e6)) {
+        return;
+    }
+    let _e12 = visit_contacts(_e2, true);
+    responses[_e2] = _e12.response;
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn apply_contacts(@builtin(global_invocation_id) gid_2: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_2, WORKGROUP_SIZE);
+    let _e6 = takes_part(_e2);
+    if ((_e2 >= arrayLength((&flags))) || !(_e6)) {
+        return;
+    }
+    let response_1 = responses[_e2];
+    let walls_1 = wall_normals[_e2];
+    let _e21 = integrated_velocities[_e2].inner;
+    let _e24 = off_walls((_e21 + response_1.velocity), walls_1);
+    integrated_velocities[_e2].inner = _e24;
+    let _e29 = angular_velocities[_e2].inner;
+    angular_velocities[_e2].inner = (_e29 + response_1.angular_velocity);
+    let _e32 = off_walls(response_1.push, walls_1);
+    let _e36 = integrated_aabbs[_e2].min;
+    integrated_aabbs[_e2].min = (_e36 + _e32);
+    let _e41 = integrated_aabbs[_e2].max;
+    integrated_aabbs[_e2].max = (_e41 + _e32);
+    return;
+}
+"#;
+}
+pub mod trail {
+    use super::{_root, _root::*};
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct TrailParameters {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub fade: f32,
+    }
+    impl TrailParameters {
+        pub const fn new(fade: f32) -> Self {
+            Self { fade }
+        }
+    }
+    pub const ENTRY_VS_FULLSCREEN: &str = "vs_fullscreen";
+    pub const ENTRY_FS_FADE: &str = "fs_fade";
+    pub const ENTRY_FS_BLIT: &str = "fs_blit";
+    #[derive(Debug)]
+    pub struct VertexEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub buffers: [wgpu::VertexBufferLayout<'static>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn vertex_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a VertexEntry<N>,
+    ) -> wgpu::VertexState<'a> {
+        wgpu::VertexState {
+            module,
+            entry_point: Some(entry.entry_point),
+            buffers: &entry.buffers,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn vs_fullscreen_entry() -> VertexEntry<0> {
+        VertexEntry {
+            entry_point: ENTRY_VS_FULLSCREEN,
+            buffers: [],
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct FragmentEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub targets: [Option<wgpu::ColorTargetState>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn fragment_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a FragmentEntry<N>,
+    ) -> wgpu::FragmentState<'a> {
+        wgpu::FragmentState {
+            module,
+            entry_point: Some(entry.entry_point),
+            targets: &entry.targets,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn fs_fade_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_FADE,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    pub fn fs_blit_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_BLIT,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub parameters: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub parameters: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                parameters: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 1] {
+            [self.parameters]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Trail::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"parameters\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::trail::TrailParameters>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Trail::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup1EntriesParams<'a> {
+        pub accumulation: &'a wgpu::TextureView,
+        pub accumulation_sampler: &'a wgpu::Sampler,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup1Entries<'a> {
+        pub accumulation: wgpu::BindGroupEntry<'a>,
+        pub accumulation_sampler: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup1Entries<'a> {
+        pub fn new(params: WgpuBindGroup1EntriesParams<'a>) -> Self {
+            Self {
+                accumulation: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(params.accumulation),
+                },
+                accumulation_sampler: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(params.accumulation_sampler),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 2] {
+            [self.accumulation, self.accumulation_sampler]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup1(wgpu::BindGroup);
+    impl WgpuBindGroup1 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Trail::BindGroup1::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"accumulation\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"accumulation_sampler\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup1Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Trail::BindGroup1"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(1, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+        pub bind_group1: &'a WgpuBindGroup1,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+            self.bind_group1.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 2]) -> [wgpu::BindGroupLayout; 2] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Trail::PipelineLayout"),
+            bind_group_layouts: &[
+                &WgpuBindGroup0::get_bind_group_layout(device),
+                &WgpuBindGroup1::get_bind_group_layout(device),
+            ],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("trail.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct TrailParameters {
+    fade: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) color: vec4<f32>,
+}
+
+@group(0) @binding(0) 
+var<uniform> parameters: TrailParameters;
+@group(1) @binding(0) 
+var accumulation: texture_2d<f32>;
+@group(1) @binding(1) 
+var accumulation_sampler: sampler;
+
+@vertex 
+fn vs_fullscreen(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput = VertexOutput();
+
+    let uv = vec2<f32>(f32(((vertex_index << 1u) & 2u)), f32((vertex_index & 2u)));
+    out.clip_position = vec4<f32>(((uv * 2f) - vec2(1f)), 0f, 1f);
+    out.uv = vec2<f32>(uv.x, (1f - uv.y));
+    let _e27 = out;
+    return _e27;
+}
+
+@fragment 
+fn fs_fade(in: VertexOutput) -> FragmentOutput {
+    let _e2 = parameters.fade;
+    return FragmentOutput(vec4(_e2));
+}
+
+@fragment 
+fn fs_blit(in_1: VertexOutput) -> FragmentOutput {
+    let _e4 = textureSample(accumulation, accumulation_sampler, in_1.uv);
+    return FragmentOutput(_e4);
+}
+"#;
+}
+pub mod heatmap_grid {
+    use super::{_root, _root::*};
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct HeatmapParameters {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub world_min: [f32; 2],
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub world_max: [f32; 2],
+        #[doc = "offset: 16, size: 8, type: `vec2<u32>`"]
+        pub grid_size: [u32; 2],
+        #[doc = "offset: 24, size: 4, type: `u32`"]
+        pub quantity: u32,
+        #[doc = "offset: 28, size: 4, type: `f32`"]
+        pub value_range: f32,
+        #[doc = "offset: 32, size: 4, type: `f32`"]
+        pub opacity: f32,
+        pub _pad_opacity: [u8; 0x4],
+    }
+    impl HeatmapParameters {
+        pub const fn new(
+            world_min: [f32; 2],
+            world_max: [f32; 2],
+            grid_size: [u32; 2],
+            quantity: u32,
+            value_range: f32,
+            opacity: f32,
+        ) -> Self {
+            Self {
+                world_min,
+                world_max,
+                grid_size,
+                quantity,
+                value_range,
+                opacity,
+                _pad_opacity: [0; 0x4],
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct HeatmapParametersInit {
+        pub world_min: [f32; 2],
+        pub world_max: [f32; 2],
+        pub grid_size: [u32; 2],
+        pub quantity: u32,
+        pub value_range: f32,
+        pub opacity: f32,
+    }
+    impl HeatmapParametersInit {
+        pub fn build(&self) -> HeatmapParameters {
+            HeatmapParameters {
+                world_min: self.world_min,
+                world_max: self.world_max,
+                grid_size: self.grid_size,
+                quantity: self.quantity,
+                value_range: self.value_range,
+                opacity: self.opacity,
+                _pad_opacity: [0; 0x4],
+            }
+        }
+    }
+    impl From<HeatmapParametersInit> for HeatmapParameters {
+        fn from(data: HeatmapParametersInit) -> Self {
+            data.build()
+        }
+    }
+    pub const HEATMAP_FIXED_POINT_SCALE: f32 = 16f32;
+    pub const HEATMAP_CELL_STRIDE: u32 = 4u32;
+    pub const HEATMAP_QUANTITY_DENSITY: u32 = 0u32;
+    pub const HEATMAP_QUANTITY_SPEED: u32 = 1u32;
+    pub const HEATMAP_QUANTITY_DIVERGENCE: u32 = 2u32;
+    pub const HEATMAP_QUANTITY_VORTICITY: u32 = 3u32;
+}
+pub mod heatmap_splat {
+    use super::{_root, _root::*};
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const SPLAT_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_splat_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline splat"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("splat"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_SPLAT: &str = "splat";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub parameters: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub velocities: wgpu::BufferBinding<'a>,
+        pub cells: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub parameters: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub velocities: wgpu::BindGroupEntry<'a>,
+        pub cells: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                parameters: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                velocities: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.velocities),
+                },
+                cells: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.cells),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 5] {
+            [self.parameters, self.flags, self.aabbs, self.velocities, self.cells]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("HeatmapSplat::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"parameters\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<
+                            _root::heatmap_grid::HeatmapParameters,
+                        >() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"cells\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("HeatmapSplat::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("HeatmapSplat::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("heatmap_splat.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct HeatmapParametersX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX {
+    world_min: vec2<f32>,
+    world_max: vec2<f32>,
+    grid_size: vec2<u32>,
+    quantity: u32,
+    value_range: f32,
+    opacity: f32,
+}
+
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+const HEATMAP_FIXED_POINT_SCALEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: f32 = 16f;
+const HEATMAP_CELL_STRIDEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: u32 = 4u;
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const WORKGROUP_SIZE: u32 = 64u;
+
+@group(0) @binding(0) 
+var<uniform> parameters_1: HeatmapParametersX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX;
+@group(0) @binding(1) 
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage, read_write> cells: array<atomic<i32>>;
+
+fn heatmap_cell_indexX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX(parameters: HeatmapParametersX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX, cell: vec2<u32>) -> u32 {
+    return (((cell.y * parameters.grid_size.x) + cell.x) * HEATMAP_CELL_STRIDEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX);
+}
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_1.x + ((gid_1.y * 65535u) * workgroup_size));
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn splat(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    let _e9 = flags[_e2].inner;
+    if ((_e2 >= arrayLength((&flags))) || ((_e9 & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) == 0u)) {
+        return;
+    }
+    let aabb = aabbs[_e2];
+    let center = ((aabb.min + aabb.max) / vec2(2f));
+    let _e26 = parameters_1.world_min;
+    let _e30 = parameters_1.world_max;
+    let _e33 = parameters_1.world_min;
+    let relative_position = ((center - _e26) / (_e30 - _e33));
+    if (any((relative_position < vec2(0f))) || any((relative_position >= vec2(1f)))) {
+        return;
+    }
+    let _e47 = parameters_1.grid_size;
+    let cell_1 = vec2<u32>((relative_position * vec2<f32>(_e47)));
+    let _e52 = parameters_1;
+    let _e53 = heatmap_cell_indexX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX(_e52, cell_1);
+    let velocity = velocities[_e2].inner;
+    let _e61 = atomicAdd((&cells[_e53]), 1i);
+    let _e70 = atomicAdd((&cells[(_e53 + 1u)]), i32((length(velocity) * HEATMAP_FIXED_POINT_SCALEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX)));
+    let _e79 = atomicAdd((&cells[(_e53 + 2u)]), i32((velocity.x * HEATMAP_FIXED_POINT_SCALEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX)));
+    let _e88 = atomicAdd((&cells[(_e53 + 3u)]), i32((velocity.y * HEATMAP_FIXED_POINT_SCALEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX)));
+    return;
+}
+"#;
+}
+pub mod heatmap {
+    use super::{_root, _root::*};
+    pub const ENTRY_VS_OVERLAY: &str = "vs_overlay";
+    pub const ENTRY_FS_OVERLAY: &str = "fs_overlay";
+    pub const ENTRY_VS_LEGEND: &str = "vs_legend";
+    pub const ENTRY_FS_LEGEND: &str = "fs_legend";
+    #[derive(Debug)]
+    pub struct VertexEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub buffers: [wgpu::VertexBufferLayout<'static>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn vertex_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a VertexEntry<N>,
+    ) -> wgpu::VertexState<'a> {
+        wgpu::VertexState {
+            module,
+            entry_point: Some(entry.entry_point),
+            buffers: &entry.buffers,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn vs_overlay_entry() -> VertexEntry<0> {
+        VertexEntry {
+            entry_point: ENTRY_VS_OVERLAY,
+            buffers: [],
+            constants: Default::default(),
+        }
+    }
+    pub fn vs_legend_entry() -> VertexEntry<0> {
+        VertexEntry {
+            entry_point: ENTRY_VS_LEGEND,
+            buffers: [],
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct FragmentEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub targets: [Option<wgpu::ColorTargetState>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn fragment_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a FragmentEntry<N>,
+    ) -> wgpu::FragmentState<'a> {
+        wgpu::FragmentState {
+            module,
+            entry_point: Some(entry.entry_point),
+            targets: &entry.targets,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn fs_overlay_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_OVERLAY,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    pub fn fs_legend_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_LEGEND,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub camera: wgpu::BufferBinding<'a>,
+        pub parameters: wgpu::BufferBinding<'a>,
+        pub cells: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub camera: wgpu::BindGroupEntry<'a>,
+        pub parameters: wgpu::BindGroupEntry<'a>,
+        pub cells: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                camera: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.camera),
+                },
+                parameters: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
+                },
+                cells: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.cells),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 3] {
+            [self.camera, self.parameters, self.cells]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Heatmap::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"camera\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<_root::common::Camera>() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"parameters\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<
+                            _root::heatmap_grid::HeatmapParameters,
+                        >() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"cells\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Heatmap::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Heatmap::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("heatmap.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct CameraX_naga_oil_mod_XMNXW23LPNYX {
+    inner: mat4x4<f32>,
+}
+
+struct HeatmapParametersX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX {
+    world_min: vec2<f32>,
+    world_max: vec2<f32>,
+    grid_size: vec2<u32>,
+    quantity: u32,
+    value_range: f32,
+    opacity: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+const UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(0.5f, 0.5f), vec2<f32>(-0.5f, 0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(0.5f, -0.5f), vec2<f32>(0.5f, 0.5f));
+const HEATMAP_QUANTITY_DENSITYX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: u32 = 0u;
+const HEATMAP_QUANTITY_SPEEDX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: u32 = 1u;
+const HEATMAP_QUANTITY_DIVERGENCEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: u32 = 2u;
+const HEATMAP_QUANTITY_VORTICITYX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: u32 = 3u;
+const HEATMAP_FIXED_POINT_SCALEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: f32 = 16f;
+const HEATMAP_CELL_STRIDEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX: u32 = 4u;
+const LEGEND_MIN: vec2<f32> = vec2<f32>(0.5f, -0.95f);
+const LEGEND_MAX: vec2<f32> = vec2<f32>(0.95f, -0.9f);
+
+@group(0) @binding(0) 
+var<uniform> camera: CameraX_naga_oil_mod_XMNXW23LPNYX;
+@group(0) @binding(1) 
+var<uniform> parameters_1: HeatmapParametersX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX;
+@group(0) @binding(2) 
+var<storage> cells: array<i32>;
+
+fn heatmap_cell_indexX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX(parameters: HeatmapParametersX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX, cell: vec2<u32>) -> u32 {
+    return (((cell.y * parameters.grid_size.x) + cell.x) * HEATMAP_CELL_STRIDEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX);
+}
+
+fn mean_velocity(cell_1: vec2<i32>) -> vec2<f32> {
+    let _e2 = parameters_1;
+    let _e4 = heatmap_cell_indexX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX(_e2, vec2<u32>(cell_1));
+    let count = cells[_e4];
+    if (count == 0i) {
+        return vec2(0f);
+    }
+    let _e16 = cells[(_e4 + 2u)];
+    let _e22 = cells[(_e4 + 3u)];
+    return ((vec2<f32>(f32(_e16), f32(_e22)) / vec2(f32(count))) / vec2(16f));
+}
+
+fn velocity_gradient(cell_2: vec2<u32>) -> mat2x2<f32> {
+    let _e2 = parameters_1.grid_size;
+    let last = (vec2<i32>(_e2) - vec2(1i));
+    let c = vec2<i32>(cell_2);
+    let left = vec2<i32>(max((c.x - 1i), 0i), c.y);
+    let right = vec2<i32>(min((c.x + 1i), last.x), c.y);
+    let down = vec2<i32>(c.x, max((c.y - 1i), 0i));
+    let up = vec2<i32>(c.x, min((c.y + 1i), last.y));
+    let _e39 = parameters_1.world_max;
+    let _e42 = parameters_1.world_min;
+    let _e46 = parameters_1.grid_size;
+    let cell_size = ((_e39 - _e42) / vec2<f32>(_e46));
+    let _e49 = mean_velocity(right);
+    let _e50 = mean_velocity(left);
+    let d_dx = ((_e49 - _e50) / vec2((f32((right.x - left.x)) * cell_size.x)));
+    let _e60 = mean_velocity(up);
+    let _e61 = mean_velocity(down);
+    let d_dy = ((_e60 - _e61) / vec2((f32((up.y - down.y)) * cell_size.y)));
+    return mat2x2<f32>(d_dx, d_dy);
+}
+
+fn cell_value(cell_3: vec2<u32>) -> f32 {
+    let _e2 = parameters_1.quantity;
+    switch _e2 {
+        case 0u: {
+            let _e4 = parameters_1;
+            let _e6 = heatmap_cell_indexX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX(_e4, cell_3);
+            let _e9 = cells[_e6];
+            return f32(_e9);
+        }
+        case 1u: {
+            let _e12 = parameters_1;
+            let _e13 = heatmap_cell_indexX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX(_e12, cell_3);
+            let count_1 = cells[_e13];
+            if (count_1 == 0i) {
+                return 0f;
+            }
+            let _e24 = cells[(_e13 + 1u)];
+            return ((f32(_e24) / f32(count_1)) / HEATMAP_FIXED_POINT_SCALEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX);
+        }
+        case 2u: {
+            let _e30 = velocity_gradient(cell_3);
+            return (_e30[0].x + _e30[1].y);
+        }
+        case 3u: {
+            let _e36 = velocity_gradient(cell_3);
+            return (_e36[0].y - _e36[1].x);
+        }
+        default: {
+            return 0f;
+        }
+    }
+}
+
+fn is_signed() -> bool {
+    let _e2 = parameters_1.quantity;
+    let _e7 = parameters_1.quantity;
+    return ((_e2 == HEATMAP_QUANTITY_DIVERGENCEX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX) || (_e7 == HEATMAP_QUANTITY_VORTICITYX_naga_oil_mod_XNBSWC5DNMFYF6Z3SNFSAX));
+}
+
+fn colormap(value_1: f32) -> vec3<f32> {
+    let _e0 = is_signed();
+    if _e0 {
+        let t = clamp(value_1, -1f, 1f);
+        return (select(vec3<f32>(0.2f, 0.5f, 1f), vec3<f32>(1f, 0.3f, 0.1f), (t > 0f)) * sqrt(abs(t)));
+    }
+    let t_1 = clamp(value_1, 0f, 1f);
+    return clamp(vec3<f32>((3f * t_1), ((3f * t_1) - 1f), ((3f * t_1) - 2f)), vec3(0f), vec3(1f));
+}
+
+@vertex 
+fn vs_overlay(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput = VertexOutput();
+
+    let quad_position = UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX[vertex_index];
+    let uv = (quad_position + vec2(0.5f));
+    let _e9 = parameters_1.world_min;
+    let _e12 = parameters_1.world_max;
+    let world_position = mix(_e9, _e12, uv);
+    let _e18 = camera.inner;
+    out.clip_position = (_e18 * vec4<f32>(world_position, 0f, 1f));
+    out.uv = uv;
+    let _e24 = out;
+    return _e24;
+}
+
+@fragment 
+fn fs_overlay(in: VertexOutput) -> @location(0) vec4<f32> {
+    let _e4 = parameters_1.grid_size;
+    let _e10 = parameters_1.grid_size;
+    let cell_4 = min(vec2<u32>((in.uv * vec2<f32>(_e4))), (_e10 - vec2(1u)));
+    let _e15 = cell_value(cell_4);
+    let _e18 = parameters_1.value_range;
+    let value_2 = (_e15 / _e18);
+    if (value_2 == 0f) {
+        discard;
+    }
+    let _e22 = colormap(value_2);
+    let _e25 = parameters_1.opacity;
+    return vec4<f32>(_e22, _e25);
+}
+
+@vertex 
+fn vs_legend(@builtin(vertex_index) vertex_index_1: u32) -> VertexOutput {
+    var out_1: VertexOutput = VertexOutput();
+
+    let uv_1 = (UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX[vertex_index_1] + vec2(0.5f));
+    out_1.clip_position = vec4<f32>(mix(LEGEND_MIN, LEGEND_MAX, uv_1), 0f, 1f);
+    out_1.uv = uv_1;
+    let _e16 = out_1;
+    return _e16;
+}
+
+@fragment 
+fn fs_legend(in_1: VertexOutput) -> @location(0) vec4<f32> {
+    var value: f32;
+
+    value = in_1.uv.x;
+    let _e4 = is_signed();
+    if _e4 {
+        let _e5 = value;
+        value = ((_e5 * 2f) - 1f);
+    }
+    let _e10 = value;
+    let _e11 = colormap(_e10);
+    return vec4<f32>(_e11, 1f);
+}
+"#;
+}
+pub mod blackhole {
+    use super::{_root, _root::*};
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct BlackHoleParameters {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub world_min: [f32; 2],
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub world_max: [f32; 2],
+    }
+    impl BlackHoleParameters {
+        pub const fn new(world_min: [f32; 2], world_max: [f32; 2]) -> Self {
+            Self { world_min, world_max }
+        }
+    }
+    pub const SPIN_BAND_MAX: f32 = 0.8f32;
+    pub const SPIN_STRENGTH_LIMIT: f32 = 100f32;
+    pub const SPIN_STRIPES: f32 = 12f32;
+    pub const PHOTON_RING_WIDTH: f32 = 0.08f32;
+    pub const CONTOURS_PER_OCTAVE: f32 = 2f32;
+    pub const ENTRY_VS_HORIZON: &str = "vs_horizon";
+    pub const ENTRY_FS_HORIZON: &str = "fs_horizon";
+    pub const ENTRY_VS_POTENTIAL: &str = "vs_potential";
+    pub const ENTRY_FS_POTENTIAL: &str = "fs_potential";
+    #[derive(Debug)]
+    pub struct VertexEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub buffers: [wgpu::VertexBufferLayout<'static>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn vertex_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a VertexEntry<N>,
+    ) -> wgpu::VertexState<'a> {
+        wgpu::VertexState {
+            module,
+            entry_point: Some(entry.entry_point),
+            buffers: &entry.buffers,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn vs_horizon_entry() -> VertexEntry<0> {
+        VertexEntry {
+            entry_point: ENTRY_VS_HORIZON,
+            buffers: [],
+            constants: Default::default(),
+        }
+    }
+    pub fn vs_potential_entry() -> VertexEntry<0> {
+        VertexEntry {
+            entry_point: ENTRY_VS_POTENTIAL,
+            buffers: [],
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct FragmentEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub targets: [Option<wgpu::ColorTargetState>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn fragment_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a FragmentEntry<N>,
+    ) -> wgpu::FragmentState<'a> {
+        wgpu::FragmentState {
+            module,
+            entry_point: Some(entry.entry_point),
+            targets: &entry.targets,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn fs_horizon_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_HORIZON,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    pub fn fs_potential_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_POTENTIAL,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub camera: wgpu::BufferBinding<'a>,
+        pub parameters: wgpu::BufferBinding<'a>,
+        pub blackhole_set: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub camera: wgpu::BindGroupEntry<'a>,
+        pub parameters: wgpu::BindGroupEntry<'a>,
+        pub blackhole_set: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                camera: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.camera),
+                },
+                parameters: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
+                },
+                blackhole_set: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.blackhole_set),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 3] {
+            [self.camera, self.parameters, self.blackhole_set]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Blackhole::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"camera\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<_root::common::Camera>() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"parameters\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<
+                            _root::blackhole::BlackHoleParameters,
+                        >() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"blackhole_set\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::blackholes::BlackHoleSet>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Blackhole::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Blackhole::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("blackhole.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct CameraX_naga_oil_mod_XMNXW23LPNYX {
+    inner: mat4x4<f32>,
+}
+
+struct BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    position: vec2<f32>,
+    velocity: vec2<f32>,
+    radius: f32,
+    mass: f32,
+    spin: f32,
+}
+
+struct BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    blackholes: array<BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, 5>,
+}
+
+struct BlackHoleParameters {
+    world_min: vec2<f32>,
+    world_max: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) position: vec2<f32>,
+    @location(1) @interpolate(flat) blackhole_index: u32,
+}
+
+const UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(0.5f, 0.5f), vec2<f32>(-0.5f, 0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(0.5f, -0.5f), vec2<f32>(0.5f, 0.5f));
+const BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: u32 = 5u;
+const BLACKHOLE_SIZE_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 10f;
+const BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 1000f;
+const SPIN_BAND_MAX: f32 = 0.8f;
+const SPIN_STRENGTH_LIMIT: f32 = 100f;
+const SPIN_STRIPES: f32 = 12f;
+const PHOTON_RING_WIDTH: f32 = 0.08f;
+const CONTOURS_PER_OCTAVE: f32 = 2f;
+
+@group(0) @binding(0) 
+var<uniform> camera: CameraX_naga_oil_mod_XMNXW23LPNYX;
+@group(0) @binding(1) 
+var<uniform> parameters: BlackHoleParameters;
+@group(0) @binding(2) 
+var<uniform> blackhole_set: BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX;
+
+fn blackhole_potentialX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole: BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, position: vec2<f32>) -> f32 {
+    let distance = length((blackhole.position - position));
+    return (((-100000f * blackhole.mass) * BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) / distance);
+}
+
+@vertex 
+fn vs_horizon(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) i: u32) -> VertexOutput {
+    var out: VertexOutput = VertexOutput();
+
+    let blackhole_1 = blackhole_set.blackholes[i];
+    let extent = (((2f * blackhole_1.radius) * BLACKHOLE_SIZE_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) * 1.8f);
+    let position_1 = (UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX[vertex_index] * extent);
+    let _e21 = camera.inner;
+    out.clip_position = (_e21 * vec4<f32>((blackhole_1.position + position_1), 0f, 1f));
+    out.position = position_1;
+    out.blackhole_index = i;
+    let _e30 = out;
+    return _e30;
+}
+
+@fragment 
+fn fs_horizon(in: VertexOutput) -> @location(0) vec4<f32> {
+    let blackhole_2 = blackhole_set.blackholes[in.blackhole_index];
+    let horizon = (blackhole_2.radius * BLACKHOLE_SIZE_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX);
+    let r = (length(in.position) / horizon);
+    if (r < 0.92f) {
+        return vec4<f32>(0f, 0f, 0f, 1f);
+    }
+    if (r < 1f) {
+        return vec4<f32>(1f, 0.6f, 0.2f, 1f);
+    }
+    let band = (min((abs(blackhole_2.spin) / SPIN_STRENGTH_LIMIT), 1f) * SPIN_BAND_MAX);
+    if (r > (1f + band)) {
+        discard;
+    }
+    let angle = (atan2(in.position.y, in.position.x) / 6.2831855f);
+    let phase = ((angle * SPIN_STRIPES) + ((sign(blackhole_2.spin) * (r - 1f)) * 2f));
+    if (fract(phase) > 0.5f) {
+        discard;
+    }
+    let color = select(vec3<f32>(1f, 0.3f, 0.8f), vec3<f32>(0.3f, 0.8f, 1f), (blackhole_2.spin > 0f));
+    return vec4<f32>(color, (0.7f * (1f - ((r - 1f) / band))));
+}
+
+@vertex 
+fn vs_potential(@builtin(vertex_index) vertex_index_1: u32) -> VertexOutput {
+    var out_1: VertexOutput = VertexOutput();
+
+    let uv = (UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX[vertex_index_1] + vec2(0.5f));
+    let _e9 = parameters.world_min;
+    let _e12 = parameters.world_max;
+    let position_2 = mix(_e9, _e12, uv);
+    let _e18 = camera.inner;
+    out_1.clip_position = (_e18 * vec4<f32>(position_2, 0f, 1f));
+    out_1.position = position_2;
+    let _e24 = out_1;
+    return _e24;
+}
+
+@fragment 
+fn fs_potential(in_1: VertexOutput) -> @location(0) vec4<f32> {
+    var potential: f32 = 0f;
+    var i_1: u32 = 0u;
+
+    loop {
+        let _e3 = i_1;
+        if (_e3 < BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) {
+        } else {
+            break;
+        }
+        {
+            let _e9 = i_1;
+            let _e11 = blackhole_set.blackholes[_e9];
+            let _e13 = blackhole_potentialX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(_e11, in_1.position);
+            let _e15 = potential;
+            potential = (_e15 + _e13);
+        }
+        continuing {
+            let _e18 = i_1;
+            i_1 = (_e18 + 1u);
+        }
+    }
+    let _e20 = potential;
+    let level = (log2(-(_e20)) * CONTOURS_PER_OCTAVE);
+    let _e31 = fwidth(level);
+    let distance_to_line = (abs((fract((level + 0.5f)) - 0.5f)) / _e31);
+    let line = (1f - clamp(distance_to_line, 0f, 1f));
+    if (line == 0f) {
+        discard;
+    }
+    return vec4<f32>(0.4f, 0.5f, 1f, (0.4f * line));
+}
+"#;
+}
+pub mod pick {
+    use super::{_root, _root::*};
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct PickQuery {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub point: [f32; 2],
+        #[doc = "offset: 8, size: 4, type: `u32`"]
+        pub index: u32,
+        pub _pad_index: [u8; 0x4],
+    }
+    impl PickQuery {
+        pub const fn new(point: [f32; 2], index: u32) -> Self {
+            Self {
+                point,
+                index,
+                _pad_index: [0; 0x4],
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct PickQueryInit {
+        pub point: [f32; 2],
+        pub index: u32,
+    }
+    impl PickQueryInit {
+        pub fn build(&self) -> PickQuery {
+            PickQuery {
+                point: self.point,
+                index: self.index,
+                _pad_index: [0; 0x4],
+            }
+        }
+    }
+    impl From<PickQueryInit> for PickQuery {
+        fn from(data: PickQueryInit) -> Self {
+            data.build()
+        }
+    }
+    #[repr(C, align(16))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct PickedObject {
+        #[doc = "offset: 0, size: 16, type: `vec4<f32>`"]
+        pub color: [f32; 4],
+        #[doc = "offset: 16, size: 16, type: `common::AABB`"]
+        pub aabb: _root::common::AABB,
+        #[doc = "offset: 32, size: 8, type: `vec2<f32>`"]
+        pub velocity: [f32; 2],
+        #[doc = "offset: 40, size: 4, type: `u32`"]
+        pub index: u32,
+        #[doc = "offset: 44, size: 4, type: `u32`"]
+        pub flags: u32,
+        #[doc = "offset: 48, size: 4, type: `f32`"]
+        pub mass: f32,
+        #[doc = "offset: 52, size: 4, type: `u32`"]
+        pub shape: u32,
+        #[doc = "offset: 56, size: 4, type: `f32`"]
+        pub angle: f32,
+        #[doc = "offset: 60, size: 4, type: `f32`"]
+        pub angular_velocity: f32,
+    }
+    impl PickedObject {
+        pub const fn new(
+            color: [f32; 4],
+            aabb: _root::common::AABB,
+            velocity: [f32; 2],
+            index: u32,
+            flags: u32,
+            mass: f32,
+            shape: u32,
+            angle: f32,
+            angular_velocity: f32,
+        ) -> Self {
+            Self {
+                color,
+                aabb,
+                velocity,
+                index,
+                flags,
+                mass,
+                shape,
+                angle,
+                angular_velocity,
+            }
+        }
+    }
+    pub const PICK_NONE: u32 = 4294967295u32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const PICK_WORKGROUP_SIZE: [u32; 3] = [1, 1, 1];
+        pub fn create_pick_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline pick"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("pick"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_PICK: &str = "pick";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub query: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub nodes: wgpu::BufferBinding<'a>,
+        pub velocities: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub shapes: wgpu::BufferBinding<'a>,
+        pub colors: wgpu::BufferBinding<'a>,
+        pub angles: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub picked: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub query: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub nodes: wgpu::BindGroupEntry<'a>,
+        pub velocities: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub shapes: wgpu::BindGroupEntry<'a>,
+        pub colors: wgpu::BindGroupEntry<'a>,
+        pub angles: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub picked: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                query: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.query),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                nodes: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.nodes),
+                },
+                velocities: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.velocities),
+                },
+                masses: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                shapes: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.shapes),
+                },
+                colors: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.colors),
+                },
+                angles: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.angles),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                picked: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.picked),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 11] {
+            [
+                self.query,
+                self.flags,
+                self.aabbs,
+                self.nodes,
+                self.velocities,
+                self.masses,
+                self.shapes,
+                self.colors,
+                self.angles,
+                self.angular_velocities,
+                self.picked,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Pick::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"query\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<_root::pick::PickQuery>() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"shapes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"colors\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"angles\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"angular_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"picked\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::pick::PickedObject>() as _
+                        ),
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Pick::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Pick::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("pick.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct ColorX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec4<f32>,
+}
+
+struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngleX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct BvhNodeX_naga_oil_mod_XMNXW23LPNYX {
+    index: u32,
+}
+
+struct BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    stack: array<u32, 31>,
+    size: u32,
+}
+
+struct PickQuery {
+    point: vec2<f32>,
+    index: u32,
+}
+
+struct PickedObject {
+    color: vec4<f32>,
+    aabb: AABBX_naga_oil_mod_XMNXW23LPNYX,
+    velocity: vec2<f32>,
+    index: u32,
+    flags: u32,
+    mass: f32,
+    shape: u32,
+    angle: f32,
+    angular_velocity: f32,
+}
+
+const FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX: u32 = 2147483648u;
+const PICK_NONE: u32 = 4294967295u;
+
+@group(0) @binding(0) 
+var<uniform> query: PickQuery;
+@group(0) @binding(1) 
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> nodes: array<BvhNodeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage> velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(6) 
+var<storage> shapes: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(7) 
+var<storage> colors: array<ColorX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(8) 
+var<storage> angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(9) 
+var<storage> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(10) 
+var<storage, read_write> picked: PickedObject;
+
+fn bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(leaf_count: u32) -> BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    var traversal: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    traversal.stack[0] = ((2u * leaf_count) - 2u);
+    traversal.size = 1u;
+    let _e10 = traversal;
+    return _e10;
+}
+
+fn bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_1: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>) -> u32 {
+    let _e3 = (*traversal_1).size;
+    (*traversal_1).size = (_e3 - 1u);
+    let _e7 = (*traversal_1).size;
+    let _e9 = (*traversal_1).stack[_e7];
+    return _e9;
+}
+
+fn bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_2: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>, node: u32) -> bool {
+    if ((node & BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+        return false;
+    }
+    let left = (node & 2147483647u);
+    let size = (*traversal_2).size;
+    (*traversal_2).stack[size] = left;
+    (*traversal_2).stack[(size + 1u)] = (left + 1u);
+    (*traversal_2).size = (size + 2u);
+    return true;
+}
+
+fn find_object(point: vec2<f32>) -> u32 {
+    var traversal_3: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+    var closest: u32 = PICK_NONE;
+    var closest_distance: f32 = 0f;
+
+    let object_count = arrayLength((&flags));
+    let _e4 = bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(object_count);
+    traversal_3 = _e4;
+    loop {
+        let _e7 = traversal_3.size;
+        if (_e7 > 0u) {
+        } else {
+            break;
+        }
+        {
+            let _e10 = bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3));
+            let aabb = aabbs[_e10];
+            if (any((point < aabb.min)) || any((point > aabb.max))) {
+                continue;
+            }
+            let node_1 = nodes[_e10].index;
+            let _e26 = bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3), node_1);
+            if _e26 {
+                continue;
+            }
+            let _e30 = flags[node_1].inner;
+            if ((_e30 & FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX) != 0u) {
+                let distance = length((((aabb.min + aabb.max) / vec2(2f)) - point));
+                let _e44 = closest;
+                let _e48 = closest_distance;
+                if ((_e44 == PICK_NONE) || (distance < _e48)) {
+                    closest = node_1;
+                    closest_distance = distance;
+                }
+            }
+        }
+    }
+    let _e51 = closest;
+    return _e51;
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn pick() {
+    var i: u32;
+    var result: PickedObject = PickedObject();
+
+    let _e3 = query.index;
+    i = _e3;
+    let _e5 = i;
+    if (_e5 == PICK_NONE) {
+        let _e10 = query.point;
+        let _e11 = find_object(_e10);
+        i = _e11;
+    }
+    let _e14 = i;
+    result.index = _e14;
+    let _e15 = i;
+    if (_e15 != PICK_NONE) {
+        let _e20 = i;
+        let _e23 = colors[_e20].inner;
+        result.color = _e23;
+        let _e26 = i;
+        let _e28 = aabbs[_e26];
+        result.aabb = _e28;
+        let _e31 = i;
+        let _e34 = velocities[_e31].inner;
+        result.velocity = _e34;
+        let _e37 = i;
+        let _e40 = flags[_e37].inner;
+        result.flags = _e40;
+        let _e43 = i;
+        let _e46 = masses[_e43].inner;
+        result.mass = _e46;
+        let _e49 = i;
+        let _e52 = shapes[_e49].inner;
+        result.shape = _e52;
+        let _e55 = i;
+        let _e58 = angles[_e55].inner;
+        result.angle = _e58;
+        let _e61 = i;
+        let _e64 = angular_velocities[_e61].inner;
+        result.angular_velocity = _e64;
+    }
+    let _e65 = result;
+    picked = _e65;
+    return;
+}
+"#;
+}
+pub mod ui {
+    use super::{_root, _root::*};
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct UiParameters {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub view_size: [f32; 2],
+    }
+    impl UiParameters {
+        pub const fn new(view_size: [f32; 2]) -> Self {
+            Self { view_size }
+        }
+    }
+    #[repr(C, align(16))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct UiQuad {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub min: [f32; 2],
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub size: [f32; 2],
+        #[doc = "offset: 16, size: 16, type: `vec4<f32>`"]
+        pub color: [f32; 4],
+        #[doc = "offset: 32, size: 4, type: `u32`"]
+        pub character: u32,
+        pub _pad_character: [u8; 0xC],
+    }
+    impl UiQuad {
+        pub const fn new(min: [f32; 2], size: [f32; 2], color: [f32; 4], character: u32) -> Self {
+            Self {
+                min,
+                size,
+                color,
+                character,
+                _pad_character: [0; 0xC],
+            }
+        }
+    }
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct UiQuadInit {
+        pub min: [f32; 2],
+        pub size: [f32; 2],
+        pub color: [f32; 4],
+        pub character: u32,
+    }
+    impl UiQuadInit {
+        pub fn build(&self) -> UiQuad {
+            UiQuad {
+                min: self.min,
+                size: self.size,
+                color: self.color,
+                character: self.character,
+                _pad_character: [0; 0xC],
+            }
+        }
+    }
+    impl From<UiQuadInit> for UiQuad {
+        fn from(data: UiQuadInit) -> Self {
+            data.build()
+        }
+    }
+    pub const UI_SOLID: u32 = 4294967295u32;
+    pub const FONT_FIRST_CHARACTER: u32 = 32u32;
+    pub const FONT_GLYPH_WIDTH: u32 = 5u32;
+    pub const FONT_GLYPH_HEIGHT: u32 = 7u32;
+    pub const ENTRY_VS_UI: &str = "vs_ui";
+    pub const ENTRY_FS_UI: &str = "fs_ui";
+    #[derive(Debug)]
+    pub struct VertexEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub buffers: [wgpu::VertexBufferLayout<'static>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn vertex_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a VertexEntry<N>,
+    ) -> wgpu::VertexState<'a> {
+        wgpu::VertexState {
+            module,
+            entry_point: Some(entry.entry_point),
+            buffers: &entry.buffers,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn vs_ui_entry() -> VertexEntry<0> {
+        VertexEntry {
+            entry_point: ENTRY_VS_UI,
+            buffers: [],
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct FragmentEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub targets: [Option<wgpu::ColorTargetState>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn fragment_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a FragmentEntry<N>,
+    ) -> wgpu::FragmentState<'a> {
+        wgpu::FragmentState {
+            module,
+            entry_point: Some(entry.entry_point),
+            targets: &entry.targets,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn fs_ui_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_UI,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub parameters: wgpu::BufferBinding<'a>,
+        pub quads: wgpu::BufferBinding<'a>,
+        pub font: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub parameters: wgpu::BindGroupEntry<'a>,
+        pub quads: wgpu::BindGroupEntry<'a>,
+        pub font: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                parameters: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
+                },
+                quads: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.quads),
+                },
+                font: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.font),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 3] {
+            [self.parameters, self.quads, self.font]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Ui::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"parameters\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<_root::ui::UiParameters>() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"quads\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"font\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Ui::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Ui::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("ui.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct UiParameters {
+    view_size: vec2<f32>,
+}
+
+struct UiQuad {
+    min: vec2<f32>,
+    size: vec2<f32>,
+    color: vec4<f32>,
+    character: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) @interpolate(flat) instance: u32,
+}
+
+const UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX: array<vec2<f32>, 6> = array<vec2<f32>, 6>(vec2<f32>(0.5f, 0.5f), vec2<f32>(-0.5f, 0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(-0.5f, -0.5f), vec2<f32>(0.5f, -0.5f), vec2<f32>(0.5f, 0.5f));
+const UI_SOLID: u32 = 4294967295u;
+const FONT_FIRST_CHARACTER: u32 = 32u;
+const FONT_GLYPH_WIDTH: u32 = 5u;
+const FONT_GLYPH_HEIGHT: u32 = 7u;
+
+@group(0) @binding(0) 
+var<uniform> parameters: UiParameters;
+@group(0) @binding(1) 
+var<storage> quads: array<UiQuad>;
+@group(0) @binding(2) 
+var<storage> font: array<u32>;
+
+@vertex 
+fn vs_ui(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) instance_index: u32) -> VertexOutput {
+    var out: VertexOutput = VertexOutput();
+
+    let uv = (UNIT_QUAD_VERTICESX_naga_oil_mod_XMNXW23LPNYX[vertex_index] + vec2(0.5f));
+    let quad = quads[instance_index];
+    let pixel = (quad.min + (uv * quad.size));
+    let _e17 = parameters.view_size;
+    let clip = (((pixel / _e17) * vec2<f32>(2f, -2f)) + vec2<f32>(-1f, 1f));
+    out.clip_position = vec4<f32>(clip, 0f, 1f);
+    out.uv = uv;
+    out.instance = instance_index;
+    let _e34 = out;
+    return _e34;
+}
+
+@fragment 
+fn fs_ui(in: VertexOutput) -> @location(0) vec4<f32> {
+    let quad_1 = quads[in.instance];
+    if (quad_1.character == UI_SOLID) {
+        return quad_1.color;
+    }
+    let glyph = (quad_1.character - FONT_FIRST_CHARACTER);
+    let cell = min(vec2<u32>((in.uv * vec2<f32>(5f, 7f))), vec2<u32>(4u, 6u));
+    let column = font[((glyph * FONT_GLYPH_WIDTH) + cell.x)];
+    if (((column >> cell.y) & 1u) == 0u) {
+        discard;
+    }
+    return quad_1.color;
+}
+"#;
+}
+pub mod diagnostics {
+    use super::{_root, _root::*};
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct DiagnosticsParameters {
+        #[doc = "offset: 0, size: 8, type: `vec2<f32>`"]
+        pub origin: [f32; 2],
+    }
+    impl DiagnosticsParameters {
+        pub const fn new(origin: [f32; 2]) -> Self {
+            Self { origin }
+        }
+    }
+    #[repr(C, align(8))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Diagnostics {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub kinetic_energy: f32,
+        #[doc = "offset: 4, size: 4, type: `f32`"]
+        pub potential_energy: f32,
+        #[doc = "offset: 8, size: 8, type: `vec2<f32>`"]
+        pub momentum: [f32; 2],
+        #[doc = "offset: 16, size: 4, type: `f32`"]
+        pub angular_momentum: f32,
+        #[doc = "offset: 20, size: 4, type: `f32`"]
+        pub mass: f32,
+        #[doc = "offset: 24, size: 4, type: `u32`"]
+        pub live: u32,
+        #[doc = "offset: 28, size: 4, type: `u32`"]
+        pub destroyed: u32,
+    }
+    impl Diagnostics {
+        pub const fn new(
+            kinetic_energy: f32,
+            potential_energy: f32,
+            momentum: [f32; 2],
+            angular_momentum: f32,
+            mass: f32,
+            live: u32,
+            destroyed: u32,
+        ) -> Self {
+            Self {
+                kinetic_energy,
+                potential_energy,
+                momentum,
+                angular_momentum,
+                mass,
+                live,
+                destroyed,
+            }
+        }
+    }
+    pub const WORKGROUP_SIZE: u32 = 256u32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const CS_REDUCE_WORKGROUP_SIZE: [u32; 3] = [256, 1, 1];
+        pub fn create_cs_reduce_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline cs_reduce"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("cs_reduce"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const CS_COMBINE_WORKGROUP_SIZE: [u32; 3] = [256, 1, 1];
+        pub fn create_cs_combine_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline cs_combine"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("cs_combine"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_CS_REDUCE: &str = "cs_reduce";
+    pub const ENTRY_CS_COMBINE: &str = "cs_combine";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub parameters: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub velocities: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub partials: wgpu::BufferBinding<'a>,
+        pub totals: wgpu::BufferBinding<'a>,
+        pub moments: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub blackhole_set: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub parameters: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub velocities: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub partials: wgpu::BindGroupEntry<'a>,
+        pub totals: wgpu::BindGroupEntry<'a>,
+        pub moments: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub blackhole_set: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                parameters: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                masses: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                velocities: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.velocities),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                partials: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.partials),
+                },
+                totals: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.totals),
+                },
+                moments: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.moments),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                blackhole_set: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.blackhole_set),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 10] {
+            [
+                self.parameters,
+                self.flags,
+                self.masses,
+                self.velocities,
+                self.aabbs,
+                self.partials,
+                self.totals,
+                self.moments,
+                self.angular_velocities,
+                self.blackhole_set,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Diagnostics::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"parameters\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<
+                            _root::diagnostics::DiagnosticsParameters,
+                        >() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"partials\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"totals\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::diagnostics::Diagnostics>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"moments\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"angular_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"blackhole_set\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::blackholes::BlackHoleSet>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Diagnostics::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Diagnostics::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("diagnostics.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct SecondMomentsX_naga_oil_mod_XMNXW23LPNYX {
+    xx: f32,
+    yy: f32,
+    xy: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    position: vec2<f32>,
+    velocity: vec2<f32>,
+    radius: f32,
+    mass: f32,
+    spin: f32,
+}
+
+struct BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    blackholes: array<BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, 5>,
+}
+
+struct DiagnosticsParameters {
+    origin: vec2<f32>,
+}
+
+struct Diagnostics {
+    kinetic_energy: f32,
+    potential_energy: f32,
+    momentum: vec2<f32>,
+    angular_momentum: f32,
+    mass: f32,
+    live: u32,
+    destroyed: u32,
+}
+
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX: u32 = 8u;
+const BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: u32 = 5u;
+const BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 1000f;
+const WORKGROUP_SIZE: u32 = 256u;
+
+@group(0) @binding(0) 
+var<uniform> parameters: DiagnosticsParameters;
+@group(0) @binding(1) 
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage, read_write> partials: array<Diagnostics>;
+@group(0) @binding(6) 
+var<storage, read_write> totals: Diagnostics;
+@group(0) @binding(7) 
+var<storage> moments_1: array<SecondMomentsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(8) 
+var<storage> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(9) 
+var<uniform> blackhole_set: BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX;
+var<workgroup> scratch: array<Diagnostics, 256>;
+
+fn moment_of_inertiaX_naga_oil_mod_XMNXW23LPNYX(moments: SecondMomentsX_naga_oil_mod_XMNXW23LPNYX, mass: f32) -> f32 {
+    return (mass * (moments.xx + moments.yy));
+}
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_1.x + ((gid_1.y * 65535u) * workgroup_size));
+}
+
+fn blackhole_potentialX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole: BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, position: vec2<f32>) -> f32 {
+    let distance = length((blackhole.position - position));
+    return (((-100000f * blackhole.mass) * BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) / distance);
+}
+
+fn object_diagnostics(i_1: u32) -> Diagnostics {
+    var out: Diagnostics = Diagnostics();
+    var potential: f32 = 0f;
+    var bh_index: u32 = 0u;
+
+    let f = flags[i_1].inner;
+    if ((f & FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX) != 0u) {
+        out.destroyed = 1u;
+    }
+    if ((f & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+        let _e19 = out;
+        return _e19;
+    }
+    let mass_1 = masses[i_1].inner;
+    let velocity = velocities[i_1].inner;
+    let _e31 = aabbs[i_1].min;
+    let _e35 = aabbs[i_1].max;
+    let position_1 = ((_e31 + _e35) / vec2(2f));
+    loop {
+        let _e41 = bh_index;
+        if (_e41 < BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) {
+        } else {
+            break;
+        }
+        {
+            let _e46 = bh_index;
+            let _e48 = blackhole_set.blackholes[_e46];
+            let _e49 = blackhole_potentialX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(_e48, position_1);
+            let _e51 = potential;
+            potential = (_e51 + _e49);
+        }
+        continuing {
+            let _e54 = bh_index;
+            bh_index = (_e54 + 1u);
+        }
+    }
+    let _e58 = parameters.origin;
+    let r = (position_1 - _e58);
+    let _e62 = moments_1[i_1];
+    let _e63 = moment_of_inertiaX_naga_oil_mod_XMNXW23LPNYX(_e62, mass_1);
+    let angular_velocity = angular_velocities[i_1].inner;
+    out.kinetic_energy = (((mass_1 * dot(velocity, velocity)) / 2f) + (((_e63 * angular_velocity) * angular_velocity) / 2f));
+    let _e79 = potential;
+    out.potential_energy = (mass_1 * _e79);
+    out.momentum = (mass_1 * velocity);
+    out.angular_momentum = ((mass_1 * ((r.x * velocity.y) - (r.y * velocity.x))) + (_e63 * angular_velocity));
+    out.mass = mass_1;
+    out.live = 1u;
+    let _e97 = out;
+    return _e97;
+}
+
+fn add(a: Diagnostics, b: Diagnostics) -> Diagnostics {
+    return Diagnostics((a.kinetic_energy + b.kinetic_energy), (a.potential_energy + b.potential_energy), (a.momentum + b.momentum), (a.angular_momentum + b.angular_momentum), (a.mass + b.mass), (a.live + b.live), (a.destroyed + b.destroyed));
+}
+
+fn reduce_workgroup(local_index_2: u32) {
+    var stride: u32 = 128u;
+
+    workgroupBarrier();
+    loop {
+        let _e2 = stride;
+        if (_e2 > 0u) {
+        } else {
+            break;
+        }
+        {
+            let _e6 = stride;
+            if (local_index_2 < _e6) {
+                let _e12 = scratch[local_index_2];
+                let _e14 = stride;
+                let _e17 = scratch[(local_index_2 + _e14)];
+                let _e18 = add(_e12, _e17);
+                scratch[local_index_2] = _e18;
+            }
+            workgroupBarrier();
+        }
+        continuing {
+            let _e20 = stride;
+            stride = (_e20 / 2u);
+        }
+    }
+    return;
+}
+
+@compute @workgroup_size(256, 1, 1) 
+fn cs_reduce(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(local_invocation_index) local_index: u32, @builtin(workgroup_id) workgroup_id: vec3<u32>) {
+    var value: Diagnostics = Diagnostics();
+
+    let _e3 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (_e3 < arrayLength((&masses))) {
+        let _e7 = object_diagnostics(_e3);
+        value = _e7;
+    }
+    let _e12 = value;
+    scratch[local_index] = _e12;
+    reduce_workgroup(local_index);
+    let workgroup_index = (workgroup_id.x + (workgroup_id.y * 65535u));
+    if ((local_index == 0u) && (workgroup_index < arrayLength((&partials)))) {
+        let _e29 = scratch[0];
+        partials[workgroup_index] = _e29;
+        return;
+    } else {
+        return;
+    }
+}
+
+@compute @workgroup_size(256, 1, 1) 
+fn cs_combine(@builtin(local_invocation_index) local_index_1: u32) {
+    var value_1: Diagnostics = Diagnostics();
+    var i: u32;
+
+    i = local_index_1;
+    loop {
+        let _e3 = i;
+        if (_e3 < arrayLength((&partials))) {
+        } else {
+            break;
+        }
+        {
+            let _e8 = value_1;
+            let _e10 = i;
+            let _e12 = partials[_e10];
+            let _e13 = add(_e8, _e12);
+            value_1 = _e13;
+        }
+        continuing {
+            let _e15 = i;
+            i = (_e15 + WORKGROUP_SIZE);
+        }
+    }
+    let _e19 = value_1;
+    scratch[local_index_1] = _e19;
+    reduce_workgroup(local_index_1);
+    if (local_index_1 == 0u) {
+        let _e24 = scratch[0];
+        totals = _e24;
+        return;
+    } else {
+        return;
+    }
+}
+"#;
+}
+pub mod constraints {
+    use super::{_root, _root::*};
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Constraint {
+        #[doc = "offset: 0, size: 4, type: `u32`"]
+        pub a: u32,
+        #[doc = "offset: 4, size: 4, type: `u32`"]
+        pub b: u32,
+        #[doc = "offset: 8, size: 4, type: `f32`"]
+        pub rest_length: f32,
+        #[doc = "offset: 12, size: 4, type: `f32`"]
+        pub compliance: f32,
+        #[doc = "offset: 16, size: 4, type: `f32`"]
+        pub damping: f32,
+    }
+    impl Constraint {
+        pub const fn new(a: u32, b: u32, rest_length: f32, compliance: f32, damping: f32) -> Self {
+            Self {
+                a,
+                b,
+                rest_length,
+                compliance,
+                damping,
+            }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct SolverPass {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub dt: f32,
+    }
+    impl SolverPass {
+        pub const fn new(dt: f32) -> Self {
+            Self { dt }
+        }
+    }
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const SOLVE_CONSTRAINTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_solve_constraints_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline solve_constraints"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("solve_constraints"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const APPLY_CORRECTIONS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_apply_corrections_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline apply_corrections"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("apply_corrections"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_SOLVE_CONSTRAINTS: &str = "solve_constraints";
+    pub const ENTRY_APPLY_CORRECTIONS: &str = "apply_corrections";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub constraints: wgpu::BufferBinding<'a>,
+        pub lambdas: wgpu::BufferBinding<'a>,
+        pub corrections: wgpu::BufferBinding<'a>,
+        pub constrained_objects: wgpu::BufferBinding<'a>,
+        pub constraint_offsets: wgpu::BufferBinding<'a>,
+        pub constraint_indices: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_velocities: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub constraints: wgpu::BindGroupEntry<'a>,
+        pub lambdas: wgpu::BindGroupEntry<'a>,
+        pub corrections: wgpu::BindGroupEntry<'a>,
+        pub constrained_objects: wgpu::BindGroupEntry<'a>,
+        pub constraint_offsets: wgpu::BindGroupEntry<'a>,
+        pub constraint_indices: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_velocities: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                constraints: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.constraints),
+                },
+                lambdas: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.lambdas),
+                },
+                corrections: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.corrections),
+                },
+                constrained_objects: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.constrained_objects),
+                },
+                constraint_offsets: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.constraint_offsets),
+                },
+                constraint_indices: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.constraint_indices),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                masses: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                integrated_aabbs: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_aabbs),
+                },
+                integrated_velocities: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_velocities),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 11] {
+            [
+                self.constraints,
+                self.lambdas,
+                self.corrections,
+                self.constrained_objects,
+                self.constraint_offsets,
+                self.constraint_indices,
+                self.flags,
+                self.masses,
+                self.aabbs,
+                self.integrated_aabbs,
+                self.integrated_velocities,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Constraints::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"constraints\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"lambdas\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"corrections\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"constrained_objects\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"constraint_offsets\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"constraint_indices\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"integrated_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"integrated_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Constraints::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Constraints::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[wgpu::PushConstantRange {
+                stages: wgpu::ShaderStages::COMPUTE,
+                range: 0..4,
+            }],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("constraints.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct Constraint {
+    a: u32,
+    b: u32,
+    rest_length: f32,
+    compliance: f32,
+    damping: f32,
+}
+
+struct SolverPass {
+    dt: f32,
+}
+
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX: u32 = 8u;
+const FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX: u32 = 32u;
+const WORKGROUP_SIZE: u32 = 64u;
+
+@group(0) @binding(0) 
+var<storage> constraints: array<Constraint>;
+@group(0) @binding(1) 
+var<storage, read_write> lambdas: array<f32>;
+@group(0) @binding(2) 
+var<storage, read_write> corrections: array<vec2<f32>>;
+@group(0) @binding(3) 
+var<storage> constrained_objects: array<u32>;
+@group(0) @binding(4) 
+var<storage> constraint_offsets: array<u32>;
+@group(0) @binding(5) 
+var<storage> constraint_indices: array<u32>;
+@group(0) @binding(6) 
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(7) 
+var<storage> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(8) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(9) 
+var<storage, read_write> integrated_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(10) 
+var<storage, read_write> integrated_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+var<push_constant> pass_parameters: SolverPass;
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_2: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_2.x + ((gid_2.y * 65535u) * workgroup_size));
+}
+
+fn inverse_mass(object: u32) -> f32 {
+    let _e4 = flags[object].inner;
+    if ((_e4 & 36u) != FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) {
+        return 0f;
+    }
+    let _e13 = masses[object].inner;
+    return (1f / _e13);
+}
+
+fn center(aabb: AABBX_naga_oil_mod_XMNXW23LPNYX) -> vec2<f32> {
+    return ((aabb.min + aabb.max) / vec2(2f));
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn solve_constraints(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (_e2 >= arrayLength((&constraints))) {
+        return;
+    }
+    let constraint = constraints[_e2];
+    let _e10 = inverse_mass(constraint.a);
+    let _e12 = inverse_mass(constraint.b);
+    let _e17 = flags[constraint.a].inner;
+    let _e22 = flags[constraint.b].inner;
+    let broken = (((_e17 | _e22) & FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX) != 0u);
+    let _e31 = integrated_aabbs[constraint.a];
+    let _e32 = center(_e31);
+    let _e36 = integrated_aabbs[constraint.b];
+    let _e37 = center(_e36);
+    let delta = (_e32 - _e37);
+    let distance = length(delta);
+    if ((broken || ((_e10 + _e12) == 0f)) || (distance == 0f)) {
+        corrections[_e2] = vec2<f32>();
+        return;
+    }
+    let dt = pass_parameters.dt;
+    let normal = (delta / vec2(distance));
+    let error = (distance - constraint.rest_length);
+    let alpha = (constraint.compliance / (dt * dt));
+    let gamma = ((constraint.compliance * constraint.damping) / dt);
+    let _e67 = integrated_aabbs[constraint.a];
+    let _e68 = center(_e67);
+    let _e72 = aabbs[constraint.a];
+    let _e73 = center(_e72);
+    let _e78 = integrated_aabbs[constraint.b];
+    let _e79 = center(_e78);
+    let _e83 = aabbs[constraint.b];
+    let _e84 = center(_e83);
+    let moved = ((_e68 - _e73) - (_e79 - _e84));
+    let _e90 = lambdas[_e2];
+    let delta_lambda = (((-(error) - (alpha * _e90)) - (gamma * dot(normal, moved))) / (((1f + gamma) * (_e10 + _e12)) + alpha));
+    let _e104 = lambdas[_e2];
+    lambdas[_e2] = (_e104 + delta_lambda);
+    corrections[_e2] = (normal * delta_lambda);
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn apply_corrections(@builtin(global_invocation_id) gid_1: vec3<u32>) {
+    var sum: vec2<f32> = vec2<f32>();
+    var j: u32;
+
+    let _e3 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1, WORKGROUP_SIZE);
+    if (_e3 >= arrayLength((&constrained_objects))) {
+        return;
+    }
+    let object_1 = constrained_objects[_e3];
+    let _e10 = inverse_mass(object_1);
+    if (_e10 == 0f) {
+        return;
+    }
+    let start = constraint_offsets[_e3];
+    let end = constraint_offsets[(_e3 + 1u)];
+    j = start;
+    loop {
+        let _e22 = j;
+        if (_e22 < end) {
+        } else {
+            break;
+        }
+        {
+            let _e25 = j;
+            let constraint_index = constraint_indices[_e25];
+            let correction = corrections[constraint_index];
+            let _e34 = constraints[constraint_index].a;
+            if (_e34 == object_1) {
+                let _e37 = sum;
+                sum = (_e37 + correction);
+            } else {
+                let _e39 = sum;
+                sum = (_e39 - correction);
+            }
+        }
+        continuing {
+            let _e42 = j;
+            j = (_e42 + 1u);
+        }
+    }
+    let _e44 = sum;
+    let offset = ((_e44 * _e10) / vec2(f32((end - start))));
+    let aabb_1 = integrated_aabbs[object_1];
+    integrated_aabbs[object_1] = AABBX_naga_oil_mod_XMNXW23LPNYX((aabb_1.min + offset), (aabb_1.max + offset));
+    let _e65 = pass_parameters.dt;
+    let _e68 = integrated_velocities[object_1].inner;
+    integrated_velocities[object_1].inner = (_e68 + (offset / vec2(_e65)));
+    return;
+}
+"#;
+}
+pub mod static_collision {
+    use super::{_root, _root::*};
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub const STATIC_RESTITUTION: f32 = 0.5f32;
+    pub const RESTITUTION_SPEED_THRESHOLD: f32 = 5f32;
+    pub const BULLET_SUBSTEPS: u32 = 8u32;
+    pub const NO_IMPACT: f32 = 2f32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const CS_MAIN_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_cs_main_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline cs_main"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("cs_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_CS_MAIN: &str = "cs_main";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub shapes: wgpu::BufferBinding<'a>,
+        pub sizes: wgpu::BufferBinding<'a>,
+        pub polygons: wgpu::BufferBinding<'a>,
+        pub polygon_vertices: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub integrated_aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_velocities: wgpu::BufferBinding<'a>,
+        pub integrated_angles: wgpu::BufferBinding<'a>,
+        pub segments: wgpu::BufferBinding<'a>,
+        pub static_aabbs: wgpu::BufferBinding<'a>,
+        pub static_nodes: wgpu::BufferBinding<'a>,
+        pub wall_normals: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub shapes: wgpu::BindGroupEntry<'a>,
+        pub sizes: wgpu::BindGroupEntry<'a>,
+        pub polygons: wgpu::BindGroupEntry<'a>,
+        pub polygon_vertices: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub integrated_aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_velocities: wgpu::BindGroupEntry<'a>,
+        pub integrated_angles: wgpu::BindGroupEntry<'a>,
+        pub segments: wgpu::BindGroupEntry<'a>,
+        pub static_aabbs: wgpu::BindGroupEntry<'a>,
+        pub static_nodes: wgpu::BindGroupEntry<'a>,
+        pub wall_normals: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                shapes: wgpu::BindGroupEntry {
+                    binding: 20,
+                    resource: wgpu::BindingResource::Buffer(params.shapes),
+                },
+                sizes: wgpu::BindGroupEntry {
+                    binding: 21,
+                    resource: wgpu::BindingResource::Buffer(params.sizes),
+                },
+                polygons: wgpu::BindGroupEntry {
+                    binding: 22,
+                    resource: wgpu::BindingResource::Buffer(params.polygons),
+                },
+                polygon_vertices: wgpu::BindGroupEntry {
+                    binding: 23,
+                    resource: wgpu::BindingResource::Buffer(params.polygon_vertices),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                integrated_aabbs: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_aabbs),
+                },
+                integrated_velocities: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_velocities),
+                },
+                integrated_angles: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_angles),
+                },
+                segments: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.segments),
+                },
+                static_aabbs: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.static_aabbs),
+                },
+                static_nodes: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.static_nodes),
+                },
+                wall_normals: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.wall_normals),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 13] {
+            [
+                self.shapes,
+                self.sizes,
+                self.polygons,
+                self.polygon_vertices,
+                self.flags,
+                self.integrated_aabbs,
+                self.integrated_velocities,
+                self.integrated_angles,
+                self.segments,
+                self.static_aabbs,
+                self.static_nodes,
+                self.wall_normals,
+                self.aabbs,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("StaticCollision::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(20): \"_root::geometry::shapes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 20,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(21): \"_root::geometry::sizes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 21,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(22): \"_root::geometry::polygons\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 22,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(23): \"_root::geometry::polygon_vertices\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 23,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(0): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"integrated_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"integrated_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"integrated_angles\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"segments\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"static_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"static_nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"wall_normals\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("StaticCollision::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("StaticCollision::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("static_collision.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngleX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct SegmentX_naga_oil_mod_XMNXW23LPNYX {
+    start: vec2<f32>,
+    end: vec2<f32>,
+}
+
+struct BvhNodeX_naga_oil_mod_XMNXW23LPNYX {
+    index: u32,
+}
+
+struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct SizeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct PolygonX_naga_oil_mod_XMNXW23LPNYX {
+    offset: u32,
+    count: u32,
+}
+
+struct PolygonVertexX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    stack: array<u32, 31>,
+    size: u32,
+}
+
+struct Impact {
+    time: f32,
+    normal: vec2<f32>,
+}
+
+struct Walls {
+    normals: vec4<f32>,
+    count: u32,
+}
+
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_BULLETX_naga_oil_mod_XMNXW23LPNYX: u32 = 16u;
+const FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX: u32 = 32u;
+const SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const SHAPE_POLYGONX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
+const SHAPE_CAPSULEX_naga_oil_mod_XMNXW23LPNYX: u32 = 3u;
+const BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX: u32 = 2147483648u;
+const WORKGROUP_SIZE: u32 = 64u;
+const STATIC_RESTITUTION: f32 = 0.5f;
+const RESTITUTION_SPEED_THRESHOLD: f32 = 5f;
+const BULLET_SUBSTEPS: u32 = 8u;
+const NO_IMPACT: f32 = 2f;
+
+@group(0) @binding(20) 
+var<storage> shapesX_naga_oil_mod_XM5SW63LFORZHSX: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(21) 
+var<storage> sizesX_naga_oil_mod_XM5SW63LFORZHSX: array<SizeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(22) 
+var<storage> polygonsX_naga_oil_mod_XM5SW63LFORZHSX: array<PolygonX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(23) 
+var<storage> polygon_verticesX_naga_oil_mod_XM5SW63LFORZHSX: array<PolygonVertexX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(0) 
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(1) 
+var<storage, read_write> integrated_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage, read_write> integrated_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> integrated_angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage> segments: array<SegmentX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage> static_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(6) 
+var<storage> static_nodes: array<BvhNodeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(7) 
+var<storage, read_write> wall_normals: array<vec4<f32>>;
+@group(0) @binding(8) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_1.x + ((gid_1.y * 65535u) * workgroup_size));
+}
+
+fn rotationX_naga_oil_mod_XMNXW23LPNYX(angle: f32) -> mat2x2<f32> {
+    let c = cos(angle);
+    let s = sin(angle);
+    return mat2x2<f32>(vec2<f32>(c, s), vec2<f32>(-(s), c));
+}
+
+fn rotated_half_extentsX_naga_oil_mod_XMNXW23LPNYX(shape: u32, size: vec2<f32>, angle_1: f32) -> vec2<f32> {
+    if (shape == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX) {
+        return (size / vec2(2f));
+    }
+    if (shape == SHAPE_CAPSULEX_naga_oil_mod_XMNXW23LPNYX) {
+        let radius_1 = (size.y / 2f);
+        return ((abs(vec2<f32>(cos(angle_1), sin(angle_1))) * ((size.x / 2f) - radius_1)) + vec2(radius_1));
+    }
+    let c_1 = abs(cos(angle_1));
+    let s_1 = abs(sin(angle_1));
+    return (vec2<f32>(((c_1 * size.x) + (s_1 * size.y)), ((s_1 * size.x) + (c_1 * size.y))) / vec2(2f));
+}
+
+fn supportX_naga_oil_mod_XM5SW63LFORZHSX(i: u32, angle_2: f32, direction: vec2<f32>) -> vec2<f32> {
+    var local: vec2<f32> = vec2<f32>();
+    var best: f32 = -1000000000000000000000000000000f;
+    var v: u32;
+
+    let size_1 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[i].inner;
+    let _e8 = rotationX_naga_oil_mod_XMNXW23LPNYX(angle_2);
+    let local_direction = (transpose(_e8) * direction);
+    let unit = (local_direction / vec2(max(length(local_direction), 0.00000000000000000001f)));
+    let _e20 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[i].inner;
+    switch _e20 {
+        case 1u: {
+            local = ((unit * size_1.x) / vec2(2f));
+        }
+        case 3u: {
+            let radius_2 = (size_1.y / 2f);
+            let end = ((size_1.x / 2f) - radius_2);
+            local = (vec2<f32>(select(-(end), end, (local_direction.x >= 0f)), 0f) + (unit * radius_2));
+        }
+        case 2u: {
+            let polygon = polygonsX_naga_oil_mod_XM5SW63LFORZHSX[i];
+            v = polygon.offset;
+            loop {
+                let _e48 = v;
+                if (_e48 < (polygon.offset + polygon.count)) {
+                } else {
+                    break;
+                }
+                {
+                    let _e54 = v;
+                    let vertex = polygon_verticesX_naga_oil_mod_XM5SW63LFORZHSX[_e54].inner;
+                    let distance = dot(vertex, local_direction);
+                    let _e60 = best;
+                    if (distance > _e60) {
+                        best = distance;
+                        local = vertex;
+                    }
+                }
+                continuing {
+                    let _e63 = v;
+                    v = (_e63 + 1u);
+                }
+            }
+        }
+        default: {
+            local = select((-(size_1) / vec2(2f)), (size_1 / vec2(2f)), (local_direction >= vec2<f32>()));
+        }
+    }
+    let _e75 = local;
+    return (_e8 * _e75);
+}
+
+fn bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(leaf_count: u32) -> BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    var traversal_1: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    traversal_1.stack[0] = ((2u * leaf_count) - 2u);
+    traversal_1.size = 1u;
+    let _e10 = traversal_1;
+    return _e10;
+}
+
+fn bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_2: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>) -> u32 {
+    let _e3 = (*traversal_2).size;
+    (*traversal_2).size = (_e3 - 1u);
+    let _e7 = (*traversal_2).size;
+    let _e9 = (*traversal_2).stack[_e7];
+    return _e9;
+}
+
+fn bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_3: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>, node: u32) -> bool {
+    if ((node & BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+        return false;
+    }
+    let left = (node & 2147483647u);
+    let size_2 = (*traversal_3).size;
+    (*traversal_3).stack[size_2] = left;
+    (*traversal_3).stack[(size_2 + 1u)] = (left + 1u);
+    (*traversal_3).size = (size_2 + 2u);
+    return true;
+}
+
+fn aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(a: AABBX_naga_oil_mod_XMNXW23LPNYX, b: AABBX_naga_oil_mod_XMNXW23LPNYX) -> bool {
+    return (all((a.min <= b.max)) && all((b.min <= a.max)));
+}
+
+fn circle_time_of_impact(start: vec2<f32>, motion: vec2<f32>, radius: f32, segment: SegmentX_naga_oil_mod_XMNXW23LPNYX) -> Impact {
+    var impact: Impact = Impact(2f, vec2<f32>());
+    var e: i32 = 0i;
+
+    let edge = (segment.end - segment.start);
+    let length_squared = dot(edge, edge);
+    if (length_squared > 0f) {
+        let side_normal = (vec2<f32>(-(edge.y), edge.x) / vec2(sqrt(length_squared)));
+        let distance_1 = dot((start - segment.start), side_normal);
+        let normal_4 = (side_normal * sign(distance_1));
+        let approach = dot(motion, normal_4);
+        if ((abs(distance_1) > radius) && (approach < 0f)) {
+            let time = ((abs(distance_1) - radius) / -(approach));
+            let along = (dot(((start + (motion * time)) - segment.start), edge) / length_squared);
+            if (((time <= 1f) && (along >= 0f)) && (along <= 1f)) {
+                impact = Impact(time, normal_4);
+            }
+        }
+    }
+    loop {
+        let _e53 = e;
+        if (_e53 < 2i) {
+        } else {
+            break;
+        }
+        {
+            let _e58 = e;
+            let offset = (start - select(segment.start, segment.end, (_e58 == 1i)));
+            let b_1 = dot(offset, motion);
+            let c_2 = (dot(offset, offset) - (radius * radius));
+            let a_1 = dot(motion, motion);
+            let discriminant = ((b_1 * b_1) - (a_1 * c_2));
+            if (((c_2 > 0f) && (b_1 < 0f)) && (discriminant >= 0f)) {
+                let time_1 = ((-(b_1) - sqrt(discriminant)) / a_1);
+                let _e84 = impact.time;
+                if ((time_1 < _e84) && (time_1 <= 1f)) {
+                    impact = Impact(time_1, normalize((offset + (motion * time_1))));
+                }
+            }
+        }
+        continuing {
+            let _e94 = e;
+            e = (_e94 + 1i);
+        }
+    }
+    let _e96 = impact;
+    return _e96;
+}
+
+fn penetration(i_1: u32, angle_3: f32, center_1: vec2<f32>, segment_1: SegmentX_naga_oil_mod_XMNXW23LPNYX) -> vec2<f32> {
+    var normal: vec2<f32>;
+
+    let edge_1 = (segment_1.end - segment_1.start);
+    let t = clamp((dot((center_1 - segment_1.start), edge_1) / max(dot(edge_1, edge_1), 0.000000000001f)), 0f, 1f);
+    let closest = (segment_1.start + (edge_1 * t));
+    normal = (center_1 - closest);
+    let _e20 = normal;
+    let distance_2 = length(_e20);
+    if (distance_2 > 0.000001f) {
+        let _e24 = normal;
+        normal = (_e24 / vec2(distance_2));
+    } else {
+        normal = normalize(vec2<f32>(-(edge_1.y), edge_1.x));
+    }
+    let _e32 = normal;
+    let _e36 = supportX_naga_oil_mod_XM5SW63LFORZHSX(i_1, angle_3, -(_e32));
+    let deepest = (center_1 + _e36);
+    let _e39 = normal;
+    let depth = dot((closest - deepest), _e39);
+    let _e41 = normal;
+    return select(vec2<f32>(), (_e41 * depth), (depth > 0f));
+}
+
+fn substepped_time_of_impact(i_2: u32, angle_4: f32, start_1: vec2<f32>, motion_1: vec2<f32>, segment_2: SegmentX_naga_oil_mod_XMNXW23LPNYX) -> Impact {
+    var step: u32 = 1u;
+    var normal_1: vec2<f32>;
+
+    let _e5 = penetration(i_2, angle_4, start_1, segment_2);
+    if any((_e5 != vec2<f32>())) {
+        return Impact(2f, vec2<f32>());
+    }
+    let edge_2 = (segment_2.end - segment_2.start);
+    let perpendicular = vec2<f32>(-(edge_2.y), edge_2.x);
+    let start_side = (perpendicular * sign(dot((start_1 - segment_2.start), perpendicular)));
+    loop {
+        let _e25 = step;
+        if (_e25 <= BULLET_SUBSTEPS) {
+        } else {
+            break;
+        }
+        {
+            let _e29 = step;
+            let _e36 = penetration(i_2, angle_4, (start_1 + ((motion_1 * f32(_e29)) / vec2(8f))), segment_2);
+            if any((_e36 != vec2<f32>())) {
+                normal_1 = normalize(_e36);
+                let _e42 = normal_1;
+                if (dot(_e42, start_side) < 0f) {
+                    normal_1 = normalize(start_side);
+                }
+                let _e47 = step;
+                let _e53 = normal_1;
+                return Impact((f32((_e47 - 1u)) / 8f), _e53);
+            }
+        }
+        continuing {
+            let _e56 = step;
+            step = (_e56 + 1u);
+        }
+    }
+    return Impact(2f, vec2<f32>());
+}
+
+fn sweep(i_3: u32, angle_5: f32, start_2: vec2<f32>, motion_2: vec2<f32>, swept: AABBX_naga_oil_mod_XMNXW23LPNYX) -> Impact {
+    var earliest: Impact = Impact(2f, vec2<f32>());
+    var traversal_4: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+    var impact_1: Impact;
+
+    let _e5 = bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(arrayLength((&segments)));
+    traversal_4 = _e5;
+    loop {
+        let _e8 = traversal_4.size;
+        if (_e8 > 0u) {
+        } else {
+            break;
+        }
+        {
+            let _e11 = bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_4));
+            let _e14 = static_aabbs[_e11];
+            let _e16 = aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(swept, _e14);
+            if !(_e16) {
+                continue;
+            }
+            let node_1 = static_nodes[_e11].index;
+            let _e22 = bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_4), node_1);
+            if _e22 {
+                continue;
+            }
+            let _e27 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[i_3].inner;
+            if (_e27 == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX) {
+                let _e34 = sizesX_naga_oil_mod_XM5SW63LFORZHSX[i_3].inner.x;
+                let _e39 = segments[node_1];
+                let _e42 = circle_time_of_impact(start_2, motion_2, (_e34 / 2f), _e39);
+                impact_1 = _e42;
+            } else {
+                let _e46 = segments[node_1];
+                let _e48 = substepped_time_of_impact(i_3, angle_5, start_2, motion_2, _e46);
+                impact_1 = _e48;
+            }
+            let _e51 = impact_1.time;
+            let _e53 = earliest.time;
+            if (_e51 < _e53) {
+                let _e55 = impact_1;
+                earliest = _e55;
+            }
+        }
+    }
+    let _e56 = earliest;
+    return _e56;
+}
+
+fn bounce(velocity_1: vec2<f32>, normal_2: vec2<f32>) -> vec2<f32> {
+    let normal_speed = dot(velocity_1, normal_2);
+    if (normal_speed >= 0f) {
+        return velocity_1;
+    }
+    let restitution = select(0f, STATIC_RESTITUTION, (-(normal_speed) > RESTITUTION_SPEED_THRESHOLD));
+    return (velocity_1 - (((1f + restitution) * normal_speed) * normal_2));
+}
+
+fn add_wall(walls_1: Walls, normal_3: vec2<f32>) -> Walls {
+    switch walls_1.count {
+        case 0u: {
+            return Walls(vec4<f32>(normal_3, 0f, 0f), 1u);
+        }
+        case 1u: {
+            return Walls(vec4<f32>(walls_1.normals.xy, normal_3), 2u);
+        }
+        default: {
+            return walls_1;
+        }
+    }
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn cs_main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    var center: vec2<f32>;
+    var velocity: vec2<f32>;
+    var moved: bool = false;
+    var walls: Walls = Walls(vec4<f32>(), 0u);
+    var traversal: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    let _e6 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (_e6 >= arrayLength((&flags))) {
+        return;
+    }
+    let f = flags[_e6].inner;
+    if (((f & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) == 0u) || ((f & FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX) != 0u)) {
+        return;
+    }
+    let aabb = integrated_aabbs[_e6];
+    let half_extents = ((aabb.max - aabb.min) / vec2(2f));
+    center = ((aabb.min + aabb.max) / vec2(2f));
+    let _e42 = integrated_velocities[_e6].inner;
+    velocity = _e42;
+    let angle_6 = integrated_angles[_e6].inner;
+    let _e51 = shapesX_naga_oil_mod_XM5SW63LFORZHSX[_e6].inner;
+    if ((_e51 == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX) || ((f & FLAG_BULLETX_naga_oil_mod_XMNXW23LPNYX) != 0u)) {
+        let start_aabb = aabbs[_e6];
+        let start_3 = ((start_aabb.min + start_aabb.max) / vec2(2f));
+        let swept_1 = AABBX_naga_oil_mod_XMNXW23LPNYX(min(start_aabb.min, aabb.min), max(start_aabb.max, aabb.max));
+        let _e75 = center;
+        let _e77 = sweep(_e6, angle_6, start_3, (_e75 - start_3), swept_1);
+        if (_e77.time <= 1f) {
+            moved = true;
+            let _e83 = center;
+            center = (start_3 + ((_e83 - start_3) * _e77.time));
+            let _e88 = velocity;
+            let _e90 = bounce(_e88, _e77.normal);
+            velocity = _e90;
+            let _e92 = walls;
+            let _e94 = add_wall(_e92, _e77.normal);
+            walls = _e94;
+        }
+    }
+    let _e95 = center;
+    let _e97 = center;
+    let query = AABBX_naga_oil_mod_XMNXW23LPNYX((_e95 - half_extents), (_e97 + half_extents));
+    let _e102 = bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(arrayLength((&segments)));
+    traversal = _e102;
+    loop {
+        let _e105 = traversal.size;
+        if (_e105 > 0u) {
+        } else {
+            break;
+        }
+        {
+            let _e108 = bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal));
+            let _e111 = static_aabbs[_e108];
+            let _e112 = aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(query, _e111);
+            if !(_e112) {
+                continue;
+            }
+            let node_2 = static_nodes[_e108].index;
+            let _e118 = bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal), node_2);
+            if _e118 {
+                continue;
+            }
+            let _e119 = center;
+            let _e122 = segments[node_2];
+            let _e123 = penetration(_e6, angle_6, _e119, _e122);
+            if any((_e123 != vec2<f32>())) {
+                moved = true;
+                let _e128 = center;
+                center = (_e128 + _e123);
+                let _e130 = velocity;
+                let _e132 = bounce(_e130, normalize(_e123));
+                velocity = _e132;
+                let _e133 = walls;
+                let _e135 = add_wall(_e133, normalize(_e123));
+                walls = _e135;
+            }
+        }
+    }
+    let _e139 = walls.normals;
+    wall_normals[_e6] = _e139;
+    let _e140 = moved;
+    if _e140 {
+        let _e143 = center;
+        let _e145 = center;
+        integrated_aabbs[_e6] = AABBX_naga_oil_mod_XMNXW23LPNYX((_e143 - half_extents), (_e145 + half_extents));
+        let _e150 = velocity;
+        integrated_velocities[_e6] = VelocityX_naga_oil_mod_XMNXW23LPNYX(_e150);
+        return;
+    } else {
+        return;
+    }
+}
+"#;
+}
+pub mod segment {
+    use super::{_root, _root::*};
+    pub const ENTRY_VS_MAIN: &str = "vs_main";
+    pub const ENTRY_FS_MAIN: &str = "fs_main";
+    #[derive(Debug)]
+    pub struct VertexEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub buffers: [wgpu::VertexBufferLayout<'static>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn vertex_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a VertexEntry<N>,
+    ) -> wgpu::VertexState<'a> {
+        wgpu::VertexState {
+            module,
+            entry_point: Some(entry.entry_point),
+            buffers: &entry.buffers,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn vs_main_entry() -> VertexEntry<0> {
+        VertexEntry {
+            entry_point: ENTRY_VS_MAIN,
+            buffers: [],
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct FragmentEntry<const N: usize> {
+        pub entry_point: &'static str,
+        pub targets: [Option<wgpu::ColorTargetState>; N],
+        pub constants: Vec<(&'static str, f64)>,
+    }
+    pub fn fragment_state<'a, const N: usize>(
+        module: &'a wgpu::ShaderModule,
+        entry: &'a FragmentEntry<N>,
+    ) -> wgpu::FragmentState<'a> {
+        wgpu::FragmentState {
+            module,
+            entry_point: Some(entry.entry_point),
+            targets: &entry.targets,
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &entry.constants,
+                ..Default::default()
+            },
+        }
+    }
+    pub fn fs_main_entry(targets: [Option<wgpu::ColorTargetState>; 1]) -> FragmentEntry<1> {
+        FragmentEntry {
+            entry_point: ENTRY_FS_MAIN,
+            targets,
+            constants: Default::default(),
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub camera: wgpu::BufferBinding<'a>,
+        pub segments: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub camera: wgpu::BindGroupEntry<'a>,
+        pub segments: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                camera: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.camera),
+                },
+                segments: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.segments),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 2] {
+            [self.camera, self.segments]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Segment::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"camera\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<_root::common::Camera>() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"segments\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Segment::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Segment::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("segment.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct CameraX_naga_oil_mod_XMNXW23LPNYX {
+    inner: mat4x4<f32>,
+}
+
+struct SegmentX_naga_oil_mod_XMNXW23LPNYX {
+    start: vec2<f32>,
+    end: vec2<f32>,
+}
+
+struct FragmentOutput {
+    @location(0) color: vec4<f32>,
+}
+
+const SEGMENT_COLOR: vec4<f32> = vec4<f32>(0.9f, 0.3f, 0.2f, 1f);
+
+@group(0) @binding(0) 
+var<uniform> camera: CameraX_naga_oil_mod_XMNXW23LPNYX;
+@group(0) @binding(1) 
+var<storage> segments: array<SegmentX_naga_oil_mod_XMNXW23LPNYX>;
+
+@vertex 
+fn vs_main(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) i: u32) -> @builtin(position) vec4<f32> {
+    let segment = segments[i];
+    let position = select(segment.start, segment.end, (vertex_index == 1u));
+    let _e12 = camera.inner;
+    return (_e12 * vec4<f32>(position, 0f, 1f));
+}
+
+@fragment 
+fn fs_main() -> FragmentOutput {
+    return FragmentOutput(vec4<f32>(0.9f, 0.3f, 0.2f, 1f));
+}
+"#;
+}
+pub mod sleep {
+    use super::{_root, _root::*};
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct SleepSettings {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub speed: f32,
+        #[doc = "offset: 4, size: 4, type: `f32`"]
+        pub angular_speed: f32,
+        #[doc = "offset: 8, size: 4, type: `f32`"]
+        pub acceleration: f32,
+        #[doc = "offset: 12, size: 4, type: `f32`"]
+        pub delay: f32,
+    }
+    impl SleepSettings {
+        pub const fn new(speed: f32, angular_speed: f32, acceleration: f32, delay: f32) -> Self {
+            Self {
+                speed,
+                angular_speed,
+                acceleration,
+                delay,
+            }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct SleepPass {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub dt: f32,
+        #[doc = "offset: 4, size: 4, type: `u32`"]
+        pub wake_all: u32,
+    }
+    impl SleepPass {
+        pub const fn new(dt: f32, wake_all: u32) -> Self {
+            Self { dt, wake_all }
+        }
+    }
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub const ISLAND_ITERATIONS: u32 = 8u32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const UPDATE_REST_TIMES_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_update_rest_times_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline update_rest_times"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("update_rest_times"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const HOOK_CONTACTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_hook_contacts_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline hook_contacts"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("hook_contacts"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const HOOK_LINKS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_hook_links_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline hook_links"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("hook_links"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const CHECK_CONTACTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_check_contacts_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline check_contacts"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("check_contacts"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const CHECK_LINKS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_check_links_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline check_links"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("check_links"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const JUMP_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_jump_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline jump"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("jump"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const MARK_RESTLESS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_mark_restless_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline mark_restless"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("mark_restless"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const APPLY_SLEEP_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_apply_sleep_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline apply_sleep"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("apply_sleep"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_UPDATE_REST_TIMES: &str = "update_rest_times";
+    pub const ENTRY_HOOK_CONTACTS: &str = "hook_contacts";
+    pub const ENTRY_HOOK_LINKS: &str = "hook_links";
+    pub const ENTRY_CHECK_CONTACTS: &str = "check_contacts";
+    pub const ENTRY_CHECK_LINKS: &str = "check_links";
+    pub const ENTRY_JUMP: &str = "jump";
+    pub const ENTRY_MARK_RESTLESS: &str = "mark_restless";
+    pub const ENTRY_APPLY_SLEEP: &str = "apply_sleep";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub flags: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub nodes: wgpu::BufferBinding<'a>,
+        pub velocities: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub rest_times: wgpu::BufferBinding<'a>,
+        pub islands: wgpu::BufferBinding<'a>,
+        pub restless: wgpu::BufferBinding<'a>,
+        pub sleeping_nodes: wgpu::BufferBinding<'a>,
+        pub links: wgpu::BufferBinding<'a>,
+        pub tools: wgpu::BufferBinding<'a>,
+        pub last_velocities: wgpu::BufferBinding<'a>,
+        pub blackhole_set: wgpu::BufferBinding<'a>,
+        pub last_blackhole_set: wgpu::BufferBinding<'a>,
+        pub settings: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub nodes: wgpu::BindGroupEntry<'a>,
+        pub velocities: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub rest_times: wgpu::BindGroupEntry<'a>,
+        pub islands: wgpu::BindGroupEntry<'a>,
+        pub restless: wgpu::BindGroupEntry<'a>,
+        pub sleeping_nodes: wgpu::BindGroupEntry<'a>,
+        pub links: wgpu::BindGroupEntry<'a>,
+        pub tools: wgpu::BindGroupEntry<'a>,
+        pub last_velocities: wgpu::BindGroupEntry<'a>,
+        pub blackhole_set: wgpu::BindGroupEntry<'a>,
+        pub last_blackhole_set: wgpu::BindGroupEntry<'a>,
+        pub settings: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                flags: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                nodes: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.nodes),
+                },
+                velocities: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.velocities),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                rest_times: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.rest_times),
+                },
+                islands: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.islands),
+                },
+                restless: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.restless),
+                },
+                sleeping_nodes: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.sleeping_nodes),
+                },
+                links: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.links),
+                },
+                tools: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.tools),
+                },
+                last_velocities: wgpu::BindGroupEntry {
+                    binding: 11,
+                    resource: wgpu::BindingResource::Buffer(params.last_velocities),
+                },
+                blackhole_set: wgpu::BindGroupEntry {
+                    binding: 12,
+                    resource: wgpu::BindingResource::Buffer(params.blackhole_set),
+                },
+                last_blackhole_set: wgpu::BindGroupEntry {
+                    binding: 13,
+                    resource: wgpu::BindingResource::Buffer(params.last_blackhole_set),
+                },
+                settings: wgpu::BindGroupEntry {
+                    binding: 14,
+                    resource: wgpu::BindingResource::Buffer(params.settings),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 15] {
+            [
+                self.flags,
+                self.aabbs,
+                self.nodes,
+                self.velocities,
+                self.angular_velocities,
+                self.rest_times,
+                self.islands,
+                self.restless,
+                self.sleeping_nodes,
+                self.links,
+                self.tools,
+                self.last_velocities,
+                self.blackhole_set,
+                self.last_blackhole_set,
+                self.settings,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Sleep::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"angular_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"rest_times\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"islands\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"restless\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"sleeping_nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"links\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"tools\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::tool_state::ToolState>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(11): \"last_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 11,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(12): \"blackhole_set\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 12,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::blackholes::BlackHoleSet>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(13): \"last_blackhole_set\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 13,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::blackholes::BlackHoleSet>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(14): \"settings\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 14,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::sleep::SleepSettings>() as _
+                        ),
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Sleep::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Sleep::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[wgpu::PushConstantRange {
+                stages: wgpu::ShaderStages::COMPUTE,
+                range: 0..8,
+            }],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("sleep.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct BvhNodeX_naga_oil_mod_XMNXW23LPNYX {
+    index: u32,
+}
+
+struct ToolStateX_naga_oil_mod_XORXW63C7ON2GC5DFX {
+    cursor: vec2<f32>,
+    cursor_strength: f32,
+    cursor_softening: f32,
+    grabbed: u32,
+    grab_velocity: vec2<f32>,
+}
+
+struct BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    position: vec2<f32>,
+    velocity: vec2<f32>,
+    radius: f32,
+    mass: f32,
+    spin: f32,
+}
+
+struct BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    blackholes: array<BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, 5>,
+}
+
+struct BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    stack: array<u32, 31>,
+    size: u32,
+}
+
+struct SleepSettings {
+    speed: f32,
+    angular_speed: f32,
+    acceleration: f32,
+    delay: f32,
+}
+
+struct SleepPass {
+    dt: f32,
+    wake_all: u32,
+}
+
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX: u32 = 8u;
+const FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX: u32 = 32u;
+const BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: u32 = 5u;
+const GRAVITATIONAL_CONSTANTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 100000f;
+const BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 1000f;
+const BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX: u32 = 2147483648u;
+const WORKGROUP_SIZE: u32 = 64u;
+const ISLAND_ITERATIONS: u32 = 8u;
+
+@group(0) @binding(0) 
+var<storage, read_write> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(1) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage> nodes: array<BvhNodeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage, read_write> rest_times: array<f32>;
+@group(0) @binding(6) 
+var<storage, read_write> islands: array<atomic<u32>>;
+@group(0) @binding(7) 
+var<storage, read_write> restless: array<atomic<u32>>;
+@group(0) @binding(8) 
+var<storage, read_write> sleeping_nodes: array<u32>;
+@group(0) @binding(9) 
+var<storage> links: array<vec2<u32>>;
+@group(0) @binding(10) 
+var<uniform> tools: ToolStateX_naga_oil_mod_XORXW63C7ON2GC5DFX;
+@group(0) @binding(11) 
+var<storage, read_write> last_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(12) 
+var<uniform> blackhole_set: BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX;
+@group(0) @binding(13) 
+var<uniform> last_blackhole_set: BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX;
+@group(0) @binding(14) 
+var<uniform> settings: SleepSettings;
+var<push_constant> pass_parameters: SleepPass;
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_8: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_8.x + ((gid_8.y * 65535u) * workgroup_size));
+}
+
+fn blackhole_gravityX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole: BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, position: vec2<f32>) -> vec2<f32> {
+    let to_blackhole = (blackhole.position - position);
+    let direction = normalize(to_blackhole);
+    let distance = length(to_blackhole);
+    let bh_gravity = ((((direction * GRAVITATIONAL_CONSTANTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) * blackhole.mass) * BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) / vec2((distance * distance)));
+    return bh_gravity;
+}
+
+fn bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(leaf_count: u32) -> BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    var traversal: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    traversal.stack[0] = ((2u * leaf_count) - 2u);
+    traversal.size = 1u;
+    let _e10 = traversal;
+    return _e10;
+}
+
+fn bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_1: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>) -> u32 {
+    let _e3 = (*traversal_1).size;
+    (*traversal_1).size = (_e3 - 1u);
+    let _e7 = (*traversal_1).size;
+    let _e9 = (*traversal_1).stack[_e7];
+    return _e9;
+}
+
+fn bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_2: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>, node: u32) -> bool {
+    if ((node & BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+        return false;
+    }
+    let left = (node & 2147483647u);
+    let size = (*traversal_2).size;
+    (*traversal_2).stack[size] = left;
+    (*traversal_2).stack[(size + 1u)] = (left + 1u);
+    (*traversal_2).size = (size + 2u);
+    return true;
+}
+
+fn aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(a: AABBX_naga_oil_mod_XMNXW23LPNYX, b: AABBX_naga_oil_mod_XMNXW23LPNYX) -> bool {
+    return (all((a.min <= b.max)) && all((b.min <= a.max)));
+}
+
+fn takes_part(i: u32) -> bool {
+    let _e4 = flags[i].inner;
+    return ((_e4 & 12u) == FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX);
+}
+
+fn pull(blackhole_1: BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, position_1: vec2<f32>) -> vec2<f32> {
+    if (blackhole_1.mass == 0f) {
+        return vec2<f32>();
+    }
+    let _e6 = blackhole_gravityX_naga_oil_mod_XMJWGCY3LNBXWYZLTX(blackhole_1, position_1);
+    return _e6;
+}
+
+fn blackhole_pull_change(position_2: vec2<f32>) -> vec2<f32> {
+    var change: vec2<f32> = vec2<f32>();
+    var k: u32 = 0u;
+
+    loop {
+        let _e3 = k;
+        if (_e3 < BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) {
+        } else {
+            break;
+        }
+        {
+            let _e8 = k;
+            let blackhole_2 = blackhole_set.blackholes[_e8];
+            let _e13 = k;
+            let last = last_blackhole_set.blackholes[_e13];
+            if (all((blackhole_2.position == last.position)) && (blackhole_2.mass == last.mass)) {
+                continue;
+            }
+            let _e25 = pull(blackhole_2, position_2);
+            let _e26 = pull(last, position_2);
+            let _e29 = change;
+            change = (_e29 + (_e25 - _e26));
+        }
+        continuing {
+            let _e32 = k;
+            k = (_e32 + 1u);
+        }
+    }
+    let _e34 = change;
+    return _e34;
+}
+
+fn hook(a_1: u32, b_1: u32) {
+    let island_a = atomicLoad((&islands[a_1]));
+    let island_b = atomicLoad((&islands[b_1]));
+    if (island_a < island_b) {
+        let _e11 = atomicMin((&islands[island_b]), island_a);
+        let _e14 = atomicMin((&islands[b_1]), island_a);
+        return;
+    } else {
+        if (island_b < island_a) {
+            let _e18 = atomicMin((&islands[island_a]), island_b);
+            let _e21 = atomicMin((&islands[a_1]), island_b);
+            return;
+        } else {
+            return;
+        }
+    }
+}
+
+fn join(a_2: u32, b_2: u32, check: bool) {
+    if !(check) {
+        hook(a_2, b_2);
+        return;
+    }
+    let island_a_1 = atomicLoad((&islands[a_2]));
+    let island_b_1 = atomicLoad((&islands[b_2]));
+    if (island_a_1 != island_b_1) {
+        let _e14 = atomicOr((&restless[island_a_1]), 1u);
+        let _e18 = atomicOr((&restless[island_b_1]), 1u);
+        return;
+    } else {
+        return;
+    }
+}
+
+fn join_contacts(i_1: u32, check_1: bool) {
+    var traversal_3: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    let _e4 = takes_part(i_1);
+    if ((i_1 >= arrayLength((&flags))) || !(_e4)) {
+        return;
+    }
+    let aabb = aabbs[i_1];
+    let _e12 = bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(arrayLength((&flags)));
+    traversal_3 = _e12;
+    loop {
+        let _e15 = traversal_3.size;
+        if (_e15 > 0u) {
+        } else {
+            break;
+        }
+        {
+            let _e18 = bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3));
+            let _e21 = aabbs[_e18];
+            let _e22 = aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(aabb, _e21);
+            if !(_e22) {
+                continue;
+            }
+            let node_1 = nodes[_e18].index;
+            let _e28 = bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3), node_1);
+            if _e28 {
+                continue;
+            }
+            let _e30 = takes_part(node_1);
+            if ((node_1 > i_1) && _e30) {
+                join(i_1, node_1, check_1);
+            }
+        }
+    }
+    return;
+}
+
+fn join_link(i_2: u32, check_2: bool) {
+    if (i_2 >= arrayLength((&links))) {
+        return;
+    }
+    let link = links[i_2];
+    let _e8 = takes_part(link.x);
+    let _e10 = takes_part(link.y);
+    if (_e8 && _e10) {
+        join(link.x, link.y, check_2);
+        return;
+    } else {
+        return;
+    }
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn update_rest_times(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (_e2 >= arrayLength((&flags))) {
+        return;
+    }
+    atomicStore((&islands[_e2]), _e2);
+    let _e8 = takes_part(_e2);
+    if !(_e8) {
+        return;
+    }
+    let v = velocities[_e2].inner;
+    let _e17 = last_velocities[_e2].inner;
+    let _e21 = pass_parameters.dt;
+    let acceleration = ((v - _e17) / vec2(_e21));
+    last_velocities[_e2].inner = v;
+    let _e30 = aabbs[_e2].min;
+    let _e34 = aabbs[_e2].max;
+    let center = ((_e30 + _e34) / vec2(2f));
+    let _e39 = blackhole_pull_change(center);
+    let _e43 = settings.speed;
+    let _e46 = settings.speed;
+    let _e52 = angular_velocities[_e2].inner;
+    let _e56 = settings.angular_speed;
+    let _e62 = settings.acceleration;
+    let _e65 = settings.acceleration;
+    let _e72 = settings.acceleration;
+    let _e75 = settings.acceleration;
+    let at_rest = ((((dot(v, v) < (_e43 * _e46)) && (abs(_e52) < _e56)) && (dot(acceleration, acceleration) < (_e62 * _e65))) && (dot(_e39, _e39) < (_e72 * _e75)));
+    let _e81 = pass_parameters.wake_all;
+    let _e87 = tools.cursor_strength;
+    let _e93 = tools.grabbed;
+    if (((at_rest && (_e81 == 0u)) && (_e87 == 0f)) && (_e2 != _e93)) {
+        let _e100 = pass_parameters.dt;
+        let _e101 = rest_times[_e2];
+        rest_times[_e2] = (_e101 + _e100);
+        return;
+    } else {
+        rest_times[_e2] = 0f;
+        return;
+    }
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn hook_contacts(@builtin(global_invocation_id) gid_1: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1, WORKGROUP_SIZE);
+    join_contacts(_e2, false);
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn hook_links(@builtin(global_invocation_id) gid_2: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_2, WORKGROUP_SIZE);
+    join_link(_e2, false);
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn check_contacts(@builtin(global_invocation_id) gid_3: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_3, WORKGROUP_SIZE);
+    join_contacts(_e2, true);
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn check_links(@builtin(global_invocation_id) gid_4: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_4, WORKGROUP_SIZE);
+    join_link(_e2, true);
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn jump(@builtin(global_invocation_id) gid_5: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_5, WORKGROUP_SIZE);
+    if (_e2 >= arrayLength((&flags))) {
+        return;
+    }
+    let island = atomicLoad((&islands[_e2]));
+    let _e13 = atomicLoad((&islands[island]));
+    let _e14 = atomicMin((&islands[_e2]), _e13);
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn mark_restless(@builtin(global_invocation_id) gid_6: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_6, WORKGROUP_SIZE);
+    let _e6 = takes_part(_e2);
+    if ((_e2 >= arrayLength((&flags))) || !(_e6)) {
+        return;
+    }
+    let _e11 = rest_times[_e2];
+    let _e14 = settings.delay;
+    if (_e11 < _e14) {
+        let _e19 = atomicLoad((&islands[_e2]));
+        let _e22 = atomicOr((&restless[_e19]), 1u);
+        return;
+    } else {
+        return;
+    }
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn apply_sleep(@builtin(global_invocation_id) gid_7: vec3<u32>) {
+    var f: u32;
+
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_7, WORKGROUP_SIZE);
+    if (_e2 >= arrayLength((&flags))) {
+        return;
+    }
+    let _e9 = flags[_e2].inner;
+    f = (_e9 & 4294967263u);
+    let _e13 = takes_part(_e2);
+    let _e17 = atomicLoad((&islands[_e2]));
+    let _e19 = atomicLoad((&restless[_e17]));
+    if (_e13 && (_e19 == 0u)) {
+        let _e24 = f;
+        f = (_e24 | FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX);
+    }
+    let _e29 = f;
+    flags[_e2].inner = _e29;
+    let _e32 = f;
+    sleeping_nodes[_e2] = u32(((_e32 & FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX) != 0u));
+    return;
+}
+"#;
+}
+pub mod accretion {
+    use super::{_root, _root::*};
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct AccretionParameters {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub dt: f32,
+        #[doc = "offset: 4, size: 4, type: `u32`"]
+        pub accrete: u32,
+    }
+    impl AccretionParameters {
+        pub const fn new(dt: f32, accrete: u32) -> Self {
+            Self { dt, accrete }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct FixedSum {
+        #[doc = "offset: 0, size: 4, type: `atomic<u32>`"]
+        pub low: u32,
+        #[doc = "offset: 4, size: 4, type: `atomic<u32>`"]
+        pub high: u32,
+    }
+    impl FixedSum {
+        pub const fn new(low: u32, high: u32) -> Self {
+            Self { low, high }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Accretion {
+        #[doc = "offset: 0, size: 24, type: `array<FixedSum, 3>`"]
+        pub sums: [FixedSum; 3],
+        #[doc = "offset: 24, size: 4, type: `atomic<u32>`"]
+        pub consumed: u32,
+    }
+    impl Accretion {
+        pub const fn new(sums: [FixedSum; 3], consumed: u32) -> Self {
+            Self { sums, consumed }
+        }
+    }
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub const MASS_SCALE: f32 = 1048576f32;
+    pub const MOMENTUM_SCALE: f32 = 65536f32;
+    pub const WORD_SCALE: f32 = 4294967300f32;
+    pub const SUM_MASS: u32 = 0u32;
+    pub const SUM_MOMENTUM_X: u32 = 1u32;
+    pub const SUM_MOMENTUM_Y: u32 = 2u32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const CONSUME_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_consume_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline consume"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("consume"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const ACCRETE_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_accrete_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline accrete"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("accrete"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_CONSUME: &str = "consume";
+    pub const ENTRY_ACCRETE: &str = "accrete";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub parameters: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub integrated_aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_velocities: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub blackhole_set: wgpu::BufferBinding<'a>,
+        pub accretion: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub parameters: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub integrated_aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_velocities: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub blackhole_set: wgpu::BindGroupEntry<'a>,
+        pub accretion: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                parameters: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.parameters),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                masses: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                integrated_aabbs: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_aabbs),
+                },
+                integrated_velocities: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_velocities),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                blackhole_set: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.blackhole_set),
+                },
+                accretion: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.accretion),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 8] {
+            [
+                self.parameters,
+                self.flags,
+                self.masses,
+                self.integrated_aabbs,
+                self.integrated_velocities,
+                self.angular_velocities,
+                self.blackhole_set,
+                self.accretion,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Accretion::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"parameters\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(std::mem::size_of::<
+                            _root::accretion::AccretionParameters,
+                        >() as _),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"integrated_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"integrated_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"angular_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"blackhole_set\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::blackholes::BlackHoleSet>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"accretion\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Accretion::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Accretion::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("accretion.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    position: vec2<f32>,
+    velocity: vec2<f32>,
+    radius: f32,
+    mass: f32,
+    spin: f32,
+}
+
+struct BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX {
+    blackholes: array<BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX, 5>,
+}
+
+struct AccretionParameters {
+    dt: f32,
+    accrete: u32,
+}
+
+struct FixedSum {
+    low: atomic<u32>,
+    high: atomic<u32>,
+}
+
+struct Accretion {
+    sums: array<FixedSum, 3>,
+    consumed: atomic<u32>,
+}
+
+const FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const FLAG_DRAW_AABBX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX: u32 = 8u;
+const BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: u32 = 5u;
+const BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 1000f;
+const BLACKHOLE_SIZE_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: f32 = 10f;
+const BLACKHOLE_DESTROY_MATTERX_naga_oil_mod_XMJWGCY3LNBXWYZLTX: bool = true;
+const WORKGROUP_SIZE: u32 = 64u;
+const MASS_SCALE: f32 = 1048576f;
+const MOMENTUM_SCALE: f32 = 65536f;
+const WORD_SCALE: f32 = 4294967300f;
+const SUM_MASS: u32 = 0u;
+const SUM_MOMENTUM_X: u32 = 1u;
+const SUM_MOMENTUM_Y: u32 = 2u;
+
+@group(0) @binding(0) 
+var<uniform> parameters: AccretionParameters;
+@group(0) @binding(1) 
+var<storage, read_write> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> integrated_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage, read_write> integrated_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage, read_write> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(6) 
+var<storage, read_write> blackhole_set: BlackHoleSetX_naga_oil_mod_XMJWGCY3LNBXWYZLTX;
+@group(0) @binding(7) 
+var<storage, read_write> accretion: array<Accretion>;
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_2: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_2.x + ((gid_2.y * 65535u) * workgroup_size));
+}
+
+fn add_to_sum(blackhole_1: u32, sum: u32, value: f32) {
+    var low: u32;
+    var high: u32;
+
+    let magnitude = abs(round(value));
+    let high_magnitude = floor((magnitude / WORD_SCALE));
+    low = u32((magnitude - (high_magnitude * WORD_SCALE)));
+    high = u32(high_magnitude);
+    if (value < 0f) {
+        let _e15 = high;
+        let _e17 = low;
+        high = (~(_e15) + select(0u, 1u, (_e17 == 0u)));
+        let _e25 = low;
+        low = (0u - _e25);
+    }
+    let _e34 = low;
+    let _e35 = atomicAdd((&accretion[blackhole_1].sums[sum].low), _e34);
+    let _e38 = low;
+    let carry = select(0u, 1u, ((_e35 + _e38) < _e35));
+    let _e47 = high;
+    let _e49 = atomicAdd((&accretion[blackhole_1].sums[sum].high), (_e47 + carry));
+    return;
+}
+
+fn sum_value(blackhole_2: u32, sum_1: u32) -> f32 {
+    let low_1 = atomicLoad((&accretion[blackhole_2].sums[sum_1].low));
+    let _e13 = atomicLoad((&accretion[blackhole_2].sums[sum_1].high));
+    let high_1 = bitcast<i32>(_e13);
+    return ((f32(high_1) * WORD_SCALE) + f32(low_1));
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn consume(@builtin(global_invocation_id) gid: vec3<u32>) {
+    var bh_index: u32 = 0u;
+
+    let _e3 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (false || (_e3 >= arrayLength((&flags)))) {
+        return;
+    }
+    let f = flags[_e3].inner;
+    if ((f & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+        return;
+    }
+    let aabb = integrated_aabbs[_e3];
+    let position = ((aabb.min + aabb.max) / vec2(2f));
+    let half_extents = ((aabb.max - aabb.min) / vec2(2f));
+    loop {
+        let _e33 = bh_index;
+        if (_e33 < BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) {
+        } else {
+            break;
+        }
+        {
+            let _e38 = bh_index;
+            let blackhole_3 = blackhole_set.blackholes[_e38];
+            let distance = (length((blackhole_3.position - position)) - max(half_extents.x, half_extents.y));
+            if (distance < (blackhole_3.radius * BLACKHOLE_SIZE_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX)) {
+                let mass = masses[_e3].inner;
+                let _e59 = integrated_velocities[_e3].inner;
+                let momentum = (mass * _e59);
+                let _e61 = bh_index;
+                add_to_sum(_e61, SUM_MASS, (mass * MASS_SCALE));
+                let _e65 = bh_index;
+                add_to_sum(_e65, SUM_MOMENTUM_X, (momentum.x * MOMENTUM_SCALE));
+                let _e70 = bh_index;
+                add_to_sum(_e70, SUM_MOMENTUM_Y, (momentum.y * MOMENTUM_SCALE));
+                let _e76 = bh_index;
+                let _e80 = atomicAdd((&accretion[_e76].consumed), 1u);
+                flags[_e3].inner = ((f & 4294967288u) | FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX);
+                integrated_velocities[_e3].inner = vec2<f32>();
+                angular_velocities[_e3].inner = 0f;
+                return;
+            }
+        }
+        continuing {
+            let _e97 = bh_index;
+            bh_index = (_e97 + 1u);
+        }
+    }
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn accrete(@builtin(global_invocation_id) gid_1: vec3<u32>) {
+    var blackhole: BlackHoleX_naga_oil_mod_XMJWGCY3LNBXWYZLTX;
+    var k: u32 = 0u;
+
+    let _e3 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1, WORKGROUP_SIZE);
+    if (_e3 >= BLACKHOLE_COUNTX_naga_oil_mod_XMJWGCY3LNBXWYZLTX) {
+        return;
+    }
+    let _e9 = blackhole_set.blackholes[_e3];
+    blackhole = _e9;
+    let _e13 = parameters.accrete;
+    if (_e13 == 0u) {
+        loop {
+            let _e17 = k;
+            if (_e17 < 3u) {
+            } else {
+                break;
+            }
+            {
+                let _e23 = k;
+                atomicStore((&accretion[_e3].sums[_e23].low), 0u);
+                let _e30 = k;
+                atomicStore((&accretion[_e3].sums[_e30].high), 0u);
+            }
+            continuing {
+                let _e35 = k;
+                k = (_e35 + 1u);
+            }
+        }
+    } else {
+        let _e38 = sum_value(_e3, SUM_MASS);
+        let accreted_mass = (_e38 / MASS_SCALE);
+        let _e42 = sum_value(_e3, SUM_MOMENTUM_X);
+        let _e44 = sum_value(_e3, SUM_MOMENTUM_Y);
+        let momentum_1 = (vec2<f32>(_e42, _e44) / vec2(65536f));
+        let _e50 = blackhole.mass;
+        let mass_1 = (_e50 * BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX);
+        blackhole.mass = ((mass_1 + accreted_mass) / BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX);
+        let _e58 = blackhole.mass;
+        let grown = (_e58 * BLACKHOLE_MASS_SCALEX_naga_oil_mod_XMJWGCY3LNBXWYZLTX);
+        if ((grown != mass_1) || any((momentum_1 != vec2<f32>()))) {
+            let _e67 = blackhole.velocity;
+            let previous_momentum = (_e67 * mass_1);
+            blackhole.velocity = ((previous_momentum + momentum_1) / vec2(grown));
+            let _e75 = blackhole.radius;
+            blackhole.radius = (_e75 * (grown / mass_1));
+            let _e78 = blackhole.velocity;
+            let taken_momentum = ((_e78 * grown) - previous_momentum);
+            add_to_sum(_e3, SUM_MASS, (-((grown - mass_1)) * MASS_SCALE));
+            add_to_sum(_e3, SUM_MOMENTUM_X, (-(taken_momentum.x) * MOMENTUM_SCALE));
+            add_to_sum(_e3, SUM_MOMENTUM_Y, (-(taken_momentum.y) * MOMENTUM_SCALE));
+        }
+    }
+    let _e98 = blackhole.velocity;
+    let _e101 = parameters.dt;
+    let _e103 = blackhole.position;
+    blackhole.position = (_e103 + (_e98 * _e101));
+    let _e108 = blackhole;
+    blackhole_set.blackholes[_e3] = _e108;
+    return;
+}
+"#;
+}
+pub mod materials {
+    use super::{_root, _root::*};
+    pub const MAX_MATERIALS: u32 = 16u32;
+    pub const MATERIAL_INERT: u32 = 0u32;
+    pub const IMPACT_NONE: u32 = 4294967295u32;
+    pub const IMPACT_SHATTER: u32 = 4294967294u32;
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Material {
+        #[doc = "offset: 0, size: 4, type: `f32`"]
+        pub merge_speed: f32,
+        #[doc = "offset: 4, size: 4, type: `f32`"]
+        pub shatter_speed: f32,
+        #[doc = "offset: 8, size: 4, type: `u32`"]
+        pub fragment_count: u32,
+        #[doc = "offset: 12, size: 4, type: `f32`"]
+        pub spread: f32,
+    }
+    impl Material {
+        pub const fn new(merge_speed: f32, shatter_speed: f32, fragment_count: u32, spread: f32) -> Self {
+            Self {
+                merge_speed,
+                shatter_speed,
+                fragment_count,
+                spread,
+            }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct MaterialTable {
+        #[doc = "offset: 0, size: 256, type: `array<materials::Material, 16>`"]
+        pub materials: [_root::materials::Material; 16],
+    }
+    impl MaterialTable {
+        pub const fn new(materials: [_root::materials::Material; 16]) -> Self {
+            Self { materials }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct MaterialId {
+        #[doc = "offset: 0, size: 4, type: `u32`"]
+        pub inner: u32,
+    }
+    impl MaterialId {
+        pub const fn new(inner: u32) -> Self {
+            Self { inner }
+        }
+    }
+    #[repr(C, align(4))]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub struct Impact {
+        #[doc = "offset: 0, size: 4, type: `u32`"]
+        pub partner: u32,
+        #[doc = "offset: 4, size: 4, type: `f32`"]
+        pub speed: f32,
+    }
+    impl Impact {
+        pub const fn new(partner: u32, speed: f32) -> Self {
+            Self { partner, speed }
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 0]) -> [wgpu::BindGroupLayout; 0] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Materials::PipelineLayout"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("materials.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct Material {
+    merge_speed: f32,
+    shatter_speed: f32,
+    fragment_count: u32,
+    spread: f32,
+}
+
+struct MaterialTable {
+    materials: array<Material, 16>,
+}
+
+struct MaterialId {
+    inner: u32,
+}
+
+struct Impact {
+    partner: u32,
+    speed: f32,
+}
+
+const MAX_MATERIALS: u32 = 16u;
+const MATERIAL_INERT: u32 = 0u;
+const IMPACT_NONE: u32 = 4294967295u;
+const IMPACT_SHATTER: u32 = 4294967294u;
+
+"#;
+}
+pub mod impacts {
+    use super::{_root, _root::*};
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const FIND_IMPACTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_find_impacts_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline find_impacts"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("find_impacts"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const SCAN_WORKGROUPS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_scan_workgroups_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline scan_workgroups"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("scan_workgroups"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const SCAN_TOTALS_WORKGROUP_SIZE: [u32; 3] = [1, 1, 1];
+        pub fn create_scan_totals_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline scan_totals"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("scan_totals"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const ADD_OFFSETS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_add_offsets_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline add_offsets"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("add_offsets"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_FIND_IMPACTS: &str = "find_impacts";
+    pub const ENTRY_SCAN_WORKGROUPS: &str = "scan_workgroups";
+    pub const ENTRY_SCAN_TOTALS: &str = "scan_totals";
+    pub const ENTRY_ADD_OFFSETS: &str = "add_offsets";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub material_table: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub shapes: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub nodes: wgpu::BufferBinding<'a>,
+        pub velocities: wgpu::BufferBinding<'a>,
+        pub materials: wgpu::BufferBinding<'a>,
+        pub linked: wgpu::BufferBinding<'a>,
+        pub impacts: wgpu::BufferBinding<'a>,
+        pub prefixes: wgpu::BufferBinding<'a>,
+        pub workgroup_totals: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub material_table: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub shapes: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub nodes: wgpu::BindGroupEntry<'a>,
+        pub velocities: wgpu::BindGroupEntry<'a>,
+        pub materials: wgpu::BindGroupEntry<'a>,
+        pub linked: wgpu::BindGroupEntry<'a>,
+        pub impacts: wgpu::BindGroupEntry<'a>,
+        pub prefixes: wgpu::BindGroupEntry<'a>,
+        pub workgroup_totals: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                material_table: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.material_table),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                shapes: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.shapes),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                nodes: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.nodes),
+                },
+                velocities: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.velocities),
+                },
+                materials: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.materials),
+                },
+                linked: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.linked),
+                },
+                impacts: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.impacts),
+                },
+                prefixes: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.prefixes),
+                },
+                workgroup_totals: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.workgroup_totals),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 11] {
+            [
+                self.material_table,
+                self.flags,
+                self.shapes,
+                self.aabbs,
+                self.nodes,
+                self.velocities,
+                self.materials,
+                self.linked,
+                self.impacts,
+                self.prefixes,
+                self.workgroup_totals,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Impacts::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"material_table\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::materials::MaterialTable>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"shapes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"materials\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"linked\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"impacts\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"prefixes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"workgroup_totals\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Impacts::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Impacts::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("impacts.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct MaterialX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    merge_speed: f32,
+    shatter_speed: f32,
+    fragment_count: u32,
+    spread: f32,
+}
+
+struct MaterialTableX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    materials: array<MaterialX_naga_oil_mod_XNVQXIZLSNFQWY4YX, 16>,
+}
+
+struct MaterialIdX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    inner: u32,
+}
+
+struct ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    partner: u32,
+    speed: f32,
+}
+
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct BvhNodeX_naga_oil_mod_XMNXW23LPNYX {
+    index: u32,
+}
+
+struct BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    stack: array<u32, 31>,
+    size: u32,
+}
+
+const MATERIAL_INERTX_naga_oil_mod_XNVQXIZLSNFQWY4YX: u32 = 0u;
+const IMPACT_NONEX_naga_oil_mod_XNVQXIZLSNFQWY4YX: u32 = 4294967295u;
+const IMPACT_SHATTERX_naga_oil_mod_XNVQXIZLSNFQWY4YX: u32 = 4294967294u;
+const FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX: u32 = 8u;
+const SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX: u32 = 2147483648u;
+const WORKGROUP_SIZE: u32 = 64u;
+
+@group(0) @binding(0) 
+var<uniform> material_table: MaterialTableX_naga_oil_mod_XNVQXIZLSNFQWY4YX;
+@group(0) @binding(1) 
+var<storage> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage> shapes: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage> nodes: array<BvhNodeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage> velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(6) 
+var<storage> materials: array<MaterialIdX_naga_oil_mod_XNVQXIZLSNFQWY4YX>;
+@group(0) @binding(7) 
+var<storage> linked: array<u32>;
+@group(0) @binding(8) 
+var<storage, read_write> impacts: array<ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX>;
+@group(0) @binding(9) 
+var<storage, read_write> prefixes: array<vec2<u32>>;
+@group(0) @binding(10) 
+var<storage, read_write> workgroup_totals: array<vec2<u32>>;
+var<workgroup> scratch: array<vec2<u32>, 64>;
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_3: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_3.x + ((gid_3.y * 65535u) * workgroup_size));
+}
+
+fn bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(leaf_count: u32) -> BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX {
+    var traversal: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    traversal.stack[0] = ((2u * leaf_count) - 2u);
+    traversal.size = 1u;
+    let _e10 = traversal;
+    return _e10;
+}
+
+fn bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_1: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>) -> u32 {
+    let _e3 = (*traversal_1).size;
+    (*traversal_1).size = (_e3 - 1u);
+    let _e7 = (*traversal_1).size;
+    let _e9 = (*traversal_1).stack[_e7];
+    return _e9;
+}
+
+fn bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(traversal_2: ptr<function, BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX>, node: u32) -> bool {
+    if ((node & BVH_NODE_TREE_FLAGX_naga_oil_mod_XMNXW23LPNYX) == 0u) {
+        return false;
+    }
+    let left = (node & 2147483647u);
+    let size = (*traversal_2).size;
+    (*traversal_2).stack[size] = left;
+    (*traversal_2).stack[(size + 1u)] = (left + 1u);
+    (*traversal_2).size = (size + 2u);
+    return true;
+}
+
+fn aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(a: AABBX_naga_oil_mod_XMNXW23LPNYX, b: AABBX_naga_oil_mod_XMNXW23LPNYX) -> bool {
+    return (all((a.min <= b.max)) && all((b.min <= a.max)));
+}
+
+fn hits(i: u32) -> bool {
+    let _e4 = flags[i].inner;
+    let _e12 = shapes[i].inner;
+    return (((_e4 & 12u) == FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) && (_e12 == SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX));
+}
+
+fn center(aabb: AABBX_naga_oil_mod_XMNXW23LPNYX) -> vec2<f32> {
+    return ((aabb.min + aabb.max) / vec2(2f));
+}
+
+fn circles_touch(a_1: AABBX_naga_oil_mod_XMNXW23LPNYX, b_1: AABBX_naga_oil_mod_XMNXW23LPNYX) -> bool {
+    let radii = ((((a_1.max.x - a_1.min.x) + b_1.max.x) - b_1.min.x) / 2f);
+    let _e15 = center(a_1);
+    let _e16 = center(b_1);
+    return (length((_e15 - _e16)) <= radii);
+}
+
+fn find_impact(i_1: u32) -> ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    var impact_1: ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX = ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX(4294967295u, 0f);
+    var traversal_3: BvhTraversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX;
+
+    let _e9 = materials[i_1].inner;
+    let material = material_table.materials[_e9];
+    let aabb_1 = aabbs[i_1];
+    let _e17 = bvh_traversalX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(arrayLength((&flags)));
+    traversal_3 = _e17;
+    loop {
+        let _e20 = traversal_3.size;
+        if (_e20 > 0u) {
+        } else {
+            break;
+        }
+        {
+            let _e23 = bvh_popX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3));
+            let _e26 = aabbs[_e23];
+            let _e27 = aabbs_overlapX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX(aabb_1, _e26);
+            if !(_e27) {
+                continue;
+            }
+            let node_1 = nodes[_e23].index;
+            let _e33 = bvh_descendX_naga_oil_mod_XMJ3GQX3UOJQXMZLSONQWYX((&traversal_3), node_1);
+            if _e33 {
+                continue;
+            }
+            let _e35 = hits(node_1);
+            let _e40 = aabbs[node_1];
+            let _e41 = circles_touch(aabb_1, _e40);
+            if (((node_1 == i_1) || !(_e35)) || !(_e41)) {
+                continue;
+            }
+            let _e47 = velocities[i_1].inner;
+            let _e51 = velocities[node_1].inner;
+            let relative = (_e47 - _e51);
+            let _e55 = aabbs[node_1];
+            let _e56 = center(_e55);
+            let _e57 = center(aabb_1);
+            if (dot(relative, (_e56 - _e57)) <= 0f) {
+                continue;
+            }
+            let speed = length(relative);
+            let _e69 = materials[node_1].inner;
+            let _e72 = material_table.materials[_e69].merge_speed;
+            let merge_speed = max(material.merge_speed, _e72);
+            if (speed > material.shatter_speed) {
+                let _e79 = impact_1.speed;
+                impact_1 = ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX(IMPACT_SHATTERX_naga_oil_mod_XNVQXIZLSNFQWY4YX, max(_e79, speed));
+            } else {
+                let _e84 = impact_1.partner;
+                let _e89 = impact_1.partner;
+                if (((speed > merge_speed) && (_e84 != IMPACT_SHATTERX_naga_oil_mod_XNVQXIZLSNFQWY4YX)) && (node_1 < _e89)) {
+                    impact_1.partner = node_1;
+                }
+            }
+        }
+    }
+    let _e93 = impact_1;
+    return _e93;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn find_impacts(@builtin(global_invocation_id) gid: vec3<u32>) {
+    var impact: ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX = ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX(4294967295u, 0f);
+    var fragment_slots: u32 = 0u;
+
+    let _e6 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (_e6 >= arrayLength((&flags))) {
+        return;
+    }
+    let _e13 = flags[_e6].inner;
+    let _e20 = linked[_e6];
+    let free = (((_e13 & 5u) == 0u) && (_e20 == 0u));
+    let _e24 = hits(_e6);
+    let _e28 = materials[_e6].inner;
+    if (_e24 && (_e28 != MATERIAL_INERTX_naga_oil_mod_XNVQXIZLSNFQWY4YX)) {
+        let _e32 = find_impact(_e6);
+        impact = _e32;
+    }
+    let _e36 = impact;
+    impacts[_e6] = _e36;
+    let _e38 = impact.partner;
+    if (_e38 == IMPACT_SHATTERX_naga_oil_mod_XNVQXIZLSNFQWY4YX) {
+        let _e46 = materials[_e6].inner;
+        let _e49 = material_table.materials[_e46].fragment_count;
+        fragment_slots = (max(_e49, 1u) - 1u);
+    }
+    let _e58 = fragment_slots;
+    prefixes[_e6] = vec2<u32>(u32(free), _e58);
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn scan_workgroups(@builtin(global_invocation_id) gid_1: vec3<u32>, @builtin(local_invocation_index) local_index: u32) {
+    var value: vec2<u32> = vec2<u32>();
+    var sum: vec2<u32> = vec2<u32>();
+    var k: u32 = 0u;
+
+    let _e4 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1, WORKGROUP_SIZE);
+    let object_count = arrayLength((&flags));
+    if (_e4 < object_count) {
+        let _e10 = prefixes[_e4];
+        value = _e10;
+    }
+    let _e15 = value;
+    scratch[local_index] = _e15;
+    workgroupBarrier();
+    loop {
+        let _e17 = k;
+        if (_e17 < local_index) {
+        } else {
+            break;
+        }
+        {
+            let _e21 = k;
+            let _e23 = scratch[_e21];
+            let _e24 = sum;
+            sum = (_e24 + _e23);
+        }
+        continuing {
+            let _e27 = k;
+            k = (_e27 + 1u);
+        }
+    }
+    if (_e4 < object_count) {
+        let _e32 = sum;
+        prefixes[_e4] = _e32;
+    }
+    let workgroup_index = (_e4 / WORKGROUP_SIZE);
+    if ((local_index == 63u) && (workgroup_index < (arrayLength((&workgroup_totals)) - 1u))) {
+        let _e45 = sum;
+        let _e46 = value;
+        workgroup_totals[workgroup_index] = (_e45 + _e46);
+        return;
+    } else {
+        return;
+    }
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn scan_totals() {
+    var sum_1: vec2<u32> = vec2<u32>();
+    var k_1: u32 = 0u;
+
+    let workgroup_count = (arrayLength((&workgroup_totals)) - 1u);
+    loop {
+        let _e7 = k_1;
+        if (_e7 < workgroup_count) {
+        } else {
+            break;
+        }
+        {
+            let _e10 = k_1;
+            let total = workgroup_totals[_e10];
+            let _e15 = k_1;
+            let _e17 = sum_1;
+            workgroup_totals[_e15] = _e17;
+            let _e18 = sum_1;
+            sum_1 = (_e18 + total);
+        }
+        continuing {
+            let _e21 = k_1;
+            k_1 = (_e21 + 1u);
+        }
+    }
+    let _e25 = sum_1;
+    workgroup_totals[workgroup_count] = _e25;
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn add_offsets(@builtin(global_invocation_id) gid_2: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_2, WORKGROUP_SIZE);
+    let object_count_1 = arrayLength((&flags));
+    if (_e2 >= object_count_1) {
+        return;
+    }
+    let _e12 = workgroup_totals[(_e2 / WORKGROUP_SIZE)];
+    let _e13 = prefixes[_e2];
+    prefixes[_e2] = (_e13 + _e12);
+    if (_e2 == (object_count_1 - 1u)) {
+        let _e26 = workgroup_totals[(arrayLength((&workgroup_totals)) - 1u)];
+        prefixes[object_count_1] = _e26;
+        return;
+    } else {
+        return;
+    }
+}
+"#;
+}
+pub mod fracture {
+    use super::{_root, _root::*};
+    pub const WORKGROUP_SIZE: u32 = 64u32;
+    pub const TAU: f32 = 6.2831855f32;
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const LIST_FREE_SLOTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_list_free_slots_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline list_free_slots"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("list_free_slots"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const RESOLVE_IMPACTS_WORKGROUP_SIZE: [u32; 3] = [64, 1, 1];
+        pub fn create_resolve_impacts_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline resolve_impacts"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("resolve_impacts"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_LIST_FREE_SLOTS: &str = "list_free_slots";
+    pub const ENTRY_RESOLVE_IMPACTS: &str = "resolve_impacts";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub material_table: wgpu::BufferBinding<'a>,
+        pub flags: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub sizes: wgpu::BufferBinding<'a>,
+        pub shapes: wgpu::BufferBinding<'a>,
+        pub colors: wgpu::BufferBinding<'a>,
+        pub moments: wgpu::BufferBinding<'a>,
+        pub materials: wgpu::BufferBinding<'a>,
+        pub integrated_aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_velocities: wgpu::BufferBinding<'a>,
+        pub integrated_angles: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub impacts: wgpu::BufferBinding<'a>,
+        pub prefixes: wgpu::BufferBinding<'a>,
+        pub free_slots: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub material_table: wgpu::BindGroupEntry<'a>,
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub sizes: wgpu::BindGroupEntry<'a>,
+        pub shapes: wgpu::BindGroupEntry<'a>,
+        pub colors: wgpu::BindGroupEntry<'a>,
+        pub moments: wgpu::BindGroupEntry<'a>,
+        pub materials: wgpu::BindGroupEntry<'a>,
+        pub integrated_aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_velocities: wgpu::BindGroupEntry<'a>,
+        pub integrated_angles: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub impacts: wgpu::BindGroupEntry<'a>,
+        pub prefixes: wgpu::BindGroupEntry<'a>,
+        pub free_slots: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                material_table: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.material_table),
+                },
+                flags: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                masses: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                sizes: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.sizes),
+                },
+                shapes: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.shapes),
+                },
+                colors: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.colors),
+                },
+                moments: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.moments),
+                },
+                materials: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.materials),
+                },
+                integrated_aabbs: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_aabbs),
+                },
+                integrated_velocities: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_velocities),
+                },
+                integrated_angles: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_angles),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 11,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                impacts: wgpu::BindGroupEntry {
+                    binding: 12,
+                    resource: wgpu::BindingResource::Buffer(params.impacts),
+                },
+                prefixes: wgpu::BindGroupEntry {
+                    binding: 13,
+                    resource: wgpu::BindingResource::Buffer(params.prefixes),
+                },
+                free_slots: wgpu::BindGroupEntry {
+                    binding: 14,
+                    resource: wgpu::BindingResource::Buffer(params.free_slots),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 15,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 16] {
+            [
+                self.material_table,
+                self.flags,
+                self.masses,
+                self.sizes,
+                self.shapes,
+                self.colors,
+                self.moments,
+                self.materials,
+                self.integrated_aabbs,
+                self.integrated_velocities,
+                self.integrated_angles,
+                self.angular_velocities,
+                self.impacts,
+                self.prefixes,
+                self.free_slots,
+                self.aabbs,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Fracture::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"material_table\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::materials::MaterialTable>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"sizes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"shapes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"colors\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"moments\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"materials\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"integrated_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"integrated_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"integrated_angles\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(11): \"angular_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 11,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(12): \"impacts\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 12,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(13): \"prefixes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 13,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(14): \"free_slots\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 14,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(15): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 15,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Fracture::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Fracture::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("fracture.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct MaterialX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    merge_speed: f32,
+    shatter_speed: f32,
+    fragment_count: u32,
+    spread: f32,
+}
+
+struct MaterialTableX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    materials: array<MaterialX_naga_oil_mod_XNVQXIZLSNFQWY4YX, 16>,
+}
+
+struct MaterialIdX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    inner: u32,
+}
+
+struct ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    partner: u32,
+    speed: f32,
+}
+
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct ColorX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec4<f32>,
+}
+
+struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngleX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct SizeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct SecondMomentsX_naga_oil_mod_XMNXW23LPNYX {
+    xx: f32,
+    yy: f32,
+    xy: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+const IMPACT_NONEX_naga_oil_mod_XNVQXIZLSNFQWY4YX: u32 = 4294967295u;
+const IMPACT_SHATTERX_naga_oil_mod_XNVQXIZLSNFQWY4YX: u32 = 4294967294u;
+const FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const FLAG_DRAW_AABBX_naga_oil_mod_XMNXW23LPNYX: u32 = 2u;
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX: u32 = 8u;
+const FLAG_SLEEPINGX_naga_oil_mod_XMNXW23LPNYX: u32 = 32u;
+const SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const WORKGROUP_SIZE: u32 = 64u;
+const TAU: f32 = 6.2831855f;
+
+@group(0) @binding(0) 
+var<uniform> material_table: MaterialTableX_naga_oil_mod_XNVQXIZLSNFQWY4YX;
+@group(0) @binding(1) 
+var<storage, read_write> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage, read_write> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage, read_write> sizes: array<SizeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage, read_write> shapes: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage, read_write> colors: array<ColorX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(6) 
+var<storage, read_write> moments: array<SecondMomentsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(7) 
+var<storage, read_write> materials: array<MaterialIdX_naga_oil_mod_XNVQXIZLSNFQWY4YX>;
+@group(0) @binding(8) 
+var<storage, read_write> integrated_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(9) 
+var<storage, read_write> integrated_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(10) 
+var<storage, read_write> integrated_angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(11) 
+var<storage, read_write> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(12) 
+var<storage> impacts: array<ImpactX_naga_oil_mod_XNVQXIZLSNFQWY4YX>;
+@group(0) @binding(13) 
+var<storage> prefixes: array<vec2<u32>>;
+@group(0) @binding(14) 
+var<storage, read_write> free_slots: array<u32>;
+@group(0) @binding(15) 
+var<storage, read_write> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+
+fn invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_2: vec3<u32>, workgroup_size: u32) -> u32 {
+    return (gid_2.x + ((gid_2.y * 65535u) * workgroup_size));
+}
+
+fn center(aabb: AABBX_naga_oil_mod_XMNXW23LPNYX) -> vec2<f32> {
+    return ((aabb.min + aabb.max) / vec2(2f));
+}
+
+fn circle_moments(radius: f32) -> SecondMomentsX_naga_oil_mod_XMNXW23LPNYX {
+    return SecondMomentsX_naga_oil_mod_XMNXW23LPNYX(((radius * radius) / 4f), ((radius * radius) / 4f), 0f);
+}
+
+fn shatter(i: u32, speed: f32) {
+    var k: u32 = 0u;
+    var slot: u32;
+
+    let _e7 = materials[i].inner;
+    let material = material_table.materials[_e7];
+    let object_count = arrayLength((&flags));
+    let first_slot = prefixes[i].y;
+    let free_count = prefixes[object_count].x;
+    let available = select(0u, (free_count - first_slot), (free_count > first_slot));
+    let fragment_count = (1u + min((max(material.fragment_count, 1u) - 1u), available));
+    if (fragment_count < 2u) {
+        return;
+    }
+    let _e37 = flags[i].inner;
+    let f = (_e37 & 4294967263u);
+    let _e43 = masses[i].inner;
+    let mass = (_e43 / f32(fragment_count));
+    let _e48 = integrated_aabbs[i];
+    let _e49 = center(_e48);
+    let _e52 = aabbs[i];
+    let _e53 = center(_e52);
+    let velocity = integrated_velocities[i].inner;
+    let angle = integrated_angles[i].inner;
+    let angular_velocity = angular_velocities[i].inner;
+    let _e70 = sizes[i].inner.x;
+    let radius_1 = (_e70 / 2f);
+    let fragment_radius = (radius_1 / sqrt(f32(fragment_count)));
+    let color = colors[i].inner;
+    let material_id = materials[i].inner;
+    loop {
+        let _e85 = k;
+        if (_e85 < fragment_count) {
+        } else {
+            break;
+        }
+        {
+            slot = i;
+            let _e88 = k;
+            if (_e88 > 0u) {
+                let _e91 = k;
+                let _e97 = free_slots[((first_slot + _e91) - 1u)];
+                slot = _e97;
+            }
+            let _e99 = k;
+            let direction_angle = (angle + ((TAU * f32(_e99)) / f32(fragment_count)));
+            let direction = vec2<f32>(cos(direction_angle), sin(direction_angle));
+            let offset = (direction * (radius_1 - fragment_radius));
+            let fragment_position = (_e49 + offset);
+            let _e112 = slot;
+            flags[_e112].inner = f;
+            let _e116 = slot;
+            masses[_e116].inner = mass;
+            let _e120 = slot;
+            sizes[_e120].inner = vec2((fragment_radius * 2f));
+            let _e127 = slot;
+            shapes[_e127].inner = SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX;
+            let _e132 = slot;
+            let _e134 = circle_moments(fragment_radius);
+            moments[_e132] = _e134;
+            let _e136 = slot;
+            colors[_e136].inner = color;
+            let _e140 = slot;
+            materials[_e140].inner = material_id;
+            let _e144 = slot;
+            integrated_aabbs[_e144] = AABBX_naga_oil_mod_XMNXW23LPNYX((fragment_position - vec2(fragment_radius)), (fragment_position + vec2(fragment_radius)));
+            let _e152 = slot;
+            aabbs[_e152] = AABBX_naga_oil_mod_XMNXW23LPNYX(((_e53 + offset) - vec2(fragment_radius)), ((_e53 + offset) + vec2(fragment_radius)));
+            let _e163 = slot;
+            integrated_velocities[_e163].inner = ((velocity + ((direction * material.spread) * speed)) + (angular_velocity * vec2<f32>(-(offset.y), offset.x)));
+            let _e177 = slot;
+            integrated_angles[_e177].inner = angle;
+            let _e181 = slot;
+            angular_velocities[_e181].inner = angular_velocity;
+        }
+        continuing {
+            let _e185 = k;
+            k = (_e185 + 1u);
+        }
+    }
+    return;
+}
+
+fn cross(a: vec2<f32>, b: vec2<f32>) -> f32 {
+    return ((a.x * b.y) - (a.y * b.x));
+}
+
+fn merge(i_1: u32, j: u32) {
+    let mi = masses[i_1].inner;
+    let mj = masses[j].inner;
+    let mass_1 = (mi + mj);
+    let _e13 = integrated_aabbs[i_1];
+    let _e14 = center(_e13);
+    let _e17 = integrated_aabbs[j];
+    let _e18 = center(_e17);
+    let vi = integrated_velocities[i_1].inner;
+    let vj = integrated_velocities[j].inner;
+    let position = (((_e14 * mi) + (_e18 * mj)) / vec2(mass_1));
+    let velocity_1 = (((vi * mi) + (vj * mj)) / vec2(mass_1));
+    let _e41 = sizes[i_1].inner.x;
+    let ri = (_e41 / 2f);
+    let _e48 = sizes[j].inner.x;
+    let rj = (_e48 / 2f);
+    let radius_2 = sqrt(((ri * ri) + (rj * rj)));
+    let _e62 = angular_velocities[i_1].inner;
+    let _e71 = angular_velocities[j].inner;
+    let _e76 = cross((_e14 - position), (vi - velocity_1));
+    let _e81 = cross((_e18 - position), (vj - velocity_1));
+    let angular_momentum = (((((((mi * ri) * ri) / 2f) * _e62) + ((((mj * rj) * rj) / 2f) * _e71)) + (mi * _e76)) + (mj * _e81));
+    let _e88 = flags[i_1].inner;
+    flags[i_1].inner = (_e88 & 4294967263u);
+    masses[i_1].inner = mass_1;
+    sizes[i_1].inner = vec2((radius_2 * 2f));
+    let _e101 = circle_moments(radius_2);
+    moments[i_1] = _e101;
+    let _e108 = colors[i_1].inner;
+    let _e113 = colors[j].inner;
+    colors[i_1].inner = (((_e108 * mi) + (_e113 * mj)) / vec4(mass_1));
+    integrated_aabbs[i_1] = AABBX_naga_oil_mod_XMNXW23LPNYX((position - vec2(radius_2)), (position + vec2(radius_2)));
+    integrated_velocities[i_1].inner = velocity_1;
+    angular_velocities[i_1].inner = (angular_momentum / (((mass_1 * radius_2) * radius_2) / 2f));
+    let _e142 = flags[j].inner;
+    flags[j].inner = ((_e142 & 4294967288u) | FLAG_DESTROYEDX_naga_oil_mod_XMNXW23LPNYX);
+    integrated_velocities[j].inner = vec2<f32>();
+    angular_velocities[j].inner = 0f;
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn list_free_slots(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid, WORKGROUP_SIZE);
+    if (_e2 >= arrayLength((&flags))) {
+        return;
+    }
+    let free_index = prefixes[_e2].x;
+    let _e15 = prefixes[(_e2 + 1u)].x;
+    if (_e15 > free_index) {
+        free_slots[free_index] = _e2;
+        return;
+    } else {
+        return;
+    }
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn resolve_impacts(@builtin(global_invocation_id) gid_1: vec3<u32>) {
+    let _e2 = invocation_indexX_naga_oil_mod_XMNXW23LPNYX(gid_1, WORKGROUP_SIZE);
+    if (_e2 >= arrayLength((&flags))) {
+        return;
+    }
+    let impact = impacts[_e2];
+    if (impact.partner == IMPACT_SHATTERX_naga_oil_mod_XNVQXIZLSNFQWY4YX) {
+        shatter(_e2, impact.speed);
+        return;
+    } else {
+        let _e23 = impacts[impact.partner].partner;
+        if (((impact.partner != IMPACT_NONEX_naga_oil_mod_XNVQXIZLSNFQWY4YX) && (_e2 < impact.partner)) && (_e23 == _e2)) {
+            merge(_e2, impact.partner);
+            return;
+        } else {
+            return;
+        }
+    }
+}
+"#;
+}
+pub mod spawn {
+    use super::{_root, _root::*};
+    pub mod compute {
+        use super::{_root, _root::*};
+        pub const SPAWN_CIRCLE_WORKGROUP_SIZE: [u32; 3] = [1, 1, 1];
+        pub fn create_spawn_circle_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline spawn_circle"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("spawn_circle"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+        pub const REFIT_FILLED_SLOTS_WORKGROUP_SIZE: [u32; 3] = [1, 1, 1];
+        pub fn create_refit_filled_slots_pipeline_embed_source(device: &wgpu::Device) -> wgpu::ComputePipeline {
+            let module = super::create_shader_module_embed_source(device);
+            let layout = super::create_pipeline_layout(device);
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Compute Pipeline refit_filled_slots"),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some("refit_filled_slots"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        }
+    }
+    pub const ENTRY_SPAWN_CIRCLE: &str = "spawn_circle";
+    pub const ENTRY_REFIT_FILLED_SLOTS: &str = "refit_filled_slots";
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0EntriesParams<'a> {
+        pub flags: wgpu::BufferBinding<'a>,
+        pub masses: wgpu::BufferBinding<'a>,
+        pub sizes: wgpu::BufferBinding<'a>,
+        pub shapes: wgpu::BufferBinding<'a>,
+        pub colors: wgpu::BufferBinding<'a>,
+        pub moments: wgpu::BufferBinding<'a>,
+        pub materials: wgpu::BufferBinding<'a>,
+        pub aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_aabbs: wgpu::BufferBinding<'a>,
+        pub integrated_velocities: wgpu::BufferBinding<'a>,
+        pub integrated_angles: wgpu::BufferBinding<'a>,
+        pub angular_velocities: wgpu::BufferBinding<'a>,
+        pub sleeping_nodes: wgpu::BufferBinding<'a>,
+        pub prefixes: wgpu::BufferBinding<'a>,
+        pub free_slots: wgpu::BufferBinding<'a>,
+        pub spawn_request: wgpu::BufferBinding<'a>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct WgpuBindGroup0Entries<'a> {
+        pub flags: wgpu::BindGroupEntry<'a>,
+        pub masses: wgpu::BindGroupEntry<'a>,
+        pub sizes: wgpu::BindGroupEntry<'a>,
+        pub shapes: wgpu::BindGroupEntry<'a>,
+        pub colors: wgpu::BindGroupEntry<'a>,
+        pub moments: wgpu::BindGroupEntry<'a>,
+        pub materials: wgpu::BindGroupEntry<'a>,
+        pub aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_aabbs: wgpu::BindGroupEntry<'a>,
+        pub integrated_velocities: wgpu::BindGroupEntry<'a>,
+        pub integrated_angles: wgpu::BindGroupEntry<'a>,
+        pub angular_velocities: wgpu::BindGroupEntry<'a>,
+        pub sleeping_nodes: wgpu::BindGroupEntry<'a>,
+        pub prefixes: wgpu::BindGroupEntry<'a>,
+        pub free_slots: wgpu::BindGroupEntry<'a>,
+        pub spawn_request: wgpu::BindGroupEntry<'a>,
+    }
+    impl<'a> WgpuBindGroup0Entries<'a> {
+        pub fn new(params: WgpuBindGroup0EntriesParams<'a>) -> Self {
+            Self {
+                flags: wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(params.flags),
+                },
+                masses: wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(params.masses),
+                },
+                sizes: wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(params.sizes),
+                },
+                shapes: wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Buffer(params.shapes),
+                },
+                colors: wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.colors),
+                },
+                moments: wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Buffer(params.moments),
+                },
+                materials: wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::Buffer(params.materials),
+                },
+                aabbs: wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: wgpu::BindingResource::Buffer(params.aabbs),
+                },
+                integrated_aabbs: wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_aabbs),
+                },
+                integrated_velocities: wgpu::BindGroupEntry {
+                    binding: 9,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_velocities),
+                },
+                integrated_angles: wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: wgpu::BindingResource::Buffer(params.integrated_angles),
+                },
+                angular_velocities: wgpu::BindGroupEntry {
+                    binding: 11,
+                    resource: wgpu::BindingResource::Buffer(params.angular_velocities),
+                },
+                sleeping_nodes: wgpu::BindGroupEntry {
+                    binding: 12,
+                    resource: wgpu::BindingResource::Buffer(params.sleeping_nodes),
+                },
+                prefixes: wgpu::BindGroupEntry {
+                    binding: 13,
+                    resource: wgpu::BindingResource::Buffer(params.prefixes),
+                },
+                free_slots: wgpu::BindGroupEntry {
+                    binding: 14,
+                    resource: wgpu::BindingResource::Buffer(params.free_slots),
+                },
+                spawn_request: wgpu::BindGroupEntry {
+                    binding: 15,
+                    resource: wgpu::BindingResource::Buffer(params.spawn_request),
+                },
+            }
+        }
+        pub fn into_array(self) -> [wgpu::BindGroupEntry<'a>; 16] {
+            [
+                self.flags,
+                self.masses,
+                self.sizes,
+                self.shapes,
+                self.colors,
+                self.moments,
+                self.materials,
+                self.aabbs,
+                self.integrated_aabbs,
+                self.integrated_velocities,
+                self.integrated_angles,
+                self.angular_velocities,
+                self.sleeping_nodes,
+                self.prefixes,
+                self.free_slots,
+                self.spawn_request,
+            ]
+        }
+        pub fn collect<B: FromIterator<wgpu::BindGroupEntry<'a>>>(self) -> B {
+            self.into_array().into_iter().collect()
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuBindGroup0(wgpu::BindGroup);
+    impl WgpuBindGroup0 {
+        pub const LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> = wgpu::BindGroupLayoutDescriptor {
+            label: Some("Spawn::BindGroup0::LayoutDescriptor"),
+            entries: &[
+                #[doc = " @binding(0): \"flags\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(1): \"masses\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(2): \"sizes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(3): \"shapes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(4): \"colors\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(5): \"moments\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(6): \"materials\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(7): \"aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(8): \"integrated_aabbs\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(9): \"integrated_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 9,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(10): \"integrated_angles\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 10,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(11): \"angular_velocities\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 11,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(12): \"sleeping_nodes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 12,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(13): \"prefixes\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 13,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(14): \"free_slots\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 14,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                #[doc = " @binding(15): \"spawn_request\""]
+                wgpu::BindGroupLayoutEntry {
+                    binding: 15,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: std::num::NonZeroU64::new(
+                            std::mem::size_of::<_root::tool_state::SpawnRequest>() as _,
+                        ),
+                    },
+                    count: None,
+                },
+            ],
+        };
+        pub fn get_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+            device.create_bind_group_layout(&Self::LAYOUT_DESCRIPTOR)
+        }
+        pub fn from_bindings(device: &wgpu::Device, bindings: WgpuBindGroup0Entries) -> Self {
+            let bind_group_layout = Self::get_bind_group_layout(device);
+            let entries = bindings.into_array();
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Spawn::BindGroup0"),
+                layout: &bind_group_layout,
+                entries: &entries,
+            });
+            Self(bind_group)
+        }
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            pass.set_bind_group(0, &self.0, &[]);
+        }
+    }
+    #[doc = " Bind groups can be set individually using their set(render_pass) method, or all at once using `WgpuBindGroups::set`."]
+    #[doc = " For optimal performance with many draw calls, it's recommended to organize bindings into bind groups based on update frequency:"]
+    #[doc = "   - Bind group 0: Least frequent updates (e.g. per frame resources)"]
+    #[doc = "   - Bind group 1: More frequent updates"]
+    #[doc = "   - Bind group 2: More frequent updates"]
+    #[doc = "   - Bind group 3: Most frequent updates (e.g. per draw resources)"]
+    #[derive(Debug, Copy, Clone)]
+    pub struct WgpuBindGroups<'a> {
+        pub bind_group0: &'a WgpuBindGroup0,
+    }
+    impl<'a> WgpuBindGroups<'a> {
+        pub fn set(&self, pass: &mut impl SetBindGroup) {
+            self.bind_group0.set(pass);
+        }
+    }
+    #[derive(Debug)]
+    pub struct WgpuPipelineLayout;
+    impl WgpuPipelineLayout {
+        pub fn bind_group_layout_entries(entries: [wgpu::BindGroupLayout; 1]) -> [wgpu::BindGroupLayout; 1] {
+            entries
+        }
+    }
+    pub fn create_pipeline_layout(device: &wgpu::Device) -> wgpu::PipelineLayout {
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Spawn::PipelineLayout"),
+            bind_group_layouts: &[&WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        })
+    }
+    pub fn create_shader_module_embed_source(device: &wgpu::Device) -> wgpu::ShaderModule {
+        let source = std::borrow::Cow::Borrowed(SHADER_STRING);
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("spawn.wgsl"),
+            source: wgpu::ShaderSource::Wgsl(source),
+        })
+    }
+    pub const SHADER_STRING: &str = r#"
+struct VelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct MassX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct FlagsX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct ColorX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec4<f32>,
+}
+
+struct ShapeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: u32,
+}
+
+struct AngleX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct AngularVelocityX_naga_oil_mod_XMNXW23LPNYX {
+    inner: f32,
+}
+
+struct SizeX_naga_oil_mod_XMNXW23LPNYX {
+    inner: vec2<f32>,
+}
+
+struct SecondMomentsX_naga_oil_mod_XMNXW23LPNYX {
+    xx: f32,
+    yy: f32,
+    xy: f32,
+}
+
+struct AABBX_naga_oil_mod_XMNXW23LPNYX {
+    min: vec2<f32>,
+    max: vec2<f32>,
+}
+
+struct MaterialIdX_naga_oil_mod_XNVQXIZLSNFQWY4YX {
+    inner: u32,
+}
+
+struct SpawnRequestX_naga_oil_mod_XORXW63C7ON2GC5DFX {
+    position: vec2<f32>,
+    velocity: vec2<f32>,
+    radius: f32,
+    mass: f32,
+    pending: u32,
+}
+
+const FLAG_DRAW_OBJECTX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX: u32 = 4u;
+const SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX: u32 = 1u;
+const MATERIAL_INERTX_naga_oil_mod_XNVQXIZLSNFQWY4YX: u32 = 0u;
+
+@group(0) @binding(0) 
+var<storage, read_write> flags: array<FlagsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(1) 
+var<storage, read_write> masses: array<MassX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(2) 
+var<storage, read_write> sizes: array<SizeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(3) 
+var<storage, read_write> shapes: array<ShapeX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(4) 
+var<storage, read_write> colors: array<ColorX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(5) 
+var<storage, read_write> moments: array<SecondMomentsX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(6) 
+var<storage, read_write> materials: array<MaterialIdX_naga_oil_mod_XNVQXIZLSNFQWY4YX>;
+@group(0) @binding(7) 
+var<storage, read_write> aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(8) 
+var<storage, read_write> integrated_aabbs: array<AABBX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(9) 
+var<storage, read_write> integrated_velocities: array<VelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(10) 
+var<storage, read_write> integrated_angles: array<AngleX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(11) 
+var<storage, read_write> angular_velocities: array<AngularVelocityX_naga_oil_mod_XMNXW23LPNYX>;
+@group(0) @binding(12) 
+var<storage, read_write> sleeping_nodes: array<u32>;
+@group(0) @binding(13) 
+var<storage> prefixes: array<vec2<u32>>;
+@group(0) @binding(14) 
+var<storage> free_slots: array<u32>;
+@group(0) @binding(15) 
+var<storage, read_write> spawn_request: SpawnRequestX_naga_oil_mod_XORXW63C7ON2GC5DFX;
+
+fn grow_ancestors(leaf: u32, object_count: u32) {
+    var node: u32;
+    var level_start: u32 = 0u;
+    var level_length: u32;
+
+    let aabb = aabbs[leaf];
+    node = leaf;
+    level_length = object_count;
+    loop {
+        let _e8 = level_length;
+        if (_e8 > 1u) {
+        } else {
+            break;
+        }
+        {
+            let _e11 = level_length;
+            let parent_count = (_e11 / 2u);
+            let _e15 = level_start;
+            let paired_end = (_e15 + (parent_count * 2u));
+            let _e19 = node;
+            if (_e19 < paired_end) {
+                let _e21 = level_start;
+                let _e22 = level_length;
+                let _e24 = node;
+                let _e25 = level_start;
+                node = ((_e21 + _e22) + ((_e24 - _e25) / 2u));
+                let _e31 = node;
+                let _e34 = node;
+                let _e37 = aabbs[_e34].min;
+                let _e41 = node;
+                let _e44 = aabbs[_e41].max;
+                aabbs[_e31] = AABBX_naga_oil_mod_XMNXW23LPNYX(min(_e37, aabb.min), max(_e44, aabb.max));
+            }
+            level_start = paired_end;
+            let _e48 = level_length;
+            level_length = (_e48 - parent_count);
+        }
+    }
+    return;
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn spawn_circle() {
+    let _e2 = spawn_request.pending;
+    if (_e2 == 0u) {
+        return;
+    }
+    spawn_request.pending = 0u;
+    let totals = prefixes[arrayLength((&flags))];
+    if (totals.y >= totals.x) {
+        return;
+    }
+    let slot = free_slots[totals.y];
+    let radius = spawn_request.radius;
+    let _e25 = spawn_request.position;
+    let _e30 = spawn_request.position;
+    let aabb_1 = AABBX_naga_oil_mod_XMNXW23LPNYX((_e25 - vec2(radius)), (_e30 + vec2(radius)));
+    flags[slot].inner = 5u;
+    let _e43 = spawn_request.mass;
+    masses[slot].inner = _e43;
+    sizes[slot].inner = vec2((radius * 2f));
+    shapes[slot].inner = SHAPE_CIRCLEX_naga_oil_mod_XMNXW23LPNYX;
+    colors[slot].inner = vec4(1f);
+    moments[slot] = SecondMomentsX_naga_oil_mod_XMNXW23LPNYX(((radius * radius) / 4f), ((radius * radius) / 4f), 0f);
+    materials[slot].inner = MATERIAL_INERTX_naga_oil_mod_XNVQXIZLSNFQWY4YX;
+    aabbs[slot] = aabb_1;
+    integrated_aabbs[slot] = aabb_1;
+    let _e82 = spawn_request.velocity;
+    integrated_velocities[slot].inner = _e82;
+    integrated_angles[slot].inner = 0f;
+    angular_velocities[slot].inner = 0f;
+    sleeping_nodes[slot] = 0u;
+    return;
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn refit_filled_slots() {
+    var k: u32 = 0u;
+
+    let object_count_1 = arrayLength((&flags));
+    let totals_1 = prefixes[object_count_1];
+    let candidates = min((totals_1.y + 1u), totals_1.x);
+    loop {
+        let _e12 = k;
+        if (_e12 < candidates) {
+        } else {
+            break;
+        }
+        {
+            let _e15 = k;
+            let slot_1 = free_slots[_e15];
+            let _e21 = flags[slot_1].inner;
+            if ((_e21 & FLAG_PHYSICALX_naga_oil_mod_XMNXW23LPNYX) != 0u) {
+                grow_ancestors(slot_1, object_count_1);
+            }
+        }
+        continuing {
+            let _e27 = k;
+            k = (_e27 + 1u);
+        }
+    }
     return;
 }
 "#;
@@ -1,18 +1,22 @@
 // Needs to be a separate shader to render all BVH AABBs
 
-#import common::{ FLAG_DRAW_AABB, Camera, Flags, AABB }
+#import common::{ FLAG_DRAW_AABB, Camera, Flags, AABB, hsv_to_rgb }
 
 @group(0) @binding(0) var<uniform> camera: Camera;
 @group(0) @binding(1) var<storage, read> flags: array<Flags>;
 @group(0) @binding(2) var<storage, read> aabbs: array<AABB>;
+/// First node index of every BVH level, level 0 are the leaves
+@group(0) @binding(3) var<storage, read> level_starts: array<u32>;
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4f,
     @location(0) flags: u32,
-    @location(1) scale: f32,
+    @location(1) level: u32,
     @location(2) quad_position: vec2f,
 }
 
+const LEAF_COLOR: vec4f = vec4f(0.6, 0.6, 0.6, 0.5);
+
 const UNIT_QUAD_VERTICES = array<vec2f, 5>(
     vec2f(0.5, 0.5),
     vec2f(-0.5, 0.5),
@@ -48,7 +52,7 @@ fn vs_main(
     let vertex = UNIT_QUAD_VERTICES[vertex_index];
     out.clip_position = camera.inner * model * vec4f(vertex, 0, 1);
     out.flags = flags_;
-    out.scale = max(scale.x, scale.y);
+    out.level = node_level(i);
     out.quad_position = vertex;
 
     return out;
@@ -64,6 +68,23 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
         discard;
     }
 
-    let intensity = min(1.0, length(in.scale) / 2000);
-    return FragmentOutput(vec4f(intensity, intensity, intensity, intensity));
+    if in.level == 0 {
+        return FragmentOutput(LEAF_COLOR);
+    }
+
+    // Internal nodes go from red right above the leaves to violet at the root
+    let top_level = max(arrayLength(&level_starts) - 1, 2);
+    let hue = f32(in.level - 1) / f32(top_level - 1) * 0.8;
+    return FragmentOutput(vec4f(hsv_to_rgb(vec3f(hue, 0.9, 1)), 1));
+}
+
+fn node_level(i: u32) -> u32 {
+    var level = 0u;
+    for (var l = 1u; l < arrayLength(&level_starts); l++) {
+        if level_starts[l] > i {
+            break;
+        }
+        level = l;
+    }
+    return level;
 }
@@ -0,0 +1,155 @@
+// Destroys the objects that crossed an event horizon during the step. With accretion enabled, their mass and
+// momentum go to the black hole that consumed them, so black holes grow and drift and the total mass and momentum
+// are conserved.
+//
+// There are no atomic floats, so `consume` sums in 64 bit fixed point with pairs of integer atomics. Integer sums don't
+// depend on the order the objects are added in, which keeps runs reproducible. Whatever the black hole's f32 state
+// can't take in a step, like a tiny mass next to a huge one, stays in the sums for the next steps instead of being
+// rounded away.
+
+#import common::{
+    FLAG_DRAW_OBJECT, FLAG_PHYSICAL, FLAG_DRAW_AABB, FLAG_DESTROYED, AABB, Mass, Velocity, Flags, AngularVelocity,
+    invocation_index
+}
+#import blackholes::{
+    BLACKHOLE_COUNT, BLACKHOLE_MASS_SCALE, BLACKHOLE_SIZE_SCALE, BLACKHOLE_DESTROY_MATTER, BlackHoleSet
+}
+
+@group(0) @binding(0) var<uniform> parameters: AccretionParameters;
+@group(0) @binding(1) var<storage, read_write> flags: array<Flags>;
+@group(0) @binding(2) var<storage, read> masses: array<Mass>;
+@group(0) @binding(3) var<storage, read> integrated_aabbs: array<AABB>;
+@group(0) @binding(4) var<storage, read_write> integrated_velocities: array<Velocity>;
+@group(0) @binding(5) var<storage, read_write> angular_velocities: array<AngularVelocity>;
+/// The same buffer the integrator and the renderer read as a uniform
+@group(0) @binding(6) var<storage, read_write> blackhole_set: BlackHoleSet;
+/// One per black hole
+@group(0) @binding(7) var<storage, read_write> accretion: array<Accretion>;
+
+const WORKGROUP_SIZE: u32 = 64;
+/// Fixed point scales of the sums, powers of two so that scaling doesn't round. The sums resolve about 1e-6 units of
+/// mass and 2e-5 units of momentum and hold up to about 8e12 and 1e14 of them.
+const MASS_SCALE: f32 = 1048576;
+const MOMENTUM_SCALE: f32 = 65536;
+const WORD_SCALE: f32 = 4294967296;
+/// Indices of the sums in `Accretion`
+const SUM_MASS: u32 = 0;
+const SUM_MOMENTUM_X: u32 = 1;
+const SUM_MOMENTUM_Y: u32 = 2;
+
+struct AccretionParameters {
+    /// Step size including the time scale, the black holes drift by their velocity times it
+    dt: f32,
+    /// Non-zero if consumed mass and momentum go to the black holes, otherwise they vanish
+    accrete: u32,
+}
+
+/// Two's complement 64 bit integer, the high word carries the overflow of the low one
+struct FixedSum {
+    low: atomic<u32>,
+    high: atomic<u32>,
+}
+
+/// What a black hole consumed, `accrete` takes from the mass and momentum sums what it adds to the black hole
+struct Accretion {
+    /// Mass and momentum in x and y, indexed with the `SUM_` constants
+    sums: array<FixedSum, 3>,
+    /// Objects consumed since the start, never cleared
+    consumed: atomic<u32>,
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn consume(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if !BLACKHOLE_DESTROY_MATTER || i >= arrayLength(&flags) {
+        return;
+    }
+    let f = flags[i].inner;
+    if (f & FLAG_PHYSICAL) == 0 {
+        return;
+    }
+
+    let aabb = integrated_aabbs[i];
+    let position = (aabb.min + aabb.max) / 2;
+    let half_extents = (aabb.max - aabb.min) / 2;
+    for (var bh_index: u32 = 0; bh_index < BLACKHOLE_COUNT; bh_index++) {
+        let blackhole = blackhole_set.blackholes[bh_index];
+        let distance = length(blackhole.position - position) - max(half_extents.x, half_extents.y);
+        if distance < blackhole.radius * BLACKHOLE_SIZE_SCALE {
+            let mass = masses[i].inner;
+            let momentum = mass * integrated_velocities[i].inner;
+            add_to_sum(bh_index, SUM_MASS, mass * MASS_SCALE);
+            add_to_sum(bh_index, SUM_MOMENTUM_X, momentum.x * MOMENTUM_SCALE);
+            add_to_sum(bh_index, SUM_MOMENTUM_Y, momentum.y * MOMENTUM_SCALE);
+            atomicAdd(&accretion[bh_index].consumed, 1u);
+
+            flags[i].inner = (f & ~(FLAG_PHYSICAL | FLAG_DRAW_OBJECT | FLAG_DRAW_AABB)) | FLAG_DESTROYED;
+            integrated_velocities[i].inner = vec2f();
+            angular_velocities[i].inner = 0;
+            return;
+        }
+    }
+}
+
+/// Adds the consumed mass and momentum to every black hole and moves it, dispatched with one invocation per black
+/// hole after `consume`
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn accrete(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= BLACKHOLE_COUNT {
+        return;
+    }
+    var blackhole = blackhole_set.blackholes[i];
+    if parameters.accrete == 0 {
+        for (var k: u32 = 0; k < 3; k++) {
+            atomicStore(&accretion[i].sums[k].low, 0u);
+            atomicStore(&accretion[i].sums[k].high, 0u);
+        }
+    } else {
+        let accreted_mass = sum_value(i, SUM_MASS) / MASS_SCALE;
+        let momentum = vec2f(sum_value(i, SUM_MOMENTUM_X), sum_value(i, SUM_MOMENTUM_Y)) / MOMENTUM_SCALE;
+        let mass = blackhole.mass * BLACKHOLE_MASS_SCALE;
+        blackhole.mass = (mass + accreted_mass) / BLACKHOLE_MASS_SCALE;
+        let grown = blackhole.mass * BLACKHOLE_MASS_SCALE;
+        if grown != mass || any(momentum != vec2f()) {
+            let previous_momentum = blackhole.velocity * mass;
+            blackhole.velocity = (previous_momentum + momentum) / grown;
+            // Like the Schwarzschild radius, the horizon grows with the mass
+            blackhole.radius *= grown / mass;
+            // Only what the black hole took leaves the sums, the rounding remainder is added in later steps
+            let taken_momentum = blackhole.velocity * grown - previous_momentum;
+            add_to_sum(i, SUM_MASS, -(grown - mass) * MASS_SCALE);
+            add_to_sum(i, SUM_MOMENTUM_X, -taken_momentum.x * MOMENTUM_SCALE);
+            add_to_sum(i, SUM_MOMENTUM_Y, -taken_momentum.y * MOMENTUM_SCALE);
+        }
+    }
+    blackhole.position += blackhole.velocity * parameters.dt;
+    blackhole_set.blackholes[i] = blackhole;
+}
+
+/// Adds `value` rounded to an integer. Splitting its magnitude into words doesn't round, as f32 values have fewer
+/// significant bits than a word.
+fn add_to_sum(blackhole: u32, sum: u32, value: f32) {
+    let magnitude = abs(round(value));
+    let high_magnitude = floor(magnitude / WORD_SCALE);
+    var low = u32(magnitude - high_magnitude * WORD_SCALE);
+    var high = u32(high_magnitude);
+    if value < 0 {
+        high = ~high + select(0u, 1u, low == 0);
+        low = 0u - low;
+    }
+    let previous_low = atomicAdd(&accretion[blackhole].sums[sum].low, low);
+    // The low word wrapped around if the result is smaller than what was added to
+    let carry = select(0u, 1u, previous_low + low < previous_low);
+    atomicAdd(&accretion[blackhole].sums[sum].high, high + carry);
+}
+
+fn sum_value(blackhole: u32, sum: u32) -> f32 {
+    let low = atomicLoad(&accretion[blackhole].sums[sum].low);
+    let high = bitcast<i32>(atomicLoad(&accretion[blackhole].sums[sum].high));
+    return f32(high) * WORD_SCALE + f32(low);
+}
@@ -0,0 +1,96 @@
+#import common::{ UNIT_QUAD_VERTICES, Camera }
+#import blackholes::{ BLACKHOLE_COUNT, BLACKHOLE_SIZE_SCALE, BlackHoleSet, blackhole_potential }
+
+struct BlackHoleParameters {
+    /// The potential contours are drawn over this area
+    world_min: vec2f,
+    world_max: vec2f,
+}
+
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<uniform> parameters: BlackHoleParameters;
+@group(0) @binding(2) var<uniform> blackhole_set: BlackHoleSet;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4f,
+    /// World position relative to the black hole for horizons, absolute for the potential
+    @location(0) position: vec2f,
+    @location(1) blackhole_index: u32,
+}
+
+/// The spin band extends up to this fraction of the horizon radius beyond the horizon
+const SPIN_BAND_MAX: f32 = 0.8;
+/// Spin at which the band reaches its maximum width
+const SPIN_STRENGTH_LIMIT: f32 = 100;
+const SPIN_STRIPES: f32 = 12;
+const PHOTON_RING_WIDTH: f32 = 0.08;
+/// Contour lines per doubling of the potential
+const CONTOURS_PER_OCTAVE: f32 = 2;
+
+@vertex
+fn vs_horizon(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) i: u32,
+) -> VertexOutput {
+    let blackhole = blackhole_set.blackholes[i];
+    let extent = 2 * blackhole.radius * BLACKHOLE_SIZE_SCALE * (1 + SPIN_BAND_MAX);
+    let position = UNIT_QUAD_VERTICES[vertex_index] * extent;
+    var out = VertexOutput();
+    out.clip_position = camera.inner * vec4f(blackhole.position + position, 0, 1);
+    out.position = position;
+    out.blackhole_index = i;
+    return out;
+}
+
+/// Draws the event horizon with a photon ring and a striped band whose twist shows the spin direction
+/// and whose width shows the spin strength
+@fragment
+fn fs_horizon(in: VertexOutput) -> @location(0) vec4f {
+    let blackhole = blackhole_set.blackholes[in.blackhole_index];
+    let horizon = blackhole.radius * BLACKHOLE_SIZE_SCALE;
+    let r = length(in.position) / horizon;
+    if r < 1 - PHOTON_RING_WIDTH {
+        return vec4f(0, 0, 0, 1);
+    }
+    if r < 1 {
+        return vec4f(1, 0.6, 0.2, 1);
+    }
+
+    let band = min(abs(blackhole.spin) / SPIN_STRENGTH_LIMIT, 1) * SPIN_BAND_MAX;
+    if r > 1 + band {
+        discard;
+    }
+    let angle = atan2(in.position.y, in.position.x) / radians(360);
+    let phase = angle * SPIN_STRIPES + sign(blackhole.spin) * (r - 1) * 2;
+    if fract(phase) > 0.5 {
+        discard;
+    }
+    let color = select(vec3f(1, 0.3, 0.8), vec3f(0.3, 0.8, 1), blackhole.spin > 0);
+    return vec4f(color, 0.7 * (1 - (r - 1) / band));
+}
+
+@vertex
+fn vs_potential(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let uv = UNIT_QUAD_VERTICES[vertex_index] + 0.5;
+    let position = mix(parameters.world_min, parameters.world_max, uv);
+    var out = VertexOutput();
+    out.clip_position = camera.inner * vec4f(position, 0, 1);
+    out.position = position;
+    return out;
+}
+
+/// Draws contour lines of the combined gravitational potential on a logarithmic scale
+@fragment
+fn fs_potential(in: VertexOutput) -> @location(0) vec4f {
+    var potential: f32 = 0;
+    for (var i: u32 = 0; i < BLACKHOLE_COUNT; i++) {
+        potential += blackhole_potential(blackhole_set.blackholes[i], in.position);
+    }
+    let level = log2(-potential) * CONTOURS_PER_OCTAVE;
+    let distance_to_line = abs(fract(level + 0.5) - 0.5) / fwidth(level);
+    let line = 1 - clamp(distance_to_line, 0, 1);
+    if line == 0 {
+        discard;
+    }
+    return vec4f(0.4, 0.5, 1, 0.4 * line);
+}
@@ -0,0 +1,51 @@
+#define_import_path blackholes
+
+// Shared by the integrator and the renderer, so that what is drawn is what acts on the objects. The black holes live
+// in a buffer because accretion moves them and makes them grow, their initial state is `BLACKHOLES` in blackholes.rs.
+
+struct BlackHole {
+    position: vec2f,
+    velocity: vec2f,
+    radius: f32,
+    /// In units of `BLACKHOLE_MASS_SCALE` object masses
+    mass: f32,
+    spin: f32
+}
+
+const BLACKHOLE_COUNT: u32 = 5;
+
+/// Bound as a uniform by the integrator and the renderer, uniform arrays need a 16 byte stride which `BlackHole` has
+struct BlackHoleSet {
+    blackholes: array<BlackHole, BLACKHOLE_COUNT>,
+}
+const BLACKHOLE_MASS_SCALE: f32 = 1 * 1000;
+const BLACKHOLE_SIZE_SCALE: f32 = 10;
+/// Objects crossing an event horizon are destroyed, and with accretion enabled their mass and momentum go to the
+/// black hole
+const BLACKHOLE_DESTROY_MATTER: bool = true;
+const GRAVITATIONAL_CONSTANT: f32 = 1 * 100000;
+
+fn blackhole_gravity(blackhole: BlackHole, position: vec2f) -> vec2f {
+    let to_blackhole = blackhole.position - position;
+    let direction = normalize(to_blackhole);
+    let distance = length(to_blackhole);
+    let bh_gravity = direction * GRAVITATIONAL_CONSTANT * blackhole.mass * BLACKHOLE_MASS_SCALE / (distance * distance);
+    return bh_gravity;
+}
+
+// Lense–Thirring formula for 2D
+// NOTE: some terms are missing and have to be reintroduced for 3D
+fn frame_dragging(blackhole: BlackHole, position: vec2f, velocity: vec2f) -> vec2f {
+    let r_vec = blackhole.position - position;
+    let r = length(r_vec);
+    let J = blackhole.spin; // scalar angular momentum (Jz)
+    let v_perp = vec2f(-velocity.y, velocity.x); // v rotated by +90 degrees
+    let a = (2.0 * GRAVITATIONAL_CONSTANT * J / pow(r, 3.0)) * v_perp;
+    return a;
+}
+
+/// Gravitational potential per unit mass, the integral of `blackhole_gravity`
+fn blackhole_potential(blackhole: BlackHole, position: vec2f) -> f32 {
+    let distance = length(blackhole.position - position);
+    return -GRAVITATIONAL_CONSTANT * blackhole.mass * BLACKHOLE_MASS_SCALE / distance;
+}
@@ -11,6 +11,9 @@ struct CombineNodePass {
 var<push_constant> params: CombineNodePass;
 @group(0) @binding(0) var<storage, read_write> aabbs: array<AABB>;
 @group(0) @binding(1) var<storage, read_write> nodes: array<BvhNode>;
+/// Non-zero for nodes whose objects all sleep, the leaves are set by the sleep pass. Sleeping objects don't move, so
+/// the AABBs of their parents are still the ones from the last build.
+@group(0) @binding(2) var<storage, read_write> sleeping_nodes: array<u32>;
 
 const WORKGROUP_SIZE: u32 = 64;
 
@@ -24,6 +27,11 @@ fn combine_nodes(@builtin(global_invocation_id) gid: vec3<u32>) {
     let src = params.src_start + index * 2;
     let dst = params.dst_start + index;
     nodes[dst] = BvhNode(src | BVH_NODE_TREE_FLAG);
+    let sleeping = sleeping_nodes[src] != 0 && sleeping_nodes[src + 1] != 0;
+    sleeping_nodes[dst] = u32(sleeping);
+    if sleeping {
+        return;
+    }
     let left_aabb = aabbs[src];
     let right_aabb = aabbs[src + 1];
     let aabb_min = min(left_aabb.min, right_aabb.min);
@@ -0,0 +1,60 @@
+#define_import_path bvh_traversal
+
+// Depth first walk over a pairwise BVH, shared by the passes that query the objects' or the level's tree:
+//
+//     var traversal = bvh_traversal(leaf_count);
+//     while traversal.size > 0 {
+//         let node_index = bvh_pop(&traversal);
+//         if !aabbs_overlap(query, aabbs[node_index]) {
+//             continue;
+//         }
+//         let node = nodes[node_index].index;
+//         if bvh_descend(&traversal, node) {
+//             continue;
+//         }
+//         // `node` is a leaf
+//     }
+
+#import common::{AABB, BVH_NODE_TREE_FLAG}
+
+/// Every combine pass of the BVH builder halves the number of nodes, and all 2n - 1 node indices stay below
+/// `BVH_NODE_TREE_FLAG`, so a tree over at most 2^30 leaves has at most 30 levels below its root
+const BVH_MAX_DEPTH: u32 = 30;
+/// The walk keeps one sibling per level above the node it visits plus the node's two children, so it never runs out
+const BVH_STACK_SIZE: u32 = BVH_MAX_DEPTH + 1;
+
+struct BvhTraversal {
+    stack: array<u32, BVH_STACK_SIZE>,
+    size: u32,
+}
+
+/// Starts at the root. The pairwise BVH always has 2n - 1 nodes with the root being the last one.
+fn bvh_traversal(leaf_count: u32) -> BvhTraversal {
+    var traversal: BvhTraversal;
+    traversal.stack[0] = 2 * leaf_count - 2;
+    traversal.size = 1;
+    return traversal;
+}
+
+/// Index of the next node to visit, only valid while `size` is non-zero
+fn bvh_pop(traversal: ptr<function, BvhTraversal>) -> u32 {
+    (*traversal).size -= 1;
+    return (*traversal).stack[(*traversal).size];
+}
+
+/// Queues the children if `node` is an inner node and returns whether it is one, otherwise it is the object's index
+fn bvh_descend(traversal: ptr<function, BvhTraversal>, node: u32) -> bool {
+    if (node & BVH_NODE_TREE_FLAG) == 0 {
+        return false;
+    }
+    let left = node & ~BVH_NODE_TREE_FLAG;
+    let size = (*traversal).size;
+    (*traversal).stack[size] = left;
+    (*traversal).stack[size + 1] = left + 1;
+    (*traversal).size = size + 2;
+    return true;
+}
+
+fn aabbs_overlap(a: AABB, b: AABB) -> bool {
+    return all(a.min <= b.max) && all(b.min <= a.max);
+}
@@ -12,6 +12,19 @@ const UNIT_QUAD_VERTICES = array<vec2f, 6>(
 const FLAG_DRAW_OBJECT: u32 = 1 << 0;
 const FLAG_DRAW_AABB: u32 = 1 << 1;
 const FLAG_PHYSICAL: u32 = 1 << 2;
+/// Set when the simulation removes an object, such objects are neither drawn nor physical
+const FLAG_DESTROYED: u32 = 1 << 3;
+/// Swept against the level in substeps, for fast objects that aren't circles, whose sweeps are exact anyway
+const FLAG_BULLET: u32 = 1 << 4;
+/// Set and cleared by the sleep pass, sleeping objects keep their state until their island wakes up
+const FLAG_SLEEPING: u32 = 1 << 5;
+
+const SHAPE_RECT: u32 = 0;
+const SHAPE_CIRCLE: u32 = 1;
+/// Convex polygon from the vertex pool, see `Polygon`
+const SHAPE_POLYGON: u32 = 2;
+/// Rounded along the x axis, the size is the full length and the diameter
+const SHAPE_CAPSULE: u32 = 3;
 
 struct Camera {
     inner: mat4x4f
@@ -37,11 +50,51 @@ struct Shape {
     inner: u32
 }
 
+/// Counterclockwise rotation in radians
+struct Angle {
+    inner: f32
+}
+
+/// Counterclockwise in radians per second
+struct AngularVelocity {
+    inner: f32
+}
+
+/// Extent of the unrotated shape, the diameter for circles
+struct Size {
+    inner: vec2f
+}
+
+/// Range of a polygon's vertices in the vertex pool, counterclockwise around the centroid in the shape's own frame.
+/// Empty for the other shapes.
+struct Polygon {
+    offset: u32,
+    count: u32,
+}
+
+struct PolygonVertex {
+    inner: vec2f
+}
+
+/// Second moments of area about the center along the shape's own axes, per unit of mass. They are computed once
+/// on the CPU, since polygons need their vertices for it.
+struct SecondMoments {
+    xx: f32,
+    yy: f32,
+    xy: f32,
+}
+
 struct AABB {
     min: vec2f,
     max: vec2f
 }
 
+/// Static wall from `start` to `end`, objects collide with it from both sides
+struct Segment {
+    start: vec2f,
+    end: vec2f,
+}
+
 const BVH_NODE_TREE_FLAG: u32 = 1 << 31;
 
 /// High bit set -> tree(index, index + 1)
@@ -49,6 +102,45 @@ struct BvhNode {
     index: u32,
 }
 
+/// Second moments of mass of the rotated body about its center
+fn second_moment_tensor(moments: SecondMoments, mass: f32, angle: f32) -> mat2x2f {
+    let r = rotation(angle);
+    return r * mat2x2f(moments.xx, moments.xy, moments.xy, moments.yy) * transpose(r) * mass;
+}
+
+fn moment_of_inertia(moments: SecondMoments, mass: f32) -> f32 {
+    return mass * (moments.xx + moments.yy);
+}
+
+/// Counterclockwise rotation by `angle`
+fn rotation(angle: f32) -> mat2x2f {
+    let c = cos(angle);
+    let s = sin(angle);
+    return mat2x2f(c, s, -s, c);
+}
+
+/// Half the extent of the AABB of the rotated shape. Polygons are bounded by their rotated size, which is loose, the
+/// shaders with access to the vertex pool bound them exactly.
+fn rotated_half_extents(shape: u32, size: vec2f, angle: f32) -> vec2f {
+    if shape == SHAPE_CIRCLE {
+        return size / 2;
+    }
+    if shape == SHAPE_CAPSULE {
+        let radius = size.y / 2;
+        return abs(vec2f(cos(angle), sin(angle))) * (size.x / 2 - radius) + radius;
+    }
+    let c = abs(cos(angle));
+    let s = abs(sin(angle));
+    return vec2f(c * size.x + s * size.y, s * size.x + c * size.y) / 2;
+}
+
+/// Dispatches are split into rows of at most 65535 workgroups, so ones with more than that have some invocations and
+/// workgroups past the end
 fn invocation_index(gid: vec3<u32>, workgroup_size: u32) -> u32 {
     return gid.x + gid.y * 65535 * workgroup_size;
+}
+
+fn hsv_to_rgb(hsv: vec3f) -> vec3f {
+    let k = fract(vec3f(hsv.x) + vec3f(1.0, 2.0 / 3.0, 1.0 / 3.0)) * 6.0 - 3.0;
+    return hsv.z * mix(vec3f(1), clamp(abs(k) - 1, vec3f(0), vec3f(1)), hsv.y);
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+#import common::{FLAG_PHYSICAL, FLAG_DESTROYED, FLAG_SLEEPING, AABB, Mass, Flags, Velocity, invocation_index}
+
+@group(0) @binding(0) var<storage, read> constraints: array<Constraint>;
+/// Accumulated Lagrange multiplier of every constraint, cleared before the first iteration of a step
+@group(0) @binding(1) var<storage, read_write> lambdas: array<f32>;
+/// Position change of the constraint's first object per unit inverse mass, written by `solve_constraints`
+@group(0) @binding(2) var<storage, read_write> corrections: array<vec2f>;
+/// Indices of the objects with at least one constraint
+@group(0) @binding(3) var<storage, read> constrained_objects: array<u32>;
+/// The constraints of `constrained_objects[i]` are `constraint_indices[constraint_offsets[i]..constraint_offsets[i + 1]]`
+@group(0) @binding(4) var<storage, read> constraint_offsets: array<u32>;
+@group(0) @binding(5) var<storage, read> constraint_indices: array<u32>;
+@group(0) @binding(6) var<storage, read> flags: array<Flags>;
+@group(0) @binding(7) var<storage, read> masses: array<Mass>;
+/// Positions at the start of the step
+@group(0) @binding(8) var<storage, read> aabbs: array<AABB>;
+@group(0) @binding(9) var<storage, read_write> integrated_aabbs: array<AABB>;
+@group(0) @binding(10) var<storage, read_write> integrated_velocities: array<Velocity>;
+
+var<push_constant> pass_parameters: SolverPass;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+struct SolverPass {
+    dt: f32,
+}
+
+/// Keeps two objects at `rest_length` from each other. Non-physical objects are anchors that don't move, destroyed
+/// objects break their constraints.
+struct Constraint {
+    a: u32,
+    b: u32,
+    rest_length: f32,
+    /// Inverse stiffness, zero for rigid distance constraints
+    compliance: f32,
+    /// Damping of springs, has no effect on rigid constraints
+    damping: f32,
+}
+
+/// XPBD step for one constraint, computed from the positions of the previous iteration
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn solve_constraints(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&constraints) {
+        return;
+    }
+
+    let constraint = constraints[i];
+    let w_a = inverse_mass(constraint.a);
+    let w_b = inverse_mass(constraint.b);
+    let broken = ((flags[constraint.a].inner | flags[constraint.b].inner) & FLAG_DESTROYED) != 0;
+    let delta = center(integrated_aabbs[constraint.a]) - center(integrated_aabbs[constraint.b]);
+    let distance = length(delta);
+    if broken || w_a + w_b == 0 || distance == 0 {
+        corrections[i] = vec2f();
+        return;
+    }
+
+    let dt = pass_parameters.dt;
+    let normal = delta / distance;
+    let error = distance - constraint.rest_length;
+    let alpha = constraint.compliance / (dt * dt);
+    let gamma = constraint.compliance * constraint.damping / dt;
+    let moved = (center(integrated_aabbs[constraint.a]) - center(aabbs[constraint.a]))
+        - (center(integrated_aabbs[constraint.b]) - center(aabbs[constraint.b]));
+    let delta_lambda = (-error - alpha * lambdas[i] - gamma * dot(normal, moved)) / ((1 + gamma) * (w_a + w_b) + alpha);
+    lambdas[i] += delta_lambda;
+    corrections[i] = normal * delta_lambda;
+}
+
+/// Jacobi update, every constrained object moves by the average of its constraints' corrections
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn apply_corrections(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&constrained_objects) {
+        return;
+    }
+
+    let object = constrained_objects[i];
+    let w = inverse_mass(object);
+    if w == 0 {
+        return;
+    }
+
+    let start = constraint_offsets[i];
+    let end = constraint_offsets[i + 1];
+    var sum = vec2f();
+    for (var j = start; j < end; j++) {
+        let constraint_index = constraint_indices[j];
+        let correction = corrections[constraint_index];
+        if constraints[constraint_index].a == object {
+            sum += correction;
+        } else {
+            sum -= correction;
+        }
+    }
+    let offset = sum * w / f32(end - start);
+    let aabb = integrated_aabbs[object];
+    integrated_aabbs[object] = AABB(aabb.min + offset, aabb.max + offset);
+    integrated_velocities[object].inner += offset / pass_parameters.dt;
+}
+
+/// Zero for anchors and sleeping objects, which have to stay where they are
+fn inverse_mass(object: u32) -> f32 {
+    if (flags[object].inner & (FLAG_PHYSICAL | FLAG_SLEEPING)) != FLAG_PHYSICAL {
+        return 0.0;
+    }
+    return 1 / masses[object].inner;
+}
+
+fn center(aabb: AABB) -> vec2f {
+    return (aabb.min + aabb.max) / 2;
+}
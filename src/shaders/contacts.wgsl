@@ -0,0 +1,448 @@
+// Collision response between objects, run after the level collisions. Contacts are found at the positions before
+// the step, and every touching pair that approaches exchanges an impulse along the contact normal at the contact
+// point. Hits off the line through the centers turn the impulse into torque, so they spin the objects up.
+//
+// Like the constraint solver it iterates Jacobi steps: both objects of a pair compute the pair's impulse from the same
+// state and apply their side of it, so no atomics are needed. An object in several contacts gets all of them at once,
+// so every impulse is shared out by the larger contact count of its pair to keep piles from being pushed apart.
+// Objects lying against a wall lose what they are pushed into it after every iteration, so that piles on the walls
+// come to rest.
+
+#import common::{
+    FLAG_PHYSICAL, FLAG_SLEEPING, SHAPE_CIRCLE, SHAPE_CAPSULE, AABB, Mass, Velocity, Flags, BvhNode,
+    Angle, AngularVelocity, SecondMoments, invocation_index, moment_of_inertia, rotation
+}
+#import bvh_traversal::{bvh_traversal, bvh_pop, bvh_descend, aabbs_overlap}
+#import geometry::{shapes, sizes, support}
+#import tool_state::ToolState
+
+@group(0) @binding(0) var<storage, read> flags: array<Flags>;
+@group(0) @binding(1) var<storage, read> masses: array<Mass>;
+@group(0) @binding(2) var<storage, read> moments: array<SecondMoments>;
+/// Before the step, the nodes of the BVH follow the objects
+@group(0) @binding(3) var<storage, read> aabbs: array<AABB>;
+@group(0) @binding(4) var<storage, read> nodes: array<BvhNode>;
+@group(0) @binding(5) var<storage, read> angles: array<Angle>;
+@group(0) @binding(6) var<storage, read_write> integrated_aabbs: array<AABB>;
+@group(0) @binding(7) var<storage, read_write> integrated_velocities: array<Velocity>;
+/// Updated in place by the integrator, so these are the angular velocities after the step as well
+@group(0) @binding(8) var<storage, read_write> angular_velocities: array<AngularVelocity>;
+@group(0) @binding(9) var<storage, read_write> contact_counts: array<u32>;
+/// Written by `solve_contacts` and added to the integrated state by `apply_contacts`
+@group(0) @binding(10) var<storage, read_write> responses: array<ContactResponse>;
+@group(0) @binding(11) var<uniform> tools: ToolState;
+/// Written by the level collisions
+@group(0) @binding(12) var<storage, read> wall_normals: array<vec4f>;
+
+const WORKGROUP_SIZE: u32 = 64;
+/// Share of the normal speed kept when two objects bounce off each other
+const CONTACT_RESTITUTION: f32 = 0.5;
+/// Slower hits don't bounce, so that objects lying on each other come to rest instead of jittering
+const RESTITUTION_SPEED_THRESHOLD: f32 = 5;
+/// Overlap left alone, so that resting contacts stay in contact
+const CONTACT_SLOP: f32 = 0.01;
+/// Share of the overlap removed per step, moving the objects apart slowly doesn't add energy
+const OVERLAP_CORRECTION: f32 = 0.2;
+const GJK_MAX_ITERATIONS: u32 = 32;
+/// GJK stops once a step brings the closest point less than this share of its squared distance closer
+const GJK_TOLERANCE: f32 = 1e-4;
+const EPA_MAX_VERTICES: u32 = 16;
+/// EPA stops once the support point is this close to the closest edge
+const EPA_TOLERANCE: f32 = 1e-3;
+/// Faces turned less than this many radians away from the contact normal touch with their whole width
+const FEATURE_ANGLE: f32 = 0.01;
+
+/// Change of the integrated state of one object from all its contacts
+struct ContactResponse {
+    velocity: vec2f,
+    /// Moves the object out of the overlap
+    push: vec2f,
+    angular_velocity: f32,
+}
+
+/// How far two objects overlap, negative if they are apart
+struct Contact {
+    /// From the first object to the second one
+    normal: vec2f,
+    depth: f32,
+    point: vec2f,
+}
+
+/// Counts the physical objects touching every awake object by traversing the BVH
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn count_contacts(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) || !takes_part(i) {
+        return;
+    }
+    contact_counts[i] = visit_contacts(i, false).count;
+}
+
+/// Sums the impulses of every awake object's contacts, `count_contacts` has to be done for all objects
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn solve_contacts(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) || !takes_part(i) {
+        return;
+    }
+    responses[i] = visit_contacts(i, true).response;
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn apply_contacts(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) || !takes_part(i) {
+        return;
+    }
+    let response = responses[i];
+    let walls = wall_normals[i];
+    integrated_velocities[i].inner = off_walls(integrated_velocities[i].inner + response.velocity, walls);
+    angular_velocities[i].inner += response.angular_velocity;
+    let push = off_walls(response.push, walls);
+    integrated_aabbs[i].min += push;
+    integrated_aabbs[i].max += push;
+}
+
+/// Drops the part of `motion` going into the walls with the normals in xy and zw, zero normals are no walls. In a
+/// corner sliding along the second wall may still go into the first one, then there is no way out.
+fn off_walls(motion: vec2f, walls: vec4f) -> vec2f {
+    let along_first = off_wall(motion, walls.xy);
+    let along_both = off_wall(along_first, walls.zw);
+    return select(along_both, vec2f(), dot(along_both, walls.xy) < -1e-6);
+}
+
+fn off_wall(motion: vec2f, normal: vec2f) -> vec2f {
+    return motion - normal * min(dot(motion, normal), 0.0);
+}
+
+struct Visit {
+    count: u32,
+    response: ContactResponse,
+}
+
+/// Goes through the objects touching object `i`, the response is only summed up if `solve` is set
+fn visit_contacts(i: u32, solve: bool) -> Visit {
+    let object_count = arrayLength(&flags);
+    let aabb = aabbs[i];
+    var visit = Visit(0, ContactResponse(vec2f(), vec2f(), 0));
+    var traversal = bvh_traversal(object_count);
+    while traversal.size > 0 {
+        let node_index = bvh_pop(&traversal);
+        if !aabbs_overlap(aabb, aabbs[node_index]) {
+            continue;
+        }
+
+        let node = nodes[node_index].index;
+        if bvh_descend(&traversal, node) {
+            continue;
+        }
+        if node == i || (flags[node].inner & FLAG_PHYSICAL) == 0 {
+            continue;
+        }
+        if !solve {
+            visit.count += u32(objects_touch(i, node));
+            continue;
+        }
+        let contact = find_contact(i, node);
+        if contact.depth > 0 {
+            let response = respond(i, node, contact);
+            visit.response.velocity += response.velocity;
+            visit.response.push += response.push;
+            visit.response.angular_velocity += response.angular_velocity;
+        }
+    }
+    return visit;
+}
+
+/// Side of object `i` of the impulse between it and object `j`
+fn respond(i: u32, j: u32, contact: Contact) -> ContactResponse {
+    let w_i = inverse_mass(i);
+    let w_j = inverse_mass(j);
+    if w_i == 0 {
+        return ContactResponse(vec2f(), vec2f(), 0);
+    }
+    let share = f32(max(max(contact_count(i), contact_count(j)), 1));
+    let normal = contact.normal;
+    let r_i = contact.point - object_center(i);
+    let r_j = contact.point - object_center(j);
+    let w_rotation_i = inverse_inertia(i);
+    let w_rotation_j = inverse_inertia(j);
+
+    var response = ContactResponse(vec2f(), vec2f(), 0);
+    let relative_velocity = point_velocity(j, r_j) - point_velocity(i, r_i);
+    let normal_speed = dot(relative_velocity, normal);
+    let arm_i = cross(r_i, normal);
+    let arm_j = cross(r_j, normal);
+    let k = w_i + w_j + arm_i * arm_i * w_rotation_i + arm_j * arm_j * w_rotation_j;
+    if normal_speed < 0 && k > 0 {
+        let restitution = select(0.0, CONTACT_RESTITUTION, -normal_speed > RESTITUTION_SPEED_THRESHOLD);
+        // Object `j` gets `impulse`, object `i` the opposite
+        let impulse = normal * -(1 + restitution) * normal_speed / k / share;
+        response.velocity = -impulse * w_i;
+        response.angular_velocity = cross(r_i, -impulse) * w_rotation_i;
+    }
+    let correction = max(contact.depth - CONTACT_SLOP, 0.0) * OVERLAP_CORRECTION / share;
+    response.push = -normal * correction * w_i / (w_i + w_j);
+    return response;
+}
+
+/// Velocity of the material of object `i` at `offset` from its center
+fn point_velocity(i: u32, offset: vec2f) -> vec2f {
+    let angular_velocity = angular_velocities[i].inner;
+    return integrated_velocities[i].inner + vec2f(-offset.y, offset.x) * angular_velocity;
+}
+
+/// Z component of the cross product
+fn cross(a: vec2f, b: vec2f) -> f32 {
+    return a.x * b.y - a.y * b.x;
+}
+
+fn takes_part(i: u32) -> bool {
+    let f = flags[i].inner;
+    return (f & FLAG_PHYSICAL) != 0 && (f & FLAG_SLEEPING) == 0;
+}
+
+/// Sleeping objects stay where they are until their island wakes, and the grabbed one follows the cursor, so both
+/// act like walls
+fn inverse_mass(i: u32) -> f32 {
+    if !takes_part(i) || i == tools.grabbed {
+        return 0;
+    }
+    return 1 / masses[i].inner;
+}
+
+fn inverse_inertia(i: u32) -> f32 {
+    let inertia = moment_of_inertia(moments[i], masses[i].inner);
+    if inverse_mass(i) == 0 || inertia <= 0 {
+        return 0;
+    }
+    return 1 / inertia;
+}
+
+/// Objects that are not awake don't count their contacts, they take each of them as the only one
+fn contact_count(i: u32) -> u32 {
+    return select(1, contact_counts[i], takes_part(i));
+}
+
+/// Narrow phase, exact for circle pairs and the distance between the cores for everything else
+fn objects_touch(a: u32, b: u32) -> bool {
+    if shapes[a].inner == SHAPE_CIRCLE && shapes[b].inner == SHAPE_CIRCLE {
+        return circle_contact(a, b).depth >= 0;
+    }
+    return length(separation(a, b).closest) <= rounding(a) + rounding(b);
+}
+
+/// Exact for circle pairs. Everything else is taken apart into its core and rounding: cores apart from each other
+/// give the contact by their distance, overlapping cores by EPA on the simplex GJK ends with.
+fn find_contact(a: u32, b: u32) -> Contact {
+    if shapes[a].inner == SHAPE_CIRCLE && shapes[b].inner == SHAPE_CIRCLE {
+        return circle_contact(a, b);
+    }
+    let separation = separation(a, b);
+    let roundings = rounding(a) + rounding(b);
+    let distance = length(separation.closest);
+    var normal: vec2f;
+    var depth: f32;
+    if distance > 0 {
+        // The closest point is core a - core b, so b lies the other way
+        normal = -separation.closest / distance;
+        depth = roundings - distance;
+    } else if separation.count == 3 {
+        let penetration = epa(a, b, separation.simplex);
+        normal = penetration.xy;
+        depth = penetration.z + roundings;
+    } else {
+        // The cores touch in a point, which has no normal of its own
+        let offset = object_center(b) - object_center(a);
+        normal = select(vec2f(1, 0), normalize(offset), any(offset != vec2f()));
+        depth = roundings;
+    }
+    if depth < 0 {
+        return Contact(vec2f(), depth, vec2f());
+    }
+    return Contact(normal, depth, contact_point(a, b, normal));
+}
+
+fn circle_contact(a: u32, b: u32) -> Contact {
+    let radius_a = sizes[a].inner.x / 2;
+    let radius_b = sizes[b].inner.x / 2;
+    let offset = object_center(b) - object_center(a);
+    let distance = length(offset);
+    let normal = select(vec2f(1, 0), offset / distance, distance > 0);
+    let depth = radius_a + radius_b - distance;
+    return Contact(normal, depth, object_center(a) + normal * (radius_a - depth / 2));
+}
+
+/// Point of the Minkowski difference core a - core b closest to the origin and the simplex of up to three points
+/// that has it. Zero if the cores overlap, then a full simplex encloses the origin.
+struct Separation {
+    closest: vec2f,
+    simplex: array<vec2f, 3>,
+    count: u32,
+}
+
+/// GJK distance between the cores of `a` and `b`. The cores are polygons, segments or points, so it ends after a few
+/// iterations.
+fn separation(a: u32, b: u32) -> Separation {
+    let start = core_difference_support(a, b, object_center(b) - object_center(a));
+    var separation = Separation(start, array<vec2f, 3>(start, vec2f(), vec2f()), 1);
+    for (var iteration: u32 = 0; iteration < GJK_MAX_ITERATIONS; iteration++) {
+        let closest = separation.closest;
+        let distance_squared = dot(closest, closest);
+        if distance_squared < 1e-12 {
+            separation.closest = vec2f();
+            return separation;
+        }
+        let point = core_difference_support(a, b, -closest);
+        if distance_squared - dot(closest, point) <= GJK_TOLERANCE * distance_squared {
+            // The difference doesn't get any closer to the origin
+            return separation;
+        }
+        separation.simplex[separation.count] = point;
+        separation.count += 1;
+        if separation.count == 2 {
+            separation.closest = closest_on_segment(separation.simplex[0], point);
+            continue;
+        }
+
+        let simplex = separation.simplex;
+        let sides = vec3f(
+            cross(simplex[1] - simplex[0], -simplex[0]),
+            cross(simplex[2] - simplex[1], -simplex[1]),
+            cross(simplex[0] - simplex[2], -simplex[2]),
+        );
+        if all(sides >= vec3f()) || all(sides <= vec3f()) {
+            separation.closest = vec2f();
+            return separation;
+        }
+        // The new point is the last one, keep the edge closest to the origin with it
+        let on_first = closest_on_segment(simplex[0], simplex[2]);
+        let on_second = closest_on_segment(simplex[1], simplex[2]);
+        separation.count = 2;
+        separation.simplex[1] = simplex[2];
+        separation.closest = on_first;
+        if dot(on_second, on_second) < dot(on_first, on_first) {
+            separation.simplex[0] = simplex[1];
+            separation.closest = on_second;
+        }
+    }
+    return separation;
+}
+
+/// Expands the triangle around the origin towards the boundary of the Minkowski difference of the cores. Returns the
+/// normal of the closest boundary edge in xy, pointing from `a` to `b`, and its distance from the origin in z.
+fn epa(a: u32, b: u32, triangle: array<vec2f, 3>) -> vec3f {
+    var polytope: array<vec2f, EPA_MAX_VERTICES>;
+    polytope[0] = triangle[0];
+    // Counterclockwise, so that the outward normal of an edge is its direction turned clockwise
+    let clockwise = cross(triangle[1] - triangle[0], triangle[2] - triangle[0]) < 0;
+    polytope[1] = select(triangle[1], triangle[2], clockwise);
+    polytope[2] = select(triangle[2], triangle[1], clockwise);
+    var count: u32 = 3;
+    var closest = vec3f();
+    for (var iteration: u32 = 0; iteration < EPA_MAX_VERTICES; iteration++) {
+        var closest_edge: u32 = 0;
+        closest = vec3f(0, 0, 1e30);
+        for (var e: u32 = 0; e < count; e++) {
+            let edge = polytope[(e + 1) % count] - polytope[e];
+            let edge_length = length(edge);
+            if edge_length < 1e-6 {
+                continue;
+            }
+            let normal = vec2f(edge.y, -edge.x) / edge_length;
+            let distance = dot(normal, polytope[e]);
+            if distance < closest.z {
+                closest = vec3f(normal, distance);
+                closest_edge = e;
+            }
+        }
+        let point = core_difference_support(a, b, closest.xy);
+        if dot(point, closest.xy) - closest.z < EPA_TOLERANCE || count == EPA_MAX_VERTICES {
+            break;
+        }
+        for (var v = count; v > closest_edge + 1; v--) {
+            polytope[v] = polytope[v - 1];
+        }
+        polytope[closest_edge + 1] = point;
+        count += 1;
+    }
+    return closest;
+}
+
+/// Middle of where the deepest features of both objects along `normal` face each other
+fn contact_point(a: u32, b: u32, normal: vec2f) -> vec2f {
+    let feature_a = feature(a, normal);
+    let feature_b = feature(b, -normal);
+    let tangent = vec2f(-normal.y, normal.x);
+    let along_a = vec2f(dot(feature_a[0], tangent), dot(feature_a[1], tangent));
+    let along_b = vec2f(dot(feature_b[0], tangent), dot(feature_b[1], tangent));
+    let low = max(min(along_a.x, along_a.y), min(along_b.x, along_b.y));
+    let high = min(max(along_a.x, along_a.y), max(along_b.x, along_b.y));
+    let across = dot(feature_a[0] + feature_a[1] + feature_b[0] + feature_b[1], normal) / 4;
+    return tangent * (low + high) / 2 + normal * across;
+}
+
+/// Ends of the face of object `i` turned towards `direction`, or its deepest point twice if no face is
+fn feature(i: u32, direction: vec2f) -> array<vec2f, 2> {
+    let angle = angles[i].inner;
+    let center = object_center(i);
+    return array<vec2f, 2>(
+        center + support(i, angle, rotation(FEATURE_ANGLE) * direction),
+        center + support(i, angle, rotation(-FEATURE_ANGLE) * direction),
+    );
+}
+
+/// Point of the segment from `start` to `end` closest to the origin
+fn closest_on_segment(start: vec2f, end: vec2f) -> vec2f {
+    let edge = end - start;
+    let t = clamp(-dot(start, edge) / max(dot(edge, edge), 1e-20), 0.0, 1.0);
+    return start + edge * t;
+}
+
+/// What is left of object `i` without its rounding, a point for circles and a segment for capsules. Unlike the
+/// outlines the cores have no curves, which GJK and EPA could only approach point by point.
+fn core_support(i: u32, direction: vec2f) -> vec2f {
+    let angle = angles[i].inner;
+    let size = sizes[i].inner;
+    switch shapes[i].inner {
+        case SHAPE_CIRCLE: {
+            return object_center(i);
+        }
+        case SHAPE_CAPSULE: {
+            let axis = rotation(angle) * vec2f(size.x / 2 - size.y / 2, 0);
+            return object_center(i) + select(-axis, axis, dot(axis, direction) >= 0);
+        }
+        default: {
+            return object_center(i) + support(i, angle, direction);
+        }
+    }
+}
+
+/// Radius the core of object `i` is rounded by
+fn rounding(i: u32) -> f32 {
+    switch shapes[i].inner {
+        case SHAPE_CIRCLE: {
+            return sizes[i].inner.x / 2;
+        }
+        case SHAPE_CAPSULE: {
+            return sizes[i].inner.y / 2;
+        }
+        default: {
+            return 0.0;
+        }
+    }
+}
+
+fn core_difference_support(a: u32, b: u32, direction: vec2f) -> vec2f {
+    return core_support(a, direction) - core_support(b, -direction);
+}
+
+fn object_center(i: u32) -> vec2f {
+    return (aabbs[i].min + aabbs[i].max) / 2;
+}
@@ -0,0 +1,129 @@
+#import common::{
+    FLAG_PHYSICAL, FLAG_DESTROYED, AABB, Mass, Velocity, Flags, SecondMoments, AngularVelocity, invocation_index,
+    moment_of_inertia
+}
+#import blackholes::{BLACKHOLE_COUNT, BlackHoleSet, blackhole_potential}
+
+@group(0) @binding(0) var<uniform> parameters: DiagnosticsParameters;
+@group(0) @binding(1) var<storage, read> flags: array<Flags>;
+@group(0) @binding(2) var<storage, read> masses: array<Mass>;
+@group(0) @binding(3) var<storage, read> velocities: array<Velocity>;
+@group(0) @binding(4) var<storage, read> aabbs: array<AABB>;
+/// One sum per workgroup of `cs_reduce`
+@group(0) @binding(5) var<storage, read_write> partials: array<Diagnostics>;
+/// Sum over all partials, written by `cs_combine`
+@group(0) @binding(6) var<storage, read_write> totals: Diagnostics;
+@group(0) @binding(7) var<storage, read> moments: array<SecondMoments>;
+@group(0) @binding(8) var<storage, read> angular_velocities: array<AngularVelocity>;
+@group(0) @binding(9) var<uniform> blackhole_set: BlackHoleSet;
+
+const WORKGROUP_SIZE: u32 = 256;
+
+struct DiagnosticsParameters {
+    /// Angular momentum is taken about this point
+    origin: vec2f,
+}
+
+/// Conserved quantities summed over the physical objects
+struct Diagnostics {
+    /// Translational and rotational
+    kinetic_energy: f32,
+    /// Relative to the black holes, zero at infinity
+    potential_energy: f32,
+    momentum: vec2f,
+    /// Orbital and spin
+    angular_momentum: f32,
+    mass: f32,
+    /// Physical objects
+    live: u32,
+    /// Objects removed by the simulation, see `FLAG_DESTROYED`
+    destroyed: u32,
+}
+
+var<workgroup> scratch: array<Diagnostics, WORKGROUP_SIZE>;
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn cs_reduce(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    var value = Diagnostics();
+    if i < arrayLength(&masses) {
+        value = object_diagnostics(i);
+    }
+    scratch[local_index] = value;
+    reduce_workgroup(local_index);
+    let workgroup_index = workgroup_id.x + workgroup_id.y * 65535;
+    if local_index == 0 && workgroup_index < arrayLength(&partials) {
+        partials[workgroup_index] = scratch[0];
+    }
+}
+
+/// Dispatched as a single workgroup after `cs_reduce`
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn cs_combine(@builtin(local_invocation_index) local_index: u32) {
+    var value = Diagnostics();
+    for (var i = local_index; i < arrayLength(&partials); i += WORKGROUP_SIZE) {
+        value = add(value, partials[i]);
+    }
+    scratch[local_index] = value;
+    reduce_workgroup(local_index);
+    if local_index == 0 {
+        totals = scratch[0];
+    }
+}
+
+/// Tree reduction of `scratch` into `scratch[0]`
+fn reduce_workgroup(local_index: u32) {
+    workgroupBarrier();
+    for (var stride = WORKGROUP_SIZE / 2; stride > 0; stride /= 2) {
+        if local_index < stride {
+            scratch[local_index] = add(scratch[local_index], scratch[local_index + stride]);
+        }
+        workgroupBarrier();
+    }
+}
+
+fn object_diagnostics(i: u32) -> Diagnostics {
+    var out = Diagnostics();
+    let f = flags[i].inner;
+    if (f & FLAG_DESTROYED) != 0 {
+        out.destroyed = 1;
+    }
+    if (f & FLAG_PHYSICAL) == 0 {
+        return out;
+    }
+
+    let mass = masses[i].inner;
+    let velocity = velocities[i].inner;
+    let position = (aabbs[i].min + aabbs[i].max) / 2;
+    var potential = 0.0;
+    for (var bh_index: u32 = 0; bh_index < BLACKHOLE_COUNT; bh_index++) {
+        potential += blackhole_potential(blackhole_set.blackholes[bh_index], position);
+    }
+    let r = position - parameters.origin;
+    let inertia = moment_of_inertia(moments[i], mass);
+    let angular_velocity = angular_velocities[i].inner;
+
+    out.kinetic_energy = mass * dot(velocity, velocity) / 2 + inertia * angular_velocity * angular_velocity / 2;
+    out.potential_energy = mass * potential;
+    out.momentum = mass * velocity;
+    out.angular_momentum = mass * (r.x * velocity.y - r.y * velocity.x) + inertia * angular_velocity;
+    out.mass = mass;
+    out.live = 1;
+    return out;
+}
+
+fn add(a: Diagnostics, b: Diagnostics) -> Diagnostics {
+    return Diagnostics(
+        a.kinetic_energy + b.kinetic_energy,
+        a.potential_energy + b.potential_energy,
+        a.momentum + b.momentum,
+        a.angular_momentum + b.angular_momentum,
+        a.mass + b.mass,
+        a.live + b.live,
+        a.destroyed + b.destroyed,
+    );
+}
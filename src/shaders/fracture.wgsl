@@ -0,0 +1,161 @@
+// Merges and shatters the circles `impacts.wgsl` picked, after the integrator and the accretion pass. Merging keeps
+// mass, momentum and angular momentum, and the area of the merged circle is the sum of both. A shattered circle
+// breaks into equal circles on a ring inside its outline, they share its mass and fly apart from its center.
+
+#import common::{
+    FLAG_DRAW_OBJECT, FLAG_DRAW_AABB, FLAG_PHYSICAL, FLAG_DESTROYED, FLAG_SLEEPING, SHAPE_CIRCLE, AABB, Velocity, Mass,
+    Flags, Color, Shape, Size, Angle, AngularVelocity, SecondMoments, invocation_index
+}
+#import materials::{IMPACT_NONE, IMPACT_SHATTER, MaterialTable, MaterialId, Impact}
+
+@group(0) @binding(0) var<uniform> material_table: MaterialTable;
+@group(0) @binding(1) var<storage, read_write> flags: array<Flags>;
+@group(0) @binding(2) var<storage, read_write> masses: array<Mass>;
+@group(0) @binding(3) var<storage, read_write> sizes: array<Size>;
+@group(0) @binding(4) var<storage, read_write> shapes: array<Shape>;
+@group(0) @binding(5) var<storage, read_write> colors: array<Color>;
+@group(0) @binding(6) var<storage, read_write> moments: array<SecondMoments>;
+@group(0) @binding(7) var<storage, read_write> materials: array<MaterialId>;
+@group(0) @binding(8) var<storage, read_write> integrated_aabbs: array<AABB>;
+@group(0) @binding(9) var<storage, read_write> integrated_velocities: array<Velocity>;
+@group(0) @binding(10) var<storage, read_write> integrated_angles: array<Angle>;
+@group(0) @binding(11) var<storage, read_write> angular_velocities: array<AngularVelocity>;
+@group(0) @binding(12) var<storage, read> impacts: array<Impact>;
+@group(0) @binding(13) var<storage, read> prefixes: array<vec2u>;
+/// Indices of the free slots in ascending order, the first ones are filled by `list_free_slots`
+@group(0) @binding(14) var<storage, read_write> free_slots: array<u32>;
+/// Before the step, where the level's sweep starts
+@group(0) @binding(15) var<storage, read_write> aabbs: array<AABB>;
+
+const WORKGROUP_SIZE: u32 = 64;
+const TAU: f32 = 6.28318530718;
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn list_free_slots(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) {
+        return;
+    }
+    let free_index = prefixes[i].x;
+    if prefixes[i + 1].x > free_index {
+        free_slots[free_index] = i;
+    }
+}
+
+/// The lower index of a merging pair does the merge, a shattering circle spawns its own fragments
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn resolve_impacts(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) {
+        return;
+    }
+    let impact = impacts[i];
+    if impact.partner == IMPACT_SHATTER {
+        shatter(i, impact.speed);
+    } else if impact.partner != IMPACT_NONE && i < impact.partner && impacts[impact.partner].partner == i {
+        merge(i, impact.partner);
+    }
+}
+
+/// Merges `j` into `i` and destroys `j`
+fn merge(i: u32, j: u32) {
+    let mi = masses[i].inner;
+    let mj = masses[j].inner;
+    let mass = mi + mj;
+    let ci = center(integrated_aabbs[i]);
+    let cj = center(integrated_aabbs[j]);
+    let vi = integrated_velocities[i].inner;
+    let vj = integrated_velocities[j].inner;
+    let position = (ci * mi + cj * mj) / mass;
+    let velocity = (vi * mi + vj * mj) / mass;
+    let ri = sizes[i].inner.x / 2;
+    let rj = sizes[j].inner.x / 2;
+    let radius = sqrt(ri * ri + rj * rj);
+
+    // Spins and orbits about the common center of mass, a uniform disk has a moment of inertia of m r² / 2
+    let angular_momentum = mi * ri * ri / 2 * angular_velocities[i].inner + mj * rj * rj / 2
+        * angular_velocities[j].inner + mi * cross(ci - position, vi - velocity)
+        + mj * cross(cj - position, vj - velocity);
+
+    flags[i].inner &= ~FLAG_SLEEPING;
+    masses[i].inner = mass;
+    sizes[i].inner = vec2f(radius * 2);
+    moments[i] = circle_moments(radius);
+    colors[i].inner = (colors[i].inner * mi + colors[j].inner * mj) / mass;
+    integrated_aabbs[i] = AABB(position - radius, position + radius);
+    integrated_velocities[i].inner = velocity;
+    angular_velocities[i].inner = angular_momentum / (mass * radius * radius / 2);
+
+    flags[j].inner = (flags[j].inner & ~(FLAG_PHYSICAL | FLAG_DRAW_OBJECT | FLAG_DRAW_AABB)) | FLAG_DESTROYED;
+    integrated_velocities[j].inner = vec2f();
+    angular_velocities[j].inner = 0;
+}
+
+/// Replaces `i` with its fragments, the first one takes its slot and the others take the free slots it reserved
+fn shatter(i: u32, speed: f32) {
+    let material = material_table.materials[materials[i].inner];
+    let object_count = arrayLength(&flags);
+    let first_slot = prefixes[i].y;
+    let free_count = prefixes[object_count].x;
+    let available = select(0u, free_count - first_slot, free_count > first_slot);
+    let fragment_count = 1 + min(max(material.fragment_count, 1) - 1, available);
+    if fragment_count < 2 {
+        return;
+    }
+
+    let f = flags[i].inner & ~FLAG_SLEEPING;
+    let mass = masses[i].inner / f32(fragment_count);
+    let position = center(integrated_aabbs[i]);
+    let start = center(aabbs[i]);
+    let velocity = integrated_velocities[i].inner;
+    let angle = integrated_angles[i].inner;
+    let angular_velocity = angular_velocities[i].inner;
+    let radius = sizes[i].inner.x / 2;
+    let fragment_radius = radius / sqrt(f32(fragment_count));
+    let color = colors[i].inner;
+    let material_id = materials[i].inner;
+    for (var k: u32 = 0; k < fragment_count; k++) {
+        var slot = i;
+        if k > 0 {
+            slot = free_slots[first_slot + k - 1];
+        }
+        let direction_angle = angle + TAU * f32(k) / f32(fragment_count);
+        let direction = vec2f(cos(direction_angle), sin(direction_angle));
+        let offset = direction * (radius - fragment_radius);
+        let fragment_position = position + offset;
+
+        flags[slot].inner = f;
+        masses[slot].inner = mass;
+        sizes[slot].inner = vec2f(fragment_radius * 2);
+        shapes[slot].inner = SHAPE_CIRCLE;
+        moments[slot] = circle_moments(fragment_radius);
+        colors[slot].inner = color;
+        materials[slot].inner = material_id;
+        integrated_aabbs[slot] = AABB(fragment_position - fragment_radius, fragment_position + fragment_radius);
+        // The fragments start the step inside the circle, otherwise the level's sweep would start from wherever the
+        // slot's previous object was
+        aabbs[slot] = AABB(start + offset - fragment_radius, start + offset + fragment_radius);
+        // The spin carries over to the fragments as their motion along the ring
+        integrated_velocities[slot].inner =
+            velocity + direction * material.spread * speed + angular_velocity * vec2f(-offset.y, offset.x);
+        integrated_angles[slot].inner = angle;
+        angular_velocities[slot].inner = angular_velocity;
+    }
+}
+
+/// Second moments per unit of mass, like `second_moments` on the CPU
+fn circle_moments(radius: f32) -> SecondMoments {
+    return SecondMoments(radius * radius / 4, radius * radius / 4, 0.0);
+}
+
+fn cross(a: vec2f, b: vec2f) -> f32 {
+    return a.x * b.y - a.y * b.x;
+}
+
+fn center(aabb: AABB) -> vec2f {
+    return (aabb.min + aabb.max) / 2;
+}
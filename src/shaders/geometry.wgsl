@@ -0,0 +1,62 @@
+#define_import_path geometry
+
+// Outlines of the objects, shared by the passes that need them exactly. The bindings are declared here, so importers
+// must not declare them again and must leave their binding numbers free.
+
+#import common::{SHAPE_CIRCLE, SHAPE_POLYGON, SHAPE_CAPSULE, Shape, Size, Polygon, PolygonVertex, rotation, rotated_half_extents}
+
+@group(0) @binding(20) var<storage, read> shapes: array<Shape>;
+@group(0) @binding(21) var<storage, read> sizes: array<Size>;
+@group(0) @binding(22) var<storage, read> polygons: array<Polygon>;
+@group(0) @binding(23) var<storage, read> polygon_vertices: array<PolygonVertex>;
+
+/// Half the extent of the AABB of object `i` rotated by `angle`. Like all AABBs it is centered on the object, for
+/// polygons that is the centroid.
+fn object_half_extents(i: u32, angle: f32) -> vec2f {
+    let shape = shapes[i].inner;
+    if shape != SHAPE_POLYGON {
+        return rotated_half_extents(shape, sizes[i].inner, angle);
+    }
+    let r = rotation(angle);
+    let polygon = polygons[i];
+    var half_extents = vec2f();
+    for (var v = polygon.offset; v < polygon.offset + polygon.count; v++) {
+        half_extents = max(half_extents, abs(r * polygon_vertices[v].inner));
+    }
+    return half_extents;
+}
+
+/// Point of object `i` rotated by `angle` furthest along `direction`, relative to its center
+fn support(i: u32, angle: f32, direction: vec2f) -> vec2f {
+    let size = sizes[i].inner;
+    let r = rotation(angle);
+    let local_direction = transpose(r) * direction;
+    let unit = local_direction / max(length(local_direction), 1e-20);
+    var local = vec2f();
+    switch shapes[i].inner {
+        case SHAPE_CIRCLE: {
+            local = unit * size.x / 2;
+        }
+        case SHAPE_CAPSULE: {
+            let radius = size.y / 2;
+            let end = size.x / 2 - radius;
+            local = vec2f(select(-end, end, local_direction.x >= 0), 0) + unit * radius;
+        }
+        case SHAPE_POLYGON: {
+            let polygon = polygons[i];
+            var best = -1e30;
+            for (var v = polygon.offset; v < polygon.offset + polygon.count; v++) {
+                let vertex = polygon_vertices[v].inner;
+                let distance = dot(vertex, local_direction);
+                if distance > best {
+                    best = distance;
+                    local = vertex;
+                }
+            }
+        }
+        default: {
+            local = select(-size / 2, size / 2, local_direction >= vec2f());
+        }
+    }
+    return r * local;
+}
@@ -0,0 +1,128 @@
+#import common::{ UNIT_QUAD_VERTICES, Camera }
+#import heatmap_grid::{
+    HEATMAP_QUANTITY_DENSITY,
+    HEATMAP_QUANTITY_SPEED,
+    HEATMAP_QUANTITY_DIVERGENCE,
+    HEATMAP_QUANTITY_VORTICITY,
+    HEATMAP_FIXED_POINT_SCALE,
+    HeatmapParameters,
+    heatmap_cell_index,
+}
+
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<uniform> parameters: HeatmapParameters;
+@group(0) @binding(2) var<storage, read> cells: array<i32>;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4f,
+    /// 0..1 over the grid or the legend
+    @location(0) uv: vec2f,
+}
+
+/// Legend bar in the bottom right corner, in clip space. Its labels are placed by `legend_labels` in
+/// `heatmap_renderer.rs`, which has the same corners.
+const LEGEND_MIN: vec2f = vec2f(0.5, -0.95);
+const LEGEND_MAX: vec2f = vec2f(0.95, -0.9);
+
+@vertex
+fn vs_overlay(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let quad_position = UNIT_QUAD_VERTICES[vertex_index];
+    let uv = quad_position + 0.5;
+    let world_position = mix(parameters.world_min, parameters.world_max, uv);
+    var out = VertexOutput();
+    out.clip_position = camera.inner * vec4f(world_position, 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+@fragment
+fn fs_overlay(in: VertexOutput) -> @location(0) vec4f {
+    let cell = min(vec2u(in.uv * vec2f(parameters.grid_size)), parameters.grid_size - 1);
+    let value = cell_value(cell) / parameters.value_range;
+    if value == 0 {
+        discard;
+    }
+    return vec4f(colormap(value), parameters.opacity);
+}
+
+@vertex
+fn vs_legend(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let uv = UNIT_QUAD_VERTICES[vertex_index] + 0.5;
+    var out = VertexOutput();
+    out.clip_position = vec4f(mix(LEGEND_MIN, LEGEND_MAX, uv), 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+@fragment
+fn fs_legend(in: VertexOutput) -> @location(0) vec4f {
+    var value = in.uv.x;
+    if is_signed() {
+        value = value * 2 - 1;
+    }
+    return vec4f(colormap(value), 1.0);
+}
+
+fn cell_value(cell: vec2u) -> f32 {
+    switch parameters.quantity {
+        case HEATMAP_QUANTITY_DENSITY: {
+            return f32(cells[heatmap_cell_index(parameters, cell)]);
+        }
+        case HEATMAP_QUANTITY_SPEED: {
+            let index = heatmap_cell_index(parameters, cell);
+            let count = cells[index];
+            if count == 0 {
+                return 0.0;
+            }
+            return f32(cells[index + 1]) / f32(count) / HEATMAP_FIXED_POINT_SCALE;
+        }
+        case HEATMAP_QUANTITY_DIVERGENCE: {
+            let gradient = velocity_gradient(cell);
+            return gradient[0].x + gradient[1].y;
+        }
+        case HEATMAP_QUANTITY_VORTICITY: {
+            let gradient = velocity_gradient(cell);
+            return gradient[0].y - gradient[1].x;
+        }
+        default: {
+            return 0.0;
+        }
+    }
+}
+
+/// Central differences of the mean velocity, column 0 is d/dx and column 1 is d/dy
+fn velocity_gradient(cell: vec2u) -> mat2x2f {
+    let last = vec2i(parameters.grid_size) - 1;
+    let c = vec2i(cell);
+    let left = vec2i(max(c.x - 1, 0), c.y);
+    let right = vec2i(min(c.x + 1, last.x), c.y);
+    let down = vec2i(c.x, max(c.y - 1, 0));
+    let up = vec2i(c.x, min(c.y + 1, last.y));
+    let cell_size = (parameters.world_max - parameters.world_min) / vec2f(parameters.grid_size);
+    let d_dx = (mean_velocity(right) - mean_velocity(left)) / (f32(right.x - left.x) * cell_size.x);
+    let d_dy = (mean_velocity(up) - mean_velocity(down)) / (f32(up.y - down.y) * cell_size.y);
+    return mat2x2f(d_dx, d_dy);
+}
+
+fn mean_velocity(cell: vec2i) -> vec2f {
+    let index = heatmap_cell_index(parameters, vec2u(cell));
+    let count = cells[index];
+    if count == 0 {
+        return vec2f(0);
+    }
+    return vec2f(f32(cells[index + 2]), f32(cells[index + 3])) / f32(count) / HEATMAP_FIXED_POINT_SCALE;
+}
+
+fn is_signed() -> bool {
+    return parameters.quantity == HEATMAP_QUANTITY_DIVERGENCE || parameters.quantity == HEATMAP_QUANTITY_VORTICITY;
+}
+
+/// Sequential black-red-yellow-white map for magnitudes and a blue-black-red map for signed quantities
+fn colormap(value: f32) -> vec3f {
+    if is_signed() {
+        let t = clamp(value, -1.0, 1.0);
+        return select(vec3f(0.2, 0.5, 1.0), vec3f(1.0, 0.3, 0.1), t > 0) * sqrt(abs(t));
+    }
+    let t = clamp(value, 0.0, 1.0);
+    return clamp(vec3f(3 * t, 3 * t - 1, 3 * t - 2), vec3f(0), vec3f(1));
+}
@@ -0,0 +1,26 @@
+#define_import_path heatmap_grid
+
+const HEATMAP_QUANTITY_DENSITY: u32 = 0;
+const HEATMAP_QUANTITY_SPEED: u32 = 1;
+const HEATMAP_QUANTITY_DIVERGENCE: u32 = 2;
+const HEATMAP_QUANTITY_VORTICITY: u32 = 3;
+
+/// Velocities are accumulated as fixed point numbers, because there are no float atomics
+const HEATMAP_FIXED_POINT_SCALE: f32 = 16;
+
+/// Every cell consists of this many consecutive values: object count, speed sum, velocity x sum, velocity y sum
+const HEATMAP_CELL_STRIDE: u32 = 4;
+
+struct HeatmapParameters {
+    world_min: vec2f,
+    world_max: vec2f,
+    grid_size: vec2u,
+    quantity: u32,
+    /// Values are divided by this before mapping them to colors
+    value_range: f32,
+    opacity: f32,
+}
+
+fn heatmap_cell_index(parameters: HeatmapParameters, cell: vec2u) -> u32 {
+    return (cell.y * parameters.grid_size.x + cell.x) * HEATMAP_CELL_STRIDE;
+}
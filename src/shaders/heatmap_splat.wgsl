@@ -0,0 +1,33 @@
+#import common::{ FLAG_PHYSICAL, AABB, Flags, Velocity, invocation_index }
+#import heatmap_grid::{ HEATMAP_FIXED_POINT_SCALE, HeatmapParameters, heatmap_cell_index }
+
+@group(0) @binding(0) var<uniform> parameters: HeatmapParameters;
+@group(0) @binding(1) var<storage, read> flags: array<Flags>;
+@group(0) @binding(2) var<storage, read> aabbs: array<AABB>;
+@group(0) @binding(3) var<storage, read> velocities: array<Velocity>;
+@group(0) @binding(4) var<storage, read_write> cells: array<atomic<i32>>;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn splat(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) || (flags[i].inner & FLAG_PHYSICAL) == 0 {
+        return;
+    }
+
+    let aabb = aabbs[i];
+    let center = (aabb.min + aabb.max) / 2;
+    let relative_position = (center - parameters.world_min) / (parameters.world_max - parameters.world_min);
+    if any(relative_position < vec2f(0)) || any(relative_position >= vec2f(1)) {
+        return;
+    }
+
+    let cell = vec2u(relative_position * vec2f(parameters.grid_size));
+    let index = heatmap_cell_index(parameters, cell);
+    let velocity = velocities[i].inner;
+    atomicAdd(&cells[index], 1);
+    atomicAdd(&cells[index + 1], i32(length(velocity) * HEATMAP_FIXED_POINT_SCALE));
+    atomicAdd(&cells[index + 2], i32(velocity.x * HEATMAP_FIXED_POINT_SCALE));
+    atomicAdd(&cells[index + 3], i32(velocity.y * HEATMAP_FIXED_POINT_SCALE));
+}
@@ -0,0 +1,160 @@
+// Finds the circles that merge or shatter in this step and reserves free slots for the fragments. A free slot is an
+// object that is neither drawn nor physical, like a destroyed one, and has no constraints that a fragment would
+// inherit.
+//
+// The slots are handed out in index order by a prefix sum over every object's free slot and fragment demand, so the
+// same step always puts the same fragments into the same slots.
+
+#import common::{
+    FLAG_DRAW_OBJECT, FLAG_PHYSICAL, FLAG_DESTROYED, SHAPE_CIRCLE, AABB, Velocity, Flags, BvhNode,
+    Shape, invocation_index
+}
+#import bvh_traversal::{bvh_traversal, bvh_pop, bvh_descend, aabbs_overlap}
+#import materials::{MATERIAL_INERT, IMPACT_NONE, IMPACT_SHATTER, MaterialTable, MaterialId, Impact}
+
+@group(0) @binding(0) var<uniform> material_table: MaterialTable;
+@group(0) @binding(1) var<storage, read> flags: array<Flags>;
+@group(0) @binding(2) var<storage, read> shapes: array<Shape>;
+@group(0) @binding(3) var<storage, read> aabbs: array<AABB>;
+@group(0) @binding(4) var<storage, read> nodes: array<BvhNode>;
+@group(0) @binding(5) var<storage, read> velocities: array<Velocity>;
+@group(0) @binding(6) var<storage, read> materials: array<MaterialId>;
+/// Non-zero for objects with constraints, their slots are never reused
+@group(0) @binding(7) var<storage, read> linked: array<u32>;
+@group(0) @binding(8) var<storage, read_write> impacts: array<Impact>;
+/// Free slots and requested fragment slots before every object, one longer than the objects to hold the totals
+@group(0) @binding(9) var<storage, read_write> prefixes: array<vec2u>;
+/// Sum of every workgroup of `scan_workgroups` and then the offset of it, the last entry holds the totals
+@group(0) @binding(10) var<storage, read_write> workgroup_totals: array<vec2u>;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+var<workgroup> scratch: array<vec2u, WORKGROUP_SIZE>;
+
+/// Decides between merging, shattering and nothing, and writes what the object adds to the prefix sums
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn find_impacts(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) {
+        return;
+    }
+    let free = (flags[i].inner & (FLAG_PHYSICAL | FLAG_DRAW_OBJECT)) == 0 && linked[i] == 0;
+    var impact = Impact(IMPACT_NONE, 0.0);
+    if hits(i) && materials[i].inner != MATERIAL_INERT {
+        impact = find_impact(i);
+    }
+    impacts[i] = impact;
+    var fragment_slots = 0u;
+    if impact.partner == IMPACT_SHATTER {
+        fragment_slots = max(material_table.materials[materials[i].inner].fragment_count, 1) - 1;
+    }
+    prefixes[i] = vec2u(u32(free), fragment_slots);
+}
+
+/// Exclusive prefix sums within every workgroup
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn scan_workgroups(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    let object_count = arrayLength(&flags);
+    var value = vec2u();
+    if i < object_count {
+        value = prefixes[i];
+    }
+    scratch[local_index] = value;
+    workgroupBarrier();
+    var sum = vec2u();
+    for (var k: u32 = 0; k < local_index; k++) {
+        sum += scratch[k];
+    }
+    if i < object_count {
+        prefixes[i] = sum;
+    }
+    let workgroup_index = i / WORKGROUP_SIZE;
+    if local_index == WORKGROUP_SIZE - 1 && workgroup_index < arrayLength(&workgroup_totals) - 1 {
+        workgroup_totals[workgroup_index] = sum + value;
+    }
+}
+
+/// Turns the workgroup sums into offsets, serially as there are few of them
+@compute @workgroup_size(1)
+fn scan_totals() {
+    let workgroup_count = arrayLength(&workgroup_totals) - 1;
+    var sum = vec2u();
+    for (var k: u32 = 0; k < workgroup_count; k++) {
+        let total = workgroup_totals[k];
+        workgroup_totals[k] = sum;
+        sum += total;
+    }
+    workgroup_totals[workgroup_count] = sum;
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn add_offsets(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    let object_count = arrayLength(&flags);
+    if i >= object_count {
+        return;
+    }
+    prefixes[i] += workgroup_totals[i / WORKGROUP_SIZE];
+    if i == object_count - 1 {
+        prefixes[object_count] = workgroup_totals[arrayLength(&workgroup_totals) - 1];
+    }
+}
+
+/// Physical circles are the only objects that merge and shatter
+fn hits(i: u32) -> bool {
+    return (flags[i].inner & (FLAG_PHYSICAL | FLAG_DESTROYED)) == FLAG_PHYSICAL && shapes[i].inner == SHAPE_CIRCLE;
+}
+
+/// Shatters at the fastest hit above the shatter speed, otherwise merges with the lowest indexed circle it hits above
+/// both merge speeds
+fn find_impact(i: u32) -> Impact {
+    let material = material_table.materials[materials[i].inner];
+    let aabb = aabbs[i];
+    var impact = Impact(IMPACT_NONE, 0.0);
+    var traversal = bvh_traversal(arrayLength(&flags));
+    while traversal.size > 0 {
+        let node_index = bvh_pop(&traversal);
+        if !aabbs_overlap(aabb, aabbs[node_index]) {
+            continue;
+        }
+
+        let node = nodes[node_index].index;
+        if bvh_descend(&traversal, node) {
+            continue;
+        }
+        if node == i || !hits(node) || !circles_touch(aabb, aabbs[node]) {
+            continue;
+        }
+
+        // Separating circles don't hit, like the fragments of a shattered one
+        let relative = velocities[i].inner - velocities[node].inner;
+        if dot(relative, center(aabbs[node]) - center(aabb)) <= 0 {
+            continue;
+        }
+        let speed = length(relative);
+        let merge_speed = max(material.merge_speed, material_table.materials[materials[node].inner].merge_speed);
+        if speed > material.shatter_speed {
+            impact = Impact(IMPACT_SHATTER, max(impact.speed, speed));
+        } else if speed > merge_speed && impact.partner != IMPACT_SHATTER && node < impact.partner {
+            impact.partner = node;
+        }
+    }
+    return impact;
+}
+
+fn circles_touch(a: AABB, b: AABB) -> bool {
+    let radii = (a.max.x - a.min.x + b.max.x - b.min.x) / 2;
+    return length(center(a) - center(b)) <= radii;
+}
+
+fn center(aabb: AABB) -> vec2f {
+    return (aabb.min + aabb.max) / 2;
+}
@@ -1,41 +1,71 @@
 #import common::{
-    FLAG_DRAW_OBJECT, FLAG_PHYSICAL, FLAG_DRAW_AABB,
-    AABB, Mass, Velocity, Position, Flags, BvhNode,
-    invocation_index
+    FLAG_PHYSICAL, FLAG_SLEEPING, SHAPE_CIRCLE, AABB, Mass, Velocity, Position, Flags, Angle, AngularVelocity, SecondMoments,
+    invocation_index, second_moment_tensor, moment_of_inertia
 }
+#import geometry::{shapes, sizes, object_half_extents}
+#import tool_state::ToolState
+#import blackholes::{BLACKHOLE_COUNT, GRAVITATIONAL_CONSTANT, BlackHoleSet, blackhole_gravity, frame_dragging}
 
-@group(0) @binding(0) var<uniform> dt: f32;
-@group(0) @binding(1) var<storage, read_write> flags: array<Flags>;
+@group(0) @binding(0) var<uniform> parameters: IntegrationParameters;
+@group(0) @binding(1) var<storage, read> flags: array<Flags>;
 @group(0) @binding(2) var<storage, read> masses: array<Mass>;
 @group(0) @binding(3) var<storage, read_write> velocities: array<Velocity>;
 @group(0) @binding(4) var<storage, read_write> aabbs: array<AABB>;
-@group(0) @binding(5) var<storage, read> nodes: array<BvhNode>;
 @group(0) @binding(6) var<storage, read_write> integrated_velocities: array<Velocity>;
 @group(0) @binding(7) var<storage, read_write> integrated_aabbs: array<AABB>;
+@group(0) @binding(9) var<uniform> tools: ToolState;
+@group(0) @binding(10) var<uniform> force_set: ForceSet;
+@group(0) @binding(11) var<storage, read> angles: array<Angle>;
+// Updated in place, the contact pass after the integrator reads the angular velocities after the step
+@group(0) @binding(12) var<storage, read_write> angular_velocities: array<AngularVelocity>;
+@group(0) @binding(13) var<storage, read_write> integrated_angles: array<Angle>;
+@group(0) @binding(14) var<storage, read> moments: array<SecondMoments>;
+/// A uniform as the storage buffers are used up, the accretion pass updates it after the step
+@group(0) @binding(15) var<uniform> blackhole_set: BlackHoleSet;
 
 const WORKGROUP_SIZE: u32 = 64;
 
-struct BlackHole {
-    position: vec2f,
+const INTEGRATOR_EULER_SYMPLECTIC: u32 = 0;
+const INTEGRATOR_LEAPFROG: u32 = 1;
+const INTEGRATOR_RK4: u32 = 2;
+
+struct IntegrationParameters {
+    dt: f32,
+    /// One of the `INTEGRATOR_*` constants
+    integrator: u32,
+}
+
+const FORCE_UNIFORM_GRAVITY: u32 = 0;
+const FORCE_POINT_ATTRACTOR: u32 = 1;
+const FORCE_LINEAR_DRAG: u32 = 2;
+const FORCE_QUADRATIC_DRAG: u32 = 3;
+const FORCE_VORTEX: u32 = 4;
+const FORCE_RADIAL_EXPLOSION: u32 = 5;
+const FORCE_WIND: u32 = 6;
+
+const MAX_FORCES: u32 = 32;
+
+/// One entry of the force registry, the meaning of the fields depends on `kind`. All forces are accelerations, they
+/// act the same on every mass.
+struct Force {
+    /// One of the `FORCE_*` constants
+    kind: u32,
+    /// Attractor and vortex strength, explosion peak acceleration or drag coefficient
+    strength: f32,
+    /// Softening radius of the attractor and vortex, reach of the explosion
     radius: f32,
-    mass: f32,
-    spin: f32
+    /// Center of the attractor, vortex and explosion
+    position: vec2f,
+    /// Acceleration of uniform gravity, air velocity of the wind
+    vector: vec2f,
 }
 
-const BLACKHOLE_COUNT: u32 = 5;
-const BLACKHOLES = array<BlackHole, BLACKHOLE_COUNT>(
-    BlackHole(vec2f(-200, 500),     2,  10,  0 * -50),
-    BlackHole(vec2f(500, 200),      1,  20,  0 * -50),
-    BlackHole(vec2f(),              2,  10,  1 * 50),
-    BlackHole(vec2f(-600, -300),    1,  20,  0 * -50),
-    BlackHole(vec2f(600, -700),     1,  10,  0 * -50),
-);
-const BLACKHOLE_MASS_SCALE: f32 = 1 * 1000;
-const BLACKHOLE_SIZE_SCALE: f32 = 10;
-const BLACKHOLE_DESTROY_MATTER: bool = true;
-const GRAVITATIONAL_CONSTANT: f32 = 1 * 100000;
-
-const GLOBAL_FORCE = vec2f();
+/// Forces acting on all physical objects besides the black holes and the cursor
+struct ForceSet {
+    // Uniform arrays have to be 16 byte aligned, which the first member always is
+    forces: array<Force, MAX_FORCES>,
+    count: u32,
+}
 
 @compute @workgroup_size(WORKGROUP_SIZE)
 fn cs_main(
@@ -46,32 +76,51 @@ fn cs_main(
         return;
     }
 
-    var f = flags[i].inner;
-    if (f & FLAG_PHYSICAL) == 0 {
+    let f = flags[i].inner;
+    if (f & FLAG_PHYSICAL) == 0 || (f & FLAG_SLEEPING) != 0 {
+        // The integrated buffers are copied back for all objects, so the others have to keep their state
+        integrated_velocities[i] = velocities[i];
+        integrated_aabbs[i] = aabbs[i];
+        integrated_angles[i] = angles[i];
         return;
     }
 
     let aabb = aabbs[i];
     let start_position = (aabb.min + aabb.max) / 2;
     var state = State(start_position, velocities[i].inner);
-    state = integrate_euler_symplectic(state);
-
-    let size = aabb.max - aabb.min;
-    if BLACKHOLE_DESTROY_MATTER {
-        for (var bh_index: u32 = 0; bh_index < BLACKHOLE_COUNT && (f & FLAG_PHYSICAL) != 0; bh_index++) {
-            let blackhole = BLACKHOLES[bh_index];
-            let distance = length(blackhole.position - state.position) - max(size.x, size.y) / 2;
-            if distance < blackhole.radius * BLACKHOLE_SIZE_SCALE {
-                f &= ~(FLAG_PHYSICAL | FLAG_DRAW_OBJECT | FLAG_DRAW_AABB);
-                state.velocity = vec2f();
-            }
+
+    let size = sizes[i].inner;
+    let mass = masses[i].inner;
+    var angle = angles[i].inner;
+    var angular_velocity = angular_velocities[i].inner;
+    let inertia = moment_of_inertia(moments[i], mass);
+    // Circles are round and their contacts frictionless, so their spin changes nothing. Skipping them saves the
+    // torque's force evaluations in scenes of many circles.
+    if inertia > 0 && shapes[i].inner != SHAPE_CIRCLE {
+        angular_velocity += torque(state, angle, angular_velocity, moments[i], size, mass) / inertia * parameters.dt;
+    }
+    angle += angular_velocity * parameters.dt;
+
+    switch parameters.integrator {
+        case INTEGRATOR_LEAPFROG: {
+            state = integrate_leapfrog(state);
+        }
+        case INTEGRATOR_RK4: {
+            state = integrate_rk4(state);
+        }
+        default: {
+            state = integrate_euler_symplectic(state);
         }
     }
+    if i == tools.grabbed {
+        state = State(tools.cursor, tools.grab_velocity);
+    }
 
-    flags[i].inner = f;
+    let half_extents = object_half_extents(i, angle);
     integrated_velocities[i].inner = state.velocity;
-    let offset = state.position - start_position;
-    integrated_aabbs[i] = AABB(aabb.min + offset, aabb.max + offset);
+    integrated_aabbs[i] = AABB(state.position - half_extents, state.position + half_extents);
+    integrated_angles[i].inner = angle;
+    angular_velocities[i].inner = angular_velocity;
 }
 
 struct State {
@@ -80,6 +129,7 @@ struct State {
 }
 
 fn integrate_euler_symplectic(state: State) -> State {
+    let dt = parameters.dt;
     let a = forces(state);
     var new_state = state;
     new_state.velocity += a * dt;
@@ -87,31 +137,127 @@ fn integrate_euler_symplectic(state: State) -> State {
     return new_state;
 }
 
+/// Kick-drift-kick, second order and symplectic for position dependent forces
+fn integrate_leapfrog(state: State) -> State {
+    let dt = parameters.dt;
+    var new_state = state;
+    new_state.velocity += forces(new_state) * dt / 2;
+    new_state.position += new_state.velocity * dt;
+    new_state.velocity += forces(new_state) * dt / 2;
+    return new_state;
+}
+
+/// Classic fourth order Runge-Kutta, not symplectic but accurate for velocity dependent forces
+fn integrate_rk4(state: State) -> State {
+    let dt = parameters.dt;
+    let k1 = State(state.velocity, forces(state));
+    let k2 = derivative(state, k1, dt / 2);
+    let k3 = derivative(state, k2, dt / 2);
+    let k4 = derivative(state, k3, dt);
+    return State(
+        state.position + (k1.position + 2 * k2.position + 2 * k3.position + k4.position) * dt / 6,
+        state.velocity + (k1.velocity + 2 * k2.velocity + 2 * k3.velocity + k4.velocity) * dt / 6,
+    );
+}
+
+/// Derivative at `state` advanced by `h` along `slope`, the position field holds the velocity and the velocity
+/// field the acceleration
+fn derivative(state: State, slope: State, h: f32) -> State {
+    let advanced = State(state.position + slope.position * h, state.velocity + slope.velocity * h);
+    return State(advanced.velocity, forces(advanced));
+}
+
 fn forces(state: State) -> vec2f {
-    var acc = GLOBAL_FORCE;
+    var acc = vec2f();
+    for (var force_index: u32 = 0; force_index < min(force_set.count, MAX_FORCES); force_index += 1) {
+        acc += registered_force(force_set.forces[force_index], state);
+    }
     for (var bh_index: u32 = 0; bh_index < BLACKHOLE_COUNT; bh_index += 1) {
-        var blackhole = BLACKHOLES[bh_index];
+        let blackhole = blackhole_set.blackholes[bh_index];
         acc += blackhole_gravity(blackhole, state.position);
-        acc += frame_dragging(blackhole, state);
+        acc += frame_dragging(blackhole, state.position, state.velocity);
     }
+    acc += cursor_force(state.position);
     return acc;
 }
 
-fn blackhole_gravity(blackhole: BlackHole, position: vec2f) -> vec2f {
-    let to_blackhole = blackhole.position - position;
-    let direction = normalize(to_blackhole);
-    let distance = length(to_blackhole);
-    let bh_gravity = direction * GRAVITATIONAL_CONSTANT * blackhole.mass * BLACKHOLE_MASS_SCALE / (distance * distance);
-    return bh_gravity;
+/// Torque of the force fields on an extended body, from how they change across it. Uniform fields exert none, tidal
+/// gravity turns elongated bodies towards the black holes, curl like frame dragging or vortices spins bodies up, and
+/// velocity dependent forces like drag act on the spin as well.
+fn torque(state: State, angle: f32, angular_velocity: f32, moments: SecondMoments, size: vec2f, mass: f32) -> f32 {
+    let second_moment = second_moment_tensor(moments, mass, angle);
+
+    // Central differences over the body's extent
+    let h = max(max(size.x, size.y) / 2, 1e-3);
+    let dx = vec2f(h, 0);
+    let dy = vec2f(0, h);
+    var field = mat2x2f(
+        (forces(State(state.position + dx, state.velocity)) - forces(State(state.position - dx, state.velocity))),
+        (forces(State(state.position + dy, state.velocity)) - forces(State(state.position - dy, state.velocity))),
+    ) * (1 / (2 * h));
+    if angular_velocity != 0 {
+        // Relative to the center the material moves at the angular velocity times the perpendicular offset
+        let hv = max(length(state.velocity), 10.0) * 0.1;
+        let dvx = vec2f(hv, 0);
+        let dvy = vec2f(0, hv);
+        let velocity_jacobian = mat2x2f(
+            (forces(State(state.position, state.velocity + dvx)) - forces(State(state.position, state.velocity - dvx))),
+            (forces(State(state.position, state.velocity + dvy)) - forces(State(state.position, state.velocity - dvy))),
+        ) * (1 / (2 * hv));
+        field += velocity_jacobian * mat2x2f(0, 1, -1, 0) * angular_velocity;
+    }
+
+    // The z component of the integral of offset x (field * offset) over the body
+    let moment = field * second_moment;
+    return moment[0][1] - moment[1][0];
+}
+
+fn cursor_force(position: vec2f) -> vec2f {
+    if tools.cursor_strength == 0 {
+        return vec2f();
+    }
+    let to_cursor = tools.cursor - position;
+    let distance_squared = dot(to_cursor, to_cursor) + tools.cursor_softening * tools.cursor_softening;
+    return to_cursor * GRAVITATIONAL_CONSTANT * tools.cursor_strength / pow(distance_squared, 1.5);
 }
 
-// Lense–Thirring formula for 2D
-// NOTE: some terms are missing and have to be reintroduced for 3D
-fn frame_dragging(blackhole: BlackHole, state: State) -> vec2f {
-    let r_vec = blackhole.position - state.position;
-    let r = length(r_vec);
-    let J = blackhole.spin; // scalar angular momentum (Jz)
-    let v_perp = vec2f(-state.velocity.y, state.velocity.x); // v rotated by +90 degrees
-    let a = (2.0 * GRAVITATIONAL_CONSTANT * J / pow(r, 3.0)) * v_perp;
-    return a;
+fn registered_force(force: Force, state: State) -> vec2f {
+    switch force.kind {
+        case FORCE_UNIFORM_GRAVITY: {
+            return force.vector;
+        }
+        case FORCE_POINT_ATTRACTOR: {
+            let to_center = force.position - state.position;
+            let distance_squared = dot(to_center, to_center) + force.radius * force.radius;
+            return to_center * force.strength / pow(distance_squared, 1.5);
+        }
+        case FORCE_LINEAR_DRAG: {
+            return -force.strength * state.velocity;
+        }
+        case FORCE_QUADRATIC_DRAG: {
+            return -force.strength * length(state.velocity) * state.velocity;
+        }
+        case FORCE_VORTEX: {
+            // Tangential, counterclockwise for positive strength, and falling off like a point vortex outside the core
+            let from_center = state.position - force.position;
+            let tangent = vec2f(-from_center.y, from_center.x);
+            return tangent * force.strength / (dot(from_center, from_center) + force.radius * force.radius);
+        }
+        case FORCE_RADIAL_EXPLOSION: {
+            // Falls off linearly from the center to zero at the radius
+            let from_center = state.position - force.position;
+            let distance = length(from_center);
+            if distance >= force.radius || distance == 0 {
+                return vec2f();
+            }
+            return from_center / distance * force.strength * (1 - distance / force.radius);
+        }
+        case FORCE_WIND: {
+            // Linear drag relative to the moving air
+            return force.strength * (force.vector - state.velocity);
+        }
+        default: {
+            return vec2f();
+        }
+    }
 }
@@ -0,0 +1,40 @@
+#define_import_path materials
+
+// What happens when circles hit each other fast, shared by the impact search and the pass that merges and shatters
+
+const MAX_MATERIALS: u32 = 16;
+/// Material of all objects unless set otherwise, it never merges or shatters
+const MATERIAL_INERT: u32 = 0;
+
+/// Impact speeds are the relative speeds of approaching circles
+struct Material {
+    /// Two touching circles merge above the larger of their merge speeds
+    merge_speed: f32,
+    /// A circle shatters above this, even if it would merge
+    shatter_speed: f32,
+    /// Circles a shattered one breaks into, fewer if there aren't enough free slots
+    fragment_count: u32,
+    /// Speed the fragments fly apart at, as a share of the impact speed
+    spread: f32,
+}
+
+/// Uniform arrays need a 16 byte stride, which `Material` has
+struct MaterialTable {
+    materials: array<Material, MAX_MATERIALS>,
+}
+
+/// Index into the material table
+struct MaterialId {
+    inner: u32
+}
+
+const IMPACT_NONE: u32 = 0xffffffff;
+const IMPACT_SHATTER: u32 = 0xfffffffe;
+
+/// Outcome of an object's hits in the current step
+struct Impact {
+    /// Object to merge with, `IMPACT_SHATTER` or `IMPACT_NONE`. A merge happens only if both chose each other.
+    partner: u32,
+    /// Fastest hit that shatters the object
+    speed: f32,
+}
@@ -0,0 +1,89 @@
+#import common::{
+    FLAG_DRAW_OBJECT, AABB, BvhNode, Color, Flags, Mass, Shape, Velocity, Angle, AngularVelocity
+}
+#import bvh_traversal::{bvh_traversal, bvh_pop, bvh_descend}
+
+const PICK_NONE: u32 = 0xffffffff;
+
+struct PickQuery {
+    /// World position to search for if `index` is `PICK_NONE`
+    point: vec2f,
+    /// Object to inspect without searching
+    index: u32,
+}
+
+struct PickedObject {
+    color: vec4f,
+    aabb: AABB,
+    velocity: vec2f,
+    /// `PICK_NONE` if there is no object at the queried point
+    index: u32,
+    flags: u32,
+    mass: f32,
+    shape: u32,
+    angle: f32,
+    angular_velocity: f32,
+}
+
+@group(0) @binding(0) var<uniform> query: PickQuery;
+@group(0) @binding(1) var<storage, read> flags: array<Flags>;
+@group(0) @binding(2) var<storage, read> aabbs: array<AABB>;
+@group(0) @binding(3) var<storage, read> nodes: array<BvhNode>;
+@group(0) @binding(4) var<storage, read> velocities: array<Velocity>;
+@group(0) @binding(5) var<storage, read> masses: array<Mass>;
+@group(0) @binding(6) var<storage, read> shapes: array<Shape>;
+@group(0) @binding(7) var<storage, read> colors: array<Color>;
+@group(0) @binding(8) var<storage, read> angles: array<Angle>;
+@group(0) @binding(9) var<storage, read> angular_velocities: array<AngularVelocity>;
+@group(0) @binding(10) var<storage, read_write> picked: PickedObject;
+
+
+@compute @workgroup_size(1)
+fn pick() {
+    var i = query.index;
+    if i == PICK_NONE {
+        i = find_object(query.point);
+    }
+
+    var result = PickedObject();
+    result.index = i;
+    if i != PICK_NONE {
+        result.color = colors[i].inner;
+        result.aabb = aabbs[i];
+        result.velocity = velocities[i].inner;
+        result.flags = flags[i].inner;
+        result.mass = masses[i].inner;
+        result.shape = shapes[i].inner;
+        result.angle = angles[i].inner;
+        result.angular_velocity = angular_velocities[i].inner;
+    }
+    picked = result;
+}
+
+/// Returns the drawn object containing `point` whose center is closest to it
+fn find_object(point: vec2f) -> u32 {
+    let object_count = arrayLength(&flags);
+    var traversal = bvh_traversal(object_count);
+    var closest = PICK_NONE;
+    var closest_distance: f32 = 0;
+    while traversal.size > 0 {
+        let node_index = bvh_pop(&traversal);
+        let aabb = aabbs[node_index];
+        if any(point < aabb.min) || any(point > aabb.max) {
+            continue;
+        }
+
+        let node = nodes[node_index].index;
+        if bvh_descend(&traversal, node) {
+            continue;
+        }
+        if (flags[node].inner & FLAG_DRAW_OBJECT) != 0 {
+            let distance = length((aabb.min + aabb.max) / 2 - point);
+            if closest == PICK_NONE || distance < closest_distance {
+                closest = node;
+                closest_distance = distance;
+            }
+        }
+    }
+    return closest;
+}
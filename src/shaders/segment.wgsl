@@ -0,0 +1,27 @@
+// Draws the level's segments as lines, one instance per segment
+
+#import common::{Camera, Segment}
+
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<storage, read> segments: array<Segment>;
+
+const SEGMENT_COLOR: vec4f = vec4f(0.9, 0.3, 0.2, 1);
+
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) i: u32,
+) -> @builtin(position) vec4f {
+    let segment = segments[i];
+    let position = select(segment.start, segment.end, vertex_index == 1);
+    return camera.inner * vec4f(position, 0, 1);
+}
+
+struct FragmentOutput {
+    @location(0) color: vec4f
+}
+
+@fragment
+fn fs_main() -> FragmentOutput {
+    return FragmentOutput(SEGMENT_COLOR);
+}
@@ -1,24 +1,54 @@
-#import common::{ UNIT_QUAD_VERTICES, FLAG_DRAW_OBJECT, Camera, Flags, AABB, Color, Shape, Velocity}
+#import common::{
+    UNIT_QUAD_VERTICES, FLAG_DRAW_OBJECT, FLAG_SLEEPING, SHAPE_RECT, SHAPE_CIRCLE, SHAPE_POLYGON, SHAPE_CAPSULE, Camera, Flags, AABB,
+    Color, Shape, Velocity, Mass, Size, Angle, Polygon, PolygonVertex, hsv_to_rgb, rotation
+}
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4f,
-    @location(0) quad_position: vec2f,
+    /// In the shape's own frame, unscaled
+    @location(0) local_position: vec2f,
     @location(1) color: vec4f,
-    @location(2) shape: u32
+    @location(2) shape: u32,
+    @location(3) size: vec2f,
+    @location(4) index: u32,
 };
 
-const SHAPE_RECT: u32 = 0;
-const SHAPE_CIRCLE: u32 = 1;
+const COLOR_MODE_OBJECT: u32 = 0;
+const COLOR_MODE_SPEED: u32 = 1;
+const COLOR_MODE_DIRECTION: u32 = 2;
+const COLOR_MODE_MASS: u32 = 3;
+const COLOR_MODE_KINETIC_ENERGY: u32 = 4;
+const COLOR_MODE_CONTACTS: u32 = 5;
+const COLOR_MODE_INDEX: u32 = 6;
+const COLOR_MODE_SLEEP: u32 = 7;
+
+struct ShapeParameters {
+    color_mode: u32,
+    /// Non-zero -> faster objects are drawn bigger
+    scale_by_speed: u32,
+    /// Index of the object drawn opaque white and at least at its real size
+    highlighted: u32,
+}
 
 @group(0) @binding(0) var<uniform> camera: Camera;
-@group(0) @binding(1) var<uniform> size_factor: f32;
+@group(0) @binding(1) var<uniform> parameters: ShapeParameters;
 @group(0) @binding(2) var<storage, read> flags: array<Flags>;
 @group(0) @binding(3) var<storage, read> aabbs: array<AABB>;
 @group(0) @binding(4) var<storage, read> colors: array<Color>;
 @group(0) @binding(5) var<storage, read> shapes: array<Shape>;
 @group(0) @binding(6) var<storage, read> velocities: array<Velocity>;
+@group(0) @binding(7) var<storage, read> masses: array<Mass>;
+@group(0) @binding(8) var<storage, read> contact_counts: array<u32>;
+@group(0) @binding(9) var<storage, read> sizes: array<Size>;
+@group(0) @binding(10) var<storage, read> angles: array<Angle>;
+@group(0) @binding(11) var<storage, read> polygons: array<Polygon>;
+@group(0) @binding(12) var<storage, read> polygon_vertices: array<PolygonVertex>;
 
 const COLORING_SPEED_LIMIT: f32 = pow(80.0, 2.0);
+const COLORING_MASS_LOG2_LIMIT: f32 = 16;
+const COLORING_KINETIC_ENERGY_LOG10_LIMIT: f32 = 10;
+const COLORING_CONTACT_LIMIT: f32 = 8;
+const FIELD_ALPHA: f32 = 0.1;
 
 @vertex
 fn vs_main(
@@ -31,22 +61,32 @@ fn vs_main(
     }
 
     let aabb = aabbs[i];
-    var scale = (aabb.max - aabb.min) * size_factor;
+    let size = sizes[i].inner;
+    var scale = size;
     var v = velocities[i].inner;
     let relative_speed = length(v) / COLORING_SPEED_LIMIT;
-    out.color = velocity_to_color(v, sqrt(relative_speed));
-    scale *= sqrt(sqrt(relative_speed)) * 1.5;
+    out.color = object_color(i, v, relative_speed);
+    if parameters.scale_by_speed != 0 {
+        scale *= sqrt(sqrt(relative_speed)) * 1.5;
+    }
+    if i == parameters.highlighted {
+        out.color = vec4f(1);
+        scale = max(scale, size);
+    }
     let center = (aabb.min + aabb.max) / 2;
+    let r = rotation(angles[i].inner);
     let model = mat4x4f(
-        scale.x, 0, 0, 0,
-        0, scale.y, 0, 0,
-        0, 0, 1, 0,
-        center.x, center.y, 0, 1,
+        vec4f(r[0] * scale.x, 0, 0),
+        vec4f(r[1] * scale.y, 0, 0),
+        vec4f(0, 0, 1, 0),
+        vec4f(center, 0, 1),
     );
     let vertex = UNIT_QUAD_VERTICES[vertex_index];
     out.clip_position = camera.inner * model * vec4f(vertex, 0, 1);
-    out.quad_position = vertex;
+    out.local_position = vertex * size;
     out.shape = shapes[i].inner;
+    out.size = size;
+    out.index = i;
 
     return out;
 }
@@ -58,24 +98,100 @@ struct FragmentOutput {
 @fragment
 fn fs_main(in: VertexOutput) -> FragmentOutput {
     var color = in.color;
-    let d = sdf_cirle(in.quad_position);
+    let d = sdf_shape(in.shape, in.local_position, in.size, in.index);
     let w = fwidth(d) / 2; // fwidth has to be calculated before any branching
-    if in.shape == SHAPE_CIRCLE {
+    if in.shape != SHAPE_RECT {
         color.a *= smoothstep(w, -w, d);
     }
     return FragmentOutput(color);
 }
 
-fn sdf_cirle(p: vec2f) -> f32 {
-    return length(p) - 0.5;
+/// Signed distance to the outline of object `i` at `p` in its own frame, negative inside
+fn sdf_shape(shape: u32, p: vec2f, size: vec2f, i: u32) -> f32 {
+    switch shape {
+        case SHAPE_CIRCLE: {
+            return length(p) - size.x / 2;
+        }
+        case SHAPE_CAPSULE: {
+            let radius = size.y / 2;
+            let end = size.x / 2 - radius;
+            return length(vec2f(p.x - clamp(p.x, -end, end), p.y)) - radius;
+        }
+        case SHAPE_POLYGON: {
+            return sdf_polygon(p, polygons[i]);
+        }
+        default: {
+            let q = abs(p) - size / 2;
+            return length(max(q, vec2f())) + min(max(q.x, q.y), 0);
+        }
+    }
+}
+
+/// Exact for convex counterclockwise polygons, the distance to the closest edge outside and the largest signed
+/// distance to the edge lines inside
+fn sdf_polygon(p: vec2f, polygon: Polygon) -> f32 {
+    var inside = -1e30;
+    var outside = 1e30;
+    for (var v: u32 = 0; v < polygon.count; v++) {
+        let a = polygon_vertices[polygon.offset + v].inner;
+        let b = polygon_vertices[polygon.offset + (v + 1) % polygon.count].inner;
+        let edge = b - a;
+        let t = clamp(dot(p - a, edge) / dot(edge, edge), 0.0, 1.0);
+        outside = min(outside, length(p - a - edge * t));
+        inside = max(inside, dot(p - a, normalize(vec2f(edge.y, -edge.x))));
+    }
+    return select(outside, inside, inside <= 0);
+}
+
+fn object_color(i: u32, velocity: vec2f, relative_speed: f32) -> vec4f {
+    const PI: f32 = 3.14159265358979;
+    switch parameters.color_mode {
+        case COLOR_MODE_OBJECT: {
+            return colors[i].inner;
+        }
+        case COLOR_MODE_DIRECTION: {
+            let hue = atan2(velocity.y, velocity.x) / (2 * PI) + 0.5;
+            let value = clamp(sqrt(relative_speed) * 4, 0.2, 1.0);
+            return vec4f(hsv_to_rgb(vec3f(hue, 1, value)), FIELD_ALPHA);
+        }
+        case COLOR_MODE_MASS: {
+            let t = log2(1 + masses[i].inner) / COLORING_MASS_LOG2_LIMIT;
+            return vec4f(spectrum_color(t), FIELD_ALPHA);
+        }
+        case COLOR_MODE_KINETIC_ENERGY: {
+            let kinetic_energy = 0.5 * masses[i].inner * dot(velocity, velocity);
+            let t = log(1 + kinetic_energy) / log(10.0) / COLORING_KINETIC_ENERGY_LOG10_LIMIT;
+            return vec4f(spectrum_color(t), FIELD_ALPHA);
+        }
+        case COLOR_MODE_CONTACTS: {
+            let t = f32(contact_counts[i]) / COLORING_CONTACT_LIMIT;
+            return vec4f(spectrum_color(t), FIELD_ALPHA);
+        }
+        case COLOR_MODE_SLEEP: {
+            let sleeping = (flags[i].inner & FLAG_SLEEPING) != 0;
+            return select(vec4f(1, 0.6, 0.2, FIELD_ALPHA), vec4f(0.2, 0.4, 1, FIELD_ALPHA), sleeping);
+        }
+        case COLOR_MODE_INDEX: {
+            // Golden ratio hue steps keep neighbouring indices apart
+            let hue = fract(f32(i) * 0.618034);
+            return vec4f(hsv_to_rgb(vec3f(hue, 0.8, 1)), FIELD_ALPHA);
+        }
+        default: {
+            return speed_to_color(sqrt(relative_speed));
+        }
+    }
+}
+
+fn speed_to_color(relative_speed: f32) -> vec4f {
+    return vec4f(spectrum_color(relative_speed), FIELD_ALPHA);
 }
 
-fn velocity_to_color(velocity: vec2f, relative_speed: f32) -> vec4f {
-    let t = clamp(relative_speed, 0.0, 1.0);
-    let lambda = mix(700.0, 380.0, t);
+/// Maps 0..1 to the visible spectrum from red to violet
+fn spectrum_color(t: f32) -> vec3f {
+    let lambda = mix(700.0, 380.0, clamp(t, 0.0, 1.0));
     let rgb = wavelength_to_rgb(lambda);
     let intensity = spectral_intensity(lambda);
-    return vec4f(rgb * intensity, 0.1);
+    return rgb * intensity;
 }
 
 fn spectral_intensity(lambda: f32) -> f32 {
@@ -0,0 +1,253 @@
+// Puts objects that have been at rest for a while to sleep, so that the integrator, the level collisions and the BVH
+// refit skip them. Objects touching each other or linked by constraints form islands that only sleep as a whole, so
+// a moving object wakes everything it touches, and a pile doesn't sleep while something in it still moves.
+//
+// Objects pulled by a steady force only pass through rest, so objects also have to keep their velocity to be at rest.
+// Moving or growing black holes pull differently than before, so the objects where that is noticeable are woken.
+//
+// Islands are found by label propagation: every object starts as its own island, touching objects hook the larger
+// label onto the smaller one and pointer jumping shortens the chains. Once converged, every object of an island is
+// labeled with its smallest index, whatever order the hooks ran in. Islands whose labels still differ after
+// `ISLAND_ITERATIONS` rounds stay awake, so no part of an island sleeps on its own and which objects sleep doesn't
+// depend on the scheduling.
+
+#import common::{
+    FLAG_PHYSICAL, FLAG_DESTROYED, FLAG_SLEEPING, AABB, Velocity, AngularVelocity, Flags, BvhNode,
+    invocation_index
+}
+#import bvh_traversal::{bvh_traversal, bvh_pop, bvh_descend, aabbs_overlap}
+#import tool_state::ToolState
+#import blackholes::{BLACKHOLE_COUNT, BlackHole, BlackHoleSet, blackhole_gravity}
+
+@group(0) @binding(0) var<storage, read_write> flags: array<Flags>;
+@group(0) @binding(1) var<storage, read> aabbs: array<AABB>;
+@group(0) @binding(2) var<storage, read> nodes: array<BvhNode>;
+@group(0) @binding(3) var<storage, read> velocities: array<Velocity>;
+@group(0) @binding(4) var<storage, read> angular_velocities: array<AngularVelocity>;
+/// Time every object has been at rest, it sleeps once its whole island has been at rest for the settings' `delay`
+@group(0) @binding(5) var<storage, read_write> rest_times: array<f32>;
+@group(0) @binding(6) var<storage, read_write> islands: array<atomic<u32>>;
+/// Indexed by island, non-zero if one of its objects isn't ready to sleep
+@group(0) @binding(7) var<storage, read_write> restless: array<atomic<u32>>;
+/// Non-zero for the BVH nodes whose objects all sleep, the BVH builder keeps their AABBs from the last step
+@group(0) @binding(8) var<storage, read_write> sleeping_nodes: array<u32>;
+/// Objects connected by constraints
+@group(0) @binding(9) var<storage, read> links: array<vec2u>;
+@group(0) @binding(10) var<uniform> tools: ToolState;
+/// Velocities in the last sleep pass, for the acceleration
+@group(0) @binding(11) var<storage, read_write> last_velocities: array<Velocity>;
+@group(0) @binding(12) var<uniform> blackhole_set: BlackHoleSet;
+/// The black holes in the last sleep pass, zeroed before the first one
+@group(0) @binding(13) var<uniform> last_blackhole_set: BlackHoleSet;
+@group(0) @binding(14) var<uniform> settings: SleepSettings;
+
+var<push_constant> pass_parameters: SleepPass;
+
+const WORKGROUP_SIZE: u32 = 64;
+const ISLAND_ITERATIONS: u32 = 8;
+
+/// When objects are at rest and how long they have to be before they sleep
+struct SleepSettings {
+    /// Objects slower than this, turning slower than `angular_speed` and accelerating less than `acceleration` are at
+    /// rest
+    speed: f32,
+    angular_speed: f32,
+    acceleration: f32,
+    /// Time a whole island has to be at rest before it sleeps
+    delay: f32,
+}
+
+struct SleepPass {
+    /// Step size including the time scale
+    dt: f32,
+    /// Non-zero if the forces changed, everything wakes up then
+    wake_all: u32,
+}
+
+/// Advances the rest times and makes every object its own island
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn update_rest_times(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) {
+        return;
+    }
+    atomicStore(&islands[i], i);
+    if !takes_part(i) {
+        return;
+    }
+    let v = velocities[i].inner;
+    let acceleration = (v - last_velocities[i].inner) / pass_parameters.dt;
+    last_velocities[i].inner = v;
+    let center = (aabbs[i].min + aabbs[i].max) / 2;
+    let pull_change = blackhole_pull_change(center);
+    let at_rest = dot(v, v) < settings.speed * settings.speed
+        && abs(angular_velocities[i].inner) < settings.angular_speed
+        && dot(acceleration, acceleration) < settings.acceleration * settings.acceleration
+        && dot(pull_change, pull_change) < settings.acceleration * settings.acceleration;
+    if at_rest && pass_parameters.wake_all == 0 && tools.cursor_strength == 0 && i != tools.grabbed {
+        rest_times[i] += pass_parameters.dt;
+    } else {
+        rest_times[i] = 0.0;
+    }
+}
+
+/// Hooks the islands of all objects whose AABBs overlap
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn hook_contacts(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    join_contacts(invocation_index(gid, WORKGROUP_SIZE), false);
+}
+
+/// Hooks the islands of objects connected by a constraint, anchors don't join them
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn hook_links(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    join_link(invocation_index(gid, WORKGROUP_SIZE), false);
+}
+
+/// Marks the islands that haven't converged as restless, they are still hooked to others by contacts
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn check_contacts(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    join_contacts(invocation_index(gid, WORKGROUP_SIZE), true);
+}
+
+/// Like `check_contacts` for the constraints
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn check_links(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    join_link(invocation_index(gid, WORKGROUP_SIZE), true);
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn jump(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) {
+        return;
+    }
+    let island = atomicLoad(&islands[i]);
+    atomicMin(&islands[i], atomicLoad(&islands[island]));
+}
+
+/// Marks the islands with objects that haven't been at rest long enough, runs after `check_contacts` and `check_links`
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn mark_restless(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) || !takes_part(i) {
+        return;
+    }
+    if rest_times[i] < settings.delay {
+        atomicOr(&restless[atomicLoad(&islands[i])], 1u);
+    }
+}
+
+/// Sets the sleep flags from the islands' state and marks the sleeping BVH leaves
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn apply_sleep(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) {
+        return;
+    }
+    var f = flags[i].inner & ~FLAG_SLEEPING;
+    if takes_part(i) && atomicLoad(&restless[atomicLoad(&islands[i])]) == 0 {
+        f |= FLAG_SLEEPING;
+    }
+    flags[i].inner = f;
+    sleeping_nodes[i] = u32((f & FLAG_SLEEPING) != 0);
+}
+
+/// Change of the black holes' pull at `position` since the last pass, only the ones that moved or grew count
+fn blackhole_pull_change(position: vec2f) -> vec2f {
+    var change = vec2f();
+    for (var k: u32 = 0; k < BLACKHOLE_COUNT; k++) {
+        let blackhole = blackhole_set.blackholes[k];
+        let last = last_blackhole_set.blackholes[k];
+        if all(blackhole.position == last.position) && blackhole.mass == last.mass {
+            continue;
+        }
+        change += pull(blackhole, position) - pull(last, position);
+    }
+    return change;
+}
+
+/// Massless black holes, like the ones before the first pass, don't pull
+fn pull(blackhole: BlackHole, position: vec2f) -> vec2f {
+    if blackhole.mass == 0 {
+        return vec2f();
+    }
+    return blackhole_gravity(blackhole, position);
+}
+
+fn join_contacts(i: u32, check: bool) {
+    if i >= arrayLength(&flags) || !takes_part(i) {
+        return;
+    }
+    let aabb = aabbs[i];
+    var traversal = bvh_traversal(arrayLength(&flags));
+    while traversal.size > 0 {
+        let node_index = bvh_pop(&traversal);
+        if !aabbs_overlap(aabb, aabbs[node_index]) {
+            continue;
+        }
+
+        let node = nodes[node_index].index;
+        if bvh_descend(&traversal, node) {
+            continue;
+        }
+        if node > i && takes_part(node) {
+            join(i, node, check);
+        }
+    }
+}
+
+fn join_link(i: u32, check: bool) {
+    if i >= arrayLength(&links) {
+        return;
+    }
+    let link = links[i];
+    if takes_part(link.x) && takes_part(link.y) {
+        join(link.x, link.y, check);
+    }
+}
+
+/// Hooks the islands of `a` and `b`, or with `check` marks them as restless if they differ
+fn join(a: u32, b: u32, check: bool) {
+    if !check {
+        hook(a, b);
+        return;
+    }
+    let island_a = atomicLoad(&islands[a]);
+    let island_b = atomicLoad(&islands[b]);
+    if island_a != island_b {
+        atomicOr(&restless[island_a], 1u);
+        atomicOr(&restless[island_b], 1u);
+    }
+}
+
+fn takes_part(i: u32) -> bool {
+    return (flags[i].inner & (FLAG_PHYSICAL | FLAG_DESTROYED)) == FLAG_PHYSICAL;
+}
+
+fn hook(a: u32, b: u32) {
+    let island_a = atomicLoad(&islands[a]);
+    let island_b = atomicLoad(&islands[b]);
+    if island_a < island_b {
+        atomicMin(&islands[island_b], island_a);
+        atomicMin(&islands[b], island_a);
+    } else if island_b < island_a {
+        atomicMin(&islands[island_a], island_b);
+        atomicMin(&islands[a], island_b);
+    }
+}
@@ -0,0 +1,94 @@
+// Spawns the paint tool's circle after the impacts are resolved. It takes the first free slot that no fragment took,
+// so it never replaces a live object and a fragment never replaces it. The BVH nodes above the filled slots are then
+// grown to contain the new objects, so that the contact solver finds them in the same step.
+
+#import common::{
+    FLAG_DRAW_OBJECT, FLAG_PHYSICAL, SHAPE_CIRCLE, AABB, Velocity, Mass, Flags, Color, Shape, Size, Angle,
+    AngularVelocity, SecondMoments
+}
+#import materials::{MATERIAL_INERT, MaterialId}
+#import tool_state::SpawnRequest
+
+@group(0) @binding(0) var<storage, read_write> flags: array<Flags>;
+@group(0) @binding(1) var<storage, read_write> masses: array<Mass>;
+@group(0) @binding(2) var<storage, read_write> sizes: array<Size>;
+@group(0) @binding(3) var<storage, read_write> shapes: array<Shape>;
+@group(0) @binding(4) var<storage, read_write> colors: array<Color>;
+@group(0) @binding(5) var<storage, read_write> moments: array<SecondMoments>;
+@group(0) @binding(6) var<storage, read_write> materials: array<MaterialId>;
+@group(0) @binding(7) var<storage, read_write> aabbs: array<AABB>;
+@group(0) @binding(8) var<storage, read_write> integrated_aabbs: array<AABB>;
+@group(0) @binding(9) var<storage, read_write> integrated_velocities: array<Velocity>;
+@group(0) @binding(10) var<storage, read_write> integrated_angles: array<Angle>;
+@group(0) @binding(11) var<storage, read_write> angular_velocities: array<AngularVelocity>;
+/// A sleeping object in the slot would keep the BVH from refitting around the new one
+@group(0) @binding(12) var<storage, read_write> sleeping_nodes: array<u32>;
+@group(0) @binding(13) var<storage, read> prefixes: array<vec2u>;
+@group(0) @binding(14) var<storage, read> free_slots: array<u32>;
+@group(0) @binding(15) var<storage, read_write> spawn_request: SpawnRequest;
+
+@compute @workgroup_size(1)
+fn spawn_circle() {
+    if spawn_request.pending == 0 {
+        return;
+    }
+    spawn_request.pending = 0;
+    // The totals of the prefix sums, the fragments asked for at most as many slots as they took
+    let totals = prefixes[arrayLength(&flags)];
+    if totals.y >= totals.x {
+        return;
+    }
+
+    let slot = free_slots[totals.y];
+    let radius = spawn_request.radius;
+    let aabb = AABB(spawn_request.position - radius, spawn_request.position + radius);
+    flags[slot].inner = FLAG_DRAW_OBJECT | FLAG_PHYSICAL;
+    masses[slot].inner = spawn_request.mass;
+    sizes[slot].inner = vec2f(radius * 2);
+    shapes[slot].inner = SHAPE_CIRCLE;
+    colors[slot].inner = vec4f(1);
+    // Second moments per unit of mass of a disk
+    moments[slot] = SecondMoments(radius * radius / 4, radius * radius / 4, 0.0);
+    materials[slot].inner = MATERIAL_INERT;
+    aabbs[slot] = aabb;
+    integrated_aabbs[slot] = aabb;
+    integrated_velocities[slot].inner = spawn_request.velocity;
+    integrated_angles[slot].inner = 0;
+    angular_velocities[slot].inner = 0;
+    sleeping_nodes[slot] = 0;
+}
+
+/// Grows the ancestors of the slots filled in this step, serially as there are few of them. The nodes may end up
+/// larger than needed until the next step rebuilds the tree.
+@compute @workgroup_size(1)
+fn refit_filled_slots() {
+    let object_count = arrayLength(&flags);
+    let totals = prefixes[object_count];
+    // The fragments took the first free slots and the painted circle the one after them
+    let candidates = min(totals.y + 1, totals.x);
+    for (var k: u32 = 0; k < candidates; k++) {
+        let slot = free_slots[k];
+        if (flags[slot].inner & FLAG_PHYSICAL) != 0 {
+            grow_ancestors(slot, object_count);
+        }
+    }
+}
+
+/// Walks up the levels of the pairwise BVH the way `calculate_passes` lays them out. Every level pairs up its nodes
+/// from the start and carries the leftover one over to the next level, which starts with it followed by the parents.
+fn grow_ancestors(leaf: u32, object_count: u32) {
+    let aabb = aabbs[leaf];
+    var node = leaf;
+    var level_start: u32 = 0;
+    var level_length = object_count;
+    while level_length > 1 {
+        let parent_count = level_length / 2;
+        let paired_end = level_start + parent_count * 2;
+        if node < paired_end {
+            node = level_start + level_length + (node - level_start) / 2;
+            aabbs[node] = AABB(min(aabbs[node].min, aabb.min), max(aabbs[node].max, aabb.max));
+        }
+        level_start = paired_end;
+        level_length -= parent_count;
+    }
+}
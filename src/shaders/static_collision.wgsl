@@ -0,0 +1,241 @@
+// Pushes the integrated objects out of the level's segments. The segments never move, so their BVH is built once and
+// every object only reads it.
+//
+// Objects fast enough to cross a wall within one step would end up behind it, so circles and bullets are first swept
+// from their position before the step to the integrated one. The sweep stops them at the first wall they hit, the
+// overlap test at the end then resolves resting contacts.
+
+#import common::{
+    FLAG_PHYSICAL, FLAG_BULLET, FLAG_SLEEPING, SHAPE_CIRCLE, AABB, Velocity, Flags, BvhNode, Angle,
+    Segment, invocation_index
+}
+#import bvh_traversal::{bvh_traversal, bvh_pop, bvh_descend, aabbs_overlap}
+#import geometry::{shapes, sizes, support}
+
+@group(0) @binding(0) var<storage, read> flags: array<Flags>;
+@group(0) @binding(1) var<storage, read_write> integrated_aabbs: array<AABB>;
+@group(0) @binding(2) var<storage, read_write> integrated_velocities: array<Velocity>;
+@group(0) @binding(3) var<storage, read> integrated_angles: array<Angle>;
+@group(0) @binding(4) var<storage, read> segments: array<Segment>;
+/// Twice the segment count long like the objects' BVH, the leaves are the segments' AABBs
+@group(0) @binding(5) var<storage, read> static_aabbs: array<AABB>;
+@group(0) @binding(6) var<storage, read> static_nodes: array<BvhNode>;
+/// Normals of the first two walls the object hit in xy and zw, the contact solver keeps objects from being pushed into
+/// them
+@group(0) @binding(7) var<storage, read_write> wall_normals: array<vec4f>;
+/// Before the step, the sweeps start here
+@group(0) @binding(8) var<storage, read> aabbs: array<AABB>;
+
+const WORKGROUP_SIZE: u32 = 64;
+/// Share of the normal speed kept when bouncing off a wall
+const STATIC_RESTITUTION: f32 = 0.5;
+/// Slower hits don't bounce, so that objects pressed against a wall come to rest instead of jittering on it
+const RESTITUTION_SPEED_THRESHOLD: f32 = 5;
+/// Overlap tests along the sweep of a bullet, it can still pass corners thinner than its motion over this many
+const BULLET_SUBSTEPS: u32 = 8;
+const NO_IMPACT: f32 = 2;
+
+/// Earliest wall hit by a sweep, `time` is the fraction of the motion before the hit or `NO_IMPACT`
+struct Impact {
+    time: f32,
+    /// Away from the wall
+    normal: vec2f,
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn cs_main(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+) {
+    let i = invocation_index(gid, WORKGROUP_SIZE);
+    if i >= arrayLength(&flags) {
+        return;
+    }
+    let f = flags[i].inner;
+    if (f & FLAG_PHYSICAL) == 0 || (f & FLAG_SLEEPING) != 0 {
+        return;
+    }
+
+    let aabb = integrated_aabbs[i];
+    let half_extents = (aabb.max - aabb.min) / 2;
+    var center = (aabb.min + aabb.max) / 2;
+    var velocity = integrated_velocities[i].inner;
+    let angle = integrated_angles[i].inner;
+    var moved = false;
+    var walls = Walls(vec4f(), 0);
+
+    if shapes[i].inner == SHAPE_CIRCLE || (f & FLAG_BULLET) != 0 {
+        let start_aabb = aabbs[i];
+        let start = (start_aabb.min + start_aabb.max) / 2;
+        let swept = AABB(min(start_aabb.min, aabb.min), max(start_aabb.max, aabb.max));
+        let impact = sweep(i, angle, start, center - start, swept);
+        if impact.time <= 1 {
+            moved = true;
+            center = start + (center - start) * impact.time;
+            velocity = bounce(velocity, impact.normal);
+            walls = add_wall(walls, impact.normal);
+        }
+    }
+
+    let query = AABB(center - half_extents, center + half_extents);
+    var traversal = bvh_traversal(arrayLength(&segments));
+    while traversal.size > 0 {
+        let node_index = bvh_pop(&traversal);
+        if !aabbs_overlap(query, static_aabbs[node_index]) {
+            continue;
+        }
+
+        let node = static_nodes[node_index].index;
+        if bvh_descend(&traversal, node) {
+            continue;
+        }
+
+        let push = penetration(i, angle, center, segments[node]);
+        if any(push != vec2f()) {
+            moved = true;
+            center += push;
+            velocity = bounce(velocity, normalize(push));
+            walls = add_wall(walls, normalize(push));
+        }
+    }
+
+    wall_normals[i] = walls.normals;
+    if moved {
+        integrated_aabbs[i] = AABB(center - half_extents, center + half_extents);
+        integrated_velocities[i] = Velocity(velocity);
+    }
+}
+
+struct Walls {
+    normals: vec4f,
+    count: u32,
+}
+
+/// Keeps the first two normals, more only happen in narrow gaps
+fn add_wall(walls: Walls, normal: vec2f) -> Walls {
+    switch walls.count {
+        case 0u: {
+            return Walls(vec4f(normal, 0, 0), 1);
+        }
+        case 1u: {
+            return Walls(vec4f(walls.normals.xy, normal), 2);
+        }
+        default: {
+            return walls;
+        }
+    }
+}
+
+/// Earliest hit of object `i` moving by `motion` from `start` against the segments overlapping `swept`, the union of
+/// its AABBs before and after the step. Walls the object already touches at `start` are left to the overlap test.
+fn sweep(i: u32, angle: f32, start: vec2f, motion: vec2f, swept: AABB) -> Impact {
+    var earliest = Impact(NO_IMPACT, vec2f());
+    var traversal = bvh_traversal(arrayLength(&segments));
+    while traversal.size > 0 {
+        let node_index = bvh_pop(&traversal);
+        if !aabbs_overlap(swept, static_aabbs[node_index]) {
+            continue;
+        }
+
+        let node = static_nodes[node_index].index;
+        if bvh_descend(&traversal, node) {
+            continue;
+        }
+
+        var impact: Impact;
+        if shapes[i].inner == SHAPE_CIRCLE {
+            impact = circle_time_of_impact(start, motion, sizes[i].inner.x / 2, segments[node]);
+        } else {
+            impact = substepped_time_of_impact(i, angle, start, motion, segments[node]);
+        }
+        if impact.time < earliest.time {
+            earliest = impact;
+        }
+    }
+    return earliest;
+}
+
+/// Exact for a circle of `radius`, it hits the segment when its center hits the capsule of that radius around it.
+/// The capsule's sides and end caps are intersected separately, a miss is `NO_IMPACT`.
+fn circle_time_of_impact(start: vec2f, motion: vec2f, radius: f32, segment: Segment) -> Impact {
+    var impact = Impact(NO_IMPACT, vec2f());
+    let edge = segment.end - segment.start;
+    let length_squared = dot(edge, edge);
+    if length_squared > 0 {
+        let side_normal = vec2f(-edge.y, edge.x) / sqrt(length_squared);
+        let distance = dot(start - segment.start, side_normal);
+        let normal = side_normal * sign(distance);
+        let approach = dot(motion, normal);
+        if abs(distance) > radius && approach < 0 {
+            let time = (abs(distance) - radius) / -approach;
+            let along = dot(start + motion * time - segment.start, edge) / length_squared;
+            if time <= 1 && along >= 0 && along <= 1 {
+                impact = Impact(time, normal);
+            }
+        }
+    }
+    for (var e = 0; e < 2; e++) {
+        let offset = start - select(segment.start, segment.end, e == 1);
+        let b = dot(offset, motion);
+        let c = dot(offset, offset) - radius * radius;
+        let a = dot(motion, motion);
+        let discriminant = b * b - a * c;
+        if c > 0 && b < 0 && discriminant >= 0 {
+            let time = (-b - sqrt(discriminant)) / a;
+            if time < impact.time && time <= 1 {
+                impact = Impact(time, normalize(offset + motion * time));
+            }
+        }
+    }
+    return impact;
+}
+
+/// Overlap tests at evenly spaced points of the motion, the object stops at the last point before the first
+/// overlapping one
+fn substepped_time_of_impact(i: u32, angle: f32, start: vec2f, motion: vec2f, segment: Segment) -> Impact {
+    if any(penetration(i, angle, start, segment) != vec2f()) {
+        return Impact(NO_IMPACT, vec2f());
+    }
+    let edge = segment.end - segment.start;
+    let perpendicular = vec2f(-edge.y, edge.x);
+    let start_side = perpendicular * sign(dot(start - segment.start, perpendicular));
+    for (var step = 1u; step <= BULLET_SUBSTEPS; step++) {
+        let push = penetration(i, angle, start + motion * f32(step) / f32(BULLET_SUBSTEPS), segment);
+        if any(push != vec2f()) {
+            // With its center already past the wall the object would be pushed through it
+            var normal = normalize(push);
+            if dot(normal, start_side) < 0 {
+                normal = normalize(start_side);
+            }
+            return Impact(f32(step - 1) / f32(BULLET_SUBSTEPS), normal);
+        }
+    }
+    return Impact(NO_IMPACT, vec2f());
+}
+
+/// Reflects the part of `velocity` going into the wall with `normal`
+fn bounce(velocity: vec2f, normal: vec2f) -> vec2f {
+    let normal_speed = dot(velocity, normal);
+    if normal_speed >= 0 {
+        return velocity;
+    }
+    let restitution = select(0.0, STATIC_RESTITUTION, -normal_speed > RESTITUTION_SPEED_THRESHOLD);
+    return velocity - (1 + restitution) * normal_speed * normal;
+}
+
+/// Smallest move that takes object `i` at `center` out of `segment`, zero if they don't overlap. The object is pushed
+/// away from the closest point of the segment, so it ends up on the side its center is on.
+fn penetration(i: u32, angle: f32, center: vec2f, segment: Segment) -> vec2f {
+    let edge = segment.end - segment.start;
+    let t = clamp(dot(center - segment.start, edge) / max(dot(edge, edge), 1e-12), 0.0, 1.0);
+    let closest = segment.start + edge * t;
+    var normal = center - closest;
+    let distance = length(normal);
+    if distance > 1e-6 {
+        normal /= distance;
+    } else {
+        normal = normalize(vec2f(-edge.y, edge.x));
+    }
+    let deepest = center + support(i, angle, -normal);
+    let depth = dot(closest - deepest, normal);
+    return select(vec2f(), normal * depth, depth > 0);
+}
@@ -0,0 +1,30 @@
+#define_import_path tool_state
+
+// Shared by the integrator, which applies the tools, the contact solver, which doesn't let contacts move the held
+// object, and the sleep pass, which keeps the objects they act on awake
+
+const TOOL_NONE: u32 = 0xffffffff;
+
+/// Interactive input from the window
+struct ToolState {
+    cursor: vec2f,
+    /// Positive attracts objects to the cursor, negative repels them, zero is off
+    cursor_strength: f32,
+    /// Plummer softening radius, keeps the cursor force finite right at the cursor
+    cursor_softening: f32,
+    /// Object held at the cursor, `TOOL_NONE` if there is none
+    grabbed: u32,
+    /// Velocity of the held object, it keeps it when released
+    grab_velocity: vec2f,
+}
+
+/// Circle asked for by the paint tool, the impact pass spawns it into a free slot, or drops it if there is none,
+/// and clears `pending`
+struct SpawnRequest {
+    position: vec2f,
+    velocity: vec2f,
+    radius: f32,
+    mass: f32,
+    /// Non-zero while the circle still has to be spawned
+    pending: u32,
+}
@@ -0,0 +1,41 @@
+// Fades the accumulation target to leave trails behind moving objects and copies it to the screen
+
+struct TrailParameters {
+    /// Fraction of the intensity removed per frame
+    fade: f32,
+}
+
+// The accumulation texture can't be bound while it is the render target, hence the separate groups
+@group(0) @binding(0) var<uniform> parameters: TrailParameters;
+@group(1) @binding(0) var accumulation: texture_2d<f32>;
+@group(1) @binding(1) var accumulation_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4f,
+    @location(0) uv: vec2f,
+}
+
+/// A single triangle covering the whole viewport
+@vertex
+fn vs_fullscreen(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out = VertexOutput();
+    let uv = vec2f(f32((vertex_index << 1) & 2), f32(vertex_index & 2));
+    out.clip_position = vec4f(uv * 2 - 1, 0, 1);
+    out.uv = vec2f(uv.x, 1 - uv.y);
+    return out;
+}
+
+struct FragmentOutput {
+    @location(0) color: vec4f
+}
+
+/// The actual fading is done by the blend state, see TrailFade
+@fragment
+fn fs_fade(in: VertexOutput) -> FragmentOutput {
+    return FragmentOutput(vec4f(parameters.fade));
+}
+
+@fragment
+fn fs_blit(in: VertexOutput) -> FragmentOutput {
+    return FragmentOutput(textureSample(accumulation, accumulation_sampler, in.uv));
+}
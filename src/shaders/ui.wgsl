@@ -0,0 +1,62 @@
+#import common::UNIT_QUAD_VERTICES
+
+@group(0) @binding(0) var<uniform> parameters: UiParameters;
+@group(0) @binding(1) var<storage, read> quads: array<UiQuad>;
+@group(0) @binding(2) var<storage, read> font: array<u32>;
+
+/// Quad filled with its color instead of a glyph
+const UI_SOLID: u32 = 0xffffffff;
+/// Glyphs are stored for the printable ASCII range starting at this character
+const FONT_FIRST_CHARACTER: u32 = 32;
+/// Every glyph is one u32 per column, the lowest bit is the top row
+const FONT_GLYPH_WIDTH: u32 = 5;
+const FONT_GLYPH_HEIGHT: u32 = 7;
+
+struct UiParameters {
+    /// Target size in pixels, quads are positioned in pixels from the top left corner
+    view_size: vec2f,
+}
+
+struct UiQuad {
+    min: vec2f,
+    size: vec2f,
+    color: vec4f,
+    /// Character code, or `UI_SOLID`
+    character: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4f,
+    /// 0..1 across the quad, y pointing down
+    @location(0) uv: vec2f,
+    @location(1) @interpolate(flat) instance: u32,
+}
+
+@vertex
+fn vs_ui(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) instance_index: u32) -> VertexOutput {
+    let uv = UNIT_QUAD_VERTICES[vertex_index] + 0.5;
+    let quad = quads[instance_index];
+    let pixel = quad.min + uv * quad.size;
+    var out = VertexOutput();
+    let clip = pixel / parameters.view_size * vec2f(2, -2) + vec2f(-1, 1);
+    out.clip_position = vec4f(clip, 0.0, 1.0);
+    out.uv = uv;
+    out.instance = instance_index;
+    return out;
+}
+
+@fragment
+fn fs_ui(in: VertexOutput) -> @location(0) vec4f {
+    let quad = quads[in.instance];
+    if quad.character == UI_SOLID {
+        return quad.color;
+    }
+    let glyph = quad.character - FONT_FIRST_CHARACTER;
+    let cell = min(vec2u(in.uv * vec2f(f32(FONT_GLYPH_WIDTH), f32(FONT_GLYPH_HEIGHT))),
+        vec2u(FONT_GLYPH_WIDTH - 1, FONT_GLYPH_HEIGHT - 1));
+    let column = font[glyph * FONT_GLYPH_WIDTH + cell.x];
+    if ((column >> cell.y) & 1) == 0 {
+        discard;
+    }
+    return quad.color;
+}
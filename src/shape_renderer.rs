@@ -1,35 +1,89 @@
 use std::ops::Range;
 
 use wgpu::{
-    BlendState, ColorTargetState, Device, MultisampleState, PipelineCache, PrimitiveState, RenderPass, RenderPipeline,
-    RenderPipelineDescriptor, TextureFormat,
+    BlendState, BufferUsages, ColorTargetState, Device, MultisampleState, PipelineCache, PrimitiveState, Queue,
+    RenderPass, RenderPipeline, RenderPipelineDescriptor, TextureFormat,
 };
 
 use crate::{
     gpu_buffer::GpuBuffer,
     shaders::{
-        common::{AABB, Camera, Color, Flags, Shape, Velocity},
-        shape,
+        common::{AABB, Angle, Camera, Color, Flags, Mass, Polygon, PolygonVertex, Shape, Size, Velocity},
+        shape::{self, ShapeParameters},
     },
 };
 
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// The color from the objects' color buffer
+    Object,
+    #[default]
+    Speed,
+    /// Hue from the velocity direction, brightness from the speed
+    Direction,
+    Mass,
+    KineticEnergy,
+    /// Number of touching objects
+    Contacts,
+    Index,
+    /// Sleeping objects blue, awake ones orange
+    Sleep,
+}
+
+impl ColorMode {
+    pub const ALL: [Self; 8] = [
+        Self::Object,
+        Self::Speed,
+        Self::Direction,
+        Self::Mass,
+        Self::KineticEnergy,
+        Self::Contacts,
+        Self::Index,
+        Self::Sleep,
+    ];
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&mode| mode == self).unwrap();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    fn to_wgsl(self) -> u32 {
+        match self {
+            Self::Object => shape::COLOR_MODE_OBJECT,
+            Self::Speed => shape::COLOR_MODE_SPEED,
+            Self::Direction => shape::COLOR_MODE_DIRECTION,
+            Self::Mass => shape::COLOR_MODE_MASS,
+            Self::KineticEnergy => shape::COLOR_MODE_KINETIC_ENERGY,
+            Self::Contacts => shape::COLOR_MODE_CONTACTS,
+            Self::Index => shape::COLOR_MODE_INDEX,
+            Self::Sleep => shape::COLOR_MODE_SLEEP,
+        }
+    }
+}
+
 pub struct ShapeRenderer {
     render_pipeline: RenderPipeline,
     bind_group: shape::WgpuBindGroup0,
+    parameters: GpuBuffer<ShapeParameters>,
 }
 
 impl ShapeRenderer {
     pub fn new(
         device: &Device,
-        swapchain_format: TextureFormat,
-        pipeline_cache: &PipelineCache,
+        target_format: TextureFormat,
+        pipeline_cache: Option<&PipelineCache>,
         camera: GpuBuffer<Camera>,
-        size_factor: GpuBuffer<f32>,
         flags: GpuBuffer<Flags>,
         aabbs: GpuBuffer<AABB>,
         colors: GpuBuffer<Color>,
         shapes: GpuBuffer<Shape>,
         velocities: GpuBuffer<Velocity>,
+        masses: GpuBuffer<Mass>,
+        contact_counts: GpuBuffer<u32>,
+        sizes: GpuBuffer<Size>,
+        angles: GpuBuffer<Angle>,
+        polygons: GpuBuffer<Polygon>,
+        polygon_vertices: GpuBuffer<PolygonVertex>,
     ) -> Self {
         let pipeline_layout = shape::create_pipeline_layout(device);
         let shader = shape::create_shader_module_embed_source(device);
@@ -39,7 +93,7 @@ impl ShapeRenderer {
 
         let color_target_state = ColorTargetState {
             blend: Some(BlendState::ALPHA_BLENDING),
-            ..ColorTargetState::from(swapchain_format)
+            ..ColorTargetState::from(target_format)
         };
         let fragment_entry = shape::fs_main_entry([Some(color_target_state)]);
         let fragment_state = shape::fragment_state(&shader, &fragment_entry);
@@ -53,28 +107,43 @@ impl ShapeRenderer {
             depth_stencil: None,
             multisample: MultisampleState::default(),
             multiview: None,
-            cache: Some(pipeline_cache),
+            cache: pipeline_cache,
         });
 
+        let parameters =
+            GpuBuffer::new(1, "shape parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
         let bind_group = shape::WgpuBindGroup0::from_bindings(
             device,
             shape::WgpuBindGroup0Entries::new(shape::WgpuBindGroup0EntriesParams {
                 camera: camera.buffer().as_entire_buffer_binding(),
-                size_factor: size_factor.buffer().as_entire_buffer_binding(),
+                parameters: parameters.buffer().as_entire_buffer_binding(),
                 flags: flags.buffer().as_entire_buffer_binding(),
                 aabbs: aabbs.buffer().as_entire_buffer_binding(),
                 colors: colors.buffer().as_entire_buffer_binding(),
                 shapes: shapes.buffer().as_entire_buffer_binding(),
                 velocities: velocities.buffer().as_entire_buffer_binding(),
+                masses: masses.buffer().as_entire_buffer_binding(),
+                contact_counts: contact_counts.buffer().as_entire_buffer_binding(),
+                sizes: sizes.buffer().as_entire_buffer_binding(),
+                angles: angles.buffer().as_entire_buffer_binding(),
+                polygons: polygons.buffer().as_entire_buffer_binding(),
+                polygon_vertices: polygon_vertices.buffer().as_entire_buffer_binding(),
             }),
         );
 
         Self {
             render_pipeline,
             bind_group,
+            parameters,
         }
     }
 
+    pub fn set_parameters(&self, queue: &Queue, color_mode: ColorMode, scale_by_speed: bool, highlighted: Option<u32>) {
+        let parameters =
+            ShapeParameters::new(color_mode.to_wgsl(), u32::from(scale_by_speed), highlighted.unwrap_or(u32::MAX));
+        self.parameters.write(queue, &[parameters]);
+    }
+
     pub fn render(&self, render_pass: &mut RenderPass<'_>, instances: Range<usize>) {
         render_pass.set_pipeline(&self.render_pipeline);
         self.bind_group.set(render_pass);
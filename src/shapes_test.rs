@@ -0,0 +1,106 @@
+use std::f32::consts::FRAC_PI_4;
+
+use crate::{
+    DT,
+    objects::{ObjectPrototype, Objects, convex_hull, polygon_half_extents, polygon_second_moments, second_moments},
+    shaders::common::{SHAPE_CAPSULE, SHAPE_CIRCLE, SHAPE_POLYGON, SHAPE_RECT},
+    simulation::Simulation,
+    test_util::{TestDevice, object, test_device_or_skip},
+};
+
+#[test]
+fn polygons_match_analytic_shapes() {
+    // Interior and collinear points are dropped, the rest is counterclockwise from the lower left
+    let hull = convex_hull(&[
+        [1.0, 1.0],
+        [-1.0, 1.0],
+        [0.0, 0.0],
+        [1.0, -1.0],
+        [-1.0, -1.0],
+        [0.0, -1.0],
+    ]);
+    assert_eq!(hull, [[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]]);
+    assert_eq!(polygon_second_moments(&hull), second_moments(SHAPE_RECT, [2.0, 2.0]));
+    // A capsule without a straight part is a disk
+    assert_eq!(second_moments(SHAPE_CAPSULE, [4.0, 4.0]), second_moments(SHAPE_CIRCLE, [4.0, 4.0]));
+    let rotated = polygon_half_extents(&hull, FRAC_PI_4);
+    assert!((rotated[0] - 2f32.sqrt()).abs() < 1e-6 && (rotated[1] - 2f32.sqrt()).abs() < 1e-6, "{rotated:?}");
+
+    // Pushed polygons are centered on their centroid
+    let mut objects = Objects::default();
+    objects.push_polygon(object(SHAPE_POLYGON, [100.0, 50.0], [0.0, 0.0]), &[[0.0, 0.0], [3.0, 0.0], [0.0, 3.0]]);
+    assert_eq!(objects.polygon(0), [[-1.0, -1.0], [2.0, -1.0], [-1.0, 2.0]]);
+    assert_eq!(objects.sizes[0].inner, [4.0, 4.0]);
+    assert_eq!((objects.aabbs[0].min, objects.aabbs[0].max), ([98.0, 48.0], [102.0, 52.0]));
+}
+
+#[test]
+fn narrow_phase_separates_shapes_with_overlapping_aabbs() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    let mut expected = Vec::new();
+    // One pair per entry, far apart from each other. The second shape overlaps the first one's AABB either way.
+    let circle = (SHAPE_CIRCLE, [2.0, 2.0]);
+    let square = (SHAPE_RECT, [10.0, 10.0]);
+    let pairs: [(PushShape, _, [f32; 2], bool); 6] = [
+        (triangle, circle, [8.0, 8.0], false),
+        (triangle, circle, [5.5, 5.5], true),
+        (capsule, circle, [6.0, -6.0], false),
+        (capsule, circle, [6.5, 6.5], true),
+        (diamond, square, [9.0, 9.0], false),
+        (diamond, square, [8.0, 8.0], true),
+    ];
+    for (i, (push_first, (shape, size), offset, touching)) in pairs.into_iter().enumerate() {
+        let origin = [-900.0 + 100.0 * i as f32, -900.0];
+        push_first(&mut objects, origin);
+        objects.push(object(shape, [origin[0] + offset[0], origin[1] + offset[1]], size));
+        expected.extend([u32::from(touching); 2]);
+    }
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.step(DT);
+    let contact_counts = simulation.buffers().contact_counts.read_back(device, queue, expected.len());
+    assert_eq!(contact_counts, expected);
+}
+
+#[test]
+fn polygon_aabbs_follow_rotation() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    let square = [[-5.0, -5.0], [5.0, -5.0], [5.0, 5.0], [-5.0, 5.0]];
+    objects.push_polygon(object(SHAPE_POLYGON, [-900.0, 900.0], [0.0, 0.0]), &square);
+    objects.angular_velocities[0].inner = 50.0;
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.step(DT);
+    let angle = simulation.read_objects()[0].angle;
+    assert!((angle - 50.0 * DT).abs() < 1e-3, "angle {angle}");
+    let aabb = simulation.buffers().aabbs.read_back(device, queue, 1)[0];
+    let expected = 10.0 * (angle.cos() + angle.sin());
+    for extent in [aabb.max[0] - aabb.min[0], aabb.max[1] - aabb.min[1]] {
+        assert!((extent - expected).abs() < 1e-3, "extent {extent}, expected {expected}");
+    }
+}
+
+/// Pushes a shape at the given position
+type PushShape = fn(&mut Objects, [f32; 2]);
+
+/// Right triangle with legs of 10 whose right angle is at `corner`
+fn triangle(objects: &mut Objects, corner: [f32; 2]) {
+    let centroid = [corner[0] + 10.0 / 3.0, corner[1] + 10.0 / 3.0];
+    objects.push_polygon(object(SHAPE_POLYGON, centroid, [0.0, 0.0]), &[[0.0, 0.0], [10.0, 0.0], [0.0, 10.0]]);
+}
+
+/// Diagonal with rounded ends 8 from the center
+fn capsule(objects: &mut Objects, center: [f32; 2]) {
+    objects.push(ObjectPrototype {
+        angle: FRAC_PI_4,
+        ..object(SHAPE_CAPSULE, center, [20.0, 4.0])
+    });
+}
+
+/// 10x10 square standing on a corner
+fn diamond(objects: &mut Objects, center: [f32; 2]) {
+    objects.push(ObjectPrototype {
+        angle: FRAC_PI_4,
+        ..object(SHAPE_RECT, center, [10.0, 10.0])
+    });
+}
@@ -0,0 +1,579 @@
+use std::{
+    collections::VecDeque,
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use wgpu::{BufferUsages, CommandEncoder, CommandEncoderDescriptor, ComputePassDescriptor, SubmissionIndex};
+
+use crate::{
+    blackholes::Accretor,
+    bvh_builder::BvhBuilder,
+    constraints::ConstraintSolver,
+    contacts::ContactSolver,
+    diagnostics::{DiagnosticsReducer, DiagnosticsSample},
+    forces::ForceRegistry,
+    gpu_buffer::GpuBuffer,
+    impacts::ImpactResolver,
+    integration::{GpuIntegrator, Integrator},
+    level::LevelCollider,
+    objects::{ObjectBuffers, ObjectState, Objects},
+    pass_duration::PassDurationMeasurer,
+    shaders::{
+        accretion::Accretion,
+        blackholes::{BlackHole, BlackHoleSet},
+        common::{
+            AABB, Angle, AngularVelocity, Color, FLAG_DRAW_OBJECT, FLAG_PHYSICAL, Flags, Mass, SecondMoments, Shape,
+            Size, Velocity,
+        },
+        diagnostics::Diagnostics,
+        integration::IntegrationParameters,
+        materials::{MATERIAL_INERT, MaterialId},
+        sleep::SleepSettings,
+        tool_state::{SpawnRequest, TOOL_NONE, ToolState},
+    },
+    sleep::SleepManager,
+    util::DeviceUtil,
+};
+
+/// Number of snapshots kept for rewinding, each one holds the flags, AABBs, velocities and rotation of all objects
+/// and the state of the black holes. With materials they hold the objects' bodies as well.
+const HISTORY_LENGTH: usize = 8;
+/// Steps between two snapshots
+const SNAPSHOT_INTERVAL: u64 = 100;
+/// Diagnostics samples kept, the oldest ones are dropped first
+const DIAGNOSTICS_LENGTH: usize = 1 << 16;
+const TIME_SCALE_RANGE: RangeInclusive<f32> = 1.0 / 64.0..=64.0;
+
+/// Sent from the UI thread to the simulation thread
+#[derive(Copy, Clone, Debug)]
+pub enum SimulationCommand {
+    TogglePause,
+    /// Runs a single step while paused
+    Step,
+    /// Multiplies the time scale, the step size is `dt * time_scale`
+    ScaleTime(f32),
+    /// Restores the most recent snapshot and pauses
+    Rewind,
+    SetIntegrator(Integrator),
+    /// Whether black holes keep the mass and momentum of what they consume
+    SetAccretion(bool),
+}
+
+/// GPU time spent in the passes of the last step
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StepDurations {
+    pub bvh: Duration,
+    pub integration: Duration,
+    pub update: Duration,
+}
+
+/// Published by the simulation thread for display
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SimulationStatus {
+    pub paused: bool,
+    pub step: u64,
+    /// Simulated time, the sum of all step sizes
+    pub time: f64,
+    pub time_scale: f32,
+    /// Step size including the time scale
+    pub dt: f32,
+    pub integrator: Integrator,
+    pub accretion: bool,
+    pub snapshots: usize,
+    pub durations: StepDurations,
+    /// Latest sample if diagnostics are enabled
+    pub diagnostics: Option<Diagnostics>,
+}
+
+struct Snapshot {
+    step: u64,
+    time: f64,
+    flags: GpuBuffer<Flags>,
+    aabbs: GpuBuffer<AABB>,
+    velocities: GpuBuffer<Velocity>,
+    angles: GpuBuffer<Angle>,
+    angular_velocities: GpuBuffer<AngularVelocity>,
+    blackholes: GpuBuffer<BlackHoleSet>,
+    accretion: GpuBuffer<Accretion>,
+    bodies: Option<BodySnapshot>,
+}
+
+/// What merging and shattering change besides the state every step changes
+struct BodySnapshot {
+    masses: GpuBuffer<Mass>,
+    colors: GpuBuffer<Color>,
+    shapes: GpuBuffer<Shape>,
+    sizes: GpuBuffer<Size>,
+    moments: GpuBuffer<SecondMoments>,
+    materials: GpuBuffer<MaterialId>,
+}
+
+impl BodySnapshot {
+    fn new(object_count: usize, device: &wgpu::Device) -> Self {
+        let usage = BufferUsages::COPY_SRC | BufferUsages::COPY_DST;
+        Self {
+            masses: GpuBuffer::new(object_count, "mass snapshot", usage, device),
+            colors: GpuBuffer::new(object_count, "color snapshot", usage, device),
+            shapes: GpuBuffer::new(object_count, "shape snapshot", usage, device),
+            sizes: GpuBuffer::new(object_count, "size snapshot", usage, device),
+            moments: GpuBuffer::new(object_count, "second moment snapshot", usage, device),
+            materials: GpuBuffer::new(object_count, "material snapshot", usage, device),
+        }
+    }
+
+    /// Pairs of the object buffers and their copies
+    fn pairs<'a>(&'a self, buffers: &'a ObjectBuffers) -> [(&'a wgpu::Buffer, &'a wgpu::Buffer); 6] {
+        [
+            (buffers.masses.buffer(), self.masses.buffer()),
+            (buffers.colors.buffer(), self.colors.buffer()),
+            (buffers.shapes.buffer(), self.shapes.buffer()),
+            (buffers.sizes.buffer(), self.sizes.buffer()),
+            (buffers.moments.buffer(), self.moments.buffer()),
+            (buffers.materials.buffer(), self.materials.buffer()),
+        ]
+    }
+}
+
+/// The physics engine. It owns the object buffers on the GPU, renderers and other users get clones of them through
+/// [`Self::buffers`].
+pub struct Simulation {
+    buffers: ObjectBuffers,
+    bvh_builder: BvhBuilder,
+    gpu_integrator: GpuIntegrator,
+    contact_solver: Option<ContactSolver>,
+    constraint_solver: Option<ConstraintSolver>,
+    level_collider: Option<LevelCollider>,
+    sleep_manager: SleepManager,
+    accretor: Accretor,
+    accretion: bool,
+    impact_resolver: Option<ImpactResolver>,
+    /// Read by the impact pass if there are free slots
+    spawn_request: Option<GpuBuffer<SpawnRequest>>,
+    tools: GpuBuffer<ToolState>,
+    forces: ForceRegistry,
+    /// Step size of the last step before the time scale is applied
+    dt: f32,
+    parameters: GpuBuffer<IntegrationParameters>,
+    integrator: Integrator,
+    time_scale: f32,
+    step: u64,
+    time: f64,
+    history: VecDeque<Snapshot>,
+    durations: StepDurations,
+    diagnostics: Option<DiagnosticsReducer>,
+    diagnostics_samples: VecDeque<DiagnosticsSample>,
+    object_count: usize,
+    integration_duration_measurer: PassDurationMeasurer,
+    bvh_duration_measurer: PassDurationMeasurer,
+    update_duration_measurer: PassDurationMeasurer,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+}
+
+impl Simulation {
+    /// Uploads `objects` and prepares the compute pipelines, the device has to come from
+    /// [`request_device`](crate::request_device)
+    pub fn new(mut objects: Objects, device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let constraints = std::mem::take(&mut objects.constraints);
+        let level = objects.level.clone();
+        let skip_contacts = objects.skip_contacts;
+        let has_materials = objects.materials.iter().any(|material| material.inner != MATERIAL_INERT);
+        let has_free_slots = objects.flags.iter().any(|flags| flags.inner & (FLAG_PHYSICAL | FLAG_DRAW_OBJECT) == 0);
+        let buffers = objects.to_buffers(device, queue);
+        let constraint_solver = ConstraintSolver::new(device, queue, &constraints, &buffers);
+        let level_collider = LevelCollider::new(device, queue, &level, &buffers);
+        let integrator = Integrator::default();
+        let parameters =
+            GpuBuffer::new(1, "integration parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        parameters.write(queue, &[IntegrationParameters::new(0.0, integrator.to_wgsl())]);
+        let tools = GpuBuffer::new(1, "tool state buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        tools.write(queue, &[ToolState::new([0.0, 0.0], 0.0, 1.0, TOOL_NONE, [0.0, 0.0])]);
+        let forces = ForceRegistry::new(device);
+        let sleep_manager = SleepManager::new(device, queue, &constraints, !skip_contacts, &buffers, tools.clone());
+        let accretor = Accretor::new(device, &buffers);
+        let spawn_request =
+            GpuBuffer::new(1, "spawn request buffer", BufferUsages::STORAGE | BufferUsages::COPY_DST, device);
+        spawn_request.write(queue, &[SpawnRequest::new([0.0, 0.0], [0.0, 0.0], 0.0, 0.0, 0)]);
+        let impact_resolver =
+            ImpactResolver::new(device, queue, has_materials, has_free_slots, &constraints, &buffers, &spawn_request);
+        let contact_solver = (!skip_contacts).then(|| ContactSolver::new(device, &buffers, tools.clone()));
+
+        let object_count = buffers.flags.len();
+        let bvh_builder = BvhBuilder::new(
+            device,
+            buffers.aabbs.clone(),
+            buffers.bvh_nodes.clone(),
+            buffers.sleeping_nodes.clone(),
+            object_count,
+        );
+
+        let gpu_integrator = GpuIntegrator::new(
+            device,
+            parameters.clone(),
+            buffers.flags.clone(),
+            buffers.masses.clone(),
+            buffers.velocities.clone(),
+            buffers.aabbs.clone(),
+            buffers.integrated_velocities.clone(),
+            buffers.integrated_aabbs.clone(),
+            buffers.shapes.clone(),
+            tools.clone(),
+            forces.buffer(),
+            buffers.sizes.clone(),
+            buffers.angles.clone(),
+            buffers.angular_velocities.clone(),
+            buffers.integrated_angles.clone(),
+            buffers.polygons.clone(),
+            buffers.polygon_vertices.clone(),
+            buffers.moments.clone(),
+            buffers.blackholes.clone(),
+        );
+
+        Self {
+            buffers,
+            bvh_builder,
+            gpu_integrator,
+            contact_solver,
+            constraint_solver,
+            level_collider,
+            sleep_manager,
+            accretor,
+            accretion: false,
+            impact_resolver,
+            tools,
+            spawn_request: has_free_slots.then_some(spawn_request),
+            forces,
+            dt: 0.0,
+            parameters,
+            integrator,
+            time_scale: 1.0,
+            step: 0,
+            time: 0.0,
+            history: VecDeque::with_capacity(HISTORY_LENGTH),
+            durations: StepDurations::default(),
+            diagnostics: None,
+            diagnostics_samples: VecDeque::new(),
+            object_count,
+            integration_duration_measurer: PassDurationMeasurer::new(device),
+            bvh_duration_measurer: PassDurationMeasurer::new(device),
+            update_duration_measurer: PassDurationMeasurer::new(device),
+            device: device.clone(),
+            queue: queue.clone(),
+        }
+    }
+
+    /// Buffers holding the objects' state, they are updated in place by every step
+    pub fn buffers(&self) -> &ObjectBuffers {
+        &self.buffers
+    }
+
+    pub fn object_count(&self) -> usize {
+        self.object_count
+    }
+
+    /// Reads the state of all objects back from the GPU, this waits for the device
+    pub fn read_objects(&self) -> Vec<ObjectState> {
+        let (device, queue) = (&self.device, &self.queue);
+        let flags = self.buffers.flags.read_back(device, queue, self.object_count);
+        let aabbs = self.buffers.aabbs.read_back(device, queue, self.object_count);
+        let velocities = self.buffers.velocities.read_back(device, queue, self.object_count);
+        let angles = self.buffers.angles.read_back(device, queue, self.object_count);
+        let angular_velocities = self.buffers.angular_velocities.read_back(device, queue, self.object_count);
+        itertools::izip!(flags, aabbs, velocities, angles, angular_velocities)
+            .map(|(flags, aabb, velocity, angle, angular_velocity)| {
+                ObjectState::new(flags, aabb, velocity, angle, angular_velocity)
+            })
+            .collect()
+    }
+
+    /// State of the black holes, they only move and grow with accretion. This waits for the device.
+    pub fn read_blackholes(&self) -> Vec<BlackHole> {
+        self.buffers.blackholes.read_back(&self.device, &self.queue, 1)[0].blackholes.to_vec()
+    }
+
+    /// Number of objects every black hole consumed, whether accretion was enabled or not. This waits for the device.
+    pub fn consumed_counts(&self) -> Vec<u32> {
+        let accretion = self.accretor.accretion();
+        accretion
+            .read_back(&self.device, &self.queue, accretion.len())
+            .iter()
+            .map(|accretion| accretion.consumed)
+            .collect()
+    }
+
+    /// Uniform read by the integrator every step, writing it from other threads steers the simulation
+    pub fn tool_state(&self) -> GpuBuffer<ToolState> {
+        self.tools.clone()
+    }
+
+    /// Circle for the paint tool to spawn in the next step, `None` if the objects have no free slots to spawn into
+    pub fn spawn_request(&self) -> Option<GpuBuffer<SpawnRequest>> {
+        self.spawn_request.clone()
+    }
+
+    /// Forces acting on the objects besides the black holes and the cursor, changes apply from the next step on.
+    /// Rewinding and resetting leave them as they are.
+    pub fn forces(&self) -> &ForceRegistry {
+        &self.forces
+    }
+
+    pub fn forces_mut(&mut self) -> &mut ForceRegistry {
+        &mut self.forces
+    }
+
+    pub fn status(&self, paused: bool) -> SimulationStatus {
+        SimulationStatus {
+            paused,
+            step: self.step,
+            time: self.time,
+            time_scale: self.time_scale,
+            dt: self.dt * self.time_scale,
+            integrator: self.integrator,
+            accretion: self.accretion,
+            snapshots: self.history.len(),
+            durations: self.durations,
+            diagnostics: self.diagnostics_samples.back().map(|sample| sample.diagnostics),
+        }
+    }
+
+    /// Reduces the conserved quantities after every step from now on, angular momentum is taken about `origin`
+    pub fn enable_diagnostics(&mut self, origin: [f32; 2]) {
+        self.diagnostics = Some(DiagnosticsReducer::new(&self.device, &self.queue, &self.buffers, origin));
+    }
+
+    /// One sample per step since diagnostics were enabled, up to the last 65536 steps. Samples after a rewind target
+    /// are dropped.
+    pub fn diagnostics(&self) -> &VecDeque<DiagnosticsSample> {
+        &self.diagnostics_samples
+    }
+
+    pub fn scale_time(&mut self, factor: f32) {
+        self.time_scale = (self.time_scale * factor).clamp(*TIME_SCALE_RANGE.start(), *TIME_SCALE_RANGE.end());
+        self.write_parameters();
+    }
+
+    pub fn set_integrator(&mut self, integrator: Integrator) {
+        self.integrator = integrator;
+        self.write_parameters();
+    }
+
+    /// With accretion, objects crossing an event horizon add their mass and momentum to the black hole, so black
+    /// holes grow and drift. Without it, the consumed matter vanishes.
+    pub fn set_accretion(&mut self, accretion: bool) {
+        self.accretion = accretion;
+        self.write_parameters();
+    }
+
+    /// Changes when objects count as at rest and how long they have to be before they sleep
+    pub fn set_sleep_settings(&mut self, settings: SleepSettings) {
+        self.sleep_manager.write_settings(&self.queue, settings);
+    }
+
+    fn write_parameters(&self) {
+        let parameters = IntegrationParameters::new(self.dt * self.time_scale, self.integrator.to_wgsl());
+        self.parameters.write(&self.queue, &[parameters]);
+        self.accretor.write_parameters(&self.queue, self.dt * self.time_scale, self.accretion);
+    }
+
+    /// Restores the most recent snapshot, returns false if there is none. Calling it again goes further back.
+    pub fn rewind(&mut self) -> bool {
+        let Some(snapshot) = self.history.pop_back() else {
+            return false;
+        };
+        self.restore(&snapshot);
+        true
+    }
+
+    fn restore(&mut self, snapshot: &Snapshot) {
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor::default());
+        let buffers = &self.buffers;
+        encoder.copy_buffer_to_buffer(snapshot.flags.buffer(), 0, buffers.flags.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(snapshot.velocities.buffer(), 0, buffers.velocities.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(snapshot.angles.buffer(), 0, buffers.angles.buffer(), 0, None);
+        let angular_velocities = buffers.angular_velocities.buffer();
+        encoder.copy_buffer_to_buffer(snapshot.angular_velocities.buffer(), 0, angular_velocities, 0, None);
+        // Only the objects' AABBs are saved, the tree is rebuilt in the next step
+        encoder.copy_buffer_to_buffer(snapshot.aabbs.buffer(), 0, buffers.aabbs.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(snapshot.blackholes.buffer(), 0, buffers.blackholes.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(snapshot.accretion.buffer(), 0, self.accretor.accretion().buffer(), 0, None);
+        if let Some(bodies) = &snapshot.bodies {
+            for (buffer, copy) in bodies.pairs(buffers) {
+                encoder.copy_buffer_to_buffer(copy, 0, buffer, 0, None);
+            }
+        }
+        self.sleep_manager.reset(&mut encoder);
+        self.device.wait_for_submission(self.queue.submit([encoder.finish()])).unwrap();
+        self.step = snapshot.step;
+        self.time = snapshot.time;
+        self.diagnostics_samples.retain(|sample| sample.step <= self.step);
+    }
+
+    fn save_snapshot(&mut self, encoder: &mut CommandEncoder) {
+        let snapshot = if self.history.len() < HISTORY_LENGTH {
+            self.create_snapshot()
+        } else {
+            Snapshot {
+                step: self.step,
+                time: self.time,
+                ..self.history.pop_front().unwrap()
+            }
+        };
+        self.copy_to_snapshot(encoder, &snapshot);
+        self.history.push_back(snapshot);
+    }
+
+    fn create_snapshot(&self) -> Snapshot {
+        let usage = BufferUsages::COPY_SRC | BufferUsages::COPY_DST;
+        Snapshot {
+            step: self.step,
+            time: self.time,
+            flags: GpuBuffer::new(self.object_count, "flags snapshot", usage, &self.device),
+            aabbs: GpuBuffer::new(self.object_count, "aabb snapshot", usage, &self.device),
+            velocities: GpuBuffer::new(self.object_count, "velocity snapshot", usage, &self.device),
+            angles: GpuBuffer::new(self.object_count, "angle snapshot", usage, &self.device),
+            angular_velocities: GpuBuffer::new(self.object_count, "angular velocity snapshot", usage, &self.device),
+            blackholes: GpuBuffer::new(1, "black hole snapshot", usage, &self.device),
+            accretion: GpuBuffer::new(self.accretor.accretion().len(), "accretion snapshot", usage, &self.device),
+            bodies: self.impact_resolver.as_ref().map(|_| BodySnapshot::new(self.object_count, &self.device)),
+        }
+    }
+
+    fn copy_to_snapshot(&self, encoder: &mut CommandEncoder, snapshot: &Snapshot) {
+        let buffers = &self.buffers;
+        encoder.copy_buffer_to_buffer(buffers.flags.buffer(), 0, snapshot.flags.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(buffers.velocities.buffer(), 0, snapshot.velocities.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(buffers.angles.buffer(), 0, snapshot.angles.buffer(), 0, None);
+        let angular_velocities = snapshot.angular_velocities.buffer();
+        encoder.copy_buffer_to_buffer(buffers.angular_velocities.buffer(), 0, angular_velocities, 0, None);
+        let aabbs_size = snapshot.aabbs.buffer().size();
+        encoder.copy_buffer_to_buffer(buffers.aabbs.buffer(), 0, snapshot.aabbs.buffer(), 0, aabbs_size);
+        encoder.copy_buffer_to_buffer(buffers.blackholes.buffer(), 0, snapshot.blackholes.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(self.accretor.accretion().buffer(), 0, snapshot.accretion.buffer(), 0, None);
+        if let Some(bodies) = &snapshot.bodies {
+            for (buffer, copy) in bodies.pairs(buffers) {
+                encoder.copy_buffer_to_buffer(buffer, 0, copy, 0, None);
+            }
+        }
+    }
+
+    /// Advances the simulation by `dt` times the time scale and waits for the step to finish, returns the BVH node
+    /// count
+    pub fn step(&mut self, dt: f32) -> u32 {
+        self.step_after(dt, None)
+    }
+
+    /// Like [`Self::step`], but the step is submitted only after `render_submission_index` is done, so that it
+    /// doesn't change the buffers while they are rendered
+    pub fn step_after(&mut self, dt: f32, render_submission_index: Option<SubmissionIndex>) -> u32 {
+        let compute_start = Instant::now();
+        if dt != self.dt {
+            self.dt = dt;
+            self.write_parameters();
+        }
+        let forces_changed = self.forces.upload(&self.queue);
+
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor::default());
+        if self.step.is_multiple_of(SNAPSHOT_INTERVAL) {
+            self.save_snapshot(&mut encoder);
+        }
+
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("bvh pass"),
+            timestamp_writes: Some(self.bvh_duration_measurer.compute_pass_timestamp_writes()),
+        });
+        self.bvh_builder.compute(&mut compute_pass);
+        drop(compute_pass);
+
+        let node_count = self.bvh_builder.node_count();
+        self.sleep_manager.compute(&mut encoder, self.dt * self.time_scale, forces_changed);
+
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("integration pass"),
+            timestamp_writes: Some(self.integration_duration_measurer.compute_pass_timestamp_writes()),
+        });
+        self.gpu_integrator.compute(&mut compute_pass);
+        drop(compute_pass);
+        self.accretor.compute(&mut encoder);
+        if let Some(impact_resolver) = &self.impact_resolver {
+            impact_resolver.compute(&mut encoder);
+        }
+        if let Some(constraint_solver) = &self.constraint_solver {
+            constraint_solver.compute(&mut encoder, self.dt * self.time_scale);
+        }
+        if let Some(level_collider) = &self.level_collider {
+            level_collider.compute(&mut encoder);
+        }
+        if let Some(contact_solver) = &self.contact_solver {
+            contact_solver.compute(&mut encoder);
+        }
+
+        self.update_duration_measurer.measure(&mut encoder, |encoder| {
+            let buffers = &self.buffers;
+            encoder.copy_buffer_to_buffer(
+                buffers.integrated_velocities.buffer(),
+                0,
+                buffers.velocities.buffer(),
+                0,
+                None,
+            );
+            // Copying the entire buffer is okay because integrated_aabbs is of object_count length
+            encoder.copy_buffer_to_buffer(buffers.integrated_aabbs.buffer(), 0, buffers.aabbs.buffer(), 0, None);
+            encoder.copy_buffer_to_buffer(buffers.integrated_angles.buffer(), 0, buffers.angles.buffer(), 0, None);
+        });
+
+        if let Some(diagnostics) = &mut self.diagnostics {
+            let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+                label: Some("diagnostics pass"),
+                timestamp_writes: None,
+            });
+            diagnostics.compute(&mut compute_pass);
+            drop(compute_pass);
+            diagnostics.copy_to_readback(&mut encoder, self.step + 1);
+        }
+
+        self.bvh_duration_measurer.update(&mut encoder);
+        self.integration_duration_measurer.update(&mut encoder);
+        self.update_duration_measurer.update(&mut encoder);
+
+        let command_buffer = encoder.finish();
+        if let Some(render_submission_index) = render_submission_index {
+            self.device.wait_for_submission(render_submission_index).unwrap();
+        }
+        let submission_index = self.queue.submit([command_buffer]);
+        if let Some(diagnostics) = &mut self.diagnostics {
+            diagnostics.map_readback();
+        }
+        log::trace!("Submitted command buffer at {:?}", compute_start.elapsed());
+        self.device.wait_for_submission(submission_index).unwrap();
+        log::trace!("Compute done in {:?}", compute_start.elapsed());
+
+        self.durations = StepDurations {
+            bvh: self.bvh_duration_measurer.duration(),
+            integration: self.integration_duration_measurer.duration(),
+            update: self.update_duration_measurer.duration(),
+        };
+        log::debug!(
+            "Built BVH with {} nodes in {:?}, integrated {} objects in {:?}, updated them in {:?}",
+            node_count,
+            self.durations.bvh,
+            self.object_count,
+            self.durations.integration,
+            self.durations.update
+        );
+
+        self.step += 1;
+        self.forces.finish_step();
+        self.time += f64::from(self.dt * self.time_scale);
+        // Waiting for the submission also finished mapping its readback
+        if let Some(diagnostics) = &mut self.diagnostics {
+            for sample in diagnostics.take_samples() {
+                if self.diagnostics_samples.len() == DIAGNOSTICS_LENGTH {
+                    self.diagnostics_samples.pop_front();
+                }
+                self.diagnostics_samples.push_back(sample);
+            }
+        }
+        node_count
+    }
+}
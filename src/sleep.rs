@@ -0,0 +1,181 @@
+use itertools::Itertools as _;
+use wgpu::{BufferUsages, CommandEncoder, ComputePass, ComputePassDescriptor, ComputePipeline, Device, Queue};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::ObjectBuffers,
+    shaders::{
+        blackholes::BlackHoleSet,
+        common::Velocity,
+        constraints::Constraint,
+        sleep::{
+            ISLAND_ITERATIONS, SleepPass, SleepSettings, WORKGROUP_SIZE, WgpuBindGroup0, WgpuBindGroup0Entries,
+            WgpuBindGroup0EntriesParams,
+            compute::{
+                create_apply_sleep_pipeline_embed_source, create_check_contacts_pipeline_embed_source,
+                create_check_links_pipeline_embed_source, create_hook_contacts_pipeline_embed_source,
+                create_hook_links_pipeline_embed_source, create_jump_pipeline_embed_source,
+                create_mark_restless_pipeline_embed_source, create_update_rest_times_pipeline_embed_source,
+            },
+        },
+        tool_state::ToolState,
+    },
+};
+
+/// Puts islands of touching or connected objects to sleep once all of them have been at rest for a while, the
+/// integrator, the level collisions, the contacts, the constraints and the BVH refit then skip them. Runs after the BVH
+/// build, as it finds the islands with it.
+pub struct SleepManager {
+    update_rest_times_pipeline: ComputePipeline,
+    hook_contacts_pipeline: ComputePipeline,
+    hook_links_pipeline: ComputePipeline,
+    jump_pipeline: ComputePipeline,
+    check_contacts_pipeline: ComputePipeline,
+    check_links_pipeline: ComputePipeline,
+    mark_restless_pipeline: ComputePipeline,
+    apply_sleep_pipeline: ComputePipeline,
+    bind_group: WgpuBindGroup0,
+    rest_times: GpuBuffer<f32>,
+    last_velocities: GpuBuffer<Velocity>,
+    restless: GpuBuffer<u32>,
+    blackholes: GpuBuffer<BlackHoleSet>,
+    last_blackholes: GpuBuffer<BlackHoleSet>,
+    settings: GpuBuffer<SleepSettings>,
+    sleeping_nodes: GpuBuffer<u32>,
+    object_count: u32,
+    link_count: u32,
+    /// Whether touching objects form islands, they don't if they pass through each other
+    contacts: bool,
+}
+
+impl SleepManager {
+    pub fn new(
+        device: &Device,
+        queue: &Queue,
+        constraints: &[Constraint],
+        contacts: bool,
+        buffers: &ObjectBuffers,
+        tools: GpuBuffer<ToolState>,
+    ) -> Self {
+        let object_count = buffers.flags.len();
+        let storage = BufferUsages::STORAGE | BufferUsages::COPY_DST;
+        let rest_times = GpuBuffer::new(object_count, "rest time buffer", storage, device);
+        let last_velocities = GpuBuffer::new(object_count, "last velocity buffer", storage, device);
+        let last_blackholes =
+            GpuBuffer::new(1, "last black hole buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        let settings =
+            GpuBuffer::new(1, "sleep settings buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        settings.write(queue, &[SleepSettings::default()]);
+        let islands = GpuBuffer::<u32>::new(object_count, "island buffer", storage, device);
+        let restless = GpuBuffer::new(object_count, "restless island buffer", storage, device);
+        // Bindings can't be empty
+        let links = GpuBuffer::new(constraints.len().max(1), "link buffer", storage, device);
+        if !constraints.is_empty() {
+            links.write(queue, &constraints.iter().map(|constraint| [constraint.a, constraint.b]).collect_vec());
+        }
+
+        let bind_group = WgpuBindGroup0::from_bindings(
+            device,
+            WgpuBindGroup0Entries::new(WgpuBindGroup0EntriesParams {
+                flags: buffers.flags.buffer().as_entire_buffer_binding(),
+                aabbs: buffers.aabbs.buffer().as_entire_buffer_binding(),
+                nodes: buffers.bvh_nodes.buffer().as_entire_buffer_binding(),
+                velocities: buffers.velocities.buffer().as_entire_buffer_binding(),
+                angular_velocities: buffers.angular_velocities.buffer().as_entire_buffer_binding(),
+                rest_times: rest_times.buffer().as_entire_buffer_binding(),
+                islands: islands.buffer().as_entire_buffer_binding(),
+                restless: restless.buffer().as_entire_buffer_binding(),
+                sleeping_nodes: buffers.sleeping_nodes.buffer().as_entire_buffer_binding(),
+                links: links.buffer().as_entire_buffer_binding(),
+                tools: tools.buffer().as_entire_buffer_binding(),
+                last_velocities: last_velocities.buffer().as_entire_buffer_binding(),
+                blackhole_set: buffers.blackholes.buffer().as_entire_buffer_binding(),
+                last_blackhole_set: last_blackholes.buffer().as_entire_buffer_binding(),
+                settings: settings.buffer().as_entire_buffer_binding(),
+            }),
+        );
+        Self {
+            update_rest_times_pipeline: create_update_rest_times_pipeline_embed_source(device),
+            hook_contacts_pipeline: create_hook_contacts_pipeline_embed_source(device),
+            hook_links_pipeline: create_hook_links_pipeline_embed_source(device),
+            jump_pipeline: create_jump_pipeline_embed_source(device),
+            check_contacts_pipeline: create_check_contacts_pipeline_embed_source(device),
+            check_links_pipeline: create_check_links_pipeline_embed_source(device),
+            mark_restless_pipeline: create_mark_restless_pipeline_embed_source(device),
+            apply_sleep_pipeline: create_apply_sleep_pipeline_embed_source(device),
+            bind_group,
+            rest_times,
+            last_velocities,
+            restless,
+            blackholes: buffers.blackholes.clone(),
+            last_blackholes,
+            settings,
+            sleeping_nodes: buffers.sleeping_nodes.clone(),
+            object_count: u32::try_from(object_count).unwrap(),
+            link_count: u32::try_from(constraints.len()).unwrap(),
+            contacts,
+        }
+    }
+
+    pub fn write_settings(&self, queue: &Queue, settings: SleepSettings) {
+        self.settings.write(queue, &[settings]);
+    }
+
+    /// Updates the sleep flags for the coming step, `dt` is the step size including the time scale. `wake_all` wakes
+    /// every object, for when the forces change.
+    pub fn compute(&self, encoder: &mut CommandEncoder, dt: f32, wake_all: bool) {
+        encoder.clear_buffer(self.restless.buffer(), 0, None);
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("sleep pass"),
+            timestamp_writes: None,
+        });
+        let pass = SleepPass::new(dt, u32::from(wake_all));
+        self.dispatch(&mut compute_pass, &self.update_rest_times_pipeline, pass, self.object_count);
+        // Without contacts and links every object is an island of its own
+        if self.contacts || self.link_count > 0 {
+            for _ in 0..ISLAND_ITERATIONS {
+                if self.contacts {
+                    self.dispatch(&mut compute_pass, &self.hook_contacts_pipeline, pass, self.object_count);
+                }
+                if self.link_count > 0 {
+                    self.dispatch(&mut compute_pass, &self.hook_links_pipeline, pass, self.link_count);
+                }
+                self.dispatch(&mut compute_pass, &self.jump_pipeline, pass, self.object_count);
+            }
+            // Islands that didn't converge stay awake
+            if self.contacts {
+                self.dispatch(&mut compute_pass, &self.check_contacts_pipeline, pass, self.object_count);
+            }
+            if self.link_count > 0 {
+                self.dispatch(&mut compute_pass, &self.check_links_pipeline, pass, self.link_count);
+            }
+        }
+        self.dispatch(&mut compute_pass, &self.mark_restless_pipeline, pass, self.object_count);
+        self.dispatch(&mut compute_pass, &self.apply_sleep_pipeline, pass, self.object_count);
+        drop(compute_pass);
+        encoder.copy_buffer_to_buffer(self.blackholes.buffer(), 0, self.last_blackholes.buffer(), 0, None);
+    }
+
+    /// Forgets how long the objects have been at rest and makes the next BVH build refit the whole tree, for when
+    /// the objects' state is overwritten
+    pub fn reset(&self, encoder: &mut CommandEncoder) {
+        encoder.clear_buffer(self.rest_times.buffer(), 0, None);
+        encoder.clear_buffer(self.last_velocities.buffer(), 0, None);
+        encoder.clear_buffer(self.sleeping_nodes.buffer(), 0, None);
+    }
+
+    fn dispatch(&self, compute_pass: &mut ComputePass, pipeline: &ComputePipeline, pass: SleepPass, invocations: u32) {
+        compute_pass.set_pipeline(pipeline);
+        self.bind_group.set(compute_pass);
+        compute_pass.set_push_constants(0, bytemuck::cast_slice(&[pass]));
+        let total_workgroups = invocations.div_ceil(WORKGROUP_SIZE);
+        compute_pass.dispatch_workgroups(total_workgroups.min(65535), total_workgroups.div_ceil(65535), 1);
+    }
+}
+
+impl Default for SleepSettings {
+    /// At rest below half a unit per second, and asleep after half a second of it
+    fn default() -> Self {
+        Self::new(0.5, 0.05, 0.5, 0.5)
+    }
+}
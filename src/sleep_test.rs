@@ -0,0 +1,143 @@
+use wgpu::{BufferUsages, CommandEncoderDescriptor, ComputePassDescriptor};
+
+use crate::{
+    DT,
+    blackholes::BLACKHOLES,
+    bvh_builder::BvhBuilder,
+    forces::Force,
+    gpu_buffer::GpuBuffer,
+    objects::{ObjectPrototype, Objects},
+    shaders::{
+        common::FLAG_SLEEPING,
+        sleep::SleepSettings,
+        tool_state::{TOOL_NONE, ToolState},
+    },
+    simulation::Simulation,
+    sleep::SleepManager,
+    test_util::{TestDevice, circle, test_device_or_skip},
+    util::DeviceUtil,
+};
+
+/// Sleeps after 20 steps at rest, and counts the circles pressed against the walls as at rest
+const QUICK_SLEEP: SleepSettings = SleepSettings::new(1.0, 0.05, 0.5, 20.0 * DT);
+
+#[test]
+fn resting_islands_sleep_together_and_wake_on_force_changes() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // The black holes press the circles into the lower right corners of two boxes, where the walls stop them. The
+    // spinning circle never comes to rest, so the one next to it stays awake with it.
+    let mut objects = Objects::default();
+    objects.push(circle([-882.0, 882.0], 2.0));
+    objects.push(circle([-886.0, 882.0], 2.0));
+    objects.push(ObjectPrototype {
+        angular_velocity: 5.0,
+        ..circle([-882.0, 782.0], 2.0)
+    });
+    objects.push(circle([-886.0, 782.0], 2.0));
+    // Falling freely
+    objects.push(circle([-900.0, 700.0], 2.0));
+    objects.level = "\
+        loop -920,880 -880,880 -880,920 -920,920
+        loop -920,780 -880,780 -880,820 -920,820
+    "
+    .parse()
+    .unwrap();
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.set_sleep_settings(QUICK_SLEEP);
+    for _ in 0..50 {
+        simulation.step(DT);
+    }
+
+    let sleeping = |simulation: &Simulation| {
+        simulation.read_objects().iter().map(|state| state.flags & FLAG_SLEEPING != 0).collect::<Vec<_>>()
+    };
+    assert_eq!(sleeping(&simulation), [true, true, false, false, false]);
+    let states = simulation.read_objects();
+    simulation.step(DT);
+    assert_eq!(simulation.read_objects()[..2], states[..2], "sleeping objects moved");
+
+    simulation.forces_mut().add(Force::LinearDrag { coefficient: 0.1 }).unwrap();
+    simulation.step(DT);
+    assert_eq!(sleeping(&simulation), [false; 5]);
+}
+
+#[test]
+fn black_holes_that_change_wake_the_objects_they_pull() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    objects.push(circle([-882.0, 882.0], 2.0));
+    objects.push(circle([882.0, 882.0], 2.0));
+    objects.level = "\
+        loop -920,880 -880,880 -880,920 -920,920
+        loop 880,880 920,880 920,920 880,920
+    "
+    .parse()
+    .unwrap();
+    let mut simulation = Simulation::new(objects, device, queue);
+    simulation.set_sleep_settings(QUICK_SLEEP);
+    for _ in 0..50 {
+        simulation.step(DT);
+    }
+    let sleeping = |simulation: &Simulation| {
+        simulation.read_objects().iter().map(|state| state.flags & FLAG_SLEEPING != 0).collect::<Vec<_>>()
+    };
+    assert_eq!(sleeping(&simulation), [true, true]);
+
+    // The black hole at the right grows a little, which is only noticeable in the box next to it
+    let mut blackholes = BLACKHOLES;
+    blackholes.blackholes[1].mass *= 1.0003;
+    simulation.buffers().blackholes.write(queue, &[blackholes]);
+    simulation.step(DT);
+    assert_eq!(sleeping(&simulation), [true, false]);
+}
+
+#[test]
+fn islands_sleep_as_a_whole() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    // A long row of overlapping circles placed in bit reversed order, its labels don't converge within the rounds of
+    // a pass, and a short one that surely does
+    let long_row = (0..4096u32).map(|i| i.reverse_bits() >> 20).collect::<Vec<_>>();
+    let mut objects = Objects::default();
+    for &position in &long_row {
+        objects.push(ObjectPrototype {
+            // Keeps the long row awake
+            velocity: if position == 0 { [1.0, 0.0] } else { [0.0, 0.0] },
+            ..circle([-900.0 + position as f32 * 0.4, 0.0], 0.25)
+        });
+    }
+    for position in 0..4 {
+        objects.push(circle([-900.0 + position as f32 * 0.4, 900.0], 0.25));
+    }
+    let object_count = objects.len();
+    let buffers = objects.to_buffers(device, queue);
+    let mut bvh_builder = BvhBuilder::new(
+        device,
+        buffers.aabbs.clone(),
+        buffers.bvh_nodes.clone(),
+        buffers.sleeping_nodes.clone(),
+        object_count,
+    );
+    let tools = GpuBuffer::new(1, "tool state buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+    tools.write(queue, &[ToolState::new([0.0, 0.0], 0.0, 1.0, TOOL_NONE, [0.0, 0.0])]);
+    let sleep_manager = SleepManager::new(device, queue, &[], true, &buffers, tools);
+
+    // Nothing is integrated, the first pass only sees the black holes appear
+    for _ in 0..3 {
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+        let mut compute_pass = encoder.begin_compute_pass(&ComputePassDescriptor::default());
+        bvh_builder.compute(&mut compute_pass);
+        drop(compute_pass);
+        sleep_manager.compute(&mut encoder, 1.0, false);
+        device.wait_for_submission(queue.submit([encoder.finish()])).unwrap();
+    }
+
+    let sleeping = buffers
+        .flags
+        .read_back(device, queue, object_count)
+        .iter()
+        .map(|flags| flags.inner & FLAG_SLEEPING != 0)
+        .collect::<Vec<_>>();
+    let (long_row, short_row) = sleeping.split_at(long_row.len());
+    assert!(long_row.iter().all(|&sleeping| !sleeping), "part of the long row sleeps");
+    assert_eq!(short_row, [true; 4]);
+}
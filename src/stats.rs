@@ -0,0 +1,186 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context as _;
+
+use crate::simulation::SimulationStatus;
+
+/// File format of a [`StatsWriter`], chosen by the file extension
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StatsFormat {
+    /// One header line and one line per sample
+    Csv,
+    /// An array with one object per sample
+    Json,
+}
+
+impl StatsFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Csv,
+        }
+    }
+}
+
+enum Value {
+    Integer(u64),
+    Float(f32),
+    Double(f64),
+    /// Empty in CSV, `null` in JSON
+    Missing,
+}
+
+impl Value {
+    fn write(&self, writer: &mut impl Write, format: StatsFormat) -> std::io::Result<()> {
+        fn finite(
+            writer: &mut impl Write,
+            format: StatsFormat,
+            value: impl Display,
+            is_finite: bool,
+        ) -> std::io::Result<()> {
+            match (is_finite, format) {
+                (true, _) => write!(writer, "{value}"),
+                (false, StatsFormat::Csv) => Ok(()),
+                (false, StatsFormat::Json) => write!(writer, "null"),
+            }
+        }
+        match *self {
+            Self::Integer(value) => write!(writer, "{value}"),
+            Self::Float(value) => finite(writer, format, value, value.is_finite()),
+            Self::Double(value) => finite(writer, format, value, value.is_finite()),
+            Self::Missing => finite(writer, format, "", false),
+        }
+    }
+}
+
+/// Writes one sample of the simulation status per `interval` steps. The file is flushed and, for JSON, closed
+/// when the writer is finished or dropped.
+pub struct StatsWriter {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    format: StatsFormat,
+    interval: u64,
+    object_count: usize,
+    sample_count: usize,
+    finished: bool,
+}
+
+impl StatsWriter {
+    pub fn create(path: &Path, interval: u64, object_count: usize) -> anyhow::Result<Self> {
+        anyhow::ensure!(interval > 0, "The stats interval must be at least 1");
+        let file = File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+        let format = StatsFormat::from_path(path);
+        let mut stats_writer = Self {
+            writer: BufWriter::new(file),
+            path: path.to_owned(),
+            format,
+            interval,
+            object_count,
+            sample_count: 0,
+            finished: false,
+        };
+        let header = match format {
+            StatsFormat::Csv => Self::fields(&SimulationStatus::default(), 0, 0).map(|(name, _)| name).join(","),
+            StatsFormat::Json => "[".to_owned(),
+        };
+        writeln!(stats_writer.writer, "{header}").context("Failed to write stats header")?;
+        Ok(stats_writer)
+    }
+
+    /// Writes a sample if `status.step` is a multiple of the interval, `node_count` is the BVH's
+    pub fn record(&mut self, status: &SimulationStatus, node_count: u32) -> anyhow::Result<()> {
+        if !status.step.is_multiple_of(self.interval) {
+            return Ok(());
+        }
+        self.write_sample(status, node_count)
+            .with_context(|| format!("Failed to write stats to {}", self.path.display()))
+    }
+
+    fn write_sample(&mut self, status: &SimulationStatus, node_count: u32) -> std::io::Result<()> {
+        let fields = Self::fields(status, self.object_count, node_count);
+        let writer = &mut self.writer;
+        match self.format {
+            StatsFormat::Csv => {
+                for (i, (_, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(writer, ",")?;
+                    }
+                    value.write(writer, self.format)?;
+                }
+                writeln!(writer)?;
+            }
+            StatsFormat::Json => {
+                if self.sample_count > 0 {
+                    writeln!(writer, ",")?;
+                }
+                write!(writer, "  {{")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    write!(writer, "{}\"{name}\": ", if i > 0 { ", " } else { "" })?;
+                    value.write(writer, self.format)?;
+                }
+                write!(writer, "}}")?;
+            }
+        }
+        self.sample_count += 1;
+        Ok(())
+    }
+
+    /// Flushes the file, closing the JSON array first
+    pub fn finish(mut self) -> anyhow::Result<()> {
+        self.finish_file().with_context(|| format!("Failed to finish {}", self.path.display()))
+    }
+
+    fn finish_file(&mut self) -> std::io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+        if self.format == StatsFormat::Json {
+            if self.sample_count > 0 {
+                writeln!(self.writer)?;
+            }
+            writeln!(self.writer, "]")?;
+        }
+        self.writer.flush()
+    }
+
+    fn fields(status: &SimulationStatus, object_count: usize, node_count: u32) -> [(&'static str, Value); 18] {
+        let durations = status.durations;
+        let diagnostics = status.diagnostics;
+        let float = |value: Option<f32>| value.map_or(Value::Missing, Value::Float);
+        let integer = |value: Option<u32>| value.map_or(Value::Missing, |value| Value::Integer(value.into()));
+        [
+            ("step", Value::Integer(status.step)),
+            ("time", Value::Double(status.time)),
+            ("dt", Value::Float(status.dt)),
+            ("bvh_seconds", Value::Double(durations.bvh.as_secs_f64())),
+            ("integration_seconds", Value::Double(durations.integration.as_secs_f64())),
+            ("update_seconds", Value::Double(durations.update.as_secs_f64())),
+            ("objects", Value::Integer(object_count as u64)),
+            ("nodes", Value::Integer(node_count.into())),
+            ("live", integer(diagnostics.map(|d| d.live))),
+            ("destroyed", integer(diagnostics.map(|d| d.destroyed))),
+            ("mass", float(diagnostics.map(|d| d.mass))),
+            ("kinetic_energy", float(diagnostics.map(|d| d.kinetic_energy))),
+            ("potential_energy", float(diagnostics.map(|d| d.potential_energy))),
+            ("total_energy", float(diagnostics.map(|d| d.total_energy()))),
+            ("momentum_x", float(diagnostics.map(|d| d.momentum[0]))),
+            ("momentum_y", float(diagnostics.map(|d| d.momentum[1]))),
+            ("angular_momentum", float(diagnostics.map(|d| d.angular_momentum))),
+            ("time_scale", Value::Float(status.time_scale)),
+        ]
+    }
+}
+
+impl Drop for StatsWriter {
+    fn drop(&mut self) {
+        if let Err(error) = self.finish_file() {
+            eprintln!("Failed to finish {}: {error}", self.path.display());
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::{env, fs, path::PathBuf, process, time::Duration};
+
+use crate::{
+    shaders::diagnostics::Diagnostics,
+    simulation::{SimulationStatus, StepDurations},
+    stats::StatsWriter,
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("collision2_stats_test_{}_{name}", process::id()))
+}
+
+fn status(step: u64, diagnostics: Option<Diagnostics>) -> SimulationStatus {
+    SimulationStatus {
+        step,
+        time: step as f64 * 0.001,
+        time_scale: 1.0,
+        dt: 0.001,
+        durations: StepDurations {
+            bvh: Duration::from_micros(250),
+            ..StepDurations::default()
+        },
+        diagnostics,
+        ..SimulationStatus::default()
+    }
+}
+
+#[test]
+fn csv_samples_every_interval() {
+    let path = temp_path("samples.csv");
+    let mut writer = StatsWriter::create(&path, 2, 10).unwrap();
+    for step in 1..=5 {
+        writer.record(&status(step, None), 19).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let csv = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    let header = lines[0].split(',').collect::<Vec<_>>();
+    assert_eq!(&header[..4], ["step", "time", "dt", "bvh_seconds"]);
+    let row = lines[1].split(',').collect::<Vec<_>>();
+    assert_eq!(row.len(), header.len());
+    let column = |name| row[header.iter().position(|&column| column == name).unwrap()];
+    assert_eq!((column("step"), column("time"), column("bvh_seconds")), ("2", "0.002", "0.00025"));
+    assert_eq!((column("objects"), column("nodes")), ("10", "19"));
+    // Without diagnostics the conservation columns are empty
+    assert_eq!((column("live"), column("total_energy")), ("", ""));
+    assert!(lines[2].starts_with("4,"));
+}
+
+#[test]
+fn json_is_closed_when_dropped() {
+    let path = temp_path("samples.json");
+    let diagnostics = Diagnostics::new(1.5, -4.0, [1.0, -2.0], 3.0, 10.0, 7, 1);
+    {
+        let mut writer = StatsWriter::create(&path, 1, 8).unwrap();
+        writer.record(&status(1, Some(diagnostics)), 15).unwrap();
+        writer.record(&status(2, Some(diagnostics)), 15).unwrap();
+    }
+
+    let json = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(json.starts_with("[\n  {\"step\": 1, "));
+    assert!(json.trim_end().ends_with("}\n]"));
+    assert_eq!(json.matches("\"step\"").count(), 2);
+    assert!(json.contains("\"live\": 7, \"destroyed\": 1, \"mass\": 10, \"kinetic_energy\": 1.5"));
+    assert!(json.contains("\"total_energy\": -2.5, \"momentum_x\": 1, \"momentum_y\": -2"));
+}
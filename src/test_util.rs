@@ -0,0 +1,101 @@
+use std::{
+    env,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::JoinHandle,
+};
+
+use color::palette::css;
+use pollster::block_on;
+
+use crate::{
+    objects::ObjectPrototype,
+    request_device,
+    shaders::common::{FLAG_DRAW_OBJECT, FLAG_PHYSICAL, SHAPE_CIRCLE},
+    util::spawn_device_poller,
+};
+
+/// Environment variable that lets the GPU tests skip themselves when there is no software adapter
+pub const ALLOW_NO_GPU: &str = "ALLOW_NO_GPU";
+
+/// Device on a software adapter with a background poller that stops when this is dropped
+pub struct TestDevice {
+    pub device: wgpu::Device,
+    pub queue: wgpu::Queue,
+    exit_requested: Arc<AtomicBool>,
+    /// Joined on drop, a poller still inside the driver when the test process exits can crash it
+    poller: Option<JoinHandle<()>>,
+}
+
+impl TestDevice {
+    /// Returns `None` if there is no software adapter and [`ALLOW_NO_GPU`] is set, tests should be skipped then.
+    /// Without it a missing adapter fails the test, so that skipped tests can't pass for green ones.
+    pub fn new() -> Option<Self> {
+        let wgpu = wgpu::Instance::new(&wgpu::InstanceDescriptor::from_env_or_default());
+        let Ok(adapter) = block_on(wgpu.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::None,
+            force_fallback_adapter: true,
+            compatible_surface: None,
+        })) else {
+            assert!(
+                env::var_os(ALLOW_NO_GPU).is_some(),
+                "No software adapter available, set {ALLOW_NO_GPU}=1 to skip the GPU tests"
+            );
+            return None;
+        };
+        let (device, queue) = request_device(&adapter);
+        let exit_requested = Arc::new(AtomicBool::new(false));
+        let poller = spawn_device_poller(device.clone(), exit_requested.clone());
+        Some(Self {
+            device,
+            queue,
+            exit_requested,
+            poller: Some(poller),
+        })
+    }
+}
+
+/// Evaluates to a [`TestDevice`], or returns from the calling test if there is none, see [`TestDevice::new`]
+macro_rules! test_device_or_skip {
+    () => {
+        match $crate::test_util::TestDevice::new() {
+            Some(test_device) => test_device,
+            None => {
+                eprintln!("Skipping {}: no software adapter available", module_path!());
+                return;
+            }
+        }
+    };
+}
+pub(crate) use test_device_or_skip;
+
+impl Drop for TestDevice {
+    fn drop(&mut self) {
+        self.exit_requested.store(true, Ordering::SeqCst);
+        if let Some(poller) = self.poller.take() {
+            poller.join().unwrap();
+        }
+    }
+}
+
+/// Physical white object at rest with a mass of 1, tests change the other fields with struct update syntax
+pub fn object(shape: u32, position: [f32; 2], size: [f32; 2]) -> ObjectPrototype {
+    ObjectPrototype {
+        flags: FLAG_DRAW_OBJECT | FLAG_PHYSICAL,
+        position,
+        velocity: [0.0, 0.0],
+        mass: 1.0,
+        size,
+        color: css::WHITE,
+        shape,
+        angle: 0.0,
+        angular_velocity: 0.0,
+    }
+}
+
+/// Like [`object`] for a circle of `radius`
+pub fn circle(position: [f32; 2], radius: f32) -> ObjectPrototype {
+    object(SHAPE_CIRCLE, position, [radius * 2.0; 2])
+}
@@ -0,0 +1,175 @@
+use std::time::Instant;
+
+use nalgebra::Vector2;
+use rand::{Rng, rngs::StdRng};
+use wgpu::Queue;
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    picker::Picker,
+    shaders::tool_state::{SpawnRequest, TOOL_NONE, ToolState},
+};
+
+/// What the left mouse button does
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Tool {
+    /// Selects an object, highlights it and follows it with the camera
+    #[default]
+    Inspect,
+    /// Holds an object at the cursor and releases it with the cursor's velocity
+    Grab,
+    /// Spawns circles under the cursor
+    Paint,
+    /// Pulls objects towards the cursor, the right button pushes them away
+    Attractor,
+}
+
+impl Tool {
+    pub const ALL: [Self; 4] = [Self::Inspect, Self::Grab, Self::Paint, Self::Attractor];
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&tool| tool == self).unwrap();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Properties of circles spawned with [`Tool::Paint`]
+#[derive(Copy, Clone, Debug)]
+pub struct SpawnSettings {
+    pub radius: f32,
+    pub velocity: [f32; 2],
+    pub mass: f32,
+}
+
+impl Default for SpawnSettings {
+    fn default() -> Self {
+        Self {
+            radius: 2.0,
+            velocity: [0.0, 0.0],
+            mass: 2.0,
+        }
+    }
+}
+
+/// Strength of the cursor force used by [`Tool::Attractor`]
+#[derive(Copy, Clone, Debug)]
+pub struct AttractorSettings {
+    pub strength: f32,
+    /// Plummer softening radius of the cursor force
+    pub softening: f32,
+}
+
+impl Default for AttractorSettings {
+    fn default() -> Self {
+        Self {
+            strength: 5000.0,
+            softening: 20.0,
+        }
+    }
+}
+
+/// Weight of the newest sample in the smoothed cursor velocity
+const CURSOR_VELOCITY_SMOOTHING: f32 = 0.3;
+
+struct Grab {
+    index: u32,
+    cursor_velocity: Vector2<f32>,
+}
+
+/// Turns mouse input into writes to the integrator's tool state and the object buffers
+pub struct Tools {
+    pub tool: Tool,
+    pub spawn: SpawnSettings,
+    pub attractor: AttractorSettings,
+    state: GpuBuffer<ToolState>,
+    /// `None` if nothing can be spawned, like in a replay
+    spawn_request: Option<GpuBuffer<SpawnRequest>>,
+    grab: Option<Grab>,
+    painting: bool,
+    /// Direction of the cursor force, 1 attracts, -1 repels and 0 is off
+    cursor_direction: f32,
+    last_cursor: Option<(Vector2<f32>, Instant)>,
+    /// Jitters the positions of spawned circles
+    rng: StdRng,
+}
+
+impl Tools {
+    pub fn new(state: GpuBuffer<ToolState>, spawn_request: Option<GpuBuffer<SpawnRequest>>, rng: StdRng) -> Self {
+        Self {
+            tool: Tool::default(),
+            spawn: SpawnSettings::default(),
+            attractor: AttractorSettings::default(),
+            state,
+            spawn_request,
+            grab: None,
+            painting: false,
+            cursor_direction: 0.0,
+            last_cursor: None,
+            rng,
+        }
+    }
+
+    /// Starts using the current tool at `cursor`, `primary` is the left button
+    pub fn press(&mut self, picker: &Picker, cursor: [f32; 2], primary: bool) {
+        match (self.tool, primary) {
+            (Tool::Grab, true) => {
+                self.grab = picker.pick(cursor).map(|object| Grab {
+                    index: object.index,
+                    cursor_velocity: Vector2::zeros(),
+                });
+            }
+            (Tool::Paint, true) => self.painting = true,
+            (Tool::Attractor, primary) => self.cursor_direction = if primary { 1.0 } else { -1.0 },
+            _ => (),
+        }
+    }
+
+    /// Stops using the current tool, a grabbed object keeps the cursor's velocity
+    pub fn release(&mut self) {
+        self.grab = None;
+        self.painting = false;
+        self.cursor_direction = 0.0;
+    }
+
+    /// Has to be called every frame with the cursor in world space
+    pub fn update(&mut self, queue: &Queue, cursor: [f32; 2]) {
+        let cursor = Vector2::from(cursor);
+        let now = Instant::now();
+        if let (Some(grab), Some((last_cursor, last_time))) = (&mut self.grab, self.last_cursor) {
+            let dt = now.duration_since(last_time).as_secs_f32();
+            if dt > 0.0 {
+                let velocity = (cursor - last_cursor) / dt;
+                grab.cursor_velocity = grab.cursor_velocity.lerp(&velocity, CURSOR_VELOCITY_SMOOTHING);
+            }
+        }
+        self.last_cursor = Some((cursor, now));
+
+        if self.painting {
+            self.spawn_circle(queue, cursor);
+        }
+
+        let (grabbed, grab_velocity) =
+            self.grab.as_ref().map_or((TOOL_NONE, Vector2::zeros()), |grab| (grab.index, grab.cursor_velocity));
+        let state = ToolState::new(
+            cursor.into(),
+            self.cursor_direction * self.attractor.strength,
+            self.attractor.softening,
+            grabbed,
+            grab_velocity.into(),
+        );
+        self.state.write(queue, &[state]);
+    }
+
+    /// Asks the simulation to spawn a circle near the cursor in its next step. It goes into a free slot, so it never
+    /// replaces another object, and it is dropped if there is none.
+    fn spawn_circle(&mut self, queue: &Queue, cursor: Vector2<f32>) {
+        let Some(spawn_request) = &self.spawn_request else {
+            return;
+        };
+        let jitter = self.spawn.radius;
+        let position =
+            cursor + Vector2::new(self.rng.random_range(-jitter..=jitter), self.rng.random_range(-jitter..=jitter));
+        let request = SpawnRequest::new(position.into(), self.spawn.velocity, self.spawn.radius, self.spawn.mass, 1);
+        spawn_request.write(queue, &[request]);
+    }
+}
@@ -0,0 +1,79 @@
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+    DT,
+    objects::Objects,
+    picker::Picker,
+    shaders::{
+        common::FLAG_PHYSICAL,
+        pick::PickedObject,
+        tool_state::{TOOL_NONE, ToolState},
+    },
+    simulation::Simulation,
+    test_util::{TestDevice, circle, test_device_or_skip},
+    tools::{Tool, Tools},
+};
+
+#[test]
+fn grabbed_object_follows_cursor() {
+    let test_device = test_device_or_skip!();
+    let object = step_with_tools(&test_device, ToolState::new([300.0, 300.0], 0.0, 20.0, 0, [5.0, 6.0]));
+    let center = [
+        (object.aabb.min[0] + object.aabb.max[0]) / 2.0,
+        (object.aabb.min[1] + object.aabb.max[1]) / 2.0,
+    ];
+    assert_eq!(center, [300.0, 300.0]);
+    assert_eq!(object.velocity, [5.0, 6.0]);
+}
+
+#[test]
+fn cursor_attracts_and_repels() {
+    let test_device = test_device_or_skip!();
+    // The cursor is left of the object, so attraction accelerates it to the left
+    let cursor = [250.0, 300.0];
+    let baseline = step_with_tools(&test_device, ToolState::new(cursor, 0.0, 20.0, TOOL_NONE, [0.0, 0.0]));
+    let attracted = step_with_tools(&test_device, ToolState::new(cursor, 1000.0, 20.0, TOOL_NONE, [0.0, 0.0]));
+    let repelled = step_with_tools(&test_device, ToolState::new(cursor, -1000.0, 20.0, TOOL_NONE, [0.0, 0.0]));
+    assert!(attracted.velocity[0] < baseline.velocity[0]);
+    assert!(repelled.velocity[0] > baseline.velocity[0]);
+}
+
+#[test]
+fn painting_fills_free_slots_only() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    objects.push(circle([300.0, 300.0], 1.0));
+    objects.push_free_slots(1);
+    let mut simulation = Simulation::new(objects, device, queue);
+    let picker = Picker::new(device, queue, simulation.buffers());
+    let mut tools = Tools::new(simulation.tool_state(), simulation.spawn_request(), StdRng::seed_from_u64(0));
+    tools.tool = Tool::Paint;
+    let mut paint = |simulation: &mut Simulation, cursor: [f32; 2]| {
+        tools.press(&picker, cursor, true);
+        tools.update(queue, cursor);
+        tools.release();
+        simulation.step(DT);
+        simulation.read_objects()
+    };
+
+    let painted = paint(&mut simulation, [-300.0, -300.0]);
+    assert!(painted.iter().all(|state| state.flags & FLAG_PHYSICAL != 0));
+    assert!(painted[1].position.iter().all(|&x| (-302.0..=-298.0).contains(&x)), "{:?}", painted[1].position);
+    // Without a free slot the circle is dropped instead of replacing one
+    let dropped = paint(&mut simulation, [300.0, -300.0]);
+    assert!(dropped.iter().zip(&painted).all(|(state, painted)| (state.position[0] - painted.position[0]).abs() < 1.0));
+}
+
+/// Runs one step with a single object at rest at (300, 300) and returns its state afterwards
+fn step_with_tools(test_device: &TestDevice, tool_state: ToolState) -> PickedObject {
+    let TestDevice { device, queue, .. } = test_device;
+    let mut objects = Objects::default();
+    for position in [[300.0, 300.0], [-300.0, -300.0]] {
+        objects.push(circle(position, 1.0));
+    }
+    let mut simulation = Simulation::new(objects, device, queue);
+    let buffers = simulation.buffers().clone();
+    simulation.tool_state().write(queue, &[tool_state]);
+    simulation.step(DT);
+    Picker::new(device, queue, &buffers).inspect(0).unwrap()
+}
@@ -0,0 +1,249 @@
+use wgpu::{
+    BlendComponent, BlendFactor, BlendOperation, BlendState, BufferUsages, Color, ColorTargetState,
+    CommandEncoderDescriptor, Device, Extent3d, LoadOp, MultisampleState, Operations, PipelineCache, PipelineLayout,
+    PipelineLayoutDescriptor, PrimitiveState, Queue, RenderPass, RenderPassColorAttachment, RenderPipeline,
+    RenderPipelineDescriptor, Sampler, SamplerDescriptor, StoreOp, Texture, TextureDescriptor, TextureDimension,
+    TextureFormat, TextureUsages, TextureView, TextureViewDescriptor,
+};
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    shaders::{
+        common::Camera,
+        trail::{self, TrailParameters},
+    },
+};
+
+/// How the accumulated image loses intensity every frame
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TrailFade {
+    /// Multiplies by `1 - fade`, trails decay exponentially and leave a faint residue in 8-bit targets
+    #[default]
+    Multiply,
+    /// Subtracts `fade`, trails have a fixed length and vanish completely
+    Subtract,
+}
+
+impl TrailFade {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Multiply => Self::Subtract,
+            Self::Subtract => Self::Multiply,
+        }
+    }
+
+    fn blend_component(self) -> BlendComponent {
+        match self {
+            Self::Multiply => BlendComponent {
+                src_factor: BlendFactor::Zero,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            Self::Subtract => BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::ReverseSubtract,
+            },
+        }
+    }
+}
+
+struct Accumulation {
+    texture: Texture,
+    view: TextureView,
+    bind_group: trail::WgpuBindGroup1,
+    size: PhysicalSize<u32>,
+}
+
+/// Keeps the rendered shapes in an accumulation target that is faded every frame instead of cleared
+pub struct TrailRenderer {
+    multiply_pipeline: RenderPipeline,
+    subtract_pipeline: RenderPipeline,
+    blit_pipeline: RenderPipeline,
+    parameters: GpuBuffer<TrailParameters>,
+    parameters_bind_group: trail::WgpuBindGroup0,
+    sampler: Sampler,
+    target_format: TextureFormat,
+    accumulation: Option<Accumulation>,
+    camera: Option<Camera>,
+    reset_requested: bool,
+    device: Device,
+}
+
+impl TrailRenderer {
+    pub fn new(device: &Device, target_format: TextureFormat, pipeline_cache: Option<&PipelineCache>) -> Self {
+        let blit_pipeline_layout = trail::create_pipeline_layout(device);
+        let fade_pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("trail fade pipeline layout"),
+            bind_group_layouts: &[&trail::WgpuBindGroup0::get_bind_group_layout(device)],
+            push_constant_ranges: &[],
+        });
+        let shader = trail::create_shader_module_embed_source(device);
+        let create_pipeline = |layout: &PipelineLayout, fragment_entry: trail::FragmentEntry<1>| {
+            let vertex_entry = trail::vs_fullscreen_entry();
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                label: None,
+                layout: Some(layout),
+                vertex: trail::vertex_state(&shader, &vertex_entry),
+                fragment: Some(trail::fragment_state(&shader, &fragment_entry)),
+                primitive: PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: MultisampleState::default(),
+                multiview: None,
+                cache: pipeline_cache,
+            })
+        };
+        let fade_pipeline = |fade: TrailFade| {
+            // Alpha is kept, so that the trails stay opaque in screenshots
+            let blend = BlendState {
+                color: fade.blend_component(),
+                alpha: BlendComponent {
+                    src_factor: BlendFactor::Zero,
+                    dst_factor: BlendFactor::One,
+                    operation: BlendOperation::Add,
+                },
+            };
+            let fragment_entry = trail::fs_fade_entry([Some(ColorTargetState {
+                blend: Some(blend),
+                ..ColorTargetState::from(target_format)
+            })]);
+            create_pipeline(&fade_pipeline_layout, fragment_entry)
+        };
+        let blit_fragment_entry = trail::fs_blit_entry([Some(ColorTargetState::from(target_format))]);
+        let parameters =
+            GpuBuffer::new(1, "trail parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        let parameters_bind_group = trail::WgpuBindGroup0::from_bindings(
+            device,
+            trail::WgpuBindGroup0Entries::new(trail::WgpuBindGroup0EntriesParams {
+                parameters: parameters.buffer().as_entire_buffer_binding(),
+            }),
+        );
+
+        Self {
+            multiply_pipeline: fade_pipeline(TrailFade::Multiply),
+            subtract_pipeline: fade_pipeline(TrailFade::Subtract),
+            blit_pipeline: create_pipeline(&blit_pipeline_layout, blit_fragment_entry),
+            parameters,
+            parameters_bind_group,
+            sampler: device.create_sampler(&SamplerDescriptor::default()),
+            target_format,
+            accumulation: None,
+            camera: None,
+            reset_requested: false,
+            device: device.clone(),
+        }
+    }
+
+    /// Clears the trails on the next frame
+    pub fn reset(&mut self) {
+        self.reset_requested = true;
+    }
+
+    /// Copies the trails of `other`, for rendering the same scene to another target like a screenshot without
+    /// disturbing the trails on screen. They are only kept if the next frame has the same size and camera as `other`'s.
+    pub fn continue_from(&mut self, other: &TrailRenderer, queue: &Queue) {
+        let Some(source) = &other.accumulation else {
+            self.reset();
+            return;
+        };
+        if self.accumulation.as_ref().is_none_or(|accumulation| accumulation.size != source.size) {
+            self.accumulation = Some(self.create_accumulation(source.size));
+        }
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_texture(
+            source.texture.as_image_copy(),
+            self.accumulation.as_ref().unwrap().texture.as_image_copy(),
+            source.texture.size(),
+        );
+        queue.submit([encoder.finish()]);
+        self.camera = other.camera;
+        self.reset_requested = other.reset_requested;
+    }
+
+    /// Prepares the accumulation target for a frame of the given size, returns the attachment to render the shapes
+    /// into. The trails are reset when the size or the camera changes, because the old pixels would be misplaced.
+    pub fn begin_frame(
+        &mut self,
+        queue: &Queue,
+        size: PhysicalSize<u32>,
+        camera: Camera,
+        fade: f32,
+    ) -> RenderPassColorAttachment<'_> {
+        self.parameters.write(queue, &[TrailParameters::new(fade)]);
+        let mut clear = std::mem::take(&mut self.reset_requested) || self.camera != Some(camera);
+        self.camera = Some(camera);
+        if self.accumulation.as_ref().is_none_or(|accumulation| accumulation.size != size) {
+            self.accumulation = Some(self.create_accumulation(size));
+            clear = true;
+        }
+
+        RenderPassColorAttachment {
+            view: &self.accumulation.as_ref().unwrap().view,
+            depth_slice: None,
+            resolve_target: None,
+            ops: Operations {
+                load: if clear {
+                    LoadOp::Clear(Color::BLACK)
+                } else {
+                    LoadOp::Load
+                },
+                store: StoreOp::Store,
+            },
+        }
+    }
+
+    /// Fades the accumulation target, must be called in the pass started with [`Self::begin_frame`]
+    pub fn fade(&self, render_pass: &mut RenderPass<'_>, fade: TrailFade) {
+        render_pass.set_pipeline(match fade {
+            TrailFade::Multiply => &self.multiply_pipeline,
+            TrailFade::Subtract => &self.subtract_pipeline,
+        });
+        self.parameters_bind_group.set(render_pass);
+        render_pass.draw(0..3, 0..1);
+    }
+
+    /// Draws the accumulated trails over the whole target
+    pub fn blit(&self, render_pass: &mut RenderPass<'_>) {
+        let accumulation = self.accumulation.as_ref().expect("begin_frame has to be called first");
+        render_pass.set_pipeline(&self.blit_pipeline);
+        self.parameters_bind_group.set(render_pass);
+        accumulation.bind_group.set(render_pass);
+        render_pass.draw(0..3, 0..1);
+    }
+
+    fn create_accumulation(&self, size: PhysicalSize<u32>) -> Accumulation {
+        let texture = self.device.create_texture(&TextureDescriptor {
+            label: Some("trail accumulation texture"),
+            size: Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: self.target_format,
+            // Copied between renderers by `continue_from`
+            usage: TextureUsages::RENDER_ATTACHMENT
+                | TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_SRC
+                | TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let bind_group = trail::WgpuBindGroup1::from_bindings(
+            &self.device,
+            trail::WgpuBindGroup1Entries::new(trail::WgpuBindGroup1EntriesParams {
+                accumulation: &view,
+                accumulation_sampler: &self.sampler,
+            }),
+        );
+        Accumulation {
+            texture,
+            view,
+            bind_group,
+            size,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::mem;
+
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    objects::{ObjectPrototype, Objects},
+    offscreen::OffscreenTarget,
+    render::{RenderParameters, Renderers, create_renderers, orthographic_camera, render_scene},
+    scene::world_aabb,
+    shaders::common::{Camera, FLAG_DRAW_OBJECT, Flags},
+    shape_renderer::ColorMode,
+    test_util::{TestDevice, circle, test_device_or_skip},
+    trail_renderer::TrailRenderer,
+};
+
+const TARGET_SIZE: PhysicalSize<u32> = PhysicalSize::new(96, 64);
+const TARGET_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+const WORLD_HEIGHT: f32 = 20.0;
+
+#[test]
+fn trails_reset_when_the_camera_moves() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    objects.push(ObjectPrototype {
+        flags: FLAG_DRAW_OBJECT,
+        ..circle([0.0, 0.0], 2.0)
+    });
+    let buffers = objects.to_buffers(device, queue);
+    let mut renderers = create_renderers(device, queue, TARGET_FORMAT, world_aabb(), &buffers);
+    let render_parameters = RenderParameters {
+        trails: true,
+        color_mode: ColorMode::Object,
+        scale_by_speed: false,
+        ..RenderParameters::default()
+    };
+    let render = |renderers: &mut Renderers, size: PhysicalSize<u32>, center: [f32; 2]| {
+        let target = OffscreenTarget::new(device, size, TARGET_FORMAT).unwrap();
+        let camera = orthographic_camera(render_parameters.zoom, size.cast(), WORLD_HEIGHT, center);
+        render_scene(
+            target.view(),
+            size,
+            Some(&target),
+            &render_parameters,
+            renderers,
+            Camera::new(camera),
+            0..1,
+            1,
+            device,
+            queue,
+        );
+        target.read_rgba(device)
+    };
+    let brightness = |rgba: &[u8], size: PhysicalSize<u32>, [x, y]: [usize; 2]| {
+        let index = (y * size.width as usize + x) * 4;
+        rgba[index..index + 3].iter().copied().max().unwrap()
+    };
+    let screenshot = |renderers: &mut Renderers, screenshot_trail_renderer: &mut TrailRenderer, size| {
+        screenshot_trail_renderer.continue_from(&renderers.trail_renderer, queue);
+        mem::swap(&mut renderers.trail_renderer, screenshot_trail_renderer);
+        let rgba = render(renderers, size, [0.0, 0.0]);
+        mem::swap(&mut renderers.trail_renderer, screenshot_trail_renderer);
+        rgba
+    };
+
+    let first = render(&mut renderers, TARGET_SIZE, [0.0, 0.0]);
+    assert!(brightness(&first, TARGET_SIZE, [48, 32]) > 64);
+
+    // Only the trail is left after hiding the circle
+    buffers.flags.write(queue, &[Flags::new(0)]);
+    let hidden = render(&mut renderers, TARGET_SIZE, [0.0, 0.0]);
+    assert!(brightness(&hidden, TARGET_SIZE, [48, 32]) > 64);
+
+    // Screenshots of the same size start from the trails on screen, others without them, and neither clears them
+    let mut screenshot_trail_renderer = TrailRenderer::new(device, TARGET_FORMAT, None);
+    let same_size = screenshot(&mut renderers, &mut screenshot_trail_renderer, TARGET_SIZE);
+    assert!(brightness(&same_size, TARGET_SIZE, [48, 32]) > 64);
+    let small_size = PhysicalSize::new(48, 32);
+    let small = screenshot(&mut renderers, &mut screenshot_trail_renderer, small_size);
+    assert_eq!(brightness(&small, small_size, [24, 16]), 0);
+    let after_screenshots = render(&mut renderers, TARGET_SIZE, [0.0, 0.0]);
+    assert!(brightness(&after_screenshots, TARGET_SIZE, [48, 32]) > 64);
+
+    // The old pixels would be misplaced after moving the camera, 5 world units are 16 pixels
+    let moved = render(&mut renderers, TARGET_SIZE, [5.0, 0.0]);
+    assert_eq!(brightness(&moved, TARGET_SIZE, [32, 32]), 0);
+    assert_eq!(brightness(&moved, TARGET_SIZE, [48, 32]), 0);
+}
@@ -0,0 +1,437 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, bail, ensure};
+use bytemuck::Zeroable;
+use wgpu::{BufferUsages, CommandEncoderDescriptor, Device, Queue};
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    objects::{ObjectBuffers, ObjectState, Objects},
+    shaders::common::{AABB, Angle, AngularVelocity, Flags, Velocity},
+    simulation::SimulationStatus,
+    util::DeviceUtil,
+};
+
+/// Start of every trajectory file, followed by the format version
+const MAGIC: [u8; 8] = *b"C2TRAJ\0\0";
+const VERSION: u32 = 3;
+/// Frames buffered in memory and written together as one chunk
+const CHUNK_FRAMES: u32 = 64;
+/// Bytes of one [`ObjectState`] in a frame
+const OBJECT_STATE_SIZE: usize = 28;
+/// Version 1 files have no rotation, their objects are read with an angle and angular velocity of zero
+const UNROTATED_VERSION: u32 = 1;
+/// Version 2 files predate polygons, their objects are read without vertices
+const VERTEXLESS_VERSION: u32 = 2;
+/// Bytes of a frame before its object states
+const FRAME_HEADER_SIZE: usize = 16;
+
+/// Per-object properties that don't change during a run, written once in the header
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedObject {
+    /// Index in the simulated scene
+    pub index: u32,
+    pub size: [f32; 2],
+    pub mass: f32,
+    pub color: [f32; 4],
+    pub shape: u32,
+    /// In the object's own frame, empty unless it is a polygon
+    pub vertices: Vec<[f32; 2]>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrajectoryHeader {
+    /// Number of objects in the simulated scene, not all of them have to be recorded
+    pub object_count: u32,
+    /// Steps between two recorded frames
+    pub interval: u32,
+    pub objects: Vec<RecordedObject>,
+}
+
+impl TrajectoryHeader {
+    /// Records the objects in `selection`, or all objects if it is `None`
+    pub fn from_objects(objects: &Objects, selection: Option<&[u32]>, interval: u32) -> anyhow::Result<Self> {
+        ensure!(interval > 0, "The recording interval must be at least 1");
+        let object_count = u32::try_from(objects.len()).unwrap();
+        let selection = match selection {
+            Some(selection) => selection.to_vec(),
+            None => (0..object_count).collect(),
+        };
+        let objects = selection
+            .into_iter()
+            .map(|index| {
+                ensure!(index < object_count, "Recorded object {index} is out of range, there are {object_count}");
+                let i = usize::try_from(index).unwrap();
+                Ok(RecordedObject {
+                    index,
+                    size: objects.sizes[i].inner,
+                    mass: objects.masses[i].inner,
+                    color: objects.colors[i].inner,
+                    shape: objects.shapes[i].inner,
+                    vertices: objects.polygon(i),
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        ensure!(!objects.is_empty(), "No objects to record");
+        Ok(Self {
+            object_count,
+            interval,
+            objects,
+        })
+    }
+
+    fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&MAGIC)?;
+        for value in [
+            VERSION,
+            self.object_count,
+            self.interval,
+            u32::try_from(self.objects.len()).unwrap(),
+        ] {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        for object in &self.objects {
+            let mut bytes = Vec::with_capacity(40);
+            bytes.extend(object.index.to_le_bytes());
+            bytes.extend(
+                object.size.iter().chain([object.mass].iter()).chain(&object.color).flat_map(|x| x.to_le_bytes()),
+            );
+            bytes.extend(object.shape.to_le_bytes());
+            bytes.extend(u32::try_from(object.vertices.len()).unwrap().to_le_bytes());
+            bytes.extend(object.vertices.iter().flatten().flat_map(|x| x.to_le_bytes()));
+            writer.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the header and the file's format version
+    fn read(reader: &mut impl Read) -> anyhow::Result<(Self, u32)> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == MAGIC, "Not a trajectory file");
+        let version = read_u32(reader)?;
+        ensure!(
+            (UNROTATED_VERSION..=VERSION).contains(&version),
+            "Unsupported trajectory version {version}, expected {VERSION}"
+        );
+        let object_count = read_u32(reader)?;
+        let interval = read_u32(reader)?;
+        let recorded_count = read_u32(reader)?;
+        let objects = (0..recorded_count)
+            .map(|_| {
+                Ok(RecordedObject {
+                    index: read_u32(reader)?,
+                    size: [read_f32(reader)?, read_f32(reader)?],
+                    mass: read_f32(reader)?,
+                    color: [
+                        read_f32(reader)?,
+                        read_f32(reader)?,
+                        read_f32(reader)?,
+                        read_f32(reader)?,
+                    ],
+                    shape: read_u32(reader)?,
+                    vertices: if version > VERTEXLESS_VERSION {
+                        let count = read_u32(reader)?;
+                        (0..count).map(|_| Ok([read_f32(reader)?, read_f32(reader)?])).collect::<io::Result<_>>()?
+                    } else {
+                        Vec::new()
+                    },
+                })
+            })
+            .collect::<io::Result<_>>()?;
+        let header = Self {
+            object_count,
+            interval,
+            objects,
+        };
+        Ok((header, version))
+    }
+}
+
+/// The recorded objects after a simulation step, in the order of [`TrajectoryHeader::objects`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrajectoryFrame {
+    pub step: u64,
+    pub time: f64,
+    pub objects: Vec<ObjectState>,
+}
+
+/// Writes a header followed by chunks of [`CHUNK_FRAMES`] frames. Each chunk starts with its frame count, all
+/// values are little endian. The last chunk is written when the writer is finished or dropped.
+pub struct TrajectoryWriter {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    recorded_count: usize,
+    chunk: Vec<u8>,
+    chunk_frames: u32,
+    finished: bool,
+}
+
+impl TrajectoryWriter {
+    pub fn create(path: &Path, header: &TrajectoryHeader) -> anyhow::Result<Self> {
+        let file = File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        header.write(&mut writer).with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(Self {
+            writer,
+            path: path.to_owned(),
+            recorded_count: header.objects.len(),
+            chunk: Vec::new(),
+            chunk_frames: 0,
+            finished: false,
+        })
+    }
+
+    pub fn write_frame(&mut self, frame: &TrajectoryFrame) -> anyhow::Result<()> {
+        ensure!(
+            frame.objects.len() == self.recorded_count,
+            "Frame has {} objects, the header {}",
+            frame.objects.len(),
+            self.recorded_count
+        );
+        self.chunk.reserve(FRAME_HEADER_SIZE + frame.objects.len() * OBJECT_STATE_SIZE);
+        self.chunk.extend(frame.step.to_le_bytes());
+        self.chunk.extend(frame.time.to_le_bytes());
+        for state in &frame.objects {
+            self.chunk.extend(state.flags.to_le_bytes());
+            let [x, y] = state.position;
+            let [vx, vy] = state.velocity;
+            self.chunk.extend([x, y, vx, vy, state.angle, state.angular_velocity].iter().flat_map(|x| x.to_le_bytes()));
+        }
+        self.chunk_frames += 1;
+        if self.chunk_frames == CHUNK_FRAMES {
+            self.write_chunk().with_context(|| format!("Failed to write {}", self.path.display()))?;
+        }
+        Ok(())
+    }
+
+    fn write_chunk(&mut self) -> io::Result<()> {
+        if self.chunk_frames > 0 {
+            self.writer.write_all(&self.chunk_frames.to_le_bytes())?;
+            self.writer.write_all(&self.chunk)?;
+            self.chunk.clear();
+            self.chunk_frames = 0;
+        }
+        Ok(())
+    }
+
+    /// Writes the buffered frames and flushes the file
+    pub fn finish(mut self) -> anyhow::Result<()> {
+        self.finish_file().with_context(|| format!("Failed to finish {}", self.path.display()))
+    }
+
+    fn finish_file(&mut self) -> io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+        self.write_chunk()?;
+        self.writer.flush()
+    }
+}
+
+impl Drop for TrajectoryWriter {
+    fn drop(&mut self) {
+        if let Err(error) = self.finish_file() {
+            eprintln!("Failed to finish {}: {error}", self.path.display());
+        }
+    }
+}
+
+/// Streams the frames of a trajectory file, only one chunk is read at a time
+pub struct TrajectoryReader {
+    reader: BufReader<File>,
+    path: PathBuf,
+    header: TrajectoryHeader,
+    /// Format version of the file, older ones are still readable
+    version: u32,
+    /// Offset of the first chunk
+    data_start: u64,
+    /// Frames left in the current chunk
+    chunk_remaining: u32,
+}
+
+impl TrajectoryReader {
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        let mut reader = BufReader::new(file);
+        let (header, version) =
+            TrajectoryHeader::read(&mut reader).with_context(|| format!("Failed to read {}", path.display()))?;
+        let data_start = reader.stream_position()?;
+        Ok(Self {
+            reader,
+            path: path.to_owned(),
+            header,
+            version,
+            data_start,
+            chunk_remaining: 0,
+        })
+    }
+
+    pub fn header(&self) -> &TrajectoryHeader {
+        &self.header
+    }
+
+    /// Returns `None` after the last frame
+    pub fn next_frame(&mut self) -> anyhow::Result<Option<TrajectoryFrame>> {
+        self.read_frame().with_context(|| format!("Failed to read {}", self.path.display()))
+    }
+
+    fn read_frame(&mut self) -> anyhow::Result<Option<TrajectoryFrame>> {
+        if self.chunk_remaining == 0 {
+            let mut frame_count = [0; 4];
+            // A chunk may only end at a frame boundary, so running out of data here is the end of the file
+            if self.reader.read(&mut frame_count[..1])? == 0 {
+                return Ok(None);
+            }
+            self.reader.read_exact(&mut frame_count[1..])?;
+            self.chunk_remaining = u32::from_le_bytes(frame_count);
+            if self.chunk_remaining == 0 {
+                bail!("Empty chunk");
+            }
+        }
+        let reader = &mut self.reader;
+        let step = u64::from_le_bytes(read_bytes(reader)?);
+        let time = f64::from_le_bytes(read_bytes(reader)?);
+        let rotated = self.version != UNROTATED_VERSION;
+        let objects = (0..self.header.objects.len())
+            .map(|_| {
+                Ok(ObjectState {
+                    flags: read_u32(reader)?,
+                    position: [read_f32(reader)?, read_f32(reader)?],
+                    velocity: [read_f32(reader)?, read_f32(reader)?],
+                    angle: if rotated { read_f32(reader)? } else { 0.0 },
+                    angular_velocity: if rotated { read_f32(reader)? } else { 0.0 },
+                })
+            })
+            .collect::<io::Result<_>>()?;
+        self.chunk_remaining -= 1;
+        Ok(Some(TrajectoryFrame { step, time, objects }))
+    }
+
+    /// Goes back to the first frame
+    pub fn restart(&mut self) -> anyhow::Result<()> {
+        self.reader.seek(SeekFrom::Start(self.data_start))?;
+        self.chunk_remaining = 0;
+        Ok(())
+    }
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    read_bytes(reader).map(u32::from_le_bytes)
+}
+
+fn read_f32(reader: &mut impl Read) -> io::Result<f32> {
+    read_bytes(reader).map(f32::from_le_bytes)
+}
+
+/// Reads the recorded objects back from the GPU every `interval` steps and writes them to a [`TrajectoryWriter`]
+pub struct TrajectoryRecorder {
+    writer: TrajectoryWriter,
+    interval: u64,
+    /// Indices of the recorded objects
+    indices: Vec<usize>,
+    flags: GpuBuffer<Flags>,
+    aabbs: GpuBuffer<AABB>,
+    velocities: GpuBuffer<Velocity>,
+    angles: GpuBuffer<Angle>,
+    angular_velocities: GpuBuffer<AngularVelocity>,
+    flags_readback: GpuBuffer<Flags>,
+    aabbs_readback: GpuBuffer<AABB>,
+    velocities_readback: GpuBuffer<Velocity>,
+    angles_readback: GpuBuffer<Angle>,
+    angular_velocities_readback: GpuBuffer<AngularVelocity>,
+    device: Device,
+    queue: Queue,
+}
+
+impl TrajectoryRecorder {
+    pub fn new(
+        path: &Path,
+        header: &TrajectoryHeader,
+        buffers: &ObjectBuffers,
+        device: &Device,
+        queue: &Queue,
+    ) -> anyhow::Result<Self> {
+        let writer = TrajectoryWriter::create(path, header)?;
+        let object_count = usize::try_from(header.object_count).unwrap();
+        let usage = BufferUsages::MAP_READ | BufferUsages::COPY_DST;
+        Ok(Self {
+            writer,
+            interval: header.interval.into(),
+            indices: header.objects.iter().map(|object| usize::try_from(object.index).unwrap()).collect(),
+            flags: buffers.flags.clone(),
+            aabbs: buffers.aabbs.clone(),
+            velocities: buffers.velocities.clone(),
+            angles: buffers.angles.clone(),
+            angular_velocities: buffers.angular_velocities.clone(),
+            flags_readback: GpuBuffer::new(object_count, "flags recording readback", usage, device),
+            aabbs_readback: GpuBuffer::new(object_count, "aabb recording readback", usage, device),
+            velocities_readback: GpuBuffer::new(object_count, "velocity recording readback", usage, device),
+            angles_readback: GpuBuffer::new(object_count, "angle recording readback", usage, device),
+            angular_velocities_readback: GpuBuffer::new(
+                object_count,
+                "angular velocity recording readback",
+                usage,
+                device,
+            ),
+            device: device.clone(),
+            queue: queue.clone(),
+        })
+    }
+
+    /// Writes a frame if `status.step` is a multiple of the interval. Rewinding the simulation doesn't remove
+    /// frames, the recording jumps back in time instead.
+    pub fn record(&mut self, status: &SimulationStatus) -> anyhow::Result<()> {
+        if !status.step.is_multiple_of(self.interval) {
+            return Ok(());
+        }
+        let mut encoder = self.device.create_command_encoder(&CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(self.flags.buffer(), 0, self.flags_readback.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(self.velocities.buffer(), 0, self.velocities_readback.buffer(), 0, None);
+        encoder.copy_buffer_to_buffer(self.angles.buffer(), 0, self.angles_readback.buffer(), 0, None);
+        let angular_velocities = self.angular_velocities_readback.buffer();
+        encoder.copy_buffer_to_buffer(self.angular_velocities.buffer(), 0, angular_velocities, 0, None);
+        // The AABB buffer also holds the BVH nodes after the objects
+        let aabbs_size = self.aabbs_readback.buffer().size();
+        encoder.copy_buffer_to_buffer(self.aabbs.buffer(), 0, self.aabbs_readback.buffer(), 0, aabbs_size);
+        self.device.wait_for_submission(self.queue.submit([encoder.finish()])).unwrap();
+
+        let object_count = self.flags_readback.len();
+        let mut flags = vec![Flags::zeroed(); object_count];
+        let mut aabbs = vec![AABB::zeroed(); object_count];
+        let mut velocities = vec![Velocity::zeroed(); object_count];
+        let mut angles = vec![Angle::zeroed(); object_count];
+        let mut angular_velocities = vec![AngularVelocity::zeroed(); object_count];
+        self.flags_readback.read(&self.device, &mut flags);
+        self.aabbs_readback.read(&self.device, &mut aabbs);
+        self.velocities_readback.read(&self.device, &mut velocities);
+        self.angles_readback.read(&self.device, &mut angles);
+        self.angular_velocities_readback.read(&self.device, &mut angular_velocities);
+
+        let objects = self
+            .indices
+            .iter()
+            .map(|&i| ObjectState::new(flags[i], aabbs[i], velocities[i], angles[i], angular_velocities[i]))
+            .collect();
+        self.writer.write_frame(&TrajectoryFrame {
+            step: status.step,
+            time: status.time,
+            objects,
+        })
+    }
+
+    pub fn finish(self) -> anyhow::Result<()> {
+        self.writer.finish()
+    }
+}
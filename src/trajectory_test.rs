@@ -0,0 +1,141 @@
+use std::{env, fs, path::PathBuf, process};
+
+use crate::{
+    DT,
+    objects::{ObjectPrototype, ObjectState, Objects},
+    picker::Picker,
+    replay::{Replay, replay_objects},
+    shaders::common::{SHAPE_CIRCLE, SHAPE_POLYGON},
+    simulation::Simulation,
+    test_util::{TestDevice, circle, test_device_or_skip},
+    trajectory::{
+        RecordedObject, TrajectoryFrame, TrajectoryHeader, TrajectoryReader, TrajectoryRecorder, TrajectoryWriter,
+    },
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("collision2_trajectory_test_{}_{name}", process::id()))
+}
+
+#[test]
+fn frames_round_trip_across_chunks() {
+    let path = temp_path("round_trip.traj");
+    let header = TrajectoryHeader {
+        object_count: 10,
+        interval: 3,
+        objects: vec![
+            RecordedObject {
+                index: 7,
+                size: [2.0, 4.0],
+                mass: 1.5,
+                color: [0.1, 0.2, 0.3, 1.0],
+                shape: SHAPE_POLYGON,
+                vertices: vec![[-1.0, -1.0], [1.0, -1.0], [0.0, 2.0]],
+            },
+            RecordedObject {
+                index: 2,
+                size: [1.0, 1.0],
+                mass: 2.0,
+                color: [1.0, 1.0, 1.0, 0.5],
+                shape: SHAPE_CIRCLE,
+                vertices: Vec::new(),
+            },
+        ],
+    };
+    // More than two chunks, the last one partially filled
+    let frames = (0..150u64)
+        .map(|i| TrajectoryFrame {
+            step: i * 3,
+            time: i as f64 * 0.003,
+            objects: (0..2)
+                .map(|j| ObjectState {
+                    flags: j,
+                    position: [i as f32, -(j as f32)],
+                    velocity: [0.5 * i as f32, 1e-3],
+                    angle: 0.1 * i as f32,
+                    angular_velocity: -(j as f32),
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    let mut writer = TrajectoryWriter::create(&path, &header).unwrap();
+    for frame in &frames {
+        writer.write_frame(frame).unwrap();
+    }
+    drop(writer);
+
+    let mut reader = TrajectoryReader::open(&path).unwrap();
+    assert_eq!(reader.header(), &header);
+    let mut read = Vec::new();
+    while let Some(frame) = reader.next_frame().unwrap() {
+        read.push(frame);
+    }
+    assert_eq!(read, frames);
+    reader.restart().unwrap();
+    assert_eq!(reader.next_frame().unwrap().as_ref(), frames.first());
+
+    fs::write(&path, b"not a trajectory").unwrap();
+    assert!(TrajectoryReader::open(&path).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn replays_recorded_objects() {
+    let TestDevice { device, queue, .. } = &test_device_or_skip!();
+    let mut objects = Objects::default();
+    for (position, velocity) in [
+        ([300.0, 300.0], [10.0, 0.0]),
+        ([0.0, 0.0], [0.0, 0.0]),
+        ([-300.0, -300.0], [0.0, -10.0]),
+    ] {
+        objects.push(ObjectPrototype {
+            velocity,
+            ..circle(position, 1.0)
+        });
+    }
+    let header = TrajectoryHeader::from_objects(&objects, Some(&[2, 0]), 2).unwrap();
+    assert!(TrajectoryHeader::from_objects(&objects, Some(&[3]), 2).is_err());
+    let path = temp_path("recording.traj");
+    let mut simulation = Simulation::new(objects, device, queue);
+    let buffers = simulation.buffers().clone();
+    let picker = Picker::new(device, queue, &buffers);
+    let mut recorder = TrajectoryRecorder::new(&path, &header, &buffers, device, queue).unwrap();
+    recorder.record(&simulation.status(false)).unwrap();
+    for _ in 0..4 {
+        simulation.step(DT);
+        recorder.record(&simulation.status(false)).unwrap();
+    }
+    recorder.finish().unwrap();
+    let recorded = [picker.inspect(2).unwrap(), picker.inspect(0).unwrap()];
+
+    let reader = TrajectoryReader::open(&path).unwrap();
+    assert_eq!(reader.header(), &header);
+    let replay_buffers = replay_objects(reader.header()).to_buffers(device, queue);
+    let replay_picker = Picker::new(device, queue, &replay_buffers);
+    let mut replay = Replay::new(reader, &replay_buffers, device, queue);
+    let mut steps = Vec::new();
+    while replay.advance().unwrap() {
+        steps.push(replay.status(false, 1.0).step);
+    }
+    fs::remove_file(&path).unwrap();
+    assert_eq!(steps, [0, 2, 4]);
+    assert_eq!(replay.node_count(), 3);
+
+    for (index, recorded) in recorded.iter().enumerate() {
+        let replayed = replay_picker.inspect(u32::try_from(index).unwrap()).unwrap();
+        assert_eq!((replayed.flags, replayed.velocity), (recorded.flags, recorded.velocity));
+        for (a, b) in [replayed.aabb.min, replayed.aabb.max]
+            .iter()
+            .flatten()
+            .zip([recorded.aabb.min, recorded.aabb.max].iter().flatten())
+        {
+            assert!((a - b).abs() < 1e-3, "replayed {:?}, recorded {:?}", replayed.aabb, recorded.aabb);
+        }
+    }
+    // The replayed tree can be queried like the simulated one
+    let center = [
+        (recorded[1].aabb.min[0] + recorded[1].aabb.max[0]) / 2.0,
+        (recorded[1].aabb.min[1] + recorded[1].aabb.max[1]) / 2.0,
+    ];
+    assert_eq!(replay_picker.pick(center).map(|picked| picked.index), Some(1));
+}
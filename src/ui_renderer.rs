@@ -0,0 +1,160 @@
+use wgpu::{
+    BlendState, BufferUsages, ColorTargetState, Device, MultisampleState, PipelineCache, PrimitiveState, Queue,
+    RenderPass, RenderPipeline, RenderPipelineDescriptor, TextureFormat,
+};
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    gpu_buffer::GpuBuffer,
+    shaders::ui::{self, FONT_FIRST_CHARACTER, FONT_GLYPH_HEIGHT, FONT_GLYPH_WIDTH, UiParameters, UiQuad},
+};
+
+/// Quads beyond this are dropped
+const MAX_QUADS: usize = 8192;
+/// Distance between the glyphs of a line in font pixels
+pub const CHARACTER_ADVANCE: f32 = FONT_GLYPH_WIDTH as f32 + 1.0;
+
+/// Columns of the printable ASCII characters, the lowest bit is the top row
+#[rustfmt::skip]
+const FONT: [[u8; FONT_GLYPH_WIDTH as usize]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x5f, 0x00, 0x00], [0x00, 0x07, 0x00, 0x07, 0x00],
+    [0x14, 0x7f, 0x14, 0x7f, 0x14], [0x24, 0x2a, 0x7f, 0x2a, 0x12], [0x23, 0x13, 0x08, 0x64, 0x62],
+    [0x36, 0x49, 0x55, 0x22, 0x50], [0x00, 0x05, 0x03, 0x00, 0x00], [0x00, 0x1c, 0x22, 0x41, 0x00],
+    [0x00, 0x41, 0x22, 0x1c, 0x00], [0x08, 0x2a, 0x1c, 0x2a, 0x08], [0x08, 0x08, 0x3e, 0x08, 0x08],
+    [0x00, 0x50, 0x30, 0x00, 0x00], [0x08, 0x08, 0x08, 0x08, 0x08], [0x00, 0x60, 0x60, 0x00, 0x00],
+    [0x20, 0x10, 0x08, 0x04, 0x02], [0x3e, 0x51, 0x49, 0x45, 0x3e], [0x00, 0x42, 0x7f, 0x40, 0x00],
+    [0x42, 0x61, 0x51, 0x49, 0x46], [0x21, 0x41, 0x45, 0x4b, 0x31], [0x18, 0x14, 0x12, 0x7f, 0x10],
+    [0x27, 0x45, 0x45, 0x45, 0x39], [0x3c, 0x4a, 0x49, 0x49, 0x30], [0x01, 0x71, 0x09, 0x05, 0x03],
+    [0x36, 0x49, 0x49, 0x49, 0x36], [0x06, 0x49, 0x49, 0x29, 0x1e], [0x00, 0x36, 0x36, 0x00, 0x00],
+    [0x00, 0x56, 0x36, 0x00, 0x00], [0x08, 0x14, 0x22, 0x41, 0x00], [0x14, 0x14, 0x14, 0x14, 0x14],
+    [0x00, 0x41, 0x22, 0x14, 0x08], [0x02, 0x01, 0x51, 0x09, 0x06], [0x32, 0x49, 0x79, 0x41, 0x3e],
+    [0x7e, 0x11, 0x11, 0x11, 0x7e], [0x7f, 0x49, 0x49, 0x49, 0x36], [0x3e, 0x41, 0x41, 0x41, 0x22],
+    [0x7f, 0x41, 0x41, 0x22, 0x1c], [0x7f, 0x49, 0x49, 0x49, 0x41], [0x7f, 0x09, 0x09, 0x09, 0x01],
+    [0x3e, 0x41, 0x49, 0x49, 0x7a], [0x7f, 0x08, 0x08, 0x08, 0x7f], [0x00, 0x41, 0x7f, 0x41, 0x00],
+    [0x20, 0x40, 0x41, 0x3f, 0x01], [0x7f, 0x08, 0x14, 0x22, 0x41], [0x7f, 0x40, 0x40, 0x40, 0x40],
+    [0x7f, 0x02, 0x0c, 0x02, 0x7f], [0x7f, 0x04, 0x08, 0x10, 0x7f], [0x3e, 0x41, 0x41, 0x41, 0x3e],
+    [0x7f, 0x09, 0x09, 0x09, 0x06], [0x3e, 0x41, 0x51, 0x21, 0x5e], [0x7f, 0x09, 0x19, 0x29, 0x46],
+    [0x46, 0x49, 0x49, 0x49, 0x31], [0x01, 0x01, 0x7f, 0x01, 0x01], [0x3f, 0x40, 0x40, 0x40, 0x3f],
+    [0x1f, 0x20, 0x40, 0x20, 0x1f], [0x3f, 0x40, 0x38, 0x40, 0x3f], [0x63, 0x14, 0x08, 0x14, 0x63],
+    [0x07, 0x08, 0x70, 0x08, 0x07], [0x61, 0x51, 0x49, 0x45, 0x43], [0x00, 0x7f, 0x41, 0x41, 0x00],
+    [0x02, 0x04, 0x08, 0x10, 0x20], [0x00, 0x41, 0x41, 0x7f, 0x00], [0x04, 0x02, 0x01, 0x02, 0x04],
+    [0x40, 0x40, 0x40, 0x40, 0x40], [0x00, 0x01, 0x02, 0x04, 0x00], [0x20, 0x54, 0x54, 0x54, 0x78],
+    [0x7f, 0x48, 0x44, 0x44, 0x38], [0x38, 0x44, 0x44, 0x44, 0x20], [0x38, 0x44, 0x44, 0x48, 0x7f],
+    [0x38, 0x54, 0x54, 0x54, 0x18], [0x08, 0x7e, 0x09, 0x01, 0x02], [0x0c, 0x52, 0x52, 0x52, 0x3e],
+    [0x7f, 0x08, 0x04, 0x04, 0x78], [0x00, 0x44, 0x7d, 0x40, 0x00], [0x20, 0x40, 0x44, 0x3d, 0x00],
+    [0x7f, 0x10, 0x28, 0x44, 0x00], [0x00, 0x41, 0x7f, 0x40, 0x00], [0x7c, 0x04, 0x18, 0x04, 0x78],
+    [0x7c, 0x08, 0x04, 0x04, 0x78], [0x38, 0x44, 0x44, 0x44, 0x38], [0x7c, 0x14, 0x14, 0x14, 0x08],
+    [0x08, 0x14, 0x14, 0x18, 0x7c], [0x7c, 0x08, 0x04, 0x04, 0x08], [0x48, 0x54, 0x54, 0x54, 0x20],
+    [0x04, 0x3f, 0x44, 0x40, 0x20], [0x3c, 0x40, 0x40, 0x20, 0x7c], [0x1c, 0x20, 0x40, 0x20, 0x1c],
+    [0x3c, 0x40, 0x30, 0x40, 0x3c], [0x44, 0x28, 0x10, 0x28, 0x44], [0x0c, 0x50, 0x50, 0x50, 0x3c],
+    [0x44, 0x64, 0x54, 0x4c, 0x44], [0x00, 0x08, 0x36, 0x41, 0x00], [0x00, 0x00, 0x7f, 0x00, 0x00],
+    [0x00, 0x41, 0x36, 0x08, 0x00], [0x08, 0x04, 0x08, 0x10, 0x08],
+];
+
+/// Returns the glyph for `character`, characters outside of printable ASCII are drawn as `?`
+pub fn font_character(character: char) -> u32 {
+    let last = FONT_FIRST_CHARACTER + FONT.len() as u32 - 1;
+    let code = u32::from(character);
+    if (FONT_FIRST_CHARACTER..=last).contains(&code) {
+        code
+    } else {
+        u32::from('?')
+    }
+}
+
+/// Adds a glyph quad for every character of `text`, starting at the top left corner `position` in pixels. `scale` is
+/// the size of a font pixel in screen pixels.
+pub fn push_text(quads: &mut Vec<UiQuad>, text: &str, position: [f32; 2], scale: f32, color: [f32; 4]) {
+    let glyph_size = [FONT_GLYPH_WIDTH as f32 * scale, FONT_GLYPH_HEIGHT as f32 * scale];
+    for (column, character) in text.chars().enumerate() {
+        if character == ' ' {
+            continue;
+        }
+        let x = position[0] + column as f32 * CHARACTER_ADVANCE * scale;
+        quads.push(UiQuad::new([x, position[1]], glyph_size, color, font_character(character)));
+    }
+}
+
+/// Width of `text` in pixels, without the space after the last glyph
+pub fn text_width(text: &str, scale: f32) -> f32 {
+    (text.chars().count() as f32 * CHARACTER_ADVANCE - 1.0).max(0.0) * scale
+}
+
+/// Draws screen space quads that are either solid or show a glyph of the built-in font, used for overlays
+pub struct UiRenderer {
+    pipeline: RenderPipeline,
+    bind_group: ui::WgpuBindGroup0,
+    parameters: GpuBuffer<UiParameters>,
+    quads: GpuBuffer<UiQuad>,
+    quad_count: u32,
+}
+
+impl UiRenderer {
+    pub fn new(
+        device: &Device,
+        target_format: TextureFormat,
+        pipeline_cache: Option<&PipelineCache>,
+        queue: &Queue,
+    ) -> Self {
+        let parameters =
+            GpuBuffer::new(1, "ui parameters buffer", BufferUsages::UNIFORM | BufferUsages::COPY_DST, device);
+        let quads = GpuBuffer::new(MAX_QUADS, "ui quad buffer", BufferUsages::STORAGE | BufferUsages::COPY_DST, device);
+        let font_columns = FONT.iter().flatten().map(|&column| u32::from(column)).collect::<Vec<_>>();
+        let font =
+            GpuBuffer::new(font_columns.len(), "font buffer", BufferUsages::STORAGE | BufferUsages::COPY_DST, device);
+        font.write(queue, &font_columns);
+        let bind_group = ui::WgpuBindGroup0::from_bindings(
+            device,
+            ui::WgpuBindGroup0Entries::new(ui::WgpuBindGroup0EntriesParams {
+                parameters: parameters.buffer().as_entire_buffer_binding(),
+                quads: quads.buffer().as_entire_buffer_binding(),
+                font: font.buffer().as_entire_buffer_binding(),
+            }),
+        );
+
+        let pipeline_layout = ui::create_pipeline_layout(device);
+        let shader = ui::create_shader_module_embed_source(device);
+        let color_target_state = ColorTargetState {
+            blend: Some(BlendState::ALPHA_BLENDING),
+            ..ColorTargetState::from(target_format)
+        };
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some("ui pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: ui::vertex_state(&shader, &ui::vs_ui_entry()),
+            fragment: Some(ui::fragment_state(&shader, &ui::fs_ui_entry([Some(color_target_state)]))),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            multiview: None,
+            cache: pipeline_cache,
+        });
+
+        Self {
+            pipeline,
+            bind_group,
+            parameters,
+            quads,
+            quad_count: 0,
+        }
+    }
+
+    /// Uploads the quads for the next [`Self::render`], they are drawn in order
+    pub fn prepare(&mut self, queue: &Queue, view_size: PhysicalSize<u32>, quads: &[UiQuad]) {
+        self.parameters.write(queue, &[UiParameters::new([view_size.width as f32, view_size.height as f32])]);
+        let quads = &quads[..quads.len().min(MAX_QUADS)];
+        if !quads.is_empty() {
+            self.quads.write(queue, quads);
+        }
+        self.quad_count = u32::try_from(quads.len()).unwrap();
+    }
+
+    pub fn render(&self, render_pass: &mut RenderPass<'_>) {
+        if self.quad_count == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline);
+        self.bind_group.set(render_pass);
+        render_pass.draw(0..6, 0..self.quad_count);
+    }
+}
@@ -1,3 +1,12 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
 use wgpu::{PollError, PollStatus, PollType, SubmissionIndex};
 
 pub trait DeviceUtil {
@@ -12,3 +21,16 @@ impl DeviceUtil for wgpu::Device {
         })
     }
 }
+
+/// Keeps polling the device in the background, so that buffer mapping callbacks are called
+pub fn spawn_device_poller(device: wgpu::Device, exit_requested: Arc<AtomicBool>) -> JoinHandle<()> {
+    thread::spawn(move || {
+        loop {
+            device.poll(PollType::Poll).unwrap();
+            if exit_requested.load(Ordering::Relaxed) {
+                break;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+    })
+}